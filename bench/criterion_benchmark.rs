@@ -3,6 +3,7 @@ use std::path::Path;
 use cairo_rs::{
     cairo_run,
     hint_processor::builtin_hint_processor::builtin_hint_processor_definition::BuiltinHintProcessor,
+    vm::runners::run_resources::RunResources,
 };
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
@@ -37,6 +38,8 @@ pub fn criterion_benchmarks(c: &mut Criterion) {
                     false,
                     "all",
                     false,
+                    false,
+                    &RunResources::default(),
                     &hint_executor,
                 )
             })
@@ -4,6 +4,7 @@ use cairo_rs::{
     cairo_run::cairo_run,
     hint_processor::builtin_hint_processor::builtin_hint_processor_definition::BuiltinHintProcessor,
     vm::errors::cairo_run_errors::CairoRunError, vm::runners::cairo_runner::CairoRunner,
+    vm::runners::run_resources::RunResources,
 };
 use iai::{black_box, main};
 
@@ -23,6 +24,8 @@ macro_rules! iai_bench_expand_prog {
                 false,
                 "all",
                 false,
+                false,
+                &RunResources::default(),
                 &hint_executor,
             )
         }
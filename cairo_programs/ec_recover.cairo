@@ -0,0 +1,68 @@
+%builtins range_check
+
+from starkware.cairo.common.cairo_secp.bigint import BigInt3, nondet_bigint3
+
+// Returns x / s modulo n.
+func div_mod_n_packed{range_check_ptr}(n: BigInt3, x: BigInt3, s: BigInt3) -> (res: BigInt3) {
+    %{
+        from starkware.cairo.common.cairo_secp.secp_utils import pack
+        from starkware.python.math_utils import div_mod, safe_div
+
+        N = pack(ids.n, PRIME)
+        x = pack(ids.x, PRIME) % N
+        s = pack(ids.s, PRIME) % N
+        value = res = div_mod(x, s, N)
+    %}
+    let (res) = nondet_bigint3();
+    return (res=res);
+}
+
+// Returns a - b, a must be greater than or equal to b.
+func sub_a_b{range_check_ptr}(a: BigInt3, b: BigInt3) -> (res: BigInt3) {
+    %{
+        from starkware.cairo.common.cairo_secp.secp_utils import pack
+        from starkware.python.math_utils import div_mod, safe_div
+
+        a = pack(ids.a, PRIME)
+        b = pack(ids.b, PRIME)
+
+        value = res = a - b
+    %}
+    let (res) = nondet_bigint3();
+    return (res=res);
+}
+
+// Returns a * b modulo m, along with k = (a * b) // m.
+func product_mod{range_check_ptr}(a: BigInt3, b: BigInt3, m: BigInt3) -> (
+    res: BigInt3, k: BigInt3
+) {
+    %{
+        from starkware.cairo.common.cairo_secp.secp_utils import pack
+        from starkware.python.math_utils import div_mod, safe_div
+
+        a = pack(ids.a, PRIME)
+        b = pack(ids.b, PRIME)
+        product = a * b
+        m = pack(ids.m, PRIME)
+
+        value = res = product % m
+    %}
+    let (res) = nondet_bigint3();
+    %{ value = k = product // m %}
+    let (k) = nondet_bigint3();
+    return (res=res, k=k);
+}
+
+func main{range_check_ptr: felt}() {
+    let (res) = div_mod_n_packed(BigInt3(177, 0, 0), BigInt3(25, 0, 0), BigInt3(5, 0, 0));
+    assert res = BigInt3(5, 0, 0);
+
+    let (res) = sub_a_b(BigInt3(100, 0, 1), BigInt3(25, 0, 0));
+    assert res = BigInt3(75, 0, 1);
+
+    let (res, k) = product_mod(BigInt3(60, 0, 0), BigInt3(2, 0, 0), BigInt3(100, 0, 0));
+    assert res = BigInt3(20, 0, 0);
+    assert k = BigInt3(1, 0, 0);
+
+    return ();
+}
@@ -0,0 +1,30 @@
+%builtins range_check bitwise keccak
+
+from starkware.cairo.common.alloc import alloc
+from starkware.cairo.common.builtin_keccak.keccak import keccak_bigend, keccak_uint256s
+from starkware.cairo.common.cairo_builtins import BitwiseBuiltin, KeccakBuiltin
+from starkware.cairo.common.uint256 import Uint256
+
+func main{range_check_ptr: felt, bitwise_ptr: BitwiseBuiltin*, keccak_ptr: KeccakBuiltin*}() {
+    alloc_locals;
+
+    let (elements: Uint256*) = alloc();
+    assert elements[0] = Uint256(34623634663146736, 598249824422424658356);
+    assert elements[1] = Uint256(5, 7);
+    let uint256s_hash = keccak_uint256s(n_elements=2, elements=elements);
+    assert uint256s_hash = Uint256(
+        247849074696459158232609716408970848567, 169897271550833506383497712476290180140
+    );
+
+    // "Hello, keccak world" split into little endian 64-bit words, the last of them partial.
+    let (inputs: felt*) = alloc();
+    assert inputs[0] = 7719218618385065288;
+    assert inputs[1] = 8031924106006061925;
+    assert inputs[2] = 6581362;
+    let bigend_hash = keccak_bigend(inputs=inputs, n_bytes=19);
+    assert bigend_hash = Uint256(
+        42769359427224947623336190781724298602, 4410420257312329371700094490889297283
+    );
+
+    return ();
+}
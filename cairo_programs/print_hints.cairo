@@ -0,0 +1,30 @@
+%builtins output
+
+from starkware.cairo.common.alloc import alloc
+from starkware.cairo.common.serialize import serialize_word
+
+struct Point {
+    x: felt,
+    y: felt,
+}
+
+func main{output_ptr: felt*}() {
+    alloc_locals;
+    local a = 17;
+    local point: Point = Point(x=3, y=4);
+    let (array: felt*) = alloc();
+    assert array[0] = 10;
+    assert array[1] = 20;
+    assert array[2] = 30;
+    local n = 3;
+
+    %{ print(ids.a) %}
+    %{ print(f"a = {ids.a}, point.x = {ids.point.x}") %}
+    %{ print("point:", ids.point) %}
+    %{
+        for i in range(ids.n):
+            print(i, memory[ids.array + i])
+    %}
+    serialize_word(a);
+    return ();
+}
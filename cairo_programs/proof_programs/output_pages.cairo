@@ -0,0 +1 @@
+../output_pages.cairo
\ No newline at end of file
@@ -0,0 +1,149 @@
+%builtins range_check
+
+from starkware.cairo.common.cairo_secp.bigint import BigInt3, UnreducedBigInt3, nondet_bigint3
+from starkware.cairo.common.cairo_secp.ec import EcPoint
+
+// Returns x reduced modulo the secp256r1 prime.
+func reduce_secp256r1{range_check_ptr}(x: UnreducedBigInt3) -> (reduced_x: BigInt3) {
+    %{
+        from starkware.cairo.common.cairo_secp.secp256r1_utils import SECP256R1_P as SECP_P
+        from starkware.cairo.common.cairo_secp.secp_utils import pack
+
+        value = pack(ids.x, PRIME) % SECP_P
+    %}
+    let (reduced_x: BigInt3) = nondet_bigint3();
+    return (reduced_x=reduced_x);
+}
+
+// Returns val divided by the secp256r1 prime, val must be a multiple of it.
+func verify_zero_secp256r1{range_check_ptr}(val: UnreducedBigInt3) -> (q: felt) {
+    alloc_locals;
+    local q;
+    %{
+        from starkware.cairo.common.cairo_secp.secp256r1_utils import SECP256R1_P as SECP_P
+        from starkware.cairo.common.cairo_secp.secp_utils import pack
+
+        q, r = divmod(pack(ids.val, PRIME), SECP_P)
+        assert r == 0, f"verify_zero: Invalid input {ids.val.d0, ids.val.d1, ids.val.d2}."
+        ids.q = q % PRIME
+    %}
+    return (q=q);
+}
+
+func compute_doubling_slope_secp256r1{range_check_ptr}(point: EcPoint) -> (slope: BigInt3) {
+    %{
+        from starkware.cairo.common.cairo_secp.secp256r1_utils import SECP256R1_ALPHA as ALPHA
+        from starkware.cairo.common.cairo_secp.secp256r1_utils import SECP256R1_P as SECP_P
+        from starkware.cairo.common.cairo_secp.secp_utils import pack
+        from starkware.python.math_utils import ec_double_slope
+
+        # Compute the slope.
+        x = pack(ids.point.x, PRIME)
+        y = pack(ids.point.y, PRIME)
+        value = slope = ec_double_slope(point=(x, y), alpha=ALPHA, p=SECP_P)
+    %}
+    let (slope: BigInt3) = nondet_bigint3();
+    return (slope=slope);
+}
+
+func compute_slope_secp256r1{range_check_ptr}(point0: EcPoint, point1: EcPoint) -> (
+    slope: BigInt3
+) {
+    %{
+        from starkware.cairo.common.cairo_secp.secp256r1_utils import SECP256R1_P as SECP_P
+        from starkware.cairo.common.cairo_secp.secp_utils import pack
+        from starkware.python.math_utils import line_slope
+
+        # Compute the slope.
+        x0 = pack(ids.point0.x, PRIME)
+        y0 = pack(ids.point0.y, PRIME)
+        x1 = pack(ids.point1.x, PRIME)
+        y1 = pack(ids.point1.y, PRIME)
+        value = slope = line_slope(point1=(x0, y0), point2=(x1, y1), p=SECP_P)
+    %}
+    let (slope: BigInt3) = nondet_bigint3();
+    return (slope=slope);
+}
+
+// Returns the y coordinate of the point with the given x coordinate, taking the one with the
+// parity of v.
+func get_point_from_x_secp256r1{range_check_ptr}(x: BigInt3, v: felt) -> (y: BigInt3) {
+    %{
+        from starkware.cairo.common.cairo_secp.secp256r1_utils import SECP256R1_ALPHA as ALPHA
+        from starkware.cairo.common.cairo_secp.secp256r1_utils import SECP256R1_B as BETA
+        from starkware.cairo.common.cairo_secp.secp256r1_utils import SECP256R1_P as SECP_P
+        from starkware.cairo.common.cairo_secp.secp_utils import pack
+        from starkware.python.math_utils import y_squared_from_x
+
+        x = pack(ids.x, PRIME) % SECP_P
+        y_square_int = y_squared_from_x(x=x, alpha=ALPHA, beta=BETA, field_prime=SECP_P)
+        y = pow(y_square_int, (SECP_P + 1) // 4, SECP_P)
+
+        # We need to decide whether to take y or SECP_P - y.
+        if ids.v % 2 == y % 2:
+            value = y
+        else:
+            value = (-y) % SECP_P
+    %}
+    let (y: BigInt3) = nondet_bigint3();
+    return (y=y);
+}
+
+// Returns a / b modulo the secp256r1 order, along with k = (res * b - a) / N.
+func div_mod_n_secp256r1{range_check_ptr}(a: BigInt3, b: BigInt3) -> (res: BigInt3, k: BigInt3) {
+    %{
+        from starkware.cairo.common.cairo_secp.secp256r1_utils import SECP256R1_N as N
+        from starkware.cairo.common.cairo_secp.secp_utils import pack
+        from starkware.python.math_utils import div_mod, safe_div
+
+        a = pack(ids.a, PRIME)
+        b = pack(ids.b, PRIME)
+        value = res = div_mod(a, b, N)
+    %}
+    let (res) = nondet_bigint3();
+    %{ value = k = safe_div(res * b - a, N) %}
+    let (k) = nondet_bigint3();
+    return (res=res, k=k);
+}
+
+func main{range_check_ptr: felt}() {
+    let generator = EcPoint(
+        BigInt3(52227620040540588600771222, 33347259622618539004134583, 8091721874918813684698062),
+        BigInt3(59685082318776612195095029, 54599710628478995760242092, 6036146923926000695307902),
+    );
+    let generator_double = EcPoint(
+        BigInt3(60574784517941929169033592, 38742641973200156549941727, 9440742814978962916680995),
+        BigInt3(50180633949907515547874257, 52108912657982010475124979, 564125721045731681407961),
+    );
+
+    let (reduced) = reduce_secp256r1(
+        UnreducedBigInt3(77371252455336267181195268, 1023, 19342813109330467168976896)
+    );
+    assert reduced = BigInt3(5, 0, 0);
+
+    let (q) = verify_zero_secp256r1(
+        UnreducedBigInt3(77371252455336267181195263, 1023, 19342813109330467168976896)
+    );
+    assert q = 1;
+
+    let (doubling_slope) = compute_doubling_slope_secp256r1(generator);
+    assert doubling_slope = BigInt3(
+        18381657395733761514123165, 60250936249481871670557728, 12262137276504867214243183
+    );
+
+    let (slope) = compute_slope_secp256r1(generator, generator_double);
+    assert slope = BigInt3(
+        3300241710965108280819846, 35049842547245525849612509, 3083023929500780383197691
+    );
+
+    let (y) = get_point_from_x_secp256r1(generator.x, 1);
+    assert y = generator.y;
+
+    let (res, k) = div_mod_n_secp256r1(BigInt3(12345, 0, 0), BigInt3(6789, 0, 0));
+    assert res = BigInt3(
+        73638922861453137500628779, 33369130533101030459997397, 4188236157124140041006928
+    );
+    assert k = BigInt3(1470, 0, 0);
+
+    return ();
+}
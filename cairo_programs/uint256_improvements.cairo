@@ -0,0 +1,129 @@
+%builtins range_check
+
+from starkware.cairo.common.uint256 import (
+    Uint256,
+    uint256_add,
+    uint256_mul,
+    uint256_lt,
+    uint256_check,
+    uint256_mul_div_mod,
+)
+
+struct Uint256Expanded {
+    B0: felt,
+    b01: felt,
+    b12: felt,
+    b23: felt,
+    b3: felt,
+}
+
+// Computes a - b modulo 2**256.
+func uint256_sub{range_check_ptr}(a: Uint256, b: Uint256) -> (res: Uint256) {
+    alloc_locals;
+    local res: Uint256;
+    %{
+        def split(num: int, num_bits_shift: int = 128, length: int = 2):
+            a = []
+            for _ in range(length):
+                a.append( num & ((1 << num_bits_shift) - 1) )
+                num = num >> num_bits_shift
+            return tuple(a)
+
+        def pack(z, num_bits_shift: int = 128) -> int:
+            limbs = (z.low, z.high)
+            return sum(limb << (num_bits_shift * i) for i, limb in enumerate(limbs))
+
+        a = pack(ids.a)
+        b = pack(ids.b)
+        res = (a - b)%2**256
+        res_split = split(res)
+        ids.res.low = res_split[0]
+        ids.res.high = res_split[1]
+    %}
+    uint256_check(res);
+    let (sum, _) = uint256_add(res, b);
+    assert sum = a;
+    return (res=res);
+}
+
+// Returns the even square root of xx modulo 2**255 - 19. xx must be a quadratic residue
+// smaller than the modulus.
+func sqrt_mod_ed25519_prime{range_check_ptr}(xx: Uint256) -> (x: Uint256) {
+    alloc_locals;
+    local x: Uint256;
+    %{
+        PRIME = 2**255 - 19
+        II = pow(2, (PRIME - 1) // 4, PRIME)
+
+        xx = ids.xx.low + (ids.xx.high<<128)
+        x = pow(xx, (PRIME + 3) // 8, PRIME)
+        if (x * x - xx) % PRIME != 0:
+            x = (x * II) % PRIME
+        if x % 2 != 0:
+            x = PRIME - x
+        ids.x.low = x & ((1<<128)-1)
+        ids.x.high = x >> 128
+    %}
+    uint256_check(x);
+    let prime = Uint256(
+        340282366920938463463374607431768211437, 170141183460469231731687303715884105727
+    );
+    let (_, _, remainder) = uint256_mul_div_mod(x, x, prime);
+    assert remainder = xx;
+    return (x=x);
+}
+
+// Unsigned division of a by div, which is given in its expanded form.
+func uint256_expanded_unsigned_div_rem{range_check_ptr}(a: Uint256, div: Uint256Expanded) -> (
+    quotient: Uint256, remainder: Uint256
+) {
+    alloc_locals;
+    local quotient: Uint256;
+    local remainder: Uint256;
+    %{
+        a = (ids.a.high << 128) + ids.a.low
+        div = (ids.div.b23 << 128) + ids.div.b01
+        quotient, remainder = divmod(a, div)
+
+        ids.quotient.low = quotient & ((1 << 128) - 1)
+        ids.quotient.high = quotient >> 128
+        ids.remainder.low = remainder & ((1 << 128) - 1)
+        ids.remainder.high = remainder >> 128
+    %}
+    uint256_check(quotient);
+    uint256_check(remainder);
+    let div_uint256 = Uint256(div.b01, div.b23);
+    let (res_mul, carry) = uint256_mul(quotient, div_uint256);
+    assert carry = Uint256(0, 0);
+    let (check_val, add_carry) = uint256_add(res_mul, remainder);
+    assert check_val = a;
+    assert add_carry = 0;
+    let (is_valid) = uint256_lt(remainder, div_uint256);
+    assert is_valid = 1;
+    return (quotient=quotient, remainder=remainder);
+}
+
+func main{range_check_ptr: felt}() {
+    let (sub) = uint256_sub(Uint256(5, 2), Uint256(3, 7));
+    assert sub = Uint256(2, 340282366920938463463374607431768211451);
+
+    let (quotient_low, quotient_high, remainder) = uint256_mul_div_mod(
+        Uint256(89, 72), Uint256(3, 7), Uint256(107, 114)
+    );
+    assert quotient_low = Uint256(143276786071974089879315624181797141668, 4);
+    assert quotient_high = Uint256(0, 0);
+    assert remainder = Uint256(322372768661941702228460154409043568767, 101);
+
+    let (root) = sqrt_mod_ed25519_prime(Uint256(25, 0));
+    assert root = Uint256(
+        340282366920938463463374607431768211432, 170141183460469231731687303715884105727
+    );
+
+    let (quotient, remainder) = uint256_expanded_unsigned_div_rem(
+        Uint256(89, 72), Uint256Expanded(3, 3, 129127208515966861312, 7, 0)
+    );
+    assert quotient = Uint256(10, 0);
+    assert remainder = Uint256(59, 2);
+
+    return ();
+}
@@ -0,0 +1,301 @@
+%builtins range_check
+
+struct Uint384 {
+    d0: felt,
+    d1: felt,
+    d2: felt,
+}
+
+struct Uint384Expanded {
+    B0: felt,
+    b01: felt,
+    b12: felt,
+    b23: felt,
+    b34: felt,
+    b45: felt,
+    b5: felt,
+}
+
+struct Uint768 {
+    d0: felt,
+    d1: felt,
+    d2: felt,
+    d3: felt,
+    d4: felt,
+    d5: felt,
+}
+
+struct Uint256 {
+    low: felt,
+    high: felt,
+}
+
+struct Uint512 {
+    d0: felt,
+    d1: felt,
+    d2: felt,
+    d3: felt,
+}
+
+const SHIFT = 2 ** 128;
+
+func unsigned_div_rem{range_check_ptr}(a: Uint384, div: Uint384) -> (
+    quotient: Uint384, remainder: Uint384
+) {
+    alloc_locals;
+    local quotient: Uint384;
+    local remainder: Uint384;
+    %{
+        def split(num: int, num_bits_shift: int, length: int):
+            a = []
+            for _ in range(length):
+                a.append( num & ((1 << num_bits_shift) - 1) )
+                num = num >> num_bits_shift
+            return tuple(a)
+
+        def pack(z, num_bits_shift: int) -> int:
+            limbs = (z.d0, z.d1, z.d2)
+            return sum(limb << (num_bits_shift * i) for i, limb in enumerate(limbs))
+
+        a = pack(ids.a, num_bits_shift = 128)
+        div = pack(ids.div, num_bits_shift = 128)
+        quotient, remainder = divmod(a, div)
+
+        quotient_split = split(quotient, num_bits_shift=128, length=3)
+        assert len(quotient_split) == 3
+
+        ids.quotient.d0 = quotient_split[0]
+        ids.quotient.d1 = quotient_split[1]
+        ids.quotient.d2 = quotient_split[2]
+
+        remainder_split = split(remainder, num_bits_shift=128, length=3)
+        ids.remainder.d0 = remainder_split[0]
+        ids.remainder.d1 = remainder_split[1]
+        ids.remainder.d2 = remainder_split[2]
+    %}
+    return (quotient=quotient, remainder=remainder);
+}
+
+func unsigned_div_rem_expanded{range_check_ptr}(a: Uint384, div: Uint384Expanded) -> (
+    quotient: Uint384, remainder: Uint384
+) {
+    alloc_locals;
+    local quotient: Uint384;
+    local remainder: Uint384;
+    %{
+        def split(num: int, num_bits_shift: int, length: int):
+            a = []
+            for _ in range(length):
+                a.append( num & ((1 << num_bits_shift) - 1) )
+                num = num >> num_bits_shift
+            return tuple(a)
+
+        def pack(z, num_bits_shift: int) -> int:
+            limbs = (z.d0, z.d1, z.d2)
+            return sum(limb << (num_bits_shift * i) for i, limb in enumerate(limbs))
+
+        def pack2(z, num_bits_shift: int) -> int:
+            limbs = (z.b01, z.b23, z.b45)
+            return sum(limb << (num_bits_shift * i) for i, limb in enumerate(limbs))
+
+        a = pack(ids.a, num_bits_shift = 128)
+        div = pack2(ids.div, num_bits_shift = 128)
+        quotient, remainder = divmod(a, div)
+
+        quotient_split = split(quotient, num_bits_shift=128, length=3)
+        assert len(quotient_split) == 3
+
+        ids.quotient.d0 = quotient_split[0]
+        ids.quotient.d1 = quotient_split[1]
+        ids.quotient.d2 = quotient_split[2]
+
+        remainder_split = split(remainder, num_bits_shift=128, length=3)
+        ids.remainder.d0 = remainder_split[0]
+        ids.remainder.d1 = remainder_split[1]
+        ids.remainder.d2 = remainder_split[2]
+    %}
+    return (quotient=quotient, remainder=remainder);
+}
+
+func split_128{range_check_ptr}(a: felt) -> (low: felt, high: felt) {
+    alloc_locals;
+    local low: felt;
+    local high: felt;
+    %{
+        ids.low = ids.a & ((1<<128) - 1)
+        ids.high = ids.a >> 128
+    %}
+    assert a = low + high * SHIFT;
+    return (low=low, high=high);
+}
+
+// Returns the carries of the limb-wise addition of a and b.
+func add_carries{range_check_ptr}(a: Uint384, b: Uint384) -> (
+    carry_d0: felt, carry_d1: felt, carry_d2: felt
+) {
+    alloc_locals;
+    local carry_d0: felt;
+    local carry_d1: felt;
+    local carry_d2: felt;
+    %{
+        sum_d0 = ids.a.d0 + ids.b.d0
+        ids.carry_d0 = 1 if sum_d0 >= ids.SHIFT else 0
+        sum_d1 = ids.a.d1 + ids.b.d1 + ids.carry_d0
+        ids.carry_d1 = 1 if sum_d1 >= ids.SHIFT else 0
+        sum_d2 = ids.a.d2 + ids.b.d2 + ids.carry_d1
+        ids.carry_d2 = 1 if sum_d2 >= ids.SHIFT else 0
+    %}
+    return (carry_d0=carry_d0, carry_d1=carry_d1, carry_d2=carry_d2);
+}
+
+func sqrt{range_check_ptr}(a: Uint384) -> (root: Uint384) {
+    alloc_locals;
+    local root: Uint384;
+    %{
+        from starkware.python.math_utils import isqrt
+
+        def split(num: int, num_bits_shift: int, length: int):
+            a = []
+            for _ in range(length):
+                a.append( num & ((1 << num_bits_shift) - 1) )
+                num = num >> num_bits_shift
+            return tuple(a)
+
+        def pack(z, num_bits_shift: int) -> int:
+            limbs = (z.d0, z.d1, z.d2)
+            return sum(limb << (num_bits_shift * i) for i, limb in enumerate(limbs))
+
+        a = pack(ids.a, num_bits_shift=128)
+        root = isqrt(a)
+        assert 0 <= root < 2 ** 192
+        root_split = split(root, num_bits_shift=128, length=3)
+        ids.root.d0 = root_split[0]
+        ids.root.d1 = root_split[1]
+        ids.root.d2 = root_split[2]
+    %}
+    return (root=root);
+}
+
+func signed_nn{range_check_ptr}(a: Uint384) -> (res: felt) {
+    %{ memory[ap] = 1 if 0 <= (ids.a.d2 % PRIME) < 2 ** 127 else 0 %}
+    jmp non_negative if [ap] != 0, ap++;
+    return (res=0);
+
+    non_negative:
+    return (res=1);
+}
+
+func unsigned_div_rem_uint768_by_uint384{range_check_ptr}(a: Uint768, div: Uint384) -> (
+    quotient: Uint768, remainder: Uint384
+) {
+    alloc_locals;
+    local quotient: Uint768;
+    local remainder: Uint384;
+    %{
+        def split(num: int, num_bits_shift: int, length: int):
+            a = []
+            for _ in range(length):
+                a.append( num & ((1 << num_bits_shift) - 1) )
+                num = num >> num_bits_shift
+            return tuple(a)
+
+        def pack(z, num_bits_shift: int) -> int:
+            limbs = (z.d0, z.d1, z.d2)
+            return sum(limb << (num_bits_shift * i) for i, limb in enumerate(limbs))
+
+        def pack_extended(z, num_bits_shift: int) -> int:
+            limbs = (z.d0, z.d1, z.d2, z.d3, z.d4, z.d5)
+            return sum(limb << (num_bits_shift * i) for i, limb in enumerate(limbs))
+
+        a = pack_extended(ids.a, num_bits_shift = 128)
+        div = pack(ids.div, num_bits_shift = 128)
+
+        quotient, remainder = divmod(a, div)
+
+        quotient_split = split(quotient, num_bits_shift=128, length=6)
+
+        ids.quotient.d0 = quotient_split[0]
+        ids.quotient.d1 = quotient_split[1]
+        ids.quotient.d2 = quotient_split[2]
+        ids.quotient.d3 = quotient_split[3]
+        ids.quotient.d4 = quotient_split[4]
+        ids.quotient.d5 = quotient_split[5]
+
+        remainder_split = split(remainder, num_bits_shift=128, length=3)
+        ids.remainder.d0 = remainder_split[0]
+        ids.remainder.d1 = remainder_split[1]
+        ids.remainder.d2 = remainder_split[2]
+    %}
+    return (quotient=quotient, remainder=remainder);
+}
+
+func inv_mod_p_uint512{range_check_ptr}(x: Uint512, p: Uint256) -> (x_inverse_mod_p: Uint256) {
+    alloc_locals;
+    local x_inverse_mod_p: Uint256;
+    %{
+        def pack_512(u, num_bits_shift: int) -> int:
+            limbs = (u.d0, u.d1, u.d2, u.d3)
+            return sum(limb << (num_bits_shift * i) for i, limb in enumerate(limbs))
+
+        x = pack_512(ids.x, num_bits_shift = 128)
+        p = ids.p.low + (ids.p.high << 128)
+        x_inverse_mod_p = pow(x,-1, p)
+
+        x_inverse_mod_p_split = (x_inverse_mod_p & ((1 << 128) - 1), x_inverse_mod_p >> 128)
+
+        ids.x_inverse_mod_p.low = x_inverse_mod_p_split[0]
+        ids.x_inverse_mod_p.high = x_inverse_mod_p_split[1]
+    %}
+    return (x_inverse_mod_p=x_inverse_mod_p);
+}
+
+func main{range_check_ptr: felt}() {
+    let (quotient, remainder) = unsigned_div_rem(
+        Uint384(83434123481193248, 82349321849739284, 839243219401320423),
+        Uint384(9283430921839492319493, 313248123482483248, 3790328402913840),
+    );
+    assert quotient = Uint384(221, 0, 0);
+    assert remainder = Uint384(
+        340282366920936411825224315027446796751,
+        340282366920938463394229121463989152931,
+        1580642357361782,
+    );
+
+    let (quotient, remainder) = unsigned_div_rem_expanded(
+        Uint384(1000, 0, 1), Uint384Expanded(0, 7, 0, 0, 0, 1, 0)
+    );
+    assert quotient = Uint384(1, 0, 0);
+    assert remainder = Uint384(993, 0, 0);
+
+    let (low, high) = split_128(2 ** 130 + 5);
+    assert low = 5;
+    assert high = 4;
+
+    let (carry_d0, carry_d1, carry_d2) = add_carries(
+        Uint384(SHIFT - 1, 2, SHIFT - 1), Uint384(1, 2, 1)
+    );
+    assert carry_d0 = 1;
+    assert carry_d1 = 0;
+    assert carry_d2 = 1;
+
+    let (root) = sqrt(Uint384(83434123481193248, 82349321849739284, 1));
+    assert root = Uint384(41174660924869641, 1, 0);
+
+    let (res) = signed_nn(Uint384(1, 1, 2 ** 127 - 1));
+    assert res = 1;
+    let (res) = signed_nn(Uint384(1, 1, 2 ** 127));
+    assert res = 0;
+
+    let (quotient, remainder) = unsigned_div_rem_uint768_by_uint384(
+        Uint768(1000, 0, 0, 0, 0, 7), Uint384(3, 0, 1)
+    );
+    assert quotient = Uint768(
+        0, 340282366920938463463374607431768211435, 340282366920938463463374607431768211455, 6, 0, 0
+    );
+    assert remainder = Uint384(1000, 63, 0);
+
+    let (x_inverse_mod_p) = inv_mod_p_uint512(Uint512(3, 0, 0, 0), Uint256(7, 0));
+    assert x_inverse_mod_p = Uint256(5, 0);
+
+    return ();
+}
@@ -1,14 +1,27 @@
 use crate::hint_processor::hint_processor_definition::HintProcessor;
+use crate::serde::deserialize_program::Member;
+use crate::types::abi::{get_return_type, CairoType};
 use crate::types::program::Program;
-use crate::vm::errors::{cairo_run_errors::CairoRunError, runner_errors::RunnerError};
-use crate::vm::runners::cairo_runner::CairoRunner;
+use crate::types::relocatable::{relocate_address, relocate_value, MaybeRelocatable, Relocatable};
+use crate::utils::to_field_element;
+use crate::vm::errors::{
+    cairo_run_errors::CairoRunError, runner_errors::RunnerError, vm_errors::VirtualMachineError,
+};
+use crate::vm::runners::builtin_runner::{BuiltinRunner, FactTopology, GPS_FACT_TOPOLOGY};
+use crate::vm::runners::cairo_runner::{CairoRunner, ExecutionResources};
+use crate::vm::runners::run_resources::RunResources;
+use crate::vm::security::verify_secure_runner;
 use crate::vm::trace::trace_entry::RelocatedTraceEntry;
 use crate::vm::vm_core::VirtualMachine;
 use num_bigint::BigInt;
+use num_integer::Integer;
+use serde::Serialize;
 use std::fs::File;
 use std::io::{self, BufWriter, Error, ErrorKind, Write};
 use std::path::Path;
+use std::str::FromStr;
 
+#[allow(clippy::too_many_arguments)]
 pub fn cairo_run(
     path: &Path,
     entrypoint: &str,
@@ -16,14 +29,44 @@ pub fn cairo_run(
     print_output: bool,
     layout: &str,
     proof_mode: bool,
+    secure_run: bool,
+    run_resources: &RunResources,
     hint_executor: &dyn HintProcessor,
 ) -> Result<CairoRunner, CairoRunError> {
+    cairo_run_with_vm(
+        path,
+        entrypoint,
+        trace_enabled,
+        print_output,
+        layout,
+        proof_mode,
+        secure_run,
+        run_resources,
+        hint_executor,
+    )
+    .map(|(cairo_runner, _)| cairo_runner)
+}
+
+/// Same as cairo_run, also returning the virtual machine the program was run on
+#[allow(clippy::too_many_arguments)]
+pub fn cairo_run_with_vm(
+    path: &Path,
+    entrypoint: &str,
+    trace_enabled: bool,
+    print_output: bool,
+    layout: &str,
+    proof_mode: bool,
+    secure_run: bool,
+    run_resources: &RunResources,
+    hint_executor: &dyn HintProcessor,
+) -> Result<(CairoRunner, VirtualMachine), CairoRunError> {
     let program = match Program::from_file(path, Some(entrypoint)) {
         Ok(program) => program,
         Err(error) => return Err(CairoRunError::Program(error)),
     };
 
     let mut cairo_runner = CairoRunner::new(&program, layout, proof_mode)?;
+    cairo_runner.set_run_resources(run_resources.clone());
     let mut vm = VirtualMachine::new(
         program.prime,
         trace_enabled,
@@ -31,21 +74,257 @@ pub fn cairo_run(
     );
     let end = cairo_runner.initialize(&mut vm)?;
 
-    cairo_runner.run_until_pc(end, &mut vm, hint_executor)?;
-    cairo_runner.end_run(false, false, &mut vm, hint_executor)?;
+    run_until_end(
+        &mut cairo_runner,
+        &mut vm,
+        end,
+        print_output,
+        proof_mode,
+        secure_run,
+        hint_executor,
+    )?;
+
+    Ok((cairo_runner, vm))
+}
+
+/// Runs the given function of a program, passing it the given arguments as laid out by its Args
+/// struct. The builtin pointers requested by its implicit arguments are passed implicitly.
+#[allow(clippy::too_many_arguments)]
+pub fn cairo_run_with_args(
+    path: &Path,
+    entrypoint: &str,
+    args: &[FuncArg],
+    trace_enabled: bool,
+    print_output: bool,
+    layout: &str,
+    secure_run: bool,
+    run_resources: &RunResources,
+    hint_executor: &dyn HintProcessor,
+) -> Result<(CairoRunner, VirtualMachine), CairoRunError> {
+    let program = match Program::from_file(path, Some(entrypoint)) {
+        Ok(program) => program,
+        Err(error) => return Err(CairoRunError::Program(error)),
+    };
+
+    let mut cairo_runner = CairoRunner::new(&program, layout, false)?;
+    cairo_runner.set_run_resources(run_resources.clone());
+    let mut vm = VirtualMachine::new(
+        program.prime,
+        trace_enabled,
+        program.error_message_attributes,
+    );
+    cairo_runner.initialize_builtins(&mut vm)?;
+    cairo_runner.initialize_segments(&mut vm, None);
+    let stack = gen_entrypoint_stack(cairo_runner.get_program(), entrypoint, args, &mut vm)?;
+    let return_fp = vm.add_memory_segment();
+    let entrypoint_pc = program.main.ok_or(RunnerError::MissingMain)?;
+    let end = cairo_runner.initialize_function_entrypoint(
+        &mut vm,
+        entrypoint_pc,
+        stack,
+        return_fp.into(),
+    )?;
+    cairo_runner.initialize_vm(&mut vm)?;
+
+    run_until_end(
+        &mut cairo_runner,
+        &mut vm,
+        end,
+        print_output,
+        false,
+        secure_run,
+        hint_executor,
+    )?;
+
+    Ok((cairo_runner, vm))
+}
+
+fn run_until_end(
+    cairo_runner: &mut CairoRunner,
+    vm: &mut VirtualMachine,
+    end: Relocatable,
+    print_output: bool,
+    proof_mode: bool,
+    secure_run: bool,
+    hint_executor: &dyn HintProcessor,
+) -> Result<(), CairoRunError> {
+    cairo_runner.run_until_pc(end, vm, hint_executor)?;
+    cairo_runner.end_run(false, false, vm, hint_executor)?;
 
     vm.verify_auto_deductions()?;
     if proof_mode {
-        cairo_runner.read_return_values(&vm)?;
-        cairo_runner.finalize_segments(&mut vm)?;
+        cairo_runner.read_return_values(vm)?;
+        cairo_runner.finalize_segments(vm)?;
+        // Programs such as the simple bootloader add output pages without describing their tree
+        // structure, their pages are still exported through the public memory
+        if has_fact_topology_attribute(vm) {
+            cairo_runner.fact_topology = cairo_runner.get_fact_topology(vm)?;
+        }
+    }
+    if secure_run {
+        // Builtin stop pointers aren't read back, so builtin segment bounds are left to the
+        // builtin self-checks
+        vm.segments.compute_effective_sizes(&vm.memory);
+        verify_secure_runner(cairo_runner, false, vm)?;
     }
-    cairo_runner.relocate(&mut vm)?;
+    cairo_runner.relocate(vm)?;
 
     if print_output {
-        write_output(&mut cairo_runner, &mut vm)?;
+        write_output(cairo_runner, vm)?;
     }
 
-    Ok(cairo_runner)
+    Ok(())
+}
+
+// Whether the program set the gps_fact_topology attribute of its output builtin
+fn has_fact_topology_attribute(vm: &VirtualMachine) -> bool {
+    vm.builtin_runners.iter().any(|(_, builtin)| match builtin {
+        BuiltinRunner::Output(output_builtin) => output_builtin
+            .get_attributes()
+            .contains_key(GPS_FACT_TOPOLOGY),
+        _ => false,
+    })
+}
+
+/// Argument of an entrypoint, either a felt or an array of arguments
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FuncArg {
+    Single(BigInt),
+    Array(Vec<FuncArg>),
+}
+
+/// Arguments of an entrypoint, parsed from felts separated by spaces or commas and arrays in
+/// brackets, e.g. `1 [2, 3] [[4] [5 6]]`. Felts can be negative or hexadecimal with a 0x prefix.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FuncArgs(pub Vec<FuncArg>);
+
+impl FromStr for FuncArgs {
+    type Err = String;
+
+    fn from_str(args: &str) -> Result<Self, Self::Err> {
+        let mut arrays = vec![Vec::new()];
+        let args = args
+            .replace('[', " [ ")
+            .replace(']', " ] ")
+            .replace(',', " ");
+        for token in args.split_whitespace() {
+            match token {
+                "[" => arrays.push(Vec::new()),
+                "]" => match (arrays.pop(), arrays.last_mut()) {
+                    (Some(array), Some(parent)) => parent.push(FuncArg::Array(array)),
+                    _ => return Err(String::from("Unmatched ] in the arguments")),
+                },
+                _ => {
+                    let felt = match token.strip_prefix("0x") {
+                        Some(hex) => BigInt::parse_bytes(hex.as_bytes(), 16),
+                        None => BigInt::parse_bytes(token.as_bytes(), 10),
+                    }
+                    .ok_or_else(|| format!("{token} is not a valid felt"))?;
+                    if let Some(array) = arrays.last_mut() {
+                        array.push(FuncArg::Single(felt));
+                    }
+                }
+            }
+        }
+        match (arrays.pop(), arrays.is_empty()) {
+            (Some(args), true) => Ok(FuncArgs(args)),
+            _ => Err(String::from("Unmatched [ in the arguments")),
+        }
+    }
+}
+
+// Builds the initial stack of a call to the given function: the builtin pointers requested by its
+// implicit arguments followed by its explicit arguments
+fn gen_entrypoint_stack(
+    program: &Program,
+    entrypoint: &str,
+    args: &[FuncArg],
+    vm: &mut VirtualMachine,
+) -> Result<Vec<MaybeRelocatable>, CairoRunError> {
+    let mut stack = Vec::new();
+    let implicit_args = get_sorted_members(program, &format!("__main__.{entrypoint}.ImplicitArgs"))
+        .unwrap_or_default();
+    for (name, _) in implicit_args {
+        let builtin_name = name.strip_suffix("_ptr").unwrap_or(name);
+        let (_, builtin) = vm
+            .builtin_runners
+            .iter()
+            .find(|(builtin_runner_name, _)| builtin_runner_name == builtin_name)
+            .ok_or_else(|| RunnerError::UnknownImplicitArg(name.to_string()))?;
+        stack.push((builtin.base(), 0).into());
+    }
+
+    let members = get_sorted_members(program, &format!("__main__.{entrypoint}.Args"))
+        .ok_or_else(|| RunnerError::UnknownEntrypointArgs(entrypoint.to_string()))?;
+    if members.len() != args.len() {
+        return Err(RunnerError::EntrypointArgCountMismatch(
+            entrypoint.to_string(),
+            members.len(),
+            args.len(),
+        )
+        .into());
+    }
+    for ((name, member), arg) in members.into_iter().zip(args) {
+        match arg {
+            FuncArg::Single(_) if member.cairo_type == "felt" => {
+                stack.push(gen_arg_value(arg, vm)?)
+            }
+            FuncArg::Array(_) if member.cairo_type.ends_with('*') => {
+                stack.push(gen_arg_value(arg, vm)?)
+            }
+            // Structs passed by value are given as the array of their cells
+            FuncArg::Array(values)
+                if CairoType::parse(&member.cairo_type)
+                    .and_then(|cairo_type| cairo_type.size(program))
+                    .ok()
+                    == Some(values.len()) =>
+            {
+                for value in values {
+                    stack.push(gen_arg_value(value, vm)?);
+                }
+            }
+            _ => {
+                return Err(RunnerError::InvalidEntrypointArg(
+                    name.to_string(),
+                    member.cairo_type.clone(),
+                )
+                .into())
+            }
+        }
+    }
+    Ok(stack)
+}
+
+// Felts are reduced modulo the prime and arrays are written to new segments
+fn gen_arg_value(
+    arg: &FuncArg,
+    vm: &mut VirtualMachine,
+) -> Result<MaybeRelocatable, VirtualMachineError> {
+    match arg {
+        FuncArg::Single(value) => Ok(MaybeRelocatable::Int(value.mod_floor(&vm.prime))),
+        FuncArg::Array(values) => {
+            let values = values
+                .iter()
+                .map(|value| gen_arg_value(value, vm))
+                .collect::<Result<Vec<_>, _>>()?;
+            vm.segments.gen_arg(&values, None, &mut vm.memory)
+        }
+    }
+}
+
+fn get_sorted_members<'a>(
+    program: &'a Program,
+    struct_name: &str,
+) -> Option<Vec<(&'a String, &'a Member)>> {
+    let mut members: Vec<_> = program
+        .identifiers
+        .get(struct_name)?
+        .members
+        .as_ref()?
+        .iter()
+        .collect();
+    members.sort_by_key(|(_, member)| member.offset);
+    Some(members)
 }
 
 pub fn write_output(
@@ -113,6 +392,153 @@ pub fn write_binary_memory(
     buffer.flush()
 }
 
+/// Writes the fact topologies of the program output as a json file, in the format expected by
+/// the provers: `{"fact_topologies": [{"tree_structure": [...], "page_sizes": [...]}]}`
+pub fn write_fact_topologies(
+    fact_topologies: &[FactTopology],
+    fact_topologies_file: &Path,
+) -> io::Result<()> {
+    let file = File::create(fact_topologies_file)?;
+    let mut buffer = BufWriter::new(file);
+    serde_json::to_writer(
+        &mut buffer,
+        &serde_json::json!({ "fact_topologies": fact_topologies }),
+    )?;
+    buffer.flush()
+}
+
+/// Machine readable summary of a run. Only the layout and the error are set if the run failed
+#[derive(Debug, Default, Serialize)]
+pub struct RunReport {
+    pub layout: String,
+    pub output: Option<RunOutput>,
+    pub return_values: Option<Vec<String>>,
+    pub execution_resources: Option<ExecutionResources>,
+    pub segment_sizes: Option<Vec<usize>>,
+    pub registers: Option<RelocatedRegisters>,
+    /// Gas consumed by the run, only set if it was given a gas budget
+    pub consumed_gas: Option<u64>,
+    pub error: Option<String>,
+}
+
+/// Values of the output builtin segment, as field elements and as signed integers
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct RunOutput {
+    pub felts: Vec<String>,
+    pub signed: Vec<String>,
+}
+
+/// Final values of the registers, after relocation
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct RelocatedRegisters {
+    pub pc: usize,
+    pub ap: usize,
+    pub fp: usize,
+}
+
+impl RunReport {
+    /// Builds the report of a finished run of the given entrypoint. The memory of the run must
+    /// have been relocated.
+    pub fn new(
+        layout: &str,
+        entrypoint: &str,
+        cairo_runner: &CairoRunner,
+        vm: &VirtualMachine,
+    ) -> Result<RunReport, CairoRunError> {
+        let relocation_table = vm.segments.relocate_segments()?;
+        let relocate = |address: &Relocatable| relocate_address(address.clone(), &relocation_table);
+
+        let program = cairo_runner.get_program();
+        let return_size = match get_return_type(program, entrypoint) {
+            Ok(return_type) => Some(return_type.size(program)?),
+            Err(RunnerError::UnknownReturnType(_)) => None,
+            Err(error) => return Err(error.into()),
+        };
+        let return_values = match return_size {
+            Some(return_size) => {
+                let mut return_values = Vec::with_capacity(return_size);
+                for offset in (1..=return_size).rev() {
+                    let address = vm.get_ap().sub(offset)?;
+                    let value = vm
+                        .get_maybe(&address)?
+                        .ok_or(RunnerError::MemoryGet(address.into()))?;
+                    return_values.push(relocate_value(value, &relocation_table)?.to_string());
+                }
+                Some(return_values)
+            }
+            None => None,
+        };
+
+        Ok(RunReport {
+            layout: layout.to_string(),
+            output: get_run_output(vm)?,
+            return_values,
+            execution_resources: Some(cairo_runner.get_execution_resources(vm)?),
+            segment_sizes: vm.segments.segment_used_sizes.clone(),
+            registers: Some(RelocatedRegisters {
+                pc: relocate(vm.get_pc())?,
+                ap: relocate(&vm.get_ap())?,
+                fp: relocate(&vm.get_fp())?,
+            }),
+            consumed_gas: cairo_runner.consumed_gas,
+            error: None,
+        })
+    }
+
+    /// Builds the report of a failed run
+    pub fn from_error(layout: &str, error: &CairoRunError) -> RunReport {
+        RunReport {
+            layout: layout.to_string(),
+            error: Some(error.to_string()),
+            ..Default::default()
+        }
+    }
+}
+
+// Reads the output builtin segment, None if the program doesn't use the output builtin
+fn get_run_output(vm: &VirtualMachine) -> Result<Option<RunOutput>, CairoRunError> {
+    let base = match vm
+        .builtin_runners
+        .iter()
+        .find_map(|(_, builtin)| match builtin {
+            BuiltinRunner::Output(output_builtin) => Some(output_builtin.base()),
+            _ => None,
+        }) {
+        Some(base) => base,
+        None => return Ok(None),
+    };
+    let size = vm
+        .segments
+        .get_segment_used_size(
+            base.try_into()
+                .map_err(|_| RunnerError::RunnerInTemporarySegment(base))?,
+        )
+        .ok_or(RunnerError::BaseNotFinished)?;
+
+    let mut output = RunOutput {
+        felts: Vec::with_capacity(size),
+        signed: Vec::with_capacity(size),
+    };
+    for offset in 0..size {
+        let value = vm
+            .get_integer(&(base, offset).into())
+            .map_err(|_| RunnerError::MemoryGet((base, offset).into()))?;
+        output.felts.push(value.mod_floor(&vm.prime).to_string());
+        output
+            .signed
+            .push(to_field_element(value.into_owned(), vm.prime.clone()).to_string());
+    }
+    Ok(Some(output))
+}
+
+/// Writes a run report as a json file
+pub fn write_json_report(report: &RunReport, report_file: &Path) -> io::Result<()> {
+    let file = File::create(report_file)?;
+    let mut buffer = BufWriter::new(file);
+    serde_json::to_writer_pretty(&mut buffer, report)?;
+    buffer.flush()
+}
+
 // encodes a given memory cell.
 fn encode_relocated_memory(memory_bytes: &mut Vec<u8>, addr: usize, memory_cell: &BigInt) {
     // append memory address to bytes vector using a 8 bytes representation
@@ -132,6 +558,7 @@ mod tests {
     use crate::{
         bigint,
         hint_processor::builtin_hint_processor::builtin_hint_processor_definition::BuiltinHintProcessor,
+        types::abi::{decode_return_values, CairoValue},
         utils::test_utils::*,
     };
     use num_bigint::Sign;
@@ -206,6 +633,8 @@ mod tests {
             false,
             "plain",
             false,
+            false,
+            &RunResources::default(),
             &hint_processor
         )
         .is_err());
@@ -224,6 +653,8 @@ mod tests {
             false,
             "plain",
             false,
+            false,
+            &RunResources::default(),
             &hint_processor
         )
         .is_err());
@@ -242,6 +673,8 @@ mod tests {
             false,
             "plain",
             false,
+            false,
+            &RunResources::default(),
             &hint_processor
         )
         .is_err());
@@ -256,6 +689,220 @@ mod tests {
         assert!(write_output(&mut cairo_runner, &mut vm).is_ok());
     }
 
+    #[test]
+    fn cairo_run_secure_run() {
+        let program_path = Path::new("cairo_programs/bitwise_output.json");
+        let hint_processor = BuiltinHintProcessor::new_empty();
+        assert!(cairo_run(
+            program_path,
+            "main",
+            false,
+            false,
+            "all",
+            false,
+            true,
+            &RunResources::default(),
+            &hint_processor,
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn parse_func_args() {
+        assert_eq!(
+            FuncArgs::from_str("1, -2 0x10 [3 [4, 5] []]"),
+            Ok(FuncArgs(vec![
+                FuncArg::Single(bigint!(1)),
+                FuncArg::Single(bigint!(-2)),
+                FuncArg::Single(bigint!(16)),
+                FuncArg::Array(vec![
+                    FuncArg::Single(bigint!(3)),
+                    FuncArg::Array(vec![
+                        FuncArg::Single(bigint!(4)),
+                        FuncArg::Single(bigint!(5))
+                    ]),
+                    FuncArg::Array(Vec::new()),
+                ]),
+            ]))
+        );
+    }
+
+    #[test]
+    fn parse_func_args_invalid() {
+        assert!(FuncArgs::from_str("[1 2").is_err());
+        assert!(FuncArgs::from_str("1 2]").is_err());
+        assert!(FuncArgs::from_str("1 two").is_err());
+    }
+
+    #[test]
+    fn cairo_run_with_args_array_sum() {
+        let hint_processor = BuiltinHintProcessor::new_empty();
+        let program_path = Path::new("cairo_programs/array_sum.json");
+        let FuncArgs(args) = FuncArgs::from_str("[9 16 25] 3").unwrap();
+        let (cairo_runner, vm) = cairo_run_with_args(
+            program_path,
+            "array_sum",
+            &args,
+            false,
+            false,
+            "all",
+            false,
+            &RunResources::default(),
+            &hint_processor,
+        )
+        .unwrap();
+        assert_eq!(
+            decode_return_values(cairo_runner.get_program(), "array_sum", &vm),
+            Ok(CairoValue::Struct(vec![(
+                String::from("sum"),
+                CairoValue::Felt(bigint!(50))
+            )]))
+        );
+    }
+
+    #[test]
+    fn cairo_run_with_args_fib() {
+        let hint_processor = BuiltinHintProcessor::new_empty();
+        let program_path = Path::new("cairo_programs/fibonacci.json");
+        let FuncArgs(args) = FuncArgs::from_str("1 1 10").unwrap();
+        let (cairo_runner, vm) = cairo_run_with_args(
+            program_path,
+            "fib",
+            &args,
+            false,
+            false,
+            "plain",
+            false,
+            &RunResources::default(),
+            &hint_processor,
+        )
+        .unwrap();
+        assert_eq!(
+            decode_return_values(cairo_runner.get_program(), "fib", &vm),
+            Ok(CairoValue::Struct(vec![(
+                String::from("res"),
+                CairoValue::Felt(bigint!(144))
+            )]))
+        );
+    }
+
+    #[test]
+    fn cairo_run_with_args_wrong_count() {
+        let hint_processor = BuiltinHintProcessor::new_empty();
+        let program_path = Path::new("cairo_programs/fibonacci.json");
+        let FuncArgs(args) = FuncArgs::from_str("1 1").unwrap();
+        let error = cairo_run_with_args(
+            program_path,
+            "fib",
+            &args,
+            false,
+            false,
+            "plain",
+            false,
+            &RunResources::default(),
+            &hint_processor,
+        )
+        .err();
+        assert!(matches!(
+            error,
+            Some(CairoRunError::Runner(RunnerError::EntrypointArgCountMismatch(name, 3, 2))) if name == "fib"
+        ));
+    }
+
+    #[test]
+    fn cairo_run_with_args_wrong_type() {
+        let hint_processor = BuiltinHintProcessor::new_empty();
+        let program_path = Path::new("cairo_programs/array_sum.json");
+        let FuncArgs(args) = FuncArgs::from_str("9 3").unwrap();
+        let error = cairo_run_with_args(
+            program_path,
+            "array_sum",
+            &args,
+            false,
+            false,
+            "all",
+            false,
+            &RunResources::default(),
+            &hint_processor,
+        )
+        .err();
+        assert!(matches!(
+            error,
+            Some(CairoRunError::Runner(RunnerError::InvalidEntrypointArg(name, cairo_type)))
+                if name == "arr" && cairo_type == "felt*"
+        ));
+    }
+
+    #[test]
+    fn run_report_of_program_with_output() {
+        let program_path = Path::new("cairo_programs/bitwise_output.json");
+        let hint_processor = BuiltinHintProcessor::new_empty();
+        let (cairo_runner, vm) = cairo_run_with_vm(
+            program_path,
+            "main",
+            false,
+            false,
+            "all",
+            false,
+            false,
+            &RunResources::default(),
+            &hint_processor,
+        )
+        .unwrap();
+        let report = RunReport::new("all", "main", &cairo_runner, &vm).unwrap();
+        assert_eq!(report.layout, "all");
+        assert_eq!(
+            report.output,
+            Some(RunOutput {
+                felts: vec![String::from("0")],
+                signed: vec![String::from("0")],
+            })
+        );
+        assert_eq!(report.return_values, Some(Vec::new()));
+        assert_eq!(
+            report.execution_resources,
+            Some(cairo_runner.get_execution_resources(&vm).unwrap())
+        );
+        assert_eq!(report.segment_sizes, vm.segments.segment_used_sizes);
+        assert!(report.registers.is_some());
+        assert_eq!(report.consumed_gas, None);
+        assert_eq!(report.error, None);
+    }
+
+    #[test]
+    fn run_report_with_gas_budget() {
+        let program_path = Path::new("cairo_programs/bitwise_output.json");
+        let hint_processor = BuiltinHintProcessor::new_empty();
+        let run_resources = RunResources {
+            gas_budget: Some(u64::MAX),
+            ..RunResources::default()
+        };
+        let (cairo_runner, vm) = cairo_run_with_vm(
+            program_path,
+            "main",
+            false,
+            false,
+            "all",
+            false,
+            false,
+            &run_resources,
+            &hint_processor,
+        )
+        .unwrap();
+        let report = RunReport::new("all", "main", &cairo_runner, &vm).unwrap();
+        assert!(report.consumed_gas.is_some());
+        assert_eq!(report.consumed_gas, cairo_runner.consumed_gas);
+    }
+
+    #[test]
+    fn run_report_from_error() {
+        let error = CairoRunError::Runner(RunnerError::NoExecBase);
+        let report = RunReport::from_error("plain", &error);
+        assert_eq!(report.layout, "plain");
+        assert_eq!(report.output, None);
+        assert_eq!(report.error, Some(error.to_string()));
+    }
+
     #[test]
     fn write_binary_trace_file() {
         let program_path = Path::new("cairo_programs/struct.json");
@@ -0,0 +1,935 @@
+use crate::any_box;
+use crate::bigint;
+use crate::cairo_run::write_fact_topologies;
+use crate::hint_processor::builtin_hint_processor::hint_utils::{
+    get_integer_from_var_name, get_member_addr_from_var_name, get_ptr_from_var_name,
+    insert_value_from_var_name,
+};
+use crate::hint_processor::hint_processor_definition::{HintProcessor, HintReference};
+use crate::hint_processor::hint_processor_utils::bigint_to_usize;
+use crate::serde::deserialize_program::{program_from_program_json, ApTracking, ProgramJson};
+use crate::types::exec_scope::ExecutionScopes;
+use crate::types::program::Program;
+use crate::types::relocatable::{MaybeRelocatable, Relocatable};
+use crate::vm::errors::memory_errors::MemoryError;
+use crate::vm::errors::runner_errors::RunnerError;
+use crate::vm::errors::vm_errors::VirtualMachineError;
+use crate::vm::runners::builtin_runner::{FactTopology, OutputBuiltinRunner, OutputBuiltinState};
+use crate::vm::vm_core::VirtualMachine;
+use num_bigint::{BigInt, Sign};
+use num_traits::{One, Signed, Zero};
+use serde::Deserialize;
+use std::any::Any;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::rc::Rc;
+
+/// Input of the simple bootloader, read from the `program_input` scope variable
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct SimpleBootloaderInput {
+    pub tasks: Vec<TaskSpec>,
+    #[serde(default)]
+    pub fact_topologies_path: Option<PathBuf>,
+    #[serde(default)]
+    pub single_page: bool,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type")]
+pub enum TaskSpec {
+    RunProgramTask {
+        program: serde_json::Value,
+        #[serde(default)]
+        program_input: serde_json::Value,
+    },
+    CairoPiePath {
+        path: PathBuf,
+    },
+}
+
+/// A task ready to be run by the bootloader
+#[derive(Debug, Clone)]
+pub struct Task {
+    pub program: Rc<Program>,
+    pub program_input: serde_json::Value,
+}
+
+impl TaskSpec {
+    pub fn load_task(&self) -> Result<Task, VirtualMachineError> {
+        match self {
+            TaskSpec::RunProgramTask {
+                program,
+                program_input,
+            } => {
+                let program_json: ProgramJson = serde_json::from_str(&program.to_string())
+                    .map_err(|err| VirtualMachineError::InvalidBootloaderInput(err.to_string()))?;
+                let program = program_from_program_json(program_json, Some("main"))
+                    .map_err(|err| VirtualMachineError::InvalidBootloaderInput(err.to_string()))?;
+                Ok(Task {
+                    program: Rc::new(program),
+                    program_input: program_input.clone(),
+                })
+            }
+            TaskSpec::CairoPiePath { .. } => Err(VirtualMachineError::InvalidBootloaderInput(
+                "CairoPiePath tasks are not supported".to_string(),
+            )),
+        }
+    }
+}
+
+/*
+Implements hint:
+%{
+    from starkware.cairo.bootloaders.simple_bootloader.objects import SimpleBootloaderInput
+    simple_bootloader_input = SimpleBootloaderInput.Schema().load(program_input)
+%}
+*/
+pub fn load_simple_bootloader_input(
+    exec_scopes: &mut ExecutionScopes,
+) -> Result<(), VirtualMachineError> {
+    let program_input = exec_scopes.get_ref::<String>("program_input")?;
+    let simple_bootloader_input: SimpleBootloaderInput = serde_json::from_str(program_input)
+        .map_err(|err| VirtualMachineError::InvalidBootloaderInput(err.to_string()))?;
+    exec_scopes.insert_value("simple_bootloader_input", simple_bootloader_input);
+    Ok(())
+}
+
+// Implements hint: %{ ids.n_tasks = len(simple_bootloader_input.tasks) %}
+pub fn set_n_tasks(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), VirtualMachineError> {
+    let n_tasks = exec_scopes
+        .get_ref::<SimpleBootloaderInput>("simple_bootloader_input")?
+        .tasks
+        .len();
+    insert_value_from_var_name("n_tasks", bigint!(n_tasks), vm, ids_data, ap_tracking)
+}
+
+// Implements hint: %{ fact_topologies = [] %}
+pub fn init_fact_topologies(exec_scopes: &mut ExecutionScopes) -> Result<(), VirtualMachineError> {
+    exec_scopes.insert_value("fact_topologies", Vec::<FactTopology>::new());
+    Ok(())
+}
+
+/*
+Implements hint:
+%{
+    from starkware.cairo.bootloaders.simple_bootloader.objects import Task
+
+    # Pass current task to execute_task.
+    task_id = len(simple_bootloader_input.tasks) - ids.n_tasks
+    task = simple_bootloader_input.tasks[task_id].load_task()
+%}
+*/
+pub fn load_task(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), VirtualMachineError> {
+    let n_tasks = get_integer_from_var_name("n_tasks", vm, ids_data, ap_tracking)?;
+    let n_tasks = bigint_to_usize(&n_tasks)?;
+    let simple_bootloader_input =
+        exec_scopes.get_ref::<SimpleBootloaderInput>("simple_bootloader_input")?;
+    let task = simple_bootloader_input
+        .tasks
+        .len()
+        .checked_sub(n_tasks)
+        .and_then(|task_id| simple_bootloader_input.tasks.get(task_id))
+        .ok_or_else(|| {
+            VirtualMachineError::InvalidBootloaderInput(format!(
+                "no task left to run with n_tasks = {}",
+                n_tasks
+            ))
+        })?
+        .load_task()?;
+    exec_scopes.insert_value("task", task);
+    Ok(())
+}
+
+/*
+Implements hint:
+%{
+    from starkware.cairo.bootloaders.simple_bootloader.utils import load_program
+
+    # Call load_program to load the program header and code to memory.
+    program_address, program_data_size = load_program(
+        task=task, memory=memory, program_header=ids.program_header,
+        builtins_offset=ids.ProgramHeader.builtin_list)
+    segments.finalize(program_address.segment_index, program_data_size)
+%}
+The program header (data_length, bootloader_version, program_main, n_builtins and the builtin
+list) is written at ids.program_header, followed by the program data.
+*/
+pub fn load_program(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), VirtualMachineError> {
+    let program = exec_scopes.get_ref::<Task>("task")?.program.clone();
+    let main = program.main.ok_or_else(|| {
+        VirtualMachineError::InvalidBootloaderInput("the task program has no main".to_string())
+    })?;
+    let header_address = get_ptr_from_var_name("program_header", vm, ids_data, ap_tracking)?;
+    let header_member_addr = |member_name: &str| {
+        get_member_addr_from_var_name("program_header", member_name, vm, ids_data, ap_tracking)
+    };
+    let data_length_addr = header_member_addr("data_length")?;
+    let bootloader_version_addr = header_member_addr("bootloader_version")?;
+    let program_main_addr = header_member_addr("program_main")?;
+    let n_builtins_addr = header_member_addr("n_builtins")?;
+    let builtin_list = header_member_addr("builtin_list")?;
+
+    let n_builtins = program.builtins.len();
+    // The program header ends with the list of builtins used by the program
+    let header_size = builtin_list.sub_rel(&header_address)? + n_builtins;
+    // data_length doesn't count the data_length field itself
+    let data_length = header_size - 1 + program.data.len();
+
+    vm.insert_value(&data_length_addr, bigint!(data_length))?;
+    vm.insert_value(&bootloader_version_addr, BigInt::zero())?;
+    vm.insert_value(&program_main_addr, bigint!(main))?;
+    vm.insert_value(&n_builtins_addr, bigint!(n_builtins))?;
+    for (index, builtin) in program.builtins.iter().enumerate() {
+        vm.insert_value(
+            &(&builtin_list + index),
+            BigInt::from_bytes_be(Sign::Plus, builtin.as_bytes()),
+        )?;
+    }
+    let program_address = &builtin_list + n_builtins;
+    vm.load_data(
+        &MaybeRelocatable::from(&program_address),
+        program.data.clone(),
+    )?;
+
+    let program_data_size = header_size + program.data.len();
+    let segment_index = program_address.segment_index;
+    vm.segments.finalize(
+        Some(program_data_size),
+        segment_index
+            .try_into()
+            .map_err(|_| MemoryError::AddressInTemporarySegment(segment_index))?,
+        None,
+    );
+    exec_scopes.insert_value("program_address", program_address);
+    exec_scopes.insert_value("program_data_size", program_data_size);
+    Ok(())
+}
+
+/*
+Implements hint:
+%{
+    # Validate hash.
+    from starkware.cairo.bootloaders.hash_program import compute_program_hash_chain
+
+    assert memory[ids.output_ptr + 1] == compute_program_hash_chain(task.get_program()), \
+      'Computed hash does not match input.'
+%}
+*/
+pub fn validate_program_hash(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), VirtualMachineError> {
+    let output_ptr = get_ptr_from_var_name("output_ptr", vm, ids_data, ap_tracking)?;
+    let program_hash = vm.get_integer(&(output_ptr + 1_usize))?.into_owned();
+    let computed_hash = exec_scopes
+        .get_ref::<Task>("task")?
+        .program
+        .compute_hash(0)
+        .map_err(|err| VirtualMachineError::InvalidBootloaderInput(err.to_string()))?;
+    if program_hash != computed_hash {
+        return Err(VirtualMachineError::ProgramHashMismatch(
+            program_hash,
+            computed_hash,
+        ));
+    }
+    Ok(())
+}
+
+/*
+Implements hint:
+%{
+    from starkware.cairo.bootloaders.simple_bootloader.objects import (
+        CairoPieTask,
+        RunProgramTask,
+        Task,
+    )
+    ...
+    if isinstance(task, RunProgramTask):
+        new_task_locals['program_input'] = task.program_input
+        new_task_locals['WITH_BOOTLOADER'] = True
+
+        vm_load_program(task.program, program_address)
+    ...
+    output_runner_data = prepare_output_runner(
+        task=task,
+        output_builtin=output_builtin,
+        output_ptr=ids.pre_execution_builtin_ptrs.output)
+    vm_enter_scope(new_task_locals)
+%}
+Loads the hints of the task program so that they run once the bootloader calls it, and starts a
+new output builtin state at the output pointer handed to the task.
+*/
+pub fn execute_task(
+    hint_processor: &dyn HintProcessor,
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), VirtualMachineError> {
+    let task = exec_scopes.get::<Task>("task")?;
+    let program_address = exec_scopes.get::<Relocatable>("program_address")?;
+    vm.load_program(&task.program, &program_address, hint_processor)?;
+
+    let output_ptr = get_builtin_ptr(
+        "pre_execution_builtin_ptrs",
+        "output",
+        vm,
+        ids_data,
+        ap_tracking,
+    )?;
+    let output_builtin = vm.get_output_builtin()?;
+    let output_runner_data = output_builtin.get_state();
+    output_builtin.new_state(&output_ptr)?;
+    exec_scopes.insert_value("output_runner_data", output_runner_data);
+
+    exec_scopes.enter_scope(HashMap::from([
+        (
+            "program_input".to_string(),
+            any_box!(task.program_input.to_string()),
+        ),
+        ("WITH_BOOTLOADER".to_string(), any_box!(true)),
+    ]));
+    Ok(())
+}
+
+/*
+Implements hint:
+%{
+    # Sanity check.
+    assert ids.program_address == program_address
+%}
+*/
+pub fn assert_program_address(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), VirtualMachineError> {
+    let ids_program_address = get_ptr_from_var_name("program_address", vm, ids_data, ap_tracking)?;
+    let program_address = exec_scopes.get::<Relocatable>("program_address")?;
+    if ids_program_address != program_address {
+        return Err(VirtualMachineError::ProgramAddressMismatch(
+            ids_program_address,
+            program_address,
+        ));
+    }
+    Ok(())
+}
+
+/*
+Implements hint:
+%{
+    from starkware.cairo.bootloaders.simple_bootloader.utils import get_task_fact_topology
+
+    # Add the fact topology of the current task to 'fact_topologies'.
+    output_start = ids.pre_execution_builtin_ptrs.output
+    output_end = ids.return_builtin_ptrs.output
+    fact_topologies.append(get_task_fact_topology(
+        output_size=output_end - output_start,
+        task=task,
+        output_builder=output_builtin,
+        output_runner_data=output_runner_data,
+    ))
+%}
+The output builtin is restored to the state it had before running the task.
+*/
+pub fn append_fact_topologies(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), VirtualMachineError> {
+    let output_start = get_builtin_ptr(
+        "pre_execution_builtin_ptrs",
+        "output",
+        vm,
+        ids_data,
+        ap_tracking,
+    )?;
+    let output_end = get_builtin_ptr("return_builtin_ptrs", "output", vm, ids_data, ap_tracking)?;
+    let output_size = output_end.sub_rel(&output_start)?;
+    let output_runner_data = exec_scopes.get::<OutputBuiltinState>("output_runner_data")?;
+
+    let output_builtin = vm.get_output_builtin()?;
+    let fact_topology = output_builtin.get_fact_topology(output_size)?;
+    output_builtin.set_state(output_runner_data);
+    exec_scopes
+        .get_mut_list_ref::<FactTopology>("fact_topologies")?
+        .push(fact_topology);
+    Ok(())
+}
+
+/*
+Implements hint:
+%{
+    # Dump fact topologies to a json file.
+    ...
+    # The task-related output is prefixed by a single word that contains the number of tasks.
+    tasks_output_start = output_builtin.base + 1
+
+    if not simple_bootloader_input.single_page:
+        # Configure the memory pages in the output builtin, based on fact_topologies.
+        configure_fact_topologies(
+            fact_topologies=fact_topologies, output_start=tasks_output_start,
+            output_builtin=output_builtin,
+        )
+
+    if simple_bootloader_input.fact_topologies_path is not None:
+        write_to_fact_topologies_file(
+            fact_topologies_path=simple_bootloader_input.fact_topologies_path,
+            fact_topologies=fact_topologies,
+        )
+%}
+*/
+pub fn configure_fact_topologies(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+) -> Result<(), VirtualMachineError> {
+    let simple_bootloader_input =
+        exec_scopes.get_ref::<SimpleBootloaderInput>("simple_bootloader_input")?;
+    let fact_topologies = exec_scopes.get_list_ref::<FactTopology>("fact_topologies")?;
+
+    if !simple_bootloader_input.single_page {
+        let output_builtin = vm.get_output_builtin()?;
+        let tasks_output_start =
+            Relocatable::from((output_builtin.base(), output_builtin.base_offset + 1));
+        add_task_pages(fact_topologies, tasks_output_start, output_builtin)?;
+    }
+
+    if let Some(fact_topologies_path) = &simple_bootloader_input.fact_topologies_path {
+        write_fact_topologies(fact_topologies, fact_topologies_path)
+            .map_err(|_| VirtualMachineError::FactTopologiesWriteFail)?;
+    }
+    Ok(())
+}
+
+// Adds a page to the output builtin for each page of each task. Page 0 is left to the
+// bootloader output, and the output size and program hash preceding the output of each task.
+fn add_task_pages(
+    fact_topologies: &[FactTopology],
+    mut output_start: Relocatable,
+    output_builtin: &mut OutputBuiltinRunner,
+) -> Result<(), RunnerError> {
+    let mut page_id = 1;
+    for fact_topology in fact_topologies {
+        output_start = output_start + 2_usize;
+        for page_size in fact_topology.page_sizes.iter() {
+            output_builtin.add_page(page_id, &output_start, *page_size)?;
+            page_id += 1;
+            output_start = output_start + *page_size;
+        }
+    }
+    Ok(())
+}
+
+// Implements hint: %{ vm_enter_scope({'n_selected_builtins': ids.n_selected_builtins}) %}
+pub fn select_builtins_enter_scope(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), VirtualMachineError> {
+    let n_selected_builtins =
+        get_integer_from_var_name("n_selected_builtins", vm, ids_data, ap_tracking)?.into_owned();
+    exec_scopes.enter_scope(HashMap::from([(
+        "n_selected_builtins".to_string(),
+        any_box!(n_selected_builtins),
+    )]));
+    Ok(())
+}
+
+/*
+Implements hint:
+%{
+    # A builtin should be selected iff its encoding appears in the selected encodings list
+    # and the list wasn't exhausted.
+    # Note that testing inclusion by a single comparison is possible since the lists are sorted.
+    ids.select_builtin = int(
+      n_selected_builtins > 0 and memory[ids.selected_encodings] == memory[ids.all_encodings])
+    if ids.select_builtin:
+      n_selected_builtins = n_selected_builtins - 1
+%}
+*/
+pub fn select_builtin(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), VirtualMachineError> {
+    let n_selected_builtins = exec_scopes.get::<BigInt>("n_selected_builtins")?;
+    let select_builtin = n_selected_builtins.is_positive() && {
+        let selected_encodings =
+            get_ptr_from_var_name("selected_encodings", vm, ids_data, ap_tracking)?;
+        let all_encodings = get_ptr_from_var_name("all_encodings", vm, ids_data, ap_tracking)?;
+        vm.get_integer(&selected_encodings)? == vm.get_integer(&all_encodings)?
+    };
+    insert_value_from_var_name(
+        "select_builtin",
+        bigint!(select_builtin as u8),
+        vm,
+        ids_data,
+        ap_tracking,
+    )?;
+    if select_builtin {
+        exec_scopes.insert_value("n_selected_builtins", n_selected_builtins - BigInt::one());
+    }
+    Ok(())
+}
+
+// Reads the pointer of a builtin from a BuiltinData struct pointed to by the given ids variable
+fn get_builtin_ptr(
+    var_name: &str,
+    builtin_name: &str,
+    vm: &VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<Relocatable, VirtualMachineError> {
+    let builtin_ptr_addr =
+        get_member_addr_from_var_name(var_name, builtin_name, vm, ids_data, ap_tracking)?;
+    Ok(vm.get_relocatable(&builtin_ptr_addr)?.into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hint_processor::builtin_hint_processor::builtin_hint_processor_definition::{
+        BuiltinHintProcessor, HintProcessorData,
+    };
+    use crate::hint_processor::builtin_hint_processor::hint_code;
+    use crate::serde::deserialize_program::Member;
+    use crate::utils::test_utils::*;
+    use crate::vm::vm_memory::memory::Memory;
+    use crate::{relocatable, vm::runners::builtin_runner::PublicMemoryPage};
+
+    const TASK_PROGRAM: &str = r#"{
+        "prime": "0x800000000000011000000000000000000000000000000000000000000000001",
+        "builtins": ["output"],
+        "data": ["0x400380007ffc8000", "0x482680017ffc8000", "0x208b7fff7fff7ffe"],
+        "identifiers": {"__main__.main": {"pc": 0, "type": "function"}},
+        "hints": {},
+        "reference_manager": {"references": []},
+        "attributes": []
+    }"#;
+
+    fn bootloader_input(task_type: &str) -> String {
+        format!(
+            r#"{{"tasks": [{{"type": "{}", "program": {}, "program_input": {{"x": 1}}}}]}}"#,
+            task_type, TASK_PROGRAM
+        )
+    }
+
+    fn task() -> Task {
+        let input: SimpleBootloaderInput =
+            serde_json::from_str(&bootloader_input("RunProgramTask")).unwrap();
+        input.tasks[0].load_task().unwrap()
+    }
+
+    // Reference to a pointer to a struct with the given felt members
+    fn struct_ptr_reference(offset1: i32, cairo_type: &str, members: &[&str]) -> HintReference {
+        let mut hint_ref = HintReference::new_simple(offset1);
+        hint_ref.cairo_type = Some(cairo_type.to_string());
+        hint_ref.members = Some(
+            members
+                .iter()
+                .enumerate()
+                .map(|(offset, name)| {
+                    (
+                        name.to_string(),
+                        Member {
+                            cairo_type: "felt".to_string(),
+                            offset,
+                        },
+                    )
+                })
+                .collect(),
+        );
+        hint_ref
+    }
+
+    fn vm_with_output_builtin() -> VirtualMachine {
+        let mut vm = vm!();
+        let mut output_builtin = OutputBuiltinRunner::new(true);
+        output_builtin.initialize_segments(&mut vm.segments, &mut vm.memory);
+        vm.builtin_runners = vec![("output".to_string(), output_builtin.into())];
+        vm
+    }
+
+    #[test]
+    fn run_load_input_and_task() {
+        let mut vm = vm!();
+        add_segments!(vm, 2);
+        vm.run_context.fp = 1;
+        let mut exec_scopes = scope![("program_input", bootloader_input("RunProgramTask"))];
+        assert_eq!(
+            run_hint!(
+                vm,
+                HashMap::new(),
+                hint_code::SIMPLE_BOOTLOADER_LOAD_INPUT,
+                &mut exec_scopes
+            ),
+            Ok(())
+        );
+        let ids_data = non_continuous_ids_data![("n_tasks", -1)];
+        assert_eq!(
+            run_hint!(
+                vm,
+                ids_data.clone(),
+                hint_code::SIMPLE_BOOTLOADER_SET_N_TASKS,
+                &mut exec_scopes
+            ),
+            Ok(())
+        );
+        check_memory![vm.memory, ((1, 0), 1)];
+        assert_eq!(
+            run_hint!(
+                vm,
+                ids_data,
+                hint_code::SIMPLE_BOOTLOADER_LOAD_TASK,
+                &mut exec_scopes
+            ),
+            Ok(())
+        );
+        let task = exec_scopes.get::<Task>("task").unwrap();
+        assert_eq!(task.program.main, Some(0));
+        assert_eq!(task.program.builtins, vec!["output".to_string()]);
+        assert_eq!(task.program_input, serde_json::json!({"x": 1}));
+    }
+
+    #[test]
+    fn run_load_task_no_task_left() {
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), 0)];
+        vm.run_context.fp = 1;
+        let input: SimpleBootloaderInput =
+            serde_json::from_str(&bootloader_input("RunProgramTask")).unwrap();
+        let mut exec_scopes = scope![("simple_bootloader_input", input)];
+        assert_eq!(
+            run_hint!(
+                vm,
+                non_continuous_ids_data![("n_tasks", -1)],
+                hint_code::SIMPLE_BOOTLOADER_LOAD_TASK,
+                &mut exec_scopes
+            ),
+            Err(VirtualMachineError::InvalidBootloaderInput(
+                "no task left to run with n_tasks = 0".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn load_cairo_pie_task_unsupported() {
+        let input: SimpleBootloaderInput = serde_json::from_str(
+            r#"{"tasks": [{"type": "CairoPiePath", "path": "task.zip"}], "single_page": true}"#,
+        )
+        .unwrap();
+        assert!(input.single_page);
+        assert_eq!(
+            input.tasks[0].load_task().unwrap_err(),
+            VirtualMachineError::InvalidBootloaderInput(
+                "CairoPiePath tasks are not supported".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn run_load_program_writes_header_and_data() {
+        let mut vm = vm!();
+        vm.run_context.fp = 1;
+        vm.memory = memory![((1, 0), (2, 0))];
+        vm.memory.data.push(Vec::new());
+        let ids_data = HashMap::from([(
+            "program_header".to_string(),
+            struct_ptr_reference(
+                -1,
+                "ProgramHeader*",
+                &[
+                    "data_length",
+                    "bootloader_version",
+                    "program_main",
+                    "n_builtins",
+                    "builtin_list",
+                ],
+            ),
+        )]);
+        let mut exec_scopes = scope![("task", task())];
+        assert_eq!(
+            run_hint!(
+                vm,
+                ids_data,
+                hint_code::EXECUTE_TASK_LOAD_PROGRAM,
+                &mut exec_scopes
+            ),
+            Ok(())
+        );
+        check_memory![
+            vm.memory,
+            ((2, 0), 7),
+            ((2, 1), 0),
+            ((2, 2), 0),
+            ((2, 3), 1),
+            // "output" as a short string
+            ((2, 4), 122550255383924_i64),
+            ((2, 5), 0x400380007ffc8000_i64),
+            ((2, 6), 0x482680017ffc8000_i64),
+            ((2, 7), 0x208b7fff7fff7ffe_i64)
+        ];
+        assert_eq!(vm.segments.segment_sizes.get(&2), Some(&8));
+        check_scope!(
+            &exec_scopes,
+            [
+                ("program_address", relocatable!(2, 5)),
+                ("program_data_size", 8_usize)
+            ]
+        );
+    }
+
+    #[test]
+    fn run_validate_program_hash() {
+        let task = task();
+        let program_hash = task.program.compute_hash(0).unwrap();
+        let mut vm = vm!();
+        vm.run_context.fp = 1;
+        vm.memory = memory![((1, 0), (2, 0)), ((2, 0), 3)];
+        vm.insert_value(&relocatable!(2, 1), program_hash.clone())
+            .unwrap();
+        let mut exec_scopes = scope![("task", task.clone())];
+        let ids_data = non_continuous_ids_data![("output_ptr", -1)];
+        assert_eq!(
+            run_hint!(
+                vm,
+                ids_data.clone(),
+                hint_code::EXECUTE_TASK_VALIDATE_HASH,
+                &mut exec_scopes
+            ),
+            Ok(())
+        );
+
+        let mut vm = vm!();
+        vm.run_context.fp = 1;
+        vm.memory = memory![((1, 0), (2, 0)), ((2, 0), 3), ((2, 1), 5)];
+        assert_eq!(
+            run_hint!(
+                vm,
+                ids_data,
+                hint_code::EXECUTE_TASK_VALIDATE_HASH,
+                &mut exec_scopes
+            ),
+            Err(VirtualMachineError::ProgramHashMismatch(
+                bigint!(5),
+                program_hash
+            ))
+        );
+    }
+
+    #[test]
+    fn run_execute_task_and_append_fact_topologies() {
+        let mut vm = vm_with_output_builtin();
+        vm.run_context.fp = 2;
+        // ids.pre_execution_builtin_ptrs and ids.return_builtin_ptrs
+        vm.memory = memory![
+            ((1, 0), (2, 0)),
+            ((1, 1), (2, 1)),
+            ((2, 0), (0, 3)),
+            ((2, 1), (0, 5))
+        ];
+        // Segment the task program is loaded into
+        vm.memory.data.push(Vec::new());
+        let ids_data = HashMap::from([
+            (
+                "pre_execution_builtin_ptrs".to_string(),
+                struct_ptr_reference(-2, "BuiltinData*", &["output"]),
+            ),
+            (
+                "return_builtin_ptrs".to_string(),
+                struct_ptr_reference(-1, "BuiltinData*", &["output"]),
+            ),
+        ]);
+        let mut exec_scopes = scope![
+            ("task", task()),
+            ("program_address", relocatable!(3, 0)),
+            ("fact_topologies", Vec::<FactTopology>::new())
+        ];
+        vm.get_output_builtin()
+            .unwrap()
+            .add_page(1, &relocatable!(0, 0), 1)
+            .unwrap();
+
+        assert_eq!(
+            run_hint!(
+                vm,
+                ids_data.clone(),
+                hint_code::EXECUTE_TASK_ENTER_SCOPE,
+                &mut exec_scopes
+            ),
+            Ok(())
+        );
+        check_memory![vm.memory, ((3, 0), 0x400380007ffc8000_i64)];
+        check_scope!(
+            &exec_scopes,
+            [
+                ("program_input", r#"{"x":1}"#.to_string()),
+                ("WITH_BOOTLOADER", true)
+            ]
+        );
+        assert!(vm.get_output_builtin().unwrap().get_pages().is_empty());
+        assert_eq!(exec_scopes.exit_scope(), Ok(()));
+
+        assert_eq!(
+            run_hint!(
+                vm,
+                ids_data,
+                hint_code::EXECUTE_TASK_APPEND_FACT_TOPOLOGIES,
+                &mut exec_scopes
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            exec_scopes.get_list::<FactTopology>("fact_topologies"),
+            Ok(vec![FactTopology {
+                tree_structure: vec![1, 0],
+                page_sizes: vec![2],
+            }])
+        );
+        // The state of the output builtin from before the task was restored
+        assert_eq!(
+            vm.get_output_builtin().unwrap().get_pages(),
+            &HashMap::from([(1, PublicMemoryPage { start: 0, size: 1 })])
+        );
+    }
+
+    #[test]
+    fn run_configure_fact_topologies() {
+        let mut vm = vm_with_output_builtin();
+        let input: SimpleBootloaderInput =
+            serde_json::from_str(&bootloader_input("RunProgramTask")).unwrap();
+        let fact_topologies = vec![
+            FactTopology {
+                tree_structure: vec![1, 0],
+                page_sizes: vec![2],
+            },
+            FactTopology {
+                tree_structure: vec![2, 1, 0, 2],
+                page_sizes: vec![1, 3],
+            },
+        ];
+        let mut exec_scopes = scope![
+            ("simple_bootloader_input", input),
+            ("fact_topologies", fact_topologies)
+        ];
+        assert_eq!(
+            run_hint!(
+                vm,
+                HashMap::new(),
+                hint_code::SIMPLE_BOOTLOADER_CONFIGURE_FACT_TOPOLOGIES,
+                &mut exec_scopes
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            vm.get_output_builtin().unwrap().get_pages(),
+            &HashMap::from([
+                (1, PublicMemoryPage { start: 3, size: 2 }),
+                (2, PublicMemoryPage { start: 7, size: 1 }),
+                (3, PublicMemoryPage { start: 8, size: 3 })
+            ])
+        );
+    }
+
+    #[test]
+    fn run_assert_program_address_mismatch() {
+        let mut vm = vm!();
+        vm.run_context.fp = 1;
+        vm.memory = memory![((1, 0), (2, 4))];
+        let mut exec_scopes = scope![("program_address", relocatable!(2, 5))];
+        assert_eq!(
+            run_hint!(
+                vm,
+                non_continuous_ids_data![("program_address", -1)],
+                hint_code::EXECUTE_TASK_ASSERT_PROGRAM_ADDRESS,
+                &mut exec_scopes
+            ),
+            Err(VirtualMachineError::ProgramAddressMismatch(
+                relocatable!(2, 4),
+                relocatable!(2, 5)
+            ))
+        );
+    }
+
+    #[test]
+    fn run_select_builtins() {
+        let mut vm = vm!();
+        vm.run_context.fp = 4;
+        // ids.n_selected_builtins, ids.selected_encodings, ids.all_encodings and
+        // ids.select_builtin
+        vm.memory = memory![
+            ((1, 0), 1),
+            ((1, 1), (2, 0)),
+            ((1, 2), (3, 0)),
+            ((2, 0), 7),
+            ((3, 0), 7)
+        ];
+        let ids_data = non_continuous_ids_data![
+            ("n_selected_builtins", -4),
+            ("selected_encodings", -3),
+            ("all_encodings", -2),
+            ("select_builtin", -1)
+        ];
+        let mut exec_scopes = ExecutionScopes::new();
+        assert_eq!(
+            run_hint!(
+                vm,
+                ids_data.clone(),
+                hint_code::SELECT_BUILTINS_ENTER_SCOPE,
+                &mut exec_scopes
+            ),
+            Ok(())
+        );
+        check_scope!(&exec_scopes, [("n_selected_builtins", bigint!(1))]);
+        assert_eq!(
+            run_hint!(
+                vm,
+                ids_data,
+                hint_code::INNER_SELECT_BUILTINS_SELECT_BUILTIN,
+                &mut exec_scopes
+            ),
+            Ok(())
+        );
+        check_memory![vm.memory, ((1, 3), 1)];
+        check_scope!(&exec_scopes, [("n_selected_builtins", bigint!(0))]);
+    }
+
+    #[test]
+    fn run_select_builtin_list_exhausted() {
+        let mut vm = vm!();
+        add_segments!(vm, 2);
+        vm.run_context.fp = 1;
+        let mut exec_scopes = scope![("n_selected_builtins", bigint!(0))];
+        assert_eq!(
+            run_hint!(
+                vm,
+                non_continuous_ids_data![("select_builtin", -1)],
+                hint_code::INNER_SELECT_BUILTINS_SELECT_BUILTIN,
+                &mut exec_scopes
+            ),
+            Ok(())
+        );
+        check_memory![vm.memory, ((1, 0), 0)];
+    }
+}
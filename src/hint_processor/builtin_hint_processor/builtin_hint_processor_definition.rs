@@ -20,7 +20,9 @@ use crate::hint_processor::builtin_hint_processor::memcpy_hint_utils::{
 use crate::hint_processor::builtin_hint_processor::memset_utils::{
     memset_continue_loop, memset_enter_scope,
 };
+use crate::hint_processor::builtin_hint_processor::output_builtin_hints::add_onchain_data_pages;
 use crate::hint_processor::builtin_hint_processor::pow_utils::pow;
+use crate::hint_processor::builtin_hint_processor::print_hints::PrintHint;
 use crate::hint_processor::builtin_hint_processor::set::set_add;
 use crate::hint_processor::builtin_hint_processor::squash_dict_utils::{
     squash_dict, squash_dict_inner_assert_len_keys, squash_dict_inner_check_access_index,
@@ -29,24 +31,45 @@ use crate::hint_processor::builtin_hint_processor::squash_dict_utils::{
     squash_dict_inner_used_accesses_assert,
 };
 use crate::hint_processor::builtin_hint_processor::uint256_utils::{
-    split_64, uint256_add, uint256_signed_nn, uint256_sqrt, uint256_unsigned_div_rem,
+    split_64, split_xx, uint256_add, uint256_expanded_unsigned_div_rem, uint256_mul_div_mod,
+    uint256_signed_nn, uint256_sqrt, uint256_sub, uint256_unsigned_div_rem,
+};
+use crate::hint_processor::builtin_hint_processor::uint384::{
+    add_no_uint384_check, uint384_signed_nn, uint384_split_128, uint384_sqrt,
+    uint384_unsigned_div_rem, uint384_unsigned_div_rem_expanded,
+};
+use crate::hint_processor::builtin_hint_processor::uint384_extension::{
+    inv_mod_p_uint512, unsigned_div_rem_uint768_by_uint384,
 };
 use crate::hint_processor::hint_processor_definition::{HintProcessor, HintReference};
 use crate::serde::deserialize_program::ApTracking;
 use crate::types::exec_scope::ExecutionScopes;
+use crate::types::identifier_manager::IdentifierManager;
 use crate::vm::errors::vm_errors::VirtualMachineError;
 use crate::vm::vm_core::VirtualMachine;
 use num_bigint::BigInt;
 use std::any::Any;
+use std::cell::RefCell;
 use std::collections::HashMap;
+use std::io::Write;
 use std::rc::Rc;
 
+use crate::hint_processor::builtin_hint_processor::bootloader::{
+    append_fact_topologies, assert_program_address, configure_fact_topologies, execute_task,
+    init_fact_topologies, load_program, load_simple_bootloader_input, load_task, select_builtin,
+    select_builtins_enter_scope, set_n_tasks, validate_program_hash,
+};
 use crate::hint_processor::builtin_hint_processor::cairo_keccak::keccak_hints::{
     block_permutation, cairo_keccak_finalize, compare_bytes_in_word_nondet,
-    compare_keccak_full_rate_in_bytes_nondet, keccak_write_args,
+    compare_keccak_full_rate_in_bytes_nondet, keccak_write_args, split_input, split_n_bytes,
+    split_output, split_output_mid_low_high,
 };
 use crate::hint_processor::builtin_hint_processor::secp::{
     bigint_utils::{bigint_to_uint256, nondet_bigint3},
+    ec_recover::{
+        ec_recover_divmod_n_packed, ec_recover_product_div_m, ec_recover_product_mod,
+        ec_recover_sub_a_b,
+    },
     ec_utils::{
         compute_doubling_slope, compute_slope, ec_double_assign_new_x, ec_double_assign_new_y,
         ec_mul_inner, ec_negate, fast_ec_add_assign_new_x, fast_ec_add_assign_new_y,
@@ -54,7 +77,10 @@ use crate::hint_processor::builtin_hint_processor::secp::{
     field_utils::{
         is_zero_assign_scope_variables, is_zero_nondet, is_zero_pack, reduce, verify_zero,
     },
-    signature::{div_mod_n_packed_divmod, div_mod_n_safe_div, get_point_from_x},
+    secp_utils::SecpCurve,
+    signature::{
+        div_mod_n_packed_divmod, div_mod_n_safe_div, get_point_from_x, get_point_from_x_with_alpha,
+    },
 };
 use crate::hint_processor::builtin_hint_processor::sha256_utils::{
     sha256_finalize, sha256_input, sha256_main,
@@ -70,6 +96,39 @@ pub struct HintProcessorData {
     pub code: String,
     pub ap_tracking: ApTracking,
     pub ids_data: HashMap<String, HintReference>,
+    // Scopes of the program from which the hint accesses identifiers, innermost last
+    pub accessible_scopes: Vec<String>,
+}
+
+// Hint data produced by compile_hint, along with the function implementing the hint, resolved
+// once so that hints aren't looked up by their code on every execution
+struct CompiledHintData {
+    hint_data: HintProcessorData,
+    hint_function: HintFunction,
+}
+
+type BuiltinHintFunction = fn(
+    &mut VirtualMachine,
+    &mut ExecutionScopes,
+    &HintProcessorData,
+    &HashMap<String, BigInt>,
+) -> Result<(), VirtualMachineError>;
+
+// Builtin hints that need the hint processor itself, like the ones loading programs whose hints
+// have to be compiled
+type ProcessorHintFunction = fn(
+    &dyn HintProcessor,
+    &mut VirtualMachine,
+    &mut ExecutionScopes,
+    &HintProcessorData,
+) -> Result<(), VirtualMachineError>;
+
+#[derive(Clone)]
+enum HintFunction {
+    Builtin(BuiltinHintFunction),
+    Processor(ProcessorHintFunction),
+    Extra(Rc<HintFunc>),
+    Print(Rc<PrintHint>),
 }
 
 impl HintProcessorData {
@@ -78,6 +137,7 @@ impl HintProcessorData {
             code,
             ap_tracking: ApTracking::default(),
             ids_data,
+            accessible_scopes: Vec::new(),
         }
     }
 }
@@ -97,21 +157,72 @@ pub struct HintFunc(
 );
 pub struct BuiltinHintProcessor {
     pub extra_hints: HashMap<String, Rc<HintFunc>>,
+    // Destination of the output of debugging print hints
+    print_writer: Rc<RefCell<dyn Write>>,
 }
 impl BuiltinHintProcessor {
     pub fn new_empty() -> Self {
-        BuiltinHintProcessor {
-            extra_hints: HashMap::new(),
-        }
+        BuiltinHintProcessor::new(HashMap::new())
     }
 
     pub fn new(extra_hints: HashMap<String, Rc<HintFunc>>) -> Self {
-        BuiltinHintProcessor { extra_hints }
+        BuiltinHintProcessor {
+            extra_hints,
+            print_writer: Rc::new(RefCell::new(std::io::stdout())),
+        }
     }
 
     pub fn add_hint(&mut self, hint_code: String, hint_func: Rc<HintFunc>) {
         self.extra_hints.insert(hint_code, hint_func);
     }
+
+    /// Sets the writer receiving the output of print hints (such as `print(ids.x)`), which
+    /// defaults to stdout
+    pub fn set_print_writer(&mut self, print_writer: Rc<RefCell<dyn Write>>) {
+        self.print_writer = print_writer;
+    }
+
+    fn get_hint_function(&self, code: &str) -> Result<HintFunction, VirtualMachineError> {
+        if let Some(hint_func) = self.extra_hints.get(code) {
+            return Ok(HintFunction::Extra(hint_func.clone()));
+        }
+        if let Some(hint_func) = get_builtin_hint_function(code) {
+            return Ok(HintFunction::Builtin(hint_func));
+        }
+        if let Some(hint_func) = get_processor_hint_function(code) {
+            return Ok(HintFunction::Processor(hint_func));
+        }
+        PrintHint::parse(code)
+            .map(|print_hint| HintFunction::Print(Rc::new(print_hint)))
+            .ok_or_else(|| VirtualMachineError::UnknownHint(code.to_string()))
+    }
+
+    fn execute_hint_function(
+        &self,
+        hint_function: &HintFunction,
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        hint_data: &HintProcessorData,
+        constants: &HashMap<String, BigInt>,
+    ) -> Result<(), VirtualMachineError> {
+        match hint_function {
+            HintFunction::Builtin(hint_func) => hint_func(vm, exec_scopes, hint_data, constants),
+            HintFunction::Processor(hint_func) => hint_func(self, vm, exec_scopes, hint_data),
+            HintFunction::Extra(hint_func) => hint_func.0(
+                vm,
+                exec_scopes,
+                &hint_data.ids_data,
+                &hint_data.ap_tracking,
+                constants,
+            ),
+            HintFunction::Print(print_hint) => print_hint.execute(
+                vm,
+                &hint_data.ids_data,
+                &hint_data.ap_tracking,
+                &mut *self.print_writer.borrow_mut(),
+            ),
+        }
+    }
 }
 
 impl HintProcessor for BuiltinHintProcessor {
@@ -122,339 +233,674 @@ impl HintProcessor for BuiltinHintProcessor {
         hint_data: &Box<dyn Any>,
         constants: &HashMap<String, BigInt>,
     ) -> Result<(), VirtualMachineError> {
+        if let Some(compiled_hint_data) = hint_data.downcast_ref::<CompiledHintData>() {
+            return self.execute_hint_function(
+                &compiled_hint_data.hint_function,
+                vm,
+                exec_scopes,
+                &compiled_hint_data.hint_data,
+                constants,
+            );
+        }
+        // Hint data built without compile_hint, resolve the hint code now
         let hint_data = hint_data
             .downcast_ref::<HintProcessorData>()
             .ok_or(VirtualMachineError::WrongHintData)?;
+        self.execute_hint_function(
+            &self.get_hint_function(&hint_data.code)?,
+            vm,
+            exec_scopes,
+            hint_data,
+            constants,
+        )
+    }
 
-        if let Some(hint_func) = self.extra_hints.get(&hint_data.code) {
-            return hint_func.0(
+    fn compile_hint(
+        &self,
+        code: &str,
+        accessible_scopes: &[String],
+        ap_tracking: &ApTracking,
+        reference_ids: &HashMap<String, usize>,
+        references: &HashMap<usize, HintReference>,
+    ) -> Result<Box<dyn Any>, VirtualMachineError> {
+        Ok(any_box!(CompiledHintData {
+            hint_function: self.get_hint_function(code)?,
+            hint_data: HintProcessorData {
+                code: code.to_string(),
+                ap_tracking: ap_tracking.clone(),
+                ids_data: get_ids_data(reference_ids, references)?,
+                accessible_scopes: accessible_scopes.to_vec(),
+            },
+        }))
+    }
+
+    fn supports_hint(&self, hint_code: &str) -> bool {
+        self.extra_hints.contains_key(hint_code)
+            || get_builtin_hint_function(hint_code).is_some()
+            || get_processor_hint_function(hint_code).is_some()
+            || PrintHint::parse(hint_code).is_some()
+    }
+}
+
+/// Resolves a block of hint code into the builtin function implementing it
+fn get_builtin_hint_function(code: &str) -> Option<BuiltinHintFunction> {
+    let hint_function: BuiltinHintFunction = match code {
+        hint_code::ADD_SEGMENT => |vm, _, _, _| add_segment(vm),
+        hint_code::IS_NN => {
+            |vm, _, hint_data, _| is_nn(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+        }
+        hint_code::IS_NN_OUT_OF_RANGE => |vm, _, hint_data, _| {
+            is_nn_out_of_range(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+        },
+        hint_code::ASSERT_LE_FELT => |vm, exec_scopes, hint_data, constants| {
+            assert_le_felt(
                 vm,
                 exec_scopes,
                 &hint_data.ids_data,
                 &hint_data.ap_tracking,
                 constants,
-            );
+            )
+        },
+        hint_code::ASSERT_LE_FELT_EXCLUDED_2 => {
+            |_, exec_scopes, _, _| assert_le_felt_excluded_2(exec_scopes)
         }
-
-        match &*hint_data.code {
-            hint_code::ADD_SEGMENT => add_segment(vm),
-            hint_code::IS_NN => is_nn(vm, &hint_data.ids_data, &hint_data.ap_tracking),
-            hint_code::IS_NN_OUT_OF_RANGE => {
-                is_nn_out_of_range(vm, &hint_data.ids_data, &hint_data.ap_tracking)
-            }
-            hint_code::ASSERT_LE_FELT => assert_le_felt(
+        hint_code::ASSERT_LE_FELT_EXCLUDED_1 => {
+            |vm, exec_scopes, _, _| assert_le_felt_excluded_1(vm, exec_scopes)
+        }
+        hint_code::ASSERT_LE_FELT_EXCLUDED_0 => {
+            |vm, exec_scopes, _, _| assert_le_felt_excluded_0(vm, exec_scopes)
+        }
+        hint_code::IS_LE_FELT => {
+            |vm, _, hint_data, _| is_le_felt(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+        }
+        hint_code::ASSERT_250_BITS => {
+            |vm, _, hint_data, _| assert_250_bit(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+        }
+        hint_code::IS_POSITIVE => {
+            |vm, _, hint_data, _| is_positive(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+        }
+        hint_code::SPLIT_INT_ASSERT_RANGE => |vm, _, hint_data, _| {
+            split_int_assert_range(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+        },
+        hint_code::SPLIT_INT => {
+            |vm, _, hint_data, _| split_int(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+        }
+        hint_code::ASSERT_NOT_EQUAL => {
+            |vm, _, hint_data, _| assert_not_equal(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+        }
+        hint_code::ASSERT_NN => {
+            |vm, _, hint_data, _| assert_nn(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+        }
+        hint_code::SQRT => {
+            |vm, _, hint_data, _| sqrt(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+        }
+        hint_code::ASSERT_NOT_ZERO => {
+            |vm, _, hint_data, _| assert_not_zero(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+        }
+        hint_code::VM_EXIT_SCOPE => |_, exec_scopes, _, _| exit_scope(exec_scopes),
+        hint_code::MEMCPY_ENTER_SCOPE => |vm, exec_scopes, hint_data, _| {
+            memcpy_enter_scope(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+        },
+        hint_code::MEMSET_ENTER_SCOPE => |vm, exec_scopes, hint_data, _| {
+            memset_enter_scope(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+        },
+        hint_code::MEMCPY_CONTINUE_COPYING => |vm, exec_scopes, hint_data, _| {
+            memcpy_continue_copying(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+        },
+        hint_code::MEMSET_CONTINUE_LOOP => |vm, exec_scopes, hint_data, _| {
+            memset_continue_loop(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+        },
+        hint_code::SPLIT_FELT => {
+            |vm, _, hint_data, _| split_felt(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+        }
+        hint_code::UNSIGNED_DIV_REM => {
+            |vm, _, hint_data, _| unsigned_div_rem(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+        }
+        hint_code::SIGNED_DIV_REM => {
+            |vm, _, hint_data, _| signed_div_rem(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+        }
+        hint_code::ASSERT_LT_FELT => {
+            |vm, _, hint_data, _| assert_lt_felt(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+        }
+        hint_code::FIND_ELEMENT => |vm, exec_scopes, hint_data, _| {
+            find_element(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+        },
+        hint_code::SEARCH_SORTED_LOWER => |vm, exec_scopes, hint_data, _| {
+            search_sorted_lower(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+        },
+        hint_code::POW => {
+            |vm, _, hint_data, _| pow(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+        }
+        hint_code::SET_ADD => {
+            |vm, _, hint_data, _| set_add(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+        }
+        hint_code::DICT_NEW => |vm, exec_scopes, _, _| dict_new(vm, exec_scopes),
+        hint_code::DICT_READ => |vm, exec_scopes, hint_data, _| {
+            dict_read(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+        },
+        hint_code::DICT_WRITE => |vm, exec_scopes, hint_data, _| {
+            dict_write(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+        },
+        hint_code::DEFAULT_DICT_NEW => |vm, exec_scopes, hint_data, _| {
+            default_dict_new(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+        },
+        hint_code::SQUASH_DICT_INNER_FIRST_ITERATION => |vm, exec_scopes, hint_data, _| {
+            squash_dict_inner_first_iteration(
                 vm,
                 exec_scopes,
                 &hint_data.ids_data,
                 &hint_data.ap_tracking,
-                constants,
-            ),
-            hint_code::ASSERT_LE_FELT_EXCLUDED_2 => assert_le_felt_excluded_2(exec_scopes),
-            hint_code::ASSERT_LE_FELT_EXCLUDED_1 => assert_le_felt_excluded_1(vm, exec_scopes),
-            hint_code::ASSERT_LE_FELT_EXCLUDED_0 => assert_le_felt_excluded_0(vm, exec_scopes),
-            hint_code::IS_LE_FELT => is_le_felt(vm, &hint_data.ids_data, &hint_data.ap_tracking),
-            hint_code::ASSERT_250_BITS => {
-                assert_250_bit(vm, &hint_data.ids_data, &hint_data.ap_tracking)
-            }
-            hint_code::IS_POSITIVE => is_positive(vm, &hint_data.ids_data, &hint_data.ap_tracking),
-            hint_code::SPLIT_INT_ASSERT_RANGE => {
-                split_int_assert_range(vm, &hint_data.ids_data, &hint_data.ap_tracking)
-            }
-            hint_code::SPLIT_INT => split_int(vm, &hint_data.ids_data, &hint_data.ap_tracking),
-            hint_code::ASSERT_NOT_EQUAL => {
-                assert_not_equal(vm, &hint_data.ids_data, &hint_data.ap_tracking)
-            }
-            hint_code::ASSERT_NN => assert_nn(vm, &hint_data.ids_data, &hint_data.ap_tracking),
-            hint_code::SQRT => sqrt(vm, &hint_data.ids_data, &hint_data.ap_tracking),
-            hint_code::ASSERT_NOT_ZERO => {
-                assert_not_zero(vm, &hint_data.ids_data, &hint_data.ap_tracking)
-            }
-            hint_code::VM_EXIT_SCOPE => exit_scope(exec_scopes),
-            hint_code::MEMCPY_ENTER_SCOPE => {
-                memcpy_enter_scope(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
-            }
-            hint_code::MEMSET_ENTER_SCOPE => {
-                memset_enter_scope(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
-            }
-            hint_code::MEMCPY_CONTINUE_COPYING => memcpy_continue_copying(
+            )
+        },
+        hint_code::USORT_ENTER_SCOPE => |_, exec_scopes, _, _| usort_enter_scope(exec_scopes),
+        hint_code::USORT_BODY => |vm, exec_scopes, hint_data, _| {
+            usort_body(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+        },
+        hint_code::USORT_VERIFY => |vm, exec_scopes, hint_data, _| {
+            verify_usort(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+        },
+        hint_code::USORT_VERIFY_MULTIPLICITY_ASSERT => {
+            |_, exec_scopes, _, _| verify_multiplicity_assert(exec_scopes)
+        }
+        hint_code::USORT_VERIFY_MULTIPLICITY_BODY => |vm, exec_scopes, hint_data, _| {
+            verify_multiplicity_body(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+        },
+        hint_code::BLAKE2S_COMPUTE => {
+            |vm, _, hint_data, _| compute_blake2s(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+        }
+        hint_code::VERIFY_ZERO => |vm, _, hint_data, constants| {
+            with_secp_curve(vm, constants, hint_data, Curve::K1, |vm, curve| {
+                verify_zero(vm, &hint_data.ids_data, &hint_data.ap_tracking, curve)
+            })
+        },
+        hint_code::NONDET_BIGINT3 => |vm, exec_scopes, hint_data, constants| {
+            nondet_bigint3(
                 vm,
                 exec_scopes,
                 &hint_data.ids_data,
                 &hint_data.ap_tracking,
-            ),
-            hint_code::MEMSET_CONTINUE_LOOP => {
-                memset_continue_loop(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
-            }
-            hint_code::SPLIT_FELT => split_felt(vm, &hint_data.ids_data, &hint_data.ap_tracking),
-            hint_code::UNSIGNED_DIV_REM => {
-                unsigned_div_rem(vm, &hint_data.ids_data, &hint_data.ap_tracking)
-            }
-            hint_code::SIGNED_DIV_REM => {
-                signed_div_rem(vm, &hint_data.ids_data, &hint_data.ap_tracking)
-            }
-            hint_code::ASSERT_LT_FELT => {
-                assert_lt_felt(vm, &hint_data.ids_data, &hint_data.ap_tracking)
-            }
-            hint_code::FIND_ELEMENT => {
-                find_element(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
-            }
-            hint_code::SEARCH_SORTED_LOWER => {
-                search_sorted_lower(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
-            }
-            hint_code::POW => pow(vm, &hint_data.ids_data, &hint_data.ap_tracking),
-            hint_code::SET_ADD => set_add(vm, &hint_data.ids_data, &hint_data.ap_tracking),
-            hint_code::DICT_NEW => dict_new(vm, exec_scopes),
-            hint_code::DICT_READ => {
-                dict_read(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
-            }
-            hint_code::DICT_WRITE => {
-                dict_write(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
-            }
-            hint_code::DEFAULT_DICT_NEW => {
-                default_dict_new(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
-            }
-            hint_code::SQUASH_DICT_INNER_FIRST_ITERATION => squash_dict_inner_first_iteration(
+                constants,
+            )
+        },
+        hint_code::REDUCE => |vm, exec_scopes, hint_data, constants| {
+            with_secp_curve(vm, constants, hint_data, Curve::K1, |vm, curve| {
+                reduce(
+                    vm,
+                    exec_scopes,
+                    &hint_data.ids_data,
+                    &hint_data.ap_tracking,
+                    curve,
+                )
+            })
+        },
+        hint_code::BLAKE2S_FINALIZE => {
+            |vm, _, hint_data, _| finalize_blake2s(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+        }
+        hint_code::BLAKE2S_ADD_UINT256 => |vm, _, hint_data, _| {
+            blake2s_add_uint256(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+        },
+        hint_code::BLAKE2S_ADD_UINT256_BIGEND => |vm, _, hint_data, _| {
+            blake2s_add_uint256_bigend(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+        },
+        hint_code::UNSAFE_KECCAK => |vm, exec_scopes, hint_data, _| {
+            unsafe_keccak(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+        },
+        hint_code::UNSAFE_KECCAK_FINALIZE => |vm, _, hint_data, _| {
+            unsafe_keccak_finalize(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+        },
+        hint_code::SQUASH_DICT_INNER_SKIP_LOOP => |vm, exec_scopes, hint_data, _| {
+            squash_dict_inner_skip_loop(
                 vm,
                 exec_scopes,
                 &hint_data.ids_data,
                 &hint_data.ap_tracking,
-            ),
-            hint_code::USORT_ENTER_SCOPE => usort_enter_scope(exec_scopes),
-            hint_code::USORT_BODY => {
-                usort_body(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
-            }
-            hint_code::USORT_VERIFY => {
-                verify_usort(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
-            }
-            hint_code::USORT_VERIFY_MULTIPLICITY_ASSERT => verify_multiplicity_assert(exec_scopes),
-            hint_code::USORT_VERIFY_MULTIPLICITY_BODY => verify_multiplicity_body(
+            )
+        },
+        hint_code::SQUASH_DICT_INNER_CHECK_ACCESS_INDEX => |vm, exec_scopes, hint_data, _| {
+            squash_dict_inner_check_access_index(
                 vm,
                 exec_scopes,
                 &hint_data.ids_data,
                 &hint_data.ap_tracking,
-            ),
-            hint_code::BLAKE2S_COMPUTE => {
-                compute_blake2s(vm, &hint_data.ids_data, &hint_data.ap_tracking)
-            }
-            hint_code::VERIFY_ZERO => {
-                verify_zero(vm, &hint_data.ids_data, &hint_data.ap_tracking, constants)
-            }
-            hint_code::NONDET_BIGINT3 => nondet_bigint3(
+            )
+        },
+        hint_code::SQUASH_DICT_INNER_CONTINUE_LOOP => |vm, exec_scopes, hint_data, _| {
+            squash_dict_inner_continue_loop(
                 vm,
                 exec_scopes,
                 &hint_data.ids_data,
                 &hint_data.ap_tracking,
-                constants,
-            ),
-            hint_code::REDUCE => reduce(
+            )
+        },
+        hint_code::SQUASH_DICT_INNER_ASSERT_LEN_KEYS => {
+            |_, exec_scopes, _, _| squash_dict_inner_assert_len_keys(exec_scopes)
+        }
+        hint_code::SQUASH_DICT_INNER_LEN_ASSERT => {
+            |_, exec_scopes, _, _| squash_dict_inner_len_assert(exec_scopes)
+        }
+        hint_code::SQUASH_DICT_INNER_USED_ACCESSES_ASSERT => |vm, exec_scopes, hint_data, _| {
+            squash_dict_inner_used_accesses_assert(
                 vm,
                 exec_scopes,
                 &hint_data.ids_data,
                 &hint_data.ap_tracking,
-                constants,
-            ),
-            hint_code::BLAKE2S_FINALIZE => {
-                finalize_blake2s(vm, &hint_data.ids_data, &hint_data.ap_tracking)
-            }
-            hint_code::BLAKE2S_ADD_UINT256 => {
-                blake2s_add_uint256(vm, &hint_data.ids_data, &hint_data.ap_tracking)
-            }
-            hint_code::BLAKE2S_ADD_UINT256_BIGEND => {
-                blake2s_add_uint256_bigend(vm, &hint_data.ids_data, &hint_data.ap_tracking)
-            }
-            hint_code::UNSAFE_KECCAK => {
-                unsafe_keccak(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
-            }
-            hint_code::UNSAFE_KECCAK_FINALIZE => {
-                unsafe_keccak_finalize(vm, &hint_data.ids_data, &hint_data.ap_tracking)
-            }
-            hint_code::SQUASH_DICT_INNER_SKIP_LOOP => squash_dict_inner_skip_loop(
+            )
+        },
+        hint_code::SQUASH_DICT_INNER_NEXT_KEY => |vm, exec_scopes, hint_data, _| {
+            squash_dict_inner_next_key(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+        },
+        hint_code::SQUASH_DICT => |vm, exec_scopes, hint_data, _| {
+            squash_dict(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+        },
+        hint_code::VM_ENTER_SCOPE => |_, exec_scopes, _, _| enter_scope(exec_scopes),
+        hint_code::DICT_UPDATE => |vm, exec_scopes, hint_data, _| {
+            dict_update(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+        },
+        hint_code::DICT_SQUASH_COPY_DICT => |vm, exec_scopes, hint_data, _| {
+            dict_squash_copy_dict(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+        },
+        hint_code::DICT_SQUASH_UPDATE_PTR => |vm, exec_scopes, hint_data, _| {
+            dict_squash_update_ptr(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+        },
+        hint_code::UINT256_ADD => {
+            |vm, _, hint_data, _| uint256_add(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+        }
+        hint_code::SPLIT_64 => {
+            |vm, _, hint_data, _| split_64(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+        }
+        hint_code::UINT256_SQRT => {
+            |vm, _, hint_data, _| uint256_sqrt(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+        }
+        hint_code::UINT256_SIGNED_NN => {
+            |vm, _, hint_data, _| uint256_signed_nn(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+        }
+        hint_code::UINT256_UNSIGNED_DIV_REM => |vm, _, hint_data, _| {
+            uint256_unsigned_div_rem(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+        },
+        hint_code::UINT256_EXPANDED_UNSIGNED_DIV_REM => |vm, _, hint_data, _| {
+            uint256_expanded_unsigned_div_rem(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+        },
+        hint_code::UINT256_MUL_DIV_MOD => |vm, _, hint_data, _| {
+            uint256_mul_div_mod(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+        },
+        hint_code::UINT256_SUB => {
+            |vm, _, hint_data, _| uint256_sub(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+        }
+        hint_code::SPLIT_XX => {
+            |vm, _, hint_data, _| split_xx(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+        }
+        hint_code::UINT384_UNSIGNED_DIV_REM => |vm, _, hint_data, _| {
+            uint384_unsigned_div_rem(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+        },
+        hint_code::UINT384_SPLIT_128 => {
+            |vm, _, hint_data, _| uint384_split_128(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+        }
+        hint_code::ADD_NO_UINT384_CHECK => |vm, _, hint_data, _| {
+            add_no_uint384_check(
                 vm,
-                exec_scopes,
                 &hint_data.ids_data,
                 &hint_data.ap_tracking,
-            ),
-            hint_code::SQUASH_DICT_INNER_CHECK_ACCESS_INDEX => {
-                squash_dict_inner_check_access_index(
+                &hint_data.accessible_scopes,
+            )
+        },
+        hint_code::UINT384_SQRT => {
+            |vm, _, hint_data, _| uint384_sqrt(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+        }
+        hint_code::UINT384_SIGNED_NN => {
+            |vm, _, hint_data, _| uint384_signed_nn(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+        }
+        hint_code::UINT384_UNSIGNED_DIV_REM_EXPANDED => |vm, _, hint_data, _| {
+            uint384_unsigned_div_rem_expanded(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+        },
+        hint_code::UNSIGNED_DIV_REM_UINT768_BY_UINT384 => |vm, _, hint_data, _| {
+            unsigned_div_rem_uint768_by_uint384(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+        },
+        hint_code::INV_MOD_P_UINT512 => {
+            |vm, _, hint_data, _| inv_mod_p_uint512(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+        }
+        hint_code::BIGINT_TO_UINT256 => |vm, _, hint_data, constants| {
+            bigint_to_uint256(vm, &hint_data.ids_data, &hint_data.ap_tracking, constants)
+        },
+        hint_code::IS_ZERO_PACK => |vm, exec_scopes, hint_data, constants| {
+            with_secp_curve(vm, constants, hint_data, Curve::K1, |vm, curve| {
+                is_zero_pack(
                     vm,
                     exec_scopes,
                     &hint_data.ids_data,
                     &hint_data.ap_tracking,
+                    curve,
                 )
-            }
-            hint_code::SQUASH_DICT_INNER_CONTINUE_LOOP => squash_dict_inner_continue_loop(
-                vm,
-                exec_scopes,
-                &hint_data.ids_data,
-                &hint_data.ap_tracking,
-            ),
-            hint_code::SQUASH_DICT_INNER_ASSERT_LEN_KEYS => {
-                squash_dict_inner_assert_len_keys(exec_scopes)
-            }
-            hint_code::SQUASH_DICT_INNER_LEN_ASSERT => squash_dict_inner_len_assert(exec_scopes),
-            hint_code::SQUASH_DICT_INNER_USED_ACCESSES_ASSERT => {
-                squash_dict_inner_used_accesses_assert(
+            })
+        },
+        hint_code::IS_ZERO_NONDET => |vm, exec_scopes, _, _| is_zero_nondet(vm, exec_scopes),
+        hint_code::IS_ZERO_ASSIGN_SCOPE_VARS => |vm, exec_scopes, hint_data, constants| {
+            with_secp_curve(vm, constants, hint_data, Curve::K1, |_, curve| {
+                is_zero_assign_scope_variables(exec_scopes, curve)
+            })
+        },
+        hint_code::DIV_MOD_N_PACKED_DIVMOD => |vm, exec_scopes, hint_data, constants| {
+            with_secp_curve(vm, constants, hint_data, Curve::K1, |vm, curve| {
+                div_mod_n_packed_divmod(
                     vm,
                     exec_scopes,
                     &hint_data.ids_data,
                     &hint_data.ap_tracking,
+                    curve,
                 )
-            }
-            hint_code::SQUASH_DICT_INNER_NEXT_KEY => squash_dict_inner_next_key(
-                vm,
-                exec_scopes,
-                &hint_data.ids_data,
-                &hint_data.ap_tracking,
-            ),
-            hint_code::SQUASH_DICT => {
-                squash_dict(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
-            }
-            hint_code::VM_ENTER_SCOPE => enter_scope(exec_scopes),
-            hint_code::DICT_UPDATE => {
-                dict_update(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
-            }
-            hint_code::DICT_SQUASH_COPY_DICT => {
-                dict_squash_copy_dict(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
-            }
-            hint_code::DICT_SQUASH_UPDATE_PTR => {
-                dict_squash_update_ptr(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
-            }
-            hint_code::UINT256_ADD => uint256_add(vm, &hint_data.ids_data, &hint_data.ap_tracking),
-            hint_code::SPLIT_64 => split_64(vm, &hint_data.ids_data, &hint_data.ap_tracking),
-            hint_code::UINT256_SQRT => {
-                uint256_sqrt(vm, &hint_data.ids_data, &hint_data.ap_tracking)
-            }
-            hint_code::UINT256_SIGNED_NN => {
-                uint256_signed_nn(vm, &hint_data.ids_data, &hint_data.ap_tracking)
-            }
-            hint_code::UINT256_UNSIGNED_DIV_REM => {
-                uint256_unsigned_div_rem(vm, &hint_data.ids_data, &hint_data.ap_tracking)
-            }
-            hint_code::BIGINT_TO_UINT256 => {
-                bigint_to_uint256(vm, &hint_data.ids_data, &hint_data.ap_tracking, constants)
-            }
-            hint_code::IS_ZERO_PACK => is_zero_pack(
-                vm,
-                exec_scopes,
-                &hint_data.ids_data,
-                &hint_data.ap_tracking,
-                constants,
-            ),
-            hint_code::IS_ZERO_NONDET => is_zero_nondet(vm, exec_scopes),
-            hint_code::IS_ZERO_ASSIGN_SCOPE_VARS => {
-                is_zero_assign_scope_variables(exec_scopes, constants)
-            }
-            hint_code::DIV_MOD_N_PACKED_DIVMOD => div_mod_n_packed_divmod(
-                vm,
-                exec_scopes,
-                &hint_data.ids_data,
-                &hint_data.ap_tracking,
-                constants,
-            ),
-            hint_code::DIV_MOD_N_SAFE_DIV => div_mod_n_safe_div(exec_scopes, constants),
-            hint_code::GET_POINT_FROM_X => get_point_from_x(
-                vm,
-                exec_scopes,
-                &hint_data.ids_data,
-                &hint_data.ap_tracking,
-                constants,
-            ),
-            hint_code::EC_NEGATE => ec_negate(
-                vm,
-                exec_scopes,
-                &hint_data.ids_data,
-                &hint_data.ap_tracking,
-                constants,
-            ),
-            hint_code::EC_DOUBLE_SCOPE => compute_doubling_slope(
-                vm,
-                exec_scopes,
-                &hint_data.ids_data,
-                &hint_data.ap_tracking,
-                constants,
-            ),
-            hint_code::COMPUTE_SLOPE => compute_slope(
-                vm,
+            })
+        },
+        hint_code::DIV_MOD_N_SAFE_DIV => |vm, exec_scopes, hint_data, constants| {
+            let identifiers = vm.get_identifiers();
+            div_mod_n_safe_div(
                 exec_scopes,
-                &hint_data.ids_data,
-                &hint_data.ap_tracking,
                 constants,
-            ),
-            hint_code::EC_DOUBLE_ASSIGN_NEW_X => ec_double_assign_new_x(
-                vm,
-                exec_scopes,
-                &hint_data.ids_data,
-                &hint_data.ap_tracking,
-                constants,
-            ),
-            hint_code::EC_DOUBLE_ASSIGN_NEW_Y => ec_double_assign_new_y(exec_scopes, constants),
-            hint_code::KECCAK_WRITE_ARGS => {
-                keccak_write_args(vm, &hint_data.ids_data, &hint_data.ap_tracking)
-            }
-            hint_code::COMPARE_BYTES_IN_WORD_NONDET => compare_bytes_in_word_nondet(
+                IdentifierManager::new(&identifiers),
+                &hint_data.accessible_scopes,
+            )
+        },
+        hint_code::GET_POINT_FROM_X => |vm, exec_scopes, hint_data, constants| {
+            with_secp_curve(vm, constants, hint_data, Curve::K1, |vm, curve| {
+                get_point_from_x(
+                    vm,
+                    exec_scopes,
+                    &hint_data.ids_data,
+                    &hint_data.ap_tracking,
+                    curve,
+                )
+            })
+        },
+        hint_code::EC_NEGATE => |vm, exec_scopes, hint_data, constants| {
+            with_secp_curve(vm, constants, hint_data, Curve::K1, |vm, curve| {
+                ec_negate(
+                    vm,
+                    exec_scopes,
+                    &hint_data.ids_data,
+                    &hint_data.ap_tracking,
+                    curve,
+                )
+            })
+        },
+        hint_code::EC_DOUBLE_SCOPE => |vm, exec_scopes, hint_data, constants| {
+            with_secp_curve(vm, constants, hint_data, Curve::K1, |vm, curve| {
+                compute_doubling_slope(
+                    vm,
+                    exec_scopes,
+                    &hint_data.ids_data,
+                    &hint_data.ap_tracking,
+                    curve,
+                )
+            })
+        },
+        hint_code::COMPUTE_SLOPE => |vm, exec_scopes, hint_data, constants| {
+            with_secp_curve(vm, constants, hint_data, Curve::K1, |vm, curve| {
+                compute_slope(
+                    vm,
+                    exec_scopes,
+                    &hint_data.ids_data,
+                    &hint_data.ap_tracking,
+                    curve,
+                )
+            })
+        },
+        hint_code::EC_DOUBLE_ASSIGN_NEW_X => |vm, exec_scopes, hint_data, constants| {
+            with_secp_curve(vm, constants, hint_data, Curve::K1, |vm, curve| {
+                ec_double_assign_new_x(
+                    vm,
+                    exec_scopes,
+                    &hint_data.ids_data,
+                    &hint_data.ap_tracking,
+                    curve,
+                )
+            })
+        },
+        hint_code::EC_DOUBLE_ASSIGN_NEW_Y => |vm, exec_scopes, hint_data, constants| {
+            with_secp_curve(vm, constants, hint_data, Curve::K1, |_, curve| {
+                ec_double_assign_new_y(exec_scopes, curve)
+            })
+        },
+        hint_code::KECCAK_WRITE_ARGS => {
+            |vm, _, hint_data, _| keccak_write_args(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+        }
+        hint_code::COMPARE_BYTES_IN_WORD_NONDET => |vm, _, hint_data, constants| {
+            compare_bytes_in_word_nondet(vm, &hint_data.ids_data, &hint_data.ap_tracking, constants)
+        },
+        hint_code::SHA256_MAIN => {
+            |vm, _, hint_data, _| sha256_main(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+        }
+        hint_code::SHA256_INPUT => {
+            |vm, _, hint_data, _| sha256_input(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+        }
+        hint_code::SHA256_FINALIZE => {
+            |vm, _, hint_data, _| sha256_finalize(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+        }
+        hint_code::COMPARE_KECCAK_FULL_RATE_IN_BYTES_NONDET => |vm, _, hint_data, constants| {
+            compare_keccak_full_rate_in_bytes_nondet(
                 vm,
                 &hint_data.ids_data,
                 &hint_data.ap_tracking,
                 constants,
-            ),
-            hint_code::SHA256_MAIN => sha256_main(vm, &hint_data.ids_data, &hint_data.ap_tracking),
-            hint_code::SHA256_INPUT => {
-                sha256_input(vm, &hint_data.ids_data, &hint_data.ap_tracking)
-            }
-            hint_code::SHA256_FINALIZE => {
-                sha256_finalize(vm, &hint_data.ids_data, &hint_data.ap_tracking)
-            }
-            hint_code::COMPARE_KECCAK_FULL_RATE_IN_BYTES_NONDET => {
-                compare_keccak_full_rate_in_bytes_nondet(
+            )
+        },
+        hint_code::BLOCK_PERMUTATION => |vm, _, hint_data, constants| {
+            block_permutation(vm, &hint_data.ids_data, &hint_data.ap_tracking, constants)
+        },
+        hint_code::CAIRO_KECCAK_FINALIZE => |vm, _, hint_data, constants| {
+            cairo_keccak_finalize(vm, &hint_data.ids_data, &hint_data.ap_tracking, constants)
+        },
+        hint_code::SPLIT_INPUT_3 => {
+            |vm, _, hint_data, _| split_input(vm, &hint_data.ids_data, &hint_data.ap_tracking, 3, 1)
+        }
+        hint_code::SPLIT_INPUT_6 => {
+            |vm, _, hint_data, _| split_input(vm, &hint_data.ids_data, &hint_data.ap_tracking, 6, 2)
+        }
+        hint_code::SPLIT_INPUT_9 => {
+            |vm, _, hint_data, _| split_input(vm, &hint_data.ids_data, &hint_data.ap_tracking, 9, 3)
+        }
+        hint_code::SPLIT_INPUT_12 => |vm, _, hint_data, _| {
+            split_input(vm, &hint_data.ids_data, &hint_data.ap_tracking, 12, 4)
+        },
+        hint_code::SPLIT_INPUT_15 => |vm, _, hint_data, _| {
+            split_input(vm, &hint_data.ids_data, &hint_data.ap_tracking, 15, 5)
+        },
+        hint_code::SPLIT_OUTPUT_0 => {
+            |vm, _, hint_data, _| split_output(vm, &hint_data.ids_data, &hint_data.ap_tracking, 0)
+        }
+        hint_code::SPLIT_OUTPUT_1 => {
+            |vm, _, hint_data, _| split_output(vm, &hint_data.ids_data, &hint_data.ap_tracking, 1)
+        }
+        hint_code::SPLIT_N_BYTES => |vm, _, hint_data, constants| {
+            split_n_bytes(vm, &hint_data.ids_data, &hint_data.ap_tracking, constants)
+        },
+        hint_code::SPLIT_OUTPUT_MID_LOW_HIGH => |vm, _, hint_data, _| {
+            split_output_mid_low_high(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+        },
+        hint_code::FAST_EC_ADD_ASSIGN_NEW_X => |vm, exec_scopes, hint_data, constants| {
+            with_secp_curve(vm, constants, hint_data, Curve::K1, |vm, curve| {
+                fast_ec_add_assign_new_x(
+                    vm,
+                    exec_scopes,
+                    &hint_data.ids_data,
+                    &hint_data.ap_tracking,
+                    curve,
+                )
+            })
+        },
+        hint_code::FAST_EC_ADD_ASSIGN_NEW_Y => |vm, exec_scopes, hint_data, constants| {
+            with_secp_curve(vm, constants, hint_data, Curve::K1, |_, curve| {
+                fast_ec_add_assign_new_y(exec_scopes, curve)
+            })
+        },
+        hint_code::EC_MUL_INNER => {
+            |vm, _, hint_data, _| ec_mul_inner(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+        }
+        hint_code::VERIFY_ZERO_SECP256R1 => |vm, _, hint_data, constants| {
+            with_secp_curve(vm, constants, hint_data, Curve::R1, |vm, curve| {
+                verify_zero(vm, &hint_data.ids_data, &hint_data.ap_tracking, curve)
+            })
+        },
+        hint_code::REDUCE_SECP256R1 => |vm, exec_scopes, hint_data, constants| {
+            with_secp_curve(vm, constants, hint_data, Curve::R1, |vm, curve| {
+                reduce(
+                    vm,
+                    exec_scopes,
+                    &hint_data.ids_data,
+                    &hint_data.ap_tracking,
+                    curve,
+                )
+            })
+        },
+        hint_code::COMPUTE_SLOPE_SECP256R1 => |vm, exec_scopes, hint_data, constants| {
+            with_secp_curve(vm, constants, hint_data, Curve::R1, |vm, curve| {
+                compute_slope(
+                    vm,
+                    exec_scopes,
+                    &hint_data.ids_data,
+                    &hint_data.ap_tracking,
+                    curve,
+                )
+            })
+        },
+        hint_code::EC_DOUBLE_SCOPE_SECP256R1 => |vm, exec_scopes, hint_data, constants| {
+            with_secp_curve(vm, constants, hint_data, Curve::R1, |vm, curve| {
+                compute_doubling_slope(
+                    vm,
+                    exec_scopes,
+                    &hint_data.ids_data,
+                    &hint_data.ap_tracking,
+                    curve,
+                )
+            })
+        },
+        hint_code::GET_POINT_FROM_X_SECP256R1 => |vm, exec_scopes, hint_data, constants| {
+            with_secp_curve(vm, constants, hint_data, Curve::R1, |vm, curve| {
+                get_point_from_x_with_alpha(
                     vm,
+                    exec_scopes,
+                    &hint_data.ids_data,
+                    &hint_data.ap_tracking,
+                    curve,
+                )
+            })
+        },
+        hint_code::DIV_MOD_N_PACKED_DIVMOD_SECP256R1 => |vm, exec_scopes, hint_data, constants| {
+            with_secp_curve(vm, constants, hint_data, Curve::R1, |vm, curve| {
+                div_mod_n_packed_divmod(
+                    vm,
+                    exec_scopes,
                     &hint_data.ids_data,
                     &hint_data.ap_tracking,
-                    constants,
+                    curve,
                 )
-            }
-            hint_code::BLOCK_PERMUTATION => {
-                block_permutation(vm, &hint_data.ids_data, &hint_data.ap_tracking, constants)
-            }
-            hint_code::CAIRO_KECCAK_FINALIZE => {
-                cairo_keccak_finalize(vm, &hint_data.ids_data, &hint_data.ap_tracking, constants)
-            }
-            hint_code::FAST_EC_ADD_ASSIGN_NEW_X => fast_ec_add_assign_new_x(
+            })
+        },
+        hint_code::EC_RECOVER_DIV_MOD_N_PACKED => |vm, exec_scopes, hint_data, _| {
+            ec_recover_divmod_n_packed(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+        },
+        hint_code::EC_RECOVER_SUB_A_B => |vm, exec_scopes, hint_data, _| {
+            ec_recover_sub_a_b(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+        },
+        hint_code::EC_RECOVER_PRODUCT_MOD => |vm, exec_scopes, hint_data, _| {
+            ec_recover_product_mod(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+        },
+        hint_code::EC_RECOVER_PRODUCT_DIV_M => {
+            |_, exec_scopes, _, _| ec_recover_product_div_m(exec_scopes)
+        }
+        hint_code::RELOCATE_SEGMENT => {
+            |vm, _, hint_data, _| relocate_segment(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+        }
+        hint_code::TEMPORARY_ARRAY => {
+            |vm, _, hint_data, _| temporary_array(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+        }
+        hint_code::ADD_ONCHAIN_DATA_PAGES => |vm, _, hint_data, _| {
+            add_onchain_data_pages(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+        },
+        hint_code::SIMPLE_BOOTLOADER_LOAD_INPUT => {
+            |_, exec_scopes, _, _| load_simple_bootloader_input(exec_scopes)
+        }
+        hint_code::SIMPLE_BOOTLOADER_SET_N_TASKS => |vm, exec_scopes, hint_data, _| {
+            set_n_tasks(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+        },
+        hint_code::SIMPLE_BOOTLOADER_INIT_FACT_TOPOLOGIES => {
+            |_, exec_scopes, _, _| init_fact_topologies(exec_scopes)
+        }
+        hint_code::SIMPLE_BOOTLOADER_LOAD_TASK => |vm, exec_scopes, hint_data, _| {
+            load_task(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+        },
+        hint_code::SIMPLE_BOOTLOADER_CONFIGURE_FACT_TOPOLOGIES => {
+            |vm, exec_scopes, _, _| configure_fact_topologies(vm, exec_scopes)
+        }
+        hint_code::EXECUTE_TASK_LOAD_PROGRAM => |vm, exec_scopes, hint_data, _| {
+            load_program(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+        },
+        hint_code::EXECUTE_TASK_VALIDATE_HASH => |vm, exec_scopes, hint_data, _| {
+            validate_program_hash(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+        },
+        hint_code::EXECUTE_TASK_ASSERT_PROGRAM_ADDRESS => |vm, exec_scopes, hint_data, _| {
+            assert_program_address(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+        },
+        hint_code::EXECUTE_TASK_APPEND_FACT_TOPOLOGIES => |vm, exec_scopes, hint_data, _| {
+            append_fact_topologies(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+        },
+        hint_code::SELECT_BUILTINS_ENTER_SCOPE => |vm, exec_scopes, hint_data, _| {
+            select_builtins_enter_scope(
                 vm,
                 exec_scopes,
                 &hint_data.ids_data,
                 &hint_data.ap_tracking,
-                constants,
-            ),
-            hint_code::FAST_EC_ADD_ASSIGN_NEW_Y => fast_ec_add_assign_new_y(exec_scopes, constants),
-            hint_code::EC_MUL_INNER => {
-                ec_mul_inner(vm, &hint_data.ids_data, &hint_data.ap_tracking)
-            }
-            hint_code::RELOCATE_SEGMENT => {
-                relocate_segment(vm, &hint_data.ids_data, &hint_data.ap_tracking)
-            }
-            hint_code::TEMPORARY_ARRAY => {
-                temporary_array(vm, &hint_data.ids_data, &hint_data.ap_tracking)
-            }
-            code => Err(VirtualMachineError::UnknownHint(code.to_string())),
-        }
-    }
+            )
+        },
+        hint_code::INNER_SELECT_BUILTINS_SELECT_BUILTIN => |vm, exec_scopes, hint_data, _| {
+            select_builtin(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+        },
+        _ => return None,
+    };
+    Some(hint_function)
+}
 
-    fn compile_hint(
-        &self,
-        code: &str,
-        ap_tracking: &ApTracking,
-        reference_ids: &HashMap<String, usize>,
-        references: &HashMap<usize, HintReference>,
-    ) -> Result<Box<dyn Any>, VirtualMachineError> {
-        Ok(any_box!(HintProcessorData {
-            code: code.to_string(),
-            ap_tracking: ap_tracking.clone(),
-            ids_data: get_ids_data(reference_ids, references)?,
-        }))
-    }
+/// Resolves a block of hint code into the builtin function implementing it, for the hints that
+/// need the hint processor
+fn get_processor_hint_function(code: &str) -> Option<ProcessorHintFunction> {
+    let hint_function: ProcessorHintFunction = match code {
+        hint_code::EXECUTE_TASK_ENTER_SCOPE => |hint_processor, vm, exec_scopes, hint_data| {
+            execute_task(
+                hint_processor,
+                vm,
+                exec_scopes,
+                &hint_data.ids_data,
+                &hint_data.ap_tracking,
+            )
+        },
+        _ => return None,
+    };
+    Some(hint_function)
+}
+
+#[derive(Clone, Copy)]
+enum Curve {
+    K1,
+    R1,
 }
 
-fn get_ids_data(
+// Runs a secp hint on the given curve, whose parameters are looked up in the program's constants
+// and in the identifiers accessible from the hint
+fn with_secp_curve(
+    vm: &mut VirtualMachine,
+    constants: &HashMap<String, BigInt>,
+    hint_data: &HintProcessorData,
+    curve: Curve,
+    hint: impl FnOnce(&mut VirtualMachine, &SecpCurve) -> Result<(), VirtualMachineError>,
+) -> Result<(), VirtualMachineError> {
+    let identifiers = vm.get_identifiers();
+    let identifier_manager = IdentifierManager::new(&identifiers);
+    let accessible_scopes = &hint_data.accessible_scopes;
+    let curve = match curve {
+        Curve::K1 => SecpCurve::Secp256k1(constants, identifier_manager, accessible_scopes),
+        Curve::R1 => SecpCurve::Secp256r1(constants, identifier_manager, accessible_scopes),
+    };
+    hint(vm, &curve)
+}
+
+pub(crate) fn get_ids_data(
     reference_ids: &HashMap<String, usize>,
     references: &HashMap<usize, HintReference>,
 ) -> Result<HashMap<String, HintReference>, VirtualMachineError> {
@@ -893,4 +1339,81 @@ mod tests {
         );
         assert_eq!(exec_scopes.data.len(), 3);
     }
+
+    #[test]
+    fn compile_unknown_hint() {
+        let hint_processor = BuiltinHintProcessor::new_empty();
+        assert!(!hint_processor.supports_hint("random_invalid_code"));
+        assert_eq!(
+            hint_processor
+                .compile_hint(
+                    "random_invalid_code",
+                    &[],
+                    &ApTracking::new(),
+                    &HashMap::new(),
+                    &HashMap::new()
+                )
+                .err(),
+            Some(VirtualMachineError::UnknownHint(
+                "random_invalid_code".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn compile_and_execute_extra_hint() {
+        let mut hint_processor = BuiltinHintProcessor::new_empty();
+        hint_processor.add_hint(
+            String::from("enter_scope_custom"),
+            Rc::new(HintFunc(Box::new(enter_scope))),
+        );
+        assert!(hint_processor.supports_hint("enter_scope_custom"));
+        let hint_data = hint_processor
+            .compile_hint(
+                "enter_scope_custom",
+                &[],
+                &ApTracking::new(),
+                &HashMap::new(),
+                &HashMap::new(),
+            )
+            .unwrap();
+        let mut vm = vm!();
+        let exec_scopes = exec_scopes_ref!();
+        assert_eq!(
+            hint_processor.execute_hint(&mut vm, exec_scopes, &hint_data, &HashMap::new()),
+            Ok(())
+        );
+        assert_eq!(exec_scopes.data.len(), 2);
+    }
+
+    #[test]
+    fn execute_hint_data_built_outside_compile_hint() {
+        let hint_processor = BuiltinHintProcessor::new_empty();
+        let hint_data = HintProcessorData {
+            code: "memory[ap] = segments.add()".to_string(),
+            ap_tracking: ApTracking::new(),
+            ids_data: HashMap::new(),
+            accessible_scopes: Vec::new(),
+        };
+        let mut vm = vm!();
+        add_segments!(vm, 1);
+        assert_eq!(
+            hint_processor.execute_hint(
+                &mut vm,
+                exec_scopes_ref!(),
+                &any_box!(hint_data),
+                &HashMap::new()
+            ),
+            Ok(())
+        );
+        check_memory![vm.memory, ((1, 0), (1, 0))];
+    }
+
+    #[test]
+    fn supports_print_hint() {
+        let hint_processor = BuiltinHintProcessor::new_empty();
+        assert!(hint_processor.supports_hint("print(ids.a)"));
+        assert!(hint_processor.supports_hint("for i in range(3):\n    print(i)"));
+        assert!(!hint_processor.supports_hint("print(ids.a)\nids.b = ids.a"));
+    }
 }
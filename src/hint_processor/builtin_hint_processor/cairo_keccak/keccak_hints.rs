@@ -1,5 +1,6 @@
 use crate::hint_processor::builtin_hint_processor::hint_utils::get_integer_from_var_name;
 use crate::hint_processor::builtin_hint_processor::hint_utils::get_ptr_from_var_name;
+use crate::hint_processor::builtin_hint_processor::hint_utils::insert_value_from_var_name;
 use crate::hint_processor::builtin_hint_processor::hint_utils::insert_value_into_ap;
 use crate::vm::vm_core::VirtualMachine;
 use crate::{
@@ -8,10 +9,11 @@ use crate::{
     vm::errors::vm_errors::VirtualMachineError,
 };
 use num_bigint::BigInt;
-use num_traits::ToPrimitive;
+use num_integer::Integer;
+use num_traits::{ToPrimitive, Zero};
 use std::borrow::Cow;
 use std::collections::HashMap;
-use std::ops::Add;
+use std::ops::{Add, Shl};
 
 // Constants in package "starkware.cairo.common.cairo_keccak.keccak".
 const BYTES_IN_WORD: &str = "starkware.cairo.common.cairo_keccak.keccak.BYTES_IN_WORD";
@@ -23,6 +25,10 @@ const KECCAK_STATE_SIZE_FELTS: &str =
 // Constants in package "starkware.cairo.common.cairo_keccak.packed_keccak".
 const BLOCK_SIZE: &str = "starkware.cairo.common.cairo_keccak.packed_keccak.BLOCK_SIZE";
 
+// Constants in package "starkware.cairo.common.builtin_keccak.keccak".
+const BUILTIN_KECCAK_BYTES_IN_WORD: &str =
+    "starkware.cairo.common.builtin_keccak.keccak.BYTES_IN_WORD";
+
 /*
 Implements hint:
     %{
@@ -232,6 +238,116 @@ pub fn cairo_keccak_finalize(
     Ok(())
 }
 
+/*
+Implements hints of the form:
+    %{ ids.high{input_key}, ids.low{input_key} = divmod(memory[ids.inputs + {input_key}], 256 ** {exponent}) %}
+for the (input_key, exponent) pairs (3, 1), (6, 2), (9, 3), (12, 4) and (15, 5)
+*/
+pub fn split_input(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+    input_key: usize,
+    exponent: u32,
+) -> Result<(), VirtualMachineError> {
+    let inputs_ptr = get_ptr_from_var_name("inputs", vm, ids_data, ap_tracking)?;
+    let input = vm.get_integer(&(inputs_ptr + input_key))?;
+    let (high, low) = input.div_mod_floor(&bigint!(256).pow(exponent));
+    insert_value_from_var_name(
+        &format!("high{}", input_key),
+        high,
+        vm,
+        ids_data,
+        ap_tracking,
+    )?;
+    insert_value_from_var_name(&format!("low{}", input_key), low, vm, ids_data, ap_tracking)
+}
+
+/*
+Implements hints of the form:
+    %{
+        ids.output{num}_low = ids.output{num} & ((1 << 128) - 1)
+        ids.output{num}_high = ids.output{num} >> 128
+    %}
+for num 0 and 1
+*/
+pub fn split_output(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+    num: u32,
+) -> Result<(), VirtualMachineError> {
+    let output_name = format!("output{}", num);
+    let output = get_integer_from_var_name(&output_name, vm, ids_data, ap_tracking)?;
+    let low = output.as_ref() & bigint!(u128::MAX);
+    let high = output.as_ref() >> 128_usize;
+    insert_value_from_var_name(
+        &format!("{}_high", output_name),
+        high,
+        vm,
+        ids_data,
+        ap_tracking,
+    )?;
+    insert_value_from_var_name(
+        &format!("{}_low", output_name),
+        low,
+        vm,
+        ids_data,
+        ap_tracking,
+    )
+}
+
+/*
+Implements hint:
+    %{ ids.n_words_to_copy, ids.n_bytes_left = divmod(ids.n_bytes, ids.BYTES_IN_WORD) %}
+*/
+pub fn split_n_bytes(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+    constants: &HashMap<String, BigInt>,
+) -> Result<(), VirtualMachineError> {
+    let n_bytes = get_integer_from_var_name("n_bytes", vm, ids_data, ap_tracking)?;
+    let bytes_in_word =
+        constants
+            .get(BUILTIN_KECCAK_BYTES_IN_WORD)
+            .ok_or(VirtualMachineError::MissingConstant(
+                BUILTIN_KECCAK_BYTES_IN_WORD,
+            ))?;
+    if bytes_in_word.is_zero() {
+        return Err(VirtualMachineError::DividedByZero);
+    }
+    let (n_words_to_copy, n_bytes_left) = n_bytes.div_mod_floor(bytes_in_word);
+    insert_value_from_var_name(
+        "n_words_to_copy",
+        n_words_to_copy,
+        vm,
+        ids_data,
+        ap_tracking,
+    )?;
+    insert_value_from_var_name("n_bytes_left", n_bytes_left, vm, ids_data, ap_tracking)
+}
+
+/*
+Implements hint:
+    %{
+        tmp, ids.output1_low = divmod(ids.output1, 256 ** 7)
+        ids.output1_high, ids.output1_mid = divmod(tmp, 2 ** 128)
+    %}
+*/
+pub fn split_output_mid_low_high(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), VirtualMachineError> {
+    let output1 = get_integer_from_var_name("output1", vm, ids_data, ap_tracking)?;
+    let (tmp, output1_low) = output1.div_mod_floor(&bigint!(256).pow(7));
+    let (output1_high, output1_mid) = tmp.div_mod_floor(&bigint!(1).shl(128_usize));
+    insert_value_from_var_name("output1_high", output1_high, vm, ids_data, ap_tracking)?;
+    insert_value_from_var_name("output1_mid", output1_mid, vm, ids_data, ap_tracking)?;
+    insert_value_from_var_name("output1_low", output1_low, vm, ids_data, ap_tracking)
+}
+
 // Helper function to transform a vector of MaybeRelocatables into a vector
 // of u64. Raises error if there are None's or if MaybeRelocatables are not Bigints.
 pub(crate) fn maybe_reloc_vec_to_u64_array(
@@ -263,6 +379,7 @@ mod tests {
     use crate::any_box;
     use crate::hint_processor::builtin_hint_processor::builtin_hint_processor_definition::BuiltinHintProcessor;
     use crate::hint_processor::builtin_hint_processor::builtin_hint_processor_definition::HintProcessorData;
+    use crate::hint_processor::builtin_hint_processor::hint_code;
     use crate::hint_processor::hint_processor_definition::HintProcessor;
     use crate::hint_processor::hint_processor_definition::HintReference;
     use crate::types::exec_scope::ExecutionScopes;
@@ -275,6 +392,8 @@ mod tests {
     use num_bigint::{BigInt, Sign};
     use std::any::Any;
 
+    from_bigint_str![56];
+
     #[test]
     fn keccak_write_args_valid_test() {
         let hint_code = "segments.write_arg(ids.inputs, [ids.low % 2 ** 64, ids.low // 2 ** 64])\nsegments.write_arg(ids.inputs + 2, [ids.high % 2 ** 64, ids.high // 2 ** 64])";
@@ -385,4 +504,104 @@ mod tests {
             Ok(())
         );
     }
+
+    #[test]
+    fn split_input_3_valid() {
+        let mut vm = vm_with_range_check!();
+        vm.memory = memory![((1, 0), (2, 0)), ((2, 3), 300)];
+        vm.run_context.fp = 3;
+        let ids_data = ids_data!["inputs", "high3", "low3"];
+        assert_eq!(run_hint!(vm, ids_data, hint_code::SPLIT_INPUT_3), Ok(()));
+        check_memory![&vm.memory, ((1, 1), 1), ((1, 2), 44)];
+    }
+
+    #[test]
+    fn split_input_15_valid() {
+        let mut vm = vm_with_range_check!();
+        vm.memory = memory![((1, 0), (2, 0)), ((2, 15), 7696581406777_u64)];
+        vm.run_context.fp = 3;
+        let ids_data = ids_data!["inputs", "high15", "low15"];
+        assert_eq!(run_hint!(vm, ids_data, hint_code::SPLIT_INPUT_15), Ok(()));
+        check_memory![&vm.memory, ((1, 1), 7), ((1, 2), 12345)];
+    }
+
+    #[test]
+    fn split_input_missing_input() {
+        let mut vm = vm_with_range_check!();
+        vm.memory = memory![((1, 0), (2, 0)), ((2, 0), 300)];
+        vm.run_context.fp = 3;
+        let ids_data = ids_data!["inputs", "high6", "low6"];
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::SPLIT_INPUT_6),
+            Err(VirtualMachineError::ExpectedInteger(
+                MaybeRelocatable::from((2, 6))
+            ))
+        );
+    }
+
+    #[test]
+    fn split_output_0_valid() {
+        let mut vm = vm_with_range_check!();
+        vm.memory = memory![((1, 0), (b"1701411834604692317316873037158841057289", 10))];
+        vm.run_context.fp = 3;
+        let ids_data = ids_data!["output0", "output0_low", "output0_high"];
+        assert_eq!(run_hint!(vm, ids_data, hint_code::SPLIT_OUTPUT_0), Ok(()));
+        check_memory![&vm.memory, ((1, 1), 9), ((1, 2), 5)];
+    }
+
+    #[test]
+    fn split_n_bytes_valid() {
+        let mut vm = vm_with_range_check!();
+        vm.memory = memory![((1, 0), 19)];
+        vm.run_context.fp = 3;
+        let ids_data = ids_data!["n_bytes", "n_words_to_copy", "n_bytes_left"];
+        assert_eq!(
+            run_hint!(
+                vm,
+                ids_data,
+                hint_code::SPLIT_N_BYTES,
+                exec_scopes_ref!(),
+                &[(BUILTIN_KECCAK_BYTES_IN_WORD, bigint!(8))]
+                    .into_iter()
+                    .map(|(k, v)| (k.to_string(), v))
+                    .collect()
+            ),
+            Ok(())
+        );
+        check_memory![&vm.memory, ((1, 1), 2), ((1, 2), 3)];
+    }
+
+    #[test]
+    fn split_n_bytes_missing_constant() {
+        let mut vm = vm_with_range_check!();
+        vm.memory = memory![((1, 0), 19)];
+        vm.run_context.fp = 3;
+        let ids_data = ids_data!["n_bytes", "n_words_to_copy", "n_bytes_left"];
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::SPLIT_N_BYTES),
+            Err(VirtualMachineError::MissingConstant(
+                BUILTIN_KECCAK_BYTES_IN_WORD
+            ))
+        );
+    }
+
+    #[test]
+    fn split_output_mid_low_high_valid() {
+        let mut vm = vm_with_range_check!();
+        //output1 = 3 * 2 ** 184 + 5 * 2 ** 56 + 7
+        vm.memory = memory![(
+            (1, 0),
+            (
+                b"73559785961562665201200657303214840814059765835002544135",
+                10
+            )
+        )];
+        vm.run_context.fp = 4;
+        let ids_data = ids_data!["output1", "output1_low", "output1_mid", "output1_high"];
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::SPLIT_OUTPUT_MID_LOW_HIGH),
+            Ok(())
+        );
+        check_memory![&vm.memory, ((1, 1), 7), ((1, 2), 5), ((1, 3), 3)];
+    }
 }
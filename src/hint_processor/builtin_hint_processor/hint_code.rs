@@ -269,6 +269,145 @@ pub(crate) const DICT_SQUASH_UPDATE_PTR: &str = r#"# Update the DictTracker's cu
 __dict_manager.get_tracker(ids.squashed_dict_start).current_ptr = \
     ids.squashed_dict_end.address_"#;
 
+pub(crate) const UINT384_UNSIGNED_DIV_REM: &str = r#"def split(num: int, num_bits_shift: int, length: int):
+    a = []
+    for _ in range(length):
+        a.append( num & ((1 << num_bits_shift) - 1) )
+        num = num >> num_bits_shift
+    return tuple(a)
+
+def pack(z, num_bits_shift: int) -> int:
+    limbs = (z.d0, z.d1, z.d2)
+    return sum(limb << (num_bits_shift * i) for i, limb in enumerate(limbs))
+
+a = pack(ids.a, num_bits_shift = 128)
+div = pack(ids.div, num_bits_shift = 128)
+quotient, remainder = divmod(a, div)
+
+quotient_split = split(quotient, num_bits_shift=128, length=3)
+assert len(quotient_split) == 3
+
+ids.quotient.d0 = quotient_split[0]
+ids.quotient.d1 = quotient_split[1]
+ids.quotient.d2 = quotient_split[2]
+
+remainder_split = split(remainder, num_bits_shift=128, length=3)
+ids.remainder.d0 = remainder_split[0]
+ids.remainder.d1 = remainder_split[1]
+ids.remainder.d2 = remainder_split[2]"#;
+
+pub(crate) const UINT384_SPLIT_128: &str = r#"ids.low = ids.a & ((1<<128) - 1)
+ids.high = ids.a >> 128"#;
+
+pub(crate) const ADD_NO_UINT384_CHECK: &str = r#"sum_d0 = ids.a.d0 + ids.b.d0
+ids.carry_d0 = 1 if sum_d0 >= ids.SHIFT else 0
+sum_d1 = ids.a.d1 + ids.b.d1 + ids.carry_d0
+ids.carry_d1 = 1 if sum_d1 >= ids.SHIFT else 0
+sum_d2 = ids.a.d2 + ids.b.d2 + ids.carry_d1
+ids.carry_d2 = 1 if sum_d2 >= ids.SHIFT else 0"#;
+
+pub(crate) const UINT384_SQRT: &str = r#"from starkware.python.math_utils import isqrt
+
+def split(num: int, num_bits_shift: int, length: int):
+    a = []
+    for _ in range(length):
+        a.append( num & ((1 << num_bits_shift) - 1) )
+        num = num >> num_bits_shift
+    return tuple(a)
+
+def pack(z, num_bits_shift: int) -> int:
+    limbs = (z.d0, z.d1, z.d2)
+    return sum(limb << (num_bits_shift * i) for i, limb in enumerate(limbs))
+
+a = pack(ids.a, num_bits_shift=128)
+root = isqrt(a)
+assert 0 <= root < 2 ** 192
+root_split = split(root, num_bits_shift=128, length=3)
+ids.root.d0 = root_split[0]
+ids.root.d1 = root_split[1]
+ids.root.d2 = root_split[2]"#;
+
+pub(crate) const UINT384_SIGNED_NN: &str =
+    r#"memory[ap] = 1 if 0 <= (ids.a.d2 % PRIME) < 2 ** 127 else 0"#;
+
+pub(crate) const UINT384_UNSIGNED_DIV_REM_EXPANDED: &str = r#"def split(num: int, num_bits_shift: int, length: int):
+    a = []
+    for _ in range(length):
+        a.append( num & ((1 << num_bits_shift) - 1) )
+        num = num >> num_bits_shift
+    return tuple(a)
+
+def pack(z, num_bits_shift: int) -> int:
+    limbs = (z.d0, z.d1, z.d2)
+    return sum(limb << (num_bits_shift * i) for i, limb in enumerate(limbs))
+
+def pack2(z, num_bits_shift: int) -> int:
+    limbs = (z.b01, z.b23, z.b45)
+    return sum(limb << (num_bits_shift * i) for i, limb in enumerate(limbs))
+
+a = pack(ids.a, num_bits_shift = 128)
+div = pack2(ids.div, num_bits_shift = 128)
+quotient, remainder = divmod(a, div)
+
+quotient_split = split(quotient, num_bits_shift=128, length=3)
+assert len(quotient_split) == 3
+
+ids.quotient.d0 = quotient_split[0]
+ids.quotient.d1 = quotient_split[1]
+ids.quotient.d2 = quotient_split[2]
+
+remainder_split = split(remainder, num_bits_shift=128, length=3)
+ids.remainder.d0 = remainder_split[0]
+ids.remainder.d1 = remainder_split[1]
+ids.remainder.d2 = remainder_split[2]"#;
+
+pub(crate) const UNSIGNED_DIV_REM_UINT768_BY_UINT384: &str = r#"def split(num: int, num_bits_shift: int, length: int):
+    a = []
+    for _ in range(length):
+        a.append( num & ((1 << num_bits_shift) - 1) )
+        num = num >> num_bits_shift
+    return tuple(a)
+
+def pack(z, num_bits_shift: int) -> int:
+    limbs = (z.d0, z.d1, z.d2)
+    return sum(limb << (num_bits_shift * i) for i, limb in enumerate(limbs))
+
+def pack_extended(z, num_bits_shift: int) -> int:
+    limbs = (z.d0, z.d1, z.d2, z.d3, z.d4, z.d5)
+    return sum(limb << (num_bits_shift * i) for i, limb in enumerate(limbs))
+
+a = pack_extended(ids.a, num_bits_shift = 128)
+div = pack(ids.div, num_bits_shift = 128)
+
+quotient, remainder = divmod(a, div)
+
+quotient_split = split(quotient, num_bits_shift=128, length=6)
+
+ids.quotient.d0 = quotient_split[0]
+ids.quotient.d1 = quotient_split[1]
+ids.quotient.d2 = quotient_split[2]
+ids.quotient.d3 = quotient_split[3]
+ids.quotient.d4 = quotient_split[4]
+ids.quotient.d5 = quotient_split[5]
+
+remainder_split = split(remainder, num_bits_shift=128, length=3)
+ids.remainder.d0 = remainder_split[0]
+ids.remainder.d1 = remainder_split[1]
+ids.remainder.d2 = remainder_split[2]"#;
+
+pub(crate) const INV_MOD_P_UINT512: &str = r#"def pack_512(u, num_bits_shift: int) -> int:
+    limbs = (u.d0, u.d1, u.d2, u.d3)
+    return sum(limb << (num_bits_shift * i) for i, limb in enumerate(limbs))
+
+x = pack_512(ids.x, num_bits_shift = 128)
+p = ids.p.low + (ids.p.high << 128)
+x_inverse_mod_p = pow(x,-1, p)
+
+x_inverse_mod_p_split = (x_inverse_mod_p & ((1 << 128) - 1), x_inverse_mod_p >> 128)
+
+ids.x_inverse_mod_p.low = x_inverse_mod_p_split[0]
+ids.x_inverse_mod_p.high = x_inverse_mod_p_split[1]"#;
+
 pub(crate) const BIGINT_TO_UINT256: &str =
     "ids.low = (ids.x.d0 + ids.x.d1 * ids.BASE) & ((1 << 128) - 1)";
 pub(crate) const UINT256_ADD: &str = r#"sum_low = ids.a.low + ids.b.low
@@ -295,6 +434,57 @@ ids.quotient.high = quotient >> 128
 ids.remainder.low = remainder & ((1 << 128) - 1)
 ids.remainder.high = remainder >> 128"#;
 
+pub(crate) const UINT256_EXPANDED_UNSIGNED_DIV_REM: &str = r#"a = (ids.a.high << 128) + ids.a.low
+div = (ids.div.b23 << 128) + ids.div.b01
+quotient, remainder = divmod(a, div)
+
+ids.quotient.low = quotient & ((1 << 128) - 1)
+ids.quotient.high = quotient >> 128
+ids.remainder.low = remainder & ((1 << 128) - 1)
+ids.remainder.high = remainder >> 128"#;
+
+pub(crate) const UINT256_MUL_DIV_MOD: &str = r#"a = (ids.a.high << 128) + ids.a.low
+b = (ids.b.high << 128) + ids.b.low
+div = (ids.div.high << 128) + ids.div.low
+quotient, remainder = divmod(a * b, div)
+
+ids.quotient_low.low = quotient & ((1 << 128) - 1)
+ids.quotient_low.high = (quotient >> 128) & ((1 << 128) - 1)
+ids.quotient_high.low = (quotient >> 256) & ((1 << 128) - 1)
+ids.quotient_high.high = quotient >> 384
+ids.remainder.low = remainder & ((1 << 128) - 1)
+ids.remainder.high = remainder >> 128"#;
+
+pub(crate) const UINT256_SUB: &str = r#"def split(num: int, num_bits_shift: int = 128, length: int = 2):
+    a = []
+    for _ in range(length):
+        a.append( num & ((1 << num_bits_shift) - 1) )
+        num = num >> num_bits_shift
+    return tuple(a)
+
+def pack(z, num_bits_shift: int = 128) -> int:
+    limbs = (z.low, z.high)
+    return sum(limb << (num_bits_shift * i) for i, limb in enumerate(limbs))
+
+a = pack(ids.a)
+b = pack(ids.b)
+res = (a - b)%2**256
+res_split = split(res)
+ids.res.low = res_split[0]
+ids.res.high = res_split[1]"#;
+
+pub(crate) const SPLIT_XX: &str = r#"PRIME = 2**255 - 19
+II = pow(2, (PRIME - 1) // 4, PRIME)
+
+xx = ids.xx.low + (ids.xx.high<<128)
+x = pow(xx, (PRIME + 3) // 8, PRIME)
+if (x * x - xx) % PRIME != 0:
+    x = (x * II) % PRIME
+if x % 2 != 0:
+    x = PRIME - x
+ids.x.low = x & ((1<<128)-1)
+ids.x.high = x >> 128"#;
+
 pub(crate) const USORT_ENTER_SCOPE: &str =
     "vm_enter_scope(dict(__usort_max_size = globals().get('__usort_max_size')))";
 pub(crate) const USORT_BODY: &str = r#"from collections import defaultdict
@@ -498,6 +688,33 @@ segments.write_arg(ids.sha256_ptr_end, padding)"#;
 pub(crate) const KECCAK_WRITE_ARGS: &str = r#"segments.write_arg(ids.inputs, [ids.low % 2 ** 64, ids.low // 2 ** 64])
 segments.write_arg(ids.inputs + 2, [ids.high % 2 ** 64, ids.high // 2 ** 64])"#;
 
+pub(crate) const SPLIT_INPUT_3: &str =
+    r#"ids.high3, ids.low3 = divmod(memory[ids.inputs + 3], 256)"#;
+
+pub(crate) const SPLIT_INPUT_6: &str =
+    r#"ids.high6, ids.low6 = divmod(memory[ids.inputs + 6], 256 ** 2)"#;
+
+pub(crate) const SPLIT_INPUT_9: &str =
+    r#"ids.high9, ids.low9 = divmod(memory[ids.inputs + 9], 256 ** 3)"#;
+
+pub(crate) const SPLIT_INPUT_12: &str =
+    r#"ids.high12, ids.low12 = divmod(memory[ids.inputs + 12], 256 ** 4)"#;
+
+pub(crate) const SPLIT_INPUT_15: &str =
+    r#"ids.high15, ids.low15 = divmod(memory[ids.inputs + 15], 256 ** 5)"#;
+
+pub(crate) const SPLIT_OUTPUT_0: &str = r#"ids.output0_low = ids.output0 & ((1 << 128) - 1)
+ids.output0_high = ids.output0 >> 128"#;
+
+pub(crate) const SPLIT_OUTPUT_1: &str = r#"ids.output1_low = ids.output1 & ((1 << 128) - 1)
+ids.output1_high = ids.output1 >> 128"#;
+
+pub(crate) const SPLIT_N_BYTES: &str =
+    r#"ids.n_words_to_copy, ids.n_bytes_left = divmod(ids.n_bytes, ids.BYTES_IN_WORD)"#;
+
+pub(crate) const SPLIT_OUTPUT_MID_LOW_HIGH: &str = r#"tmp, ids.output1_low = divmod(ids.output1, 256 ** 7)
+ids.output1_high, ids.output1_mid = divmod(tmp, 2 ** 128)"#;
+
 pub(crate) const COMPARE_BYTES_IN_WORD_NONDET: &str =
     r#"memory[ap] = to_felt_or_relocatable(ids.n_bytes < ids.BYTES_IN_WORD)"#;
 
@@ -535,7 +752,231 @@ pub(crate) const FAST_EC_ADD_ASSIGN_NEW_Y: &str =
 
 pub(crate) const EC_MUL_INNER: &str = r#"memory[ap] = (ids.scalar % PRIME) % 2"#;
 
+pub(crate) const VERIFY_ZERO_SECP256R1: &str = r#"from starkware.cairo.common.cairo_secp.secp256r1_utils import SECP256R1_P as SECP_P
+from starkware.cairo.common.cairo_secp.secp_utils import pack
+
+q, r = divmod(pack(ids.val, PRIME), SECP_P)
+assert r == 0, f"verify_zero: Invalid input {ids.val.d0, ids.val.d1, ids.val.d2}."
+ids.q = q % PRIME"#;
+
+pub(crate) const REDUCE_SECP256R1: &str = r#"from starkware.cairo.common.cairo_secp.secp256r1_utils import SECP256R1_P as SECP_P
+from starkware.cairo.common.cairo_secp.secp_utils import pack
+
+value = pack(ids.x, PRIME) % SECP_P"#;
+
+pub(crate) const COMPUTE_SLOPE_SECP256R1: &str = r#"from starkware.cairo.common.cairo_secp.secp256r1_utils import SECP256R1_P as SECP_P
+from starkware.cairo.common.cairo_secp.secp_utils import pack
+from starkware.python.math_utils import line_slope
+
+# Compute the slope.
+x0 = pack(ids.point0.x, PRIME)
+y0 = pack(ids.point0.y, PRIME)
+x1 = pack(ids.point1.x, PRIME)
+y1 = pack(ids.point1.y, PRIME)
+value = slope = line_slope(point1=(x0, y0), point2=(x1, y1), p=SECP_P)"#;
+
+pub(crate) const EC_DOUBLE_SCOPE_SECP256R1: &str = r#"from starkware.cairo.common.cairo_secp.secp256r1_utils import SECP256R1_ALPHA as ALPHA
+from starkware.cairo.common.cairo_secp.secp256r1_utils import SECP256R1_P as SECP_P
+from starkware.cairo.common.cairo_secp.secp_utils import pack
+from starkware.python.math_utils import ec_double_slope
+
+# Compute the slope.
+x = pack(ids.point.x, PRIME)
+y = pack(ids.point.y, PRIME)
+value = slope = ec_double_slope(point=(x, y), alpha=ALPHA, p=SECP_P)"#;
+
+pub(crate) const GET_POINT_FROM_X_SECP256R1: &str = r#"from starkware.cairo.common.cairo_secp.secp256r1_utils import SECP256R1_ALPHA as ALPHA
+from starkware.cairo.common.cairo_secp.secp256r1_utils import SECP256R1_B as BETA
+from starkware.cairo.common.cairo_secp.secp256r1_utils import SECP256R1_P as SECP_P
+from starkware.cairo.common.cairo_secp.secp_utils import pack
+from starkware.python.math_utils import y_squared_from_x
+
+x = pack(ids.x, PRIME) % SECP_P
+y_square_int = y_squared_from_x(x=x, alpha=ALPHA, beta=BETA, field_prime=SECP_P)
+y = pow(y_square_int, (SECP_P + 1) // 4, SECP_P)
+
+# We need to decide whether to take y or SECP_P - y.
+if ids.v % 2 == y % 2:
+    value = y
+else:
+    value = (-y) % SECP_P"#;
+
+pub(crate) const DIV_MOD_N_PACKED_DIVMOD_SECP256R1: &str = r#"from starkware.cairo.common.cairo_secp.secp256r1_utils import SECP256R1_N as N
+from starkware.cairo.common.cairo_secp.secp_utils import pack
+from starkware.python.math_utils import div_mod, safe_div
+
+a = pack(ids.a, PRIME)
+b = pack(ids.b, PRIME)
+value = res = div_mod(a, b, N)"#;
+
+pub(crate) const EC_RECOVER_DIV_MOD_N_PACKED: &str = r#"from starkware.cairo.common.cairo_secp.secp_utils import pack
+from starkware.python.math_utils import div_mod, safe_div
+
+N = pack(ids.n, PRIME)
+x = pack(ids.x, PRIME) % N
+s = pack(ids.s, PRIME) % N
+value = res = div_mod(x, s, N)"#;
+
+pub(crate) const EC_RECOVER_SUB_A_B: &str = r#"from starkware.cairo.common.cairo_secp.secp_utils import pack
+from starkware.python.math_utils import div_mod, safe_div
+
+a = pack(ids.a, PRIME)
+b = pack(ids.b, PRIME)
+
+value = res = a - b"#;
+
+pub(crate) const EC_RECOVER_PRODUCT_MOD: &str = r#"from starkware.cairo.common.cairo_secp.secp_utils import pack
+from starkware.python.math_utils import div_mod, safe_div
+
+a = pack(ids.a, PRIME)
+b = pack(ids.b, PRIME)
+product = a * b
+m = pack(ids.m, PRIME)
+
+value = res = product % m"#;
+
+pub(crate) const EC_RECOVER_PRODUCT_DIV_M: &str = r#"value = k = product // m"#;
+
 pub(crate) const RELOCATE_SEGMENT: &str =
     r#"memory.add_relocation_rule(src_ptr=ids.src_ptr, dest_ptr=ids.dest_ptr)"#;
 
 pub(crate) const TEMPORARY_ARRAY: &str = r#"ids.temporary_array = segments.add_temp_segment()"#;
+
+pub(crate) const ADD_ONCHAIN_DATA_PAGES: &str = r#"from starkware.python.math_utils import div_ceil
+onchain_data_start = ids.da_start
+onchain_data_size = ids.output_ptr - onchain_data_start
+
+max_page_size = 3800
+n_pages = div_ceil(onchain_data_size, max_page_size)
+for i in range(n_pages):
+    start_offset = i * max_page_size
+    output_builtin.add_page(
+        page_id=1 + i,
+        page_start=onchain_data_start + start_offset,
+        page_size=min(onchain_data_size - start_offset, max_page_size),
+    )
+# Set the tree structure to a root with two children:
+# * A leaf which represents the main part
+# * An inner node for the onchain data part (which contains n_pages children).
+#
+# This is encoded using the following sequence:
+output_builtin.add_attribute('gps_fact_topology', [
+    # Push 1 + n_pages pages (all of the pages).
+    1 + n_pages,
+    # Create a parent node for the last n_pages.
+    n_pages,
+    # Don't push additional pages.
+    0,
+    # Take the first page (the main part) and the node that was created (onchain data)
+    # and use them to construct the root of the fact tree.
+    2,
+])"#;
+
+pub(crate) const SIMPLE_BOOTLOADER_LOAD_INPUT: &str = r#"from starkware.cairo.bootloaders.simple_bootloader.objects import SimpleBootloaderInput
+simple_bootloader_input = SimpleBootloaderInput.Schema().load(program_input)"#;
+
+pub(crate) const SIMPLE_BOOTLOADER_SET_N_TASKS: &str =
+    r#"ids.n_tasks = len(simple_bootloader_input.tasks)"#;
+
+pub(crate) const SIMPLE_BOOTLOADER_INIT_FACT_TOPOLOGIES: &str = r#"fact_topologies = []"#;
+
+pub(crate) const SIMPLE_BOOTLOADER_LOAD_TASK: &str = r#"from starkware.cairo.bootloaders.simple_bootloader.objects import Task
+
+# Pass current task to execute_task.
+task_id = len(simple_bootloader_input.tasks) - ids.n_tasks
+task = simple_bootloader_input.tasks[task_id].load_task()"#;
+
+pub(crate) const SIMPLE_BOOTLOADER_CONFIGURE_FACT_TOPOLOGIES: &str = r#"# Dump fact topologies to a json file.
+from starkware.cairo.bootloaders.simple_bootloader.utils import (
+    configure_fact_topologies,
+    write_to_fact_topologies_file,
+)
+
+# The task-related output is prefixed by a single word that contains the number of tasks.
+tasks_output_start = output_builtin.base + 1
+
+if not simple_bootloader_input.single_page:
+    # Configure the memory pages in the output builtin, based on fact_topologies.
+    configure_fact_topologies(
+        fact_topologies=fact_topologies, output_start=tasks_output_start,
+        output_builtin=output_builtin,
+    )
+
+if simple_bootloader_input.fact_topologies_path is not None:
+    write_to_fact_topologies_file(
+        fact_topologies_path=simple_bootloader_input.fact_topologies_path,
+        fact_topologies=fact_topologies,
+    )"#;
+
+pub(crate) const EXECUTE_TASK_LOAD_PROGRAM: &str = r#"from starkware.cairo.bootloaders.simple_bootloader.utils import load_program
+
+# Call load_program to load the program header and code to memory.
+program_address, program_data_size = load_program(
+    task=task, memory=memory, program_header=ids.program_header,
+    builtins_offset=ids.ProgramHeader.builtin_list)
+segments.finalize(program_address.segment_index, program_data_size)"#;
+
+pub(crate) const EXECUTE_TASK_VALIDATE_HASH: &str = r#"# Validate hash.
+from starkware.cairo.bootloaders.hash_program import compute_program_hash_chain
+
+assert memory[ids.output_ptr + 1] == compute_program_hash_chain(task.get_program()), \
+  'Computed hash does not match input.'"#;
+
+pub(crate) const EXECUTE_TASK_ENTER_SCOPE: &str = r#"from starkware.cairo.bootloaders.simple_bootloader.objects import (
+    CairoPieTask,
+    RunProgramTask,
+    Task,
+)
+from starkware.cairo.bootloaders.simple_bootloader.utils import (
+    load_cairo_pie,
+    prepare_output_runner,
+)
+
+assert isinstance(task, Task)
+n_builtins = len(task.get_program().builtins)
+new_task_locals = {}
+if isinstance(task, RunProgramTask):
+    new_task_locals['program_input'] = task.program_input
+    new_task_locals['WITH_BOOTLOADER'] = True
+
+    vm_load_program(task.program, program_address)
+elif isinstance(task, CairoPieTask):
+    ret_pc = ids.ret_pc_label.instruction_offset_ - ids.call_task.instruction_offset_ + pc
+    load_cairo_pie(
+        task=task.cairo_pie, memory=memory, segments=segments,
+        program_address=program_address, execution_segment_address= ap - n_builtins,
+        builtin_runners=builtin_runners, ret_fp=fp, ret_pc=ret_pc)
+else:
+    raise NotImplementedError(f'Unexpected task type: {type(task).__name__}.')
+
+output_runner_data = prepare_output_runner(
+    task=task,
+    output_builtin=output_builtin,
+    output_ptr=ids.pre_execution_builtin_ptrs.output)
+vm_enter_scope(new_task_locals)"#;
+
+pub(crate) const EXECUTE_TASK_ASSERT_PROGRAM_ADDRESS: &str = r#"# Sanity check.
+assert ids.program_address == program_address"#;
+
+pub(crate) const EXECUTE_TASK_APPEND_FACT_TOPOLOGIES: &str = r#"from starkware.cairo.bootloaders.simple_bootloader.utils import get_task_fact_topology
+
+# Add the fact topology of the current task to 'fact_topologies'.
+output_start = ids.pre_execution_builtin_ptrs.output
+output_end = ids.return_builtin_ptrs.output
+fact_topologies.append(get_task_fact_topology(
+    output_size=output_end - output_start,
+    task=task,
+    output_builder=output_builtin,
+    output_runner_data=output_runner_data,
+))"#;
+
+pub(crate) const SELECT_BUILTINS_ENTER_SCOPE: &str =
+    r#"vm_enter_scope({'n_selected_builtins': ids.n_selected_builtins})"#;
+
+pub(crate) const INNER_SELECT_BUILTINS_SELECT_BUILTIN: &str = r#"# A builtin should be selected iff its encoding appears in the selected encodings list
+# and the list wasn't exhausted.
+# Note that testing inclusion by a single comparison is possible since the lists are sorted.
+ids.select_builtin = int(
+  n_selected_builtins > 0 and memory[ids.selected_encodings] == memory[ids.all_encodings])
+if ids.select_builtin:
+  n_selected_builtins = n_selected_builtins - 1"#;
@@ -1,8 +1,9 @@
+use crate::hint_processor::cairo_struct::{get_member_addr, is_struct_type, CairoStruct};
 use crate::hint_processor::hint_processor_definition::HintReference;
 use crate::hint_processor::hint_processor_utils::bigint_to_usize;
 use crate::hint_processor::hint_processor_utils::compute_addr_from_reference;
 use crate::hint_processor::hint_processor_utils::get_integer_from_reference;
-use crate::serde::deserialize_program::ApTracking;
+use crate::serde::deserialize_program::{ApTracking, Member};
 use crate::types::relocatable::MaybeRelocatable;
 use crate::types::relocatable::Relocatable;
 use crate::vm::errors::vm_errors::VirtualMachineError;
@@ -110,6 +111,61 @@ pub fn get_reference_from_var_name<'a>(
         .ok_or(VirtualMachineError::FailedToGetIds)
 }
 
+//Reads the struct held by the given ids variable (or pointed to by it, if the variable is a
+//pointer), using the struct members resolved from the program identifiers
+pub fn get_struct_from_var_name<T: CairoStruct>(
+    var_name: &str,
+    vm: &VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<T, VirtualMachineError> {
+    let hint_reference = get_reference_from_var_name(var_name, ids_data)?;
+    if let Some(cairo_type) = &hint_reference.cairo_type {
+        if !is_struct_type(cairo_type, T::CAIRO_TYPE) {
+            return Err(VirtualMachineError::UnexpectedStructType(
+                var_name.to_string(),
+                T::CAIRO_TYPE,
+                cairo_type.clone(),
+            ));
+        }
+    }
+    let (base, members) = get_struct_base_from_var_name(var_name, vm, ids_data, ap_tracking)?;
+    T::from_memory(vm, var_name, &base, members)
+}
+
+//Returns the address of a member of the struct held by (or pointed to by) the given ids variable
+pub fn get_member_addr_from_var_name(
+    var_name: &str,
+    member_name: &str,
+    vm: &VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<Relocatable, VirtualMachineError> {
+    let (base, members) = get_struct_base_from_var_name(var_name, vm, ids_data, ap_tracking)?;
+    get_member_addr(var_name, &base, members, member_name)
+}
+
+fn get_struct_base_from_var_name<'a>(
+    var_name: &str,
+    vm: &VirtualMachine,
+    ids_data: &'a HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(Relocatable, &'a HashMap<String, Member>), VirtualMachineError> {
+    let hint_reference = get_reference_from_var_name(var_name, ids_data)?;
+    let members = hint_reference
+        .members
+        .as_ref()
+        .ok_or_else(|| VirtualMachineError::UnknownStructMembers(var_name.to_string()))?;
+    let is_pointer =
+        matches!(&hint_reference.cairo_type, Some(cairo_type) if cairo_type.ends_with('*'));
+    let base = if is_pointer {
+        get_ptr_from_var_name(var_name, vm, ids_data, ap_tracking)?
+    } else {
+        get_relocatable_from_var_name(var_name, vm, ids_data, ap_tracking)?
+    };
+    Ok((base, members))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -137,4 +193,107 @@ mod tests {
             Ok(relocatable!(0, 2))
         );
     }
+
+    crate::cairo_struct! {
+        #[derive(Debug, PartialEq)]
+        struct Uint256 = "Uint256" {
+            low: BigInt,
+            high: BigInt,
+        }
+    }
+
+    fn uint256_reference(offset1: i32, cairo_type: &str) -> HintReference {
+        let mut hint_ref = HintReference::new(offset1, 0, false, true);
+        hint_ref.cairo_type = Some(cairo_type.to_string());
+        hint_ref.members = Some(HashMap::from([
+            (
+                "low".to_string(),
+                Member {
+                    cairo_type: "felt".to_string(),
+                    offset: 0,
+                },
+            ),
+            (
+                "high".to_string(),
+                Member {
+                    cairo_type: "felt".to_string(),
+                    offset: 1,
+                },
+            ),
+        ]));
+        hint_ref
+    }
+
+    #[test]
+    fn get_struct_from_var_name_value() {
+        let mut vm = vm!();
+        vm.run_context.fp = 2;
+        vm.memory = memory![((1, 0), 3), ((1, 1), 4)];
+        let ids_data = HashMap::from([(
+            "a".to_string(),
+            uint256_reference(-2, "starkware.cairo.common.uint256.Uint256"),
+        )]);
+
+        assert_eq!(
+            get_struct_from_var_name::<Uint256>("a", &vm, &ids_data, &ApTracking::new()),
+            Ok(Uint256 {
+                low: bigint!(3),
+                high: bigint!(4)
+            })
+        );
+        assert_eq!(
+            get_member_addr_from_var_name("a", "high", &vm, &ids_data, &ApTracking::new()),
+            Ok(relocatable!(1, 1))
+        );
+    }
+
+    #[test]
+    fn get_struct_from_var_name_pointer() {
+        let mut vm = vm!();
+        vm.run_context.fp = 1;
+        vm.memory = memory![((1, 0), (2, 0)), ((2, 0), 5), ((2, 1), 6)];
+        let ids_data = HashMap::from([("a".to_string(), uint256_reference(-1, "Uint256*"))]);
+
+        assert_eq!(
+            get_struct_from_var_name::<Uint256>("a", &vm, &ids_data, &ApTracking::new()),
+            Ok(Uint256 {
+                low: bigint!(5),
+                high: bigint!(6)
+            })
+        );
+        assert_eq!(
+            get_member_addr_from_var_name("a", "low", &vm, &ids_data, &ApTracking::new()),
+            Ok(relocatable!(2, 0))
+        );
+    }
+
+    #[test]
+    fn get_struct_from_var_name_unexpected_type() {
+        let vm = vm!();
+        let ids_data = HashMap::from([("a".to_string(), uint256_reference(-1, "BigInt3"))]);
+
+        assert_eq!(
+            get_struct_from_var_name::<Uint256>("a", &vm, &ids_data, &ApTracking::new()),
+            Err(VirtualMachineError::UnexpectedStructType(
+                "a".to_string(),
+                "Uint256",
+                "BigInt3".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn get_struct_from_var_name_unknown_members() {
+        let vm = vm!();
+        let ids_data = HashMap::from([("a".to_string(), HintReference::new_simple(-1))]);
+
+        assert_eq!(
+            get_struct_from_var_name::<Uint256>("a", &vm, &ids_data, &ApTracking::new()),
+            Err(VirtualMachineError::UnknownStructMembers("a".to_string()))
+        );
+        assert_eq!(
+            get_member_addr_from_var_name("a", "low", &vm, &ids_data, &ApTracking::new()),
+            Err(VirtualMachineError::UnknownStructMembers("a".to_string()))
+        );
+    }
 }
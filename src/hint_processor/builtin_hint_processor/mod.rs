@@ -1,5 +1,6 @@
 pub mod blake2s_hash;
 pub mod blake2s_utils;
+pub mod bootloader;
 pub mod builtin_hint_processor_definition;
 pub mod cairo_keccak;
 pub mod dict_hint_utils;
@@ -11,11 +12,15 @@ pub mod keccak_utils;
 pub mod math_utils;
 pub mod memcpy_hint_utils;
 pub mod memset_utils;
+pub mod output_builtin_hints;
 pub mod pow_utils;
+pub mod print_hints;
 pub mod secp;
 pub mod segments;
 pub mod set;
 pub mod sha256_utils;
 pub mod squash_dict_utils;
 pub mod uint256_utils;
+pub mod uint384;
+pub mod uint384_extension;
 pub mod usort;
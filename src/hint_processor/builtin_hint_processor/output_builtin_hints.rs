@@ -0,0 +1,146 @@
+use crate::hint_processor::builtin_hint_processor::hint_utils::get_ptr_from_var_name;
+use crate::hint_processor::hint_processor_definition::HintReference;
+use crate::serde::deserialize_program::ApTracking;
+use crate::vm::errors::vm_errors::VirtualMachineError;
+use crate::vm::runners::builtin_runner::GPS_FACT_TOPOLOGY;
+use crate::vm::vm_core::VirtualMachine;
+use num_integer::div_ceil;
+use std::collections::HashMap;
+
+// Maximal number of output cells in each onchain data page
+const MAX_PAGE_SIZE: usize = 3800;
+
+/*
+Implements hint:
+%{
+    from starkware.python.math_utils import div_ceil
+    onchain_data_start = ids.da_start
+    onchain_data_size = ids.output_ptr - onchain_data_start
+
+    max_page_size = 3800
+    n_pages = div_ceil(onchain_data_size, max_page_size)
+    for i in range(n_pages):
+        start_offset = i * max_page_size
+        output_builtin.add_page(
+            page_id=1 + i,
+            page_start=onchain_data_start + start_offset,
+            page_size=min(onchain_data_size - start_offset, max_page_size),
+        )
+    # Set the tree structure to a root with two children:
+    # * A leaf which represents the main part
+    # * An inner node for the onchain data part (which contains n_pages children).
+    #
+    # This is encoded using the following sequence:
+    output_builtin.add_attribute('gps_fact_topology', [
+        # Push 1 + n_pages pages (all of the pages).
+        1 + n_pages,
+        # Create a parent node for the last n_pages.
+        n_pages,
+        # Don't push additional pages.
+        0,
+        # Take the first page (the main part) and the node that was created (onchain data)
+        # and use them to construct the root of the fact tree.
+        2,
+    ])
+%}
+*/
+pub fn add_onchain_data_pages(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), VirtualMachineError> {
+    let onchain_data_start = get_ptr_from_var_name("da_start", vm, ids_data, ap_tracking)?;
+    let output_ptr = get_ptr_from_var_name("output_ptr", vm, ids_data, ap_tracking)?;
+    let onchain_data_size = output_ptr.sub_rel(&onchain_data_start)?;
+    let n_pages = div_ceil(onchain_data_size, MAX_PAGE_SIZE);
+
+    let output_builtin = vm.get_output_builtin()?;
+    for i in 0..n_pages {
+        let start_offset = i * MAX_PAGE_SIZE;
+        output_builtin.add_page(
+            1 + i,
+            &(onchain_data_start.clone() + start_offset),
+            (onchain_data_size - start_offset).min(MAX_PAGE_SIZE),
+        )?;
+    }
+    output_builtin.add_attribute(
+        GPS_FACT_TOPOLOGY.to_string(),
+        vec![1 + n_pages, n_pages, 0, 2],
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::any_box;
+    use crate::hint_processor::builtin_hint_processor::builtin_hint_processor_definition::{
+        BuiltinHintProcessor, HintProcessorData,
+    };
+    use crate::hint_processor::builtin_hint_processor::hint_code;
+    use crate::hint_processor::hint_processor_definition::HintProcessor;
+    use crate::relocatable;
+    use crate::types::exec_scope::ExecutionScopes;
+    use crate::types::relocatable::Relocatable;
+    use crate::utils::test_utils::*;
+    use crate::vm::errors::runner_errors::RunnerError;
+    use crate::vm::runners::builtin_runner::{OutputBuiltinRunner, PublicMemoryPage};
+    use num_bigint::{BigInt, Sign};
+    use std::any::Any;
+
+    fn vm_with_output_builtin() -> VirtualMachine {
+        let mut vm = vm!();
+        let mut output_builtin = OutputBuiltinRunner::new(true);
+        output_builtin.initialize_segments(&mut vm.segments, &mut vm.memory);
+        vm.builtin_runners = vec![("output".to_string(), output_builtin.into())];
+        add_segments!(vm, 1);
+        vm.run_context.fp = 2;
+        vm
+    }
+
+    #[test]
+    fn run_add_onchain_data_pages() {
+        let mut vm = vm_with_output_builtin();
+        vm.insert_value(&relocatable!(1, 0), relocatable!(0, 2))
+            .unwrap();
+        vm.insert_value(&relocatable!(1, 1), relocatable!(0, 5))
+            .unwrap();
+        let ids_data = non_continuous_ids_data![("da_start", -2), ("output_ptr", -1)];
+
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::ADD_ONCHAIN_DATA_PAGES),
+            Ok(())
+        );
+
+        let output_builtin = vm.get_output_builtin().unwrap();
+        assert_eq!(
+            output_builtin.get_pages(),
+            &HashMap::from([(1, PublicMemoryPage { start: 2, size: 3 })])
+        );
+        assert_eq!(
+            output_builtin.get_attributes(),
+            &HashMap::from([(GPS_FACT_TOPOLOGY.to_string(), vec![2, 1, 0, 2])])
+        );
+    }
+
+    #[test]
+    fn run_add_onchain_data_pages_attribute_already_set() {
+        let mut vm = vm_with_output_builtin();
+        vm.insert_value(&relocatable!(1, 0), relocatable!(0, 0))
+            .unwrap();
+        vm.insert_value(&relocatable!(1, 1), relocatable!(0, 0))
+            .unwrap();
+        vm.get_output_builtin()
+            .unwrap()
+            .add_attribute(GPS_FACT_TOPOLOGY.to_string(), vec![1, 0])
+            .unwrap();
+        let ids_data = non_continuous_ids_data![("da_start", -2), ("output_ptr", -1)];
+
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::ADD_ONCHAIN_DATA_PAGES),
+            Err(VirtualMachineError::RunnerError(
+                RunnerError::OutputAttributeAlreadySet(GPS_FACT_TOPOLOGY.to_string())
+            ))
+        );
+    }
+}
@@ -0,0 +1,739 @@
+use crate::hint_processor::builtin_hint_processor::hint_utils::{
+    get_member_addr_from_var_name, get_reference_from_var_name,
+};
+use crate::hint_processor::hint_processor_definition::HintReference;
+use crate::hint_processor::hint_processor_utils::{
+    bigint_to_usize, compute_addr_from_reference, get_ptr_from_reference,
+};
+use crate::hint_processor::python_hint_processor::interpreter::MAX_OPERATIONS;
+use crate::serde::deserialize_program::ApTracking;
+use crate::types::relocatable::{MaybeRelocatable, Relocatable};
+use crate::vm::errors::python_hint_errors::PythonHintError;
+use crate::vm::errors::vm_errors::VirtualMachineError;
+use crate::vm::vm_core::VirtualMachine;
+use num_bigint::BigInt;
+use num_traits::{One, Signed, ToPrimitive, Zero};
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::io::Write;
+
+/// Debugging hint made only of `print` calls and `for` loops over a `range`, such as:
+/// ```text
+/// print(f"x = {ids.x}")
+/// for i in range(ids.n):
+///     print(memory[ids.array + i])
+/// ```
+/// Printed values can be integer literals, loop variables, `ids` variables (and their struct
+/// members), `memory` accesses and sums of them.
+#[derive(Debug, PartialEq)]
+pub(crate) struct PrintHint(Vec<PrintStatement>);
+
+#[derive(Debug, PartialEq)]
+enum PrintStatement {
+    Print(Vec<PrintArg>),
+    ForRange {
+        var: String,
+        stop: PrintExpr,
+        body: Vec<PrintStatement>,
+    },
+}
+
+#[derive(Debug, PartialEq)]
+enum PrintArg {
+    Str(String),
+    FString(Vec<PrintArg>),
+    Expr(PrintExpr),
+}
+
+#[derive(Debug, PartialEq)]
+enum PrintExpr {
+    Int(BigInt),
+    LoopVar(String),
+    Ids(String, Option<String>),
+    Memory(Box<PrintExpr>),
+    Add(Box<PrintExpr>, Box<PrintExpr>),
+}
+
+impl PrintHint {
+    /// Recognizes the given hint code as a print hint, returns None if it isn't one
+    pub(crate) fn parse(code: &str) -> Option<PrintHint> {
+        let lines: Vec<(usize, &str)> = code
+            .lines()
+            .filter(|line| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+            .map(|line| (line.len() - line.trim_start().len(), line.trim()))
+            .collect();
+        if lines.is_empty() {
+            return None;
+        }
+        parse_block(&lines, &mut Vec::new()).map(PrintHint)
+    }
+
+    pub(crate) fn execute(
+        &self,
+        vm: &VirtualMachine,
+        ids_data: &HashMap<String, HintReference>,
+        ap_tracking: &ApTracking,
+        writer: &mut dyn Write,
+    ) -> Result<(), VirtualMachineError> {
+        let context = PrintContext {
+            vm,
+            ids_data,
+            ap_tracking,
+            remaining_iterations: Cell::new(MAX_OPERATIONS),
+        };
+        context.execute_block(&self.0, &mut Vec::new(), writer)
+    }
+}
+
+// Parses a block of lines sharing the indentation of the first one
+fn parse_block(
+    lines: &[(usize, &str)],
+    loop_vars: &mut Vec<String>,
+) -> Option<Vec<PrintStatement>> {
+    let indent = lines.first()?.0;
+    let mut statements = Vec::new();
+    let mut index = 0;
+    while index < lines.len() {
+        let (line_indent, line) = lines[index];
+        if line_indent != indent {
+            return None;
+        }
+        index += 1;
+        let mut cursor = Cursor(line);
+        if cursor.eat_keyword("print") {
+            statements.push(PrintStatement::Print(parse_print_args(
+                &mut cursor,
+                loop_vars,
+            )?));
+        } else if cursor.eat_keyword("for") {
+            let var = cursor.name()?;
+            if !cursor.eat_keyword("in") || !cursor.eat_keyword("range") || !cursor.eat("(") {
+                return None;
+            }
+            let stop = parse_expr(&mut cursor, loop_vars)?;
+            if !cursor.eat(")") || !cursor.eat(":") {
+                return None;
+            }
+            let body_len = lines[index..]
+                .iter()
+                .take_while(|(body_indent, _)| *body_indent > indent)
+                .count();
+            loop_vars.push(var.to_string());
+            let body = parse_block(&lines[index..index + body_len], loop_vars);
+            loop_vars.pop();
+            index += body_len;
+            statements.push(PrintStatement::ForRange {
+                var: var.to_string(),
+                stop,
+                body: body?,
+            });
+        } else {
+            return None;
+        }
+        if !cursor.at_end() {
+            return None;
+        }
+    }
+    Some(statements)
+}
+
+fn parse_print_args(cursor: &mut Cursor, loop_vars: &[String]) -> Option<Vec<PrintArg>> {
+    if !cursor.eat("(") {
+        return None;
+    }
+    let mut args = Vec::new();
+    if cursor.eat(")") {
+        return Some(args);
+    }
+    loop {
+        args.push(parse_print_arg(cursor, loop_vars)?);
+        if cursor.eat(")") {
+            return Some(args);
+        }
+        if !cursor.eat(",") {
+            return None;
+        }
+    }
+}
+
+fn parse_print_arg(cursor: &mut Cursor, loop_vars: &[String]) -> Option<PrintArg> {
+    cursor.skip_whitespace();
+    if let Some(rest) = cursor.0.strip_prefix('f') {
+        if rest.starts_with('"') || rest.starts_with('\'') {
+            cursor.0 = rest;
+            return parse_fstring(&cursor.string()?, loop_vars);
+        }
+    }
+    if cursor.0.starts_with('"') || cursor.0.starts_with('\'') {
+        return cursor.string().map(PrintArg::Str);
+    }
+    parse_expr(cursor, loop_vars).map(PrintArg::Expr)
+}
+
+// Splits the contents of an f-string into literals and replacement fields, format specs and
+// conversions aren't supported
+fn parse_fstring(contents: &str, loop_vars: &[String]) -> Option<PrintArg> {
+    let mut parts = Vec::new();
+    let mut literal = String::new();
+    let mut chars = contents.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                literal.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                literal.push('}');
+            }
+            '{' => {
+                let mut field = String::new();
+                loop {
+                    match chars.next()? {
+                        '}' => break,
+                        c => field.push(c),
+                    }
+                }
+                let mut cursor = Cursor(&field);
+                let expr = parse_expr(&mut cursor, loop_vars)?;
+                if !cursor.at_end() {
+                    return None;
+                }
+                if !literal.is_empty() {
+                    parts.push(PrintArg::Str(std::mem::take(&mut literal)));
+                }
+                parts.push(PrintArg::Expr(expr));
+            }
+            '}' => return None,
+            _ => literal.push(c),
+        }
+    }
+    if !literal.is_empty() {
+        parts.push(PrintArg::Str(literal));
+    }
+    Some(PrintArg::FString(parts))
+}
+
+fn parse_expr(cursor: &mut Cursor, loop_vars: &[String]) -> Option<PrintExpr> {
+    let mut expr = parse_term(cursor, loop_vars)?;
+    while cursor.eat("+") {
+        expr = PrintExpr::Add(Box::new(expr), Box::new(parse_term(cursor, loop_vars)?));
+    }
+    Some(expr)
+}
+
+fn parse_term(cursor: &mut Cursor, loop_vars: &[String]) -> Option<PrintExpr> {
+    cursor.skip_whitespace();
+    if cursor.0.starts_with(|c: char| c.is_ascii_digit()) {
+        let digits = cursor.take_while(|c| c.is_ascii_digit());
+        return digits.parse().ok().map(PrintExpr::Int);
+    }
+    match cursor.name()? {
+        "ids" => {
+            if !cursor.eat(".") {
+                return None;
+            }
+            let name = cursor.name()?.to_string();
+            let member = if cursor.eat(".") {
+                Some(cursor.name()?.to_string())
+            } else {
+                None
+            };
+            Some(PrintExpr::Ids(name, member))
+        }
+        "memory" => {
+            if !cursor.eat("[") {
+                return None;
+            }
+            let addr = parse_expr(cursor, loop_vars)?;
+            if !cursor.eat("]") {
+                return None;
+            }
+            Some(PrintExpr::Memory(Box::new(addr)))
+        }
+        name if loop_vars.iter().any(|var| var == name) => {
+            Some(PrintExpr::LoopVar(name.to_string()))
+        }
+        _ => None,
+    }
+}
+
+// Minimal cursor over a line of hint code
+struct Cursor<'a>(&'a str);
+
+impl<'a> Cursor<'a> {
+    fn skip_whitespace(&mut self) {
+        self.0 = self.0.trim_start();
+    }
+
+    fn at_end(&mut self) -> bool {
+        self.skip_whitespace();
+        self.0.is_empty() || self.0.starts_with('#')
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        match self.0.strip_prefix(token) {
+            Some(rest) => {
+                self.0 = rest;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        let start = self.0;
+        match self.name() {
+            Some(name) if name == keyword => true,
+            _ => {
+                self.0 = start;
+                false
+            }
+        }
+    }
+
+    fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &'a str {
+        let input = self.0;
+        let end = input.find(|c| !predicate(c)).unwrap_or(input.len());
+        let (taken, rest) = input.split_at(end);
+        self.0 = rest;
+        taken
+    }
+
+    fn name(&mut self) -> Option<&'a str> {
+        self.skip_whitespace();
+        if !self
+            .0
+            .starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        {
+            return None;
+        }
+        Some(self.take_while(|c| c.is_ascii_alphanumeric() || c == '_'))
+    }
+
+    // Reads a single or double quoted string literal, handling the common escape sequences
+    fn string(&mut self) -> Option<String> {
+        let input = self.0;
+        let mut chars = input.char_indices();
+        let (_, quote) = chars.next()?;
+        let mut value = String::new();
+        while let Some((index, c)) = chars.next() {
+            match c {
+                '\\' => value.push(match chars.next()?.1 {
+                    'n' => '\n',
+                    't' => '\t',
+                    escaped @ ('\\' | '\'' | '"') => escaped,
+                    _ => return None,
+                }),
+                c if c == quote => {
+                    self.0 = &input[index + 1..];
+                    return Some(value);
+                }
+                c => value.push(c),
+            }
+        }
+        None
+    }
+}
+
+struct PrintContext<'a> {
+    vm: &'a VirtualMachine,
+    ids_data: &'a HashMap<String, HintReference>,
+    ap_tracking: &'a ApTracking,
+    // Loop iterations left to the hint, shared by nested loops
+    remaining_iterations: Cell<usize>,
+}
+
+impl<'a> PrintContext<'a> {
+    fn execute_block(
+        &self,
+        statements: &[PrintStatement],
+        loop_vars: &mut Vec<(String, BigInt)>,
+        writer: &mut dyn Write,
+    ) -> Result<(), VirtualMachineError> {
+        for statement in statements {
+            match statement {
+                PrintStatement::Print(args) => {
+                    let args = args
+                        .iter()
+                        .map(|arg| self.format_arg(arg, loop_vars))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    writeln!(writer, "{}", args.join(" "))
+                        .map_err(|_| VirtualMachineError::PrintHintWriteFail)?;
+                }
+                PrintStatement::ForRange { var, stop, body } => {
+                    let stop = match self.evaluate(stop, loop_vars)? {
+                        MaybeRelocatable::Int(stop) => stop,
+                        MaybeRelocatable::RelocatableValue(_) => {
+                            return Err(PythonHintError::TypeError(
+                                "range() expects an integer".to_string(),
+                            )
+                            .into())
+                        }
+                    };
+                    self.charge(&stop)?;
+                    let mut i = BigInt::zero();
+                    while i < stop {
+                        loop_vars.push((var.clone(), i.clone()));
+                        let result = self.execute_block(body, loop_vars, writer);
+                        loop_vars.pop();
+                        result?;
+                        i += BigInt::one();
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // Counts the iterations of a range(stop) loop against the budget of the hint before running it
+    fn charge(&self, stop: &BigInt) -> Result<(), VirtualMachineError> {
+        let iterations = if stop.is_positive() {
+            stop.to_usize()
+        } else {
+            Some(0)
+        };
+        match iterations
+            .and_then(|iterations| self.remaining_iterations.get().checked_sub(iterations))
+        {
+            Some(remaining_iterations) => {
+                self.remaining_iterations.set(remaining_iterations);
+                Ok(())
+            }
+            None => Err(PythonHintError::OperationLimitExceeded(MAX_OPERATIONS).into()),
+        }
+    }
+
+    fn format_arg(
+        &self,
+        arg: &PrintArg,
+        loop_vars: &[(String, BigInt)],
+    ) -> Result<String, VirtualMachineError> {
+        match arg {
+            PrintArg::Str(value) => Ok(value.clone()),
+            PrintArg::FString(parts) => parts
+                .iter()
+                .map(|part| self.format_arg(part, loop_vars))
+                .collect(),
+            PrintArg::Expr(PrintExpr::Ids(name, None)) => self.format_ids_variable(name),
+            PrintArg::Expr(expr) => Ok(format_value(&self.evaluate(expr, loop_vars)?)),
+        }
+    }
+
+    // Structs are printed along with their members, sorted by offset
+    fn format_ids_variable(&self, name: &str) -> Result<String, VirtualMachineError> {
+        let hint_reference = get_reference_from_var_name(name, self.ids_data)?;
+        let (cairo_type, members) = match (&hint_reference.cairo_type, &hint_reference.members) {
+            (Some(cairo_type), Some(members)) if !cairo_type.ends_with('*') => {
+                (cairo_type, members)
+            }
+            _ => return Ok(format_value(&self.get_ids_variable(name)?)),
+        };
+        let base = compute_addr_from_reference(hint_reference, self.vm, self.ap_tracking)?;
+        let mut members: Vec<_> = members.iter().collect();
+        members.sort_by_key(|(_, member)| member.offset);
+        let mut fields = Vec::new();
+        for (member_name, member) in members {
+            let value = self.read_memory(&(&base + member.offset))?;
+            fields.push(format!("{}={}", member_name, format_value(&value)));
+        }
+        let struct_name = cairo_type.rsplit('.').next().unwrap_or(cairo_type);
+        Ok(format!("{}({})", struct_name, fields.join(", ")))
+    }
+
+    fn evaluate(
+        &self,
+        expr: &PrintExpr,
+        loop_vars: &[(String, BigInt)],
+    ) -> Result<MaybeRelocatable, VirtualMachineError> {
+        match expr {
+            PrintExpr::Int(value) => Ok(MaybeRelocatable::Int(value.clone())),
+            // The parser only accepts names of enclosing loop variables
+            PrintExpr::LoopVar(name) => loop_vars
+                .iter()
+                .rev()
+                .find(|(var, _)| var == name)
+                .map(|(_, value)| MaybeRelocatable::Int(value.clone()))
+                .ok_or_else(|| PythonHintError::NameError(name.clone()).into()),
+            PrintExpr::Ids(name, None) => self.get_ids_variable(name),
+            PrintExpr::Ids(name, Some(member)) => self.read_memory(&get_member_addr_from_var_name(
+                name,
+                member,
+                self.vm,
+                self.ids_data,
+                self.ap_tracking,
+            )?),
+            PrintExpr::Memory(addr) => match self.evaluate(addr, loop_vars)? {
+                MaybeRelocatable::RelocatableValue(addr) => self.read_memory(&addr),
+                MaybeRelocatable::Int(addr) => Err(PythonHintError::TypeError(format!(
+                    "memory can only be indexed by addresses, got {}",
+                    addr
+                ))
+                .into()),
+            },
+            PrintExpr::Add(a, b) => {
+                match (self.evaluate(a, loop_vars)?, self.evaluate(b, loop_vars)?) {
+                    (MaybeRelocatable::Int(a), MaybeRelocatable::Int(b)) => {
+                        Ok(MaybeRelocatable::Int(a + b))
+                    }
+                    (MaybeRelocatable::RelocatableValue(addr), MaybeRelocatable::Int(offset))
+                    | (MaybeRelocatable::Int(offset), MaybeRelocatable::RelocatableValue(addr)) => {
+                        Ok(MaybeRelocatable::from(&addr + bigint_to_usize(&offset)?))
+                    }
+                    _ => Err(PythonHintError::TypeError(
+                        "can't add two relocatable values".to_string(),
+                    )
+                    .into()),
+                }
+            }
+        }
+    }
+
+    // Structs held by the variable are used as their address, like pointers to structs
+    fn get_ids_variable(&self, name: &str) -> Result<MaybeRelocatable, VirtualMachineError> {
+        let hint_reference = get_reference_from_var_name(name, self.ids_data)?;
+        if hint_reference.register.is_none() {
+            if let Some(immediate) = &hint_reference.immediate {
+                return Ok(MaybeRelocatable::Int(immediate.clone()));
+            }
+        }
+        let is_pointer =
+            matches!(&hint_reference.cairo_type, Some(cairo_type) if cairo_type.ends_with('*'));
+        if hint_reference.members.is_some() && !is_pointer {
+            return Ok(MaybeRelocatable::from(compute_addr_from_reference(
+                hint_reference,
+                self.vm,
+                self.ap_tracking,
+            )?));
+        }
+        if !hint_reference.dereference {
+            return Ok(MaybeRelocatable::from(get_ptr_from_reference(
+                self.vm,
+                hint_reference,
+                self.ap_tracking,
+            )?));
+        }
+        let addr = compute_addr_from_reference(hint_reference, self.vm, self.ap_tracking)?;
+        self.read_memory(&addr)
+    }
+
+    fn read_memory(&self, addr: &Relocatable) -> Result<MaybeRelocatable, VirtualMachineError> {
+        self.vm
+            .get_maybe(addr)?
+            .ok_or_else(|| VirtualMachineError::MemoryGet(addr.into()))
+    }
+}
+
+fn format_value(value: &MaybeRelocatable) -> String {
+    match value {
+        MaybeRelocatable::Int(value) => value.to_string(),
+        MaybeRelocatable::RelocatableValue(addr) => {
+            format!("{}:{}", addr.segment_index, addr.offset)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::any_box;
+    use crate::hint_processor::builtin_hint_processor::builtin_hint_processor_definition::{
+        BuiltinHintProcessor, HintProcessorData,
+    };
+    use crate::hint_processor::hint_processor_definition::HintProcessor;
+    use crate::serde::deserialize_program::Member;
+    use crate::types::exec_scope::ExecutionScopes;
+    use crate::utils::test_utils::*;
+    use crate::vm::errors::memory_errors::MemoryError;
+    use crate::vm::vm_memory::memory::Memory;
+    use crate::{bigint, bigint_str};
+    use num_bigint::Sign;
+    use std::any::Any;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    fn run_print_hint(
+        vm: &mut VirtualMachine,
+        ids_data: HashMap<String, HintReference>,
+        hint_code: &str,
+    ) -> Result<String, VirtualMachineError> {
+        let output = Rc::new(RefCell::new(Vec::<u8>::new()));
+        let mut hint_processor = BuiltinHintProcessor::new_empty();
+        hint_processor.set_print_writer(output.clone());
+        let hint_data = HintProcessorData::new_default(hint_code.to_string(), ids_data);
+        hint_processor.execute_hint(
+            vm,
+            exec_scopes_ref!(),
+            &any_box!(hint_data),
+            &HashMap::new(),
+        )?;
+        let output = output.borrow().clone();
+        Ok(String::from_utf8(output).unwrap())
+    }
+
+    fn point_reference(offset1: i32, cairo_type: &str) -> HintReference {
+        let mut hint_ref = HintReference::new_simple(offset1);
+        hint_ref.cairo_type = Some(cairo_type.to_string());
+        hint_ref.members = Some(HashMap::from([
+            (
+                "y".to_string(),
+                Member {
+                    cairo_type: "felt".to_string(),
+                    offset: 1,
+                },
+            ),
+            (
+                "x".to_string(),
+                Member {
+                    cairo_type: "felt".to_string(),
+                    offset: 0,
+                },
+            ),
+        ]));
+        hint_ref
+    }
+
+    #[test]
+    fn parse_print_hints() {
+        assert!(PrintHint::parse("print(ids.a)").is_some());
+        assert!(PrintHint::parse("print(f\"a = {ids.a.low}, {{b}}\")").is_some());
+        assert!(PrintHint::parse("print('x', ids.a, 3)\nprint()").is_some());
+        assert!(
+            PrintHint::parse("for i in range(ids.n):\n    print(memory[ids.array + i])").is_some()
+        );
+        assert!(PrintHint::parse(
+            "for i in range(2):\n    for j in range(3):\n        print(f\"{i} {j}\")"
+        )
+        .is_some());
+    }
+
+    #[test]
+    fn parse_non_print_hints() {
+        assert_eq!(PrintHint::parse(""), None);
+        assert_eq!(PrintHint::parse("memory[ap] = 0"), None);
+        assert_eq!(PrintHint::parse("print(ids.a)\nids.b = 1"), None);
+        assert_eq!(PrintHint::parse("print(j)"), None);
+        assert_eq!(PrintHint::parse("print(ids.a, sep='')"), None);
+        assert_eq!(PrintHint::parse("print(f\"{ids.a:x}\")"), None);
+        assert_eq!(PrintHint::parse("for i in range(2):\nprint(i)"), None);
+        assert_eq!(PrintHint::parse("print(ids.a) + 1"), None);
+    }
+
+    #[test]
+    fn run_print_felt_and_pointer() {
+        let mut vm = vm!();
+        vm.run_context.fp = 2;
+        vm.memory = memory![((1, 0), 17), ((1, 1), (2, 3))];
+        let ids_data = non_continuous_ids_data![("a", -2), ("ptr", -1)];
+        assert_eq!(
+            run_print_hint(&mut vm, ids_data, "print(ids.a)\nprint('ptr:', ids.ptr)"),
+            Ok("17\nptr: 2:3\n".to_string())
+        );
+    }
+
+    #[test]
+    fn run_print_fstring() {
+        let mut vm = vm!();
+        vm.run_context.fp = 1;
+        vm.memory = memory![((1, 0), 5)];
+        let ids_data = non_continuous_ids_data![("a", -1)];
+        assert_eq!(
+            run_print_hint(
+                &mut vm,
+                ids_data,
+                "print(f\"a = {ids.a}, a + 1 = {ids.a + 1}\")"
+            ),
+            Ok("a = 5, a + 1 = 6\n".to_string())
+        );
+    }
+
+    #[test]
+    fn run_print_memory_loop() {
+        let mut vm = vm!();
+        vm.run_context.fp = 2;
+        vm.memory = memory![
+            ((1, 0), (2, 0)),
+            ((1, 1), 3),
+            ((2, 0), 10),
+            ((2, 1), 20),
+            ((2, 2), 30)
+        ];
+        let ids_data = non_continuous_ids_data![("array", -2), ("n", -1)];
+        let hint_code = "for i in range(ids.n):\n    print(i, memory[ids.array + i])";
+        assert_eq!(
+            run_print_hint(&mut vm, ids_data, hint_code),
+            Ok("0 10\n1 20\n2 30\n".to_string())
+        );
+    }
+
+    #[test]
+    fn run_print_unbounded_loop() {
+        let mut vm = vm!();
+        vm.run_context.fp = 1;
+        vm.memory = memory![(
+            (1, 0),
+            (bigint_str!(
+                b"3618502788666131213697322783095070105623107215331596699973092056135872020480"
+            ))
+        )];
+        let ids_data = non_continuous_ids_data![("n", -1)];
+        assert_eq!(
+            run_print_hint(&mut vm, ids_data, "for i in range(ids.n):\n    print(i)"),
+            Err(PythonHintError::OperationLimitExceeded(MAX_OPERATIONS).into())
+        );
+        let hint_code = "for i in range(1000):\n    for j in range(1001):\n        print(i)";
+        assert_eq!(
+            run_print_hint(&mut vm, HashMap::new(), hint_code),
+            Err(PythonHintError::OperationLimitExceeded(MAX_OPERATIONS).into())
+        );
+    }
+
+    #[test]
+    fn run_print_struct() {
+        let mut vm = vm!();
+        vm.run_context.fp = 3;
+        vm.memory = memory![((1, 0), 1), ((1, 1), 2), ((1, 2), (1, 0))];
+        let mut ids_data = HashMap::new();
+        ids_data.insert("point".to_string(), point_reference(-3, "__main__.Point"));
+        ids_data.insert(
+            "point_ptr".to_string(),
+            point_reference(-1, "__main__.Point*"),
+        );
+        let hint_code = "print(ids.point)\nprint(ids.point_ptr, ids.point_ptr.y)";
+        assert_eq!(
+            run_print_hint(&mut vm, ids_data, hint_code),
+            Ok("Point(x=1, y=2)\n1:0 2\n".to_string())
+        );
+    }
+
+    #[test]
+    fn run_print_missing_member() {
+        let mut vm = vm!();
+        vm.run_context.fp = 2;
+        vm.memory = memory![((1, 0), 1), ((1, 1), 2)];
+        let mut ids_data = HashMap::new();
+        ids_data.insert("point".to_string(), point_reference(-2, "__main__.Point"));
+        assert_eq!(
+            run_print_hint(&mut vm, ids_data, "print(ids.point.z)"),
+            Err(VirtualMachineError::MissingStructMember(
+                "point".to_string(),
+                "z".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn run_print_memory_unknown_value() {
+        let mut vm = vm!();
+        vm.run_context.fp = 1;
+        vm.memory = memory![((1, 0), (2, 0))];
+        let ids_data = non_continuous_ids_data![("array", -1)];
+        assert_eq!(
+            run_print_hint(&mut vm, ids_data, "print(memory[ids.array + 4])"),
+            Err(VirtualMachineError::MemoryGet(MaybeRelocatable::from((
+                2, 4
+            ))))
+        );
+    }
+}
@@ -0,0 +1,257 @@
+use crate::hint_processor::builtin_hint_processor::secp::secp_utils::pack_from_var_name;
+use crate::hint_processor::hint_processor_definition::HintReference;
+use crate::vm::vm_core::VirtualMachine;
+use crate::{
+    math_utils::div_mod, serde::deserialize_program::ApTracking,
+    types::exec_scope::ExecutionScopes, vm::errors::vm_errors::VirtualMachineError,
+};
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::Zero;
+use std::collections::HashMap;
+
+/* Implements hint:
+from starkware.cairo.common.cairo_secp.secp_utils import pack
+from starkware.python.math_utils import div_mod, safe_div
+
+N = pack(ids.n, PRIME)
+x = pack(ids.x, PRIME) % N
+s = pack(ids.s, PRIME) % N
+value = res = div_mod(x, s, N)
+*/
+pub fn ec_recover_divmod_n_packed(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), VirtualMachineError> {
+    let n = pack_from_var_name("n", vm, ids_data, ap_tracking)?;
+    if n.is_zero() {
+        return Err(VirtualMachineError::DividedByZero);
+    }
+    let x = pack_from_var_name("x", vm, ids_data, ap_tracking)?.mod_floor(&n);
+    let s = pack_from_var_name("s", vm, ids_data, ap_tracking)?.mod_floor(&n);
+
+    let value = div_mod(&x, &s, &n);
+    exec_scopes.insert_value("value", value.clone());
+    exec_scopes.insert_value("res", value);
+    Ok(())
+}
+
+/* Implements hint:
+from starkware.cairo.common.cairo_secp.secp_utils import pack
+from starkware.python.math_utils import div_mod, safe_div
+
+a = pack(ids.a, PRIME)
+b = pack(ids.b, PRIME)
+
+value = res = a - b
+*/
+pub fn ec_recover_sub_a_b(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), VirtualMachineError> {
+    let a = pack_from_var_name("a", vm, ids_data, ap_tracking)?;
+    let b = pack_from_var_name("b", vm, ids_data, ap_tracking)?;
+
+    let value = a - b;
+    exec_scopes.insert_value("value", value.clone());
+    exec_scopes.insert_value("res", value);
+    Ok(())
+}
+
+/* Implements hint:
+from starkware.cairo.common.cairo_secp.secp_utils import pack
+from starkware.python.math_utils import div_mod, safe_div
+
+a = pack(ids.a, PRIME)
+b = pack(ids.b, PRIME)
+product = a * b
+m = pack(ids.m, PRIME)
+
+value = res = product % m
+*/
+pub fn ec_recover_product_mod(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), VirtualMachineError> {
+    let a = pack_from_var_name("a", vm, ids_data, ap_tracking)?;
+    let b = pack_from_var_name("b", vm, ids_data, ap_tracking)?;
+    let m = pack_from_var_name("m", vm, ids_data, ap_tracking)?;
+    if m.is_zero() {
+        return Err(VirtualMachineError::DividedByZero);
+    }
+
+    let product = a * b;
+    let value = product.mod_floor(&m);
+    exec_scopes.insert_value("product", product);
+    exec_scopes.insert_value("m", m);
+    exec_scopes.insert_value("value", value.clone());
+    exec_scopes.insert_value("res", value);
+    Ok(())
+}
+
+// Implements hint:
+// value = k = product // m
+// product and m are imported into the scope by the ec_recover_product_mod hint
+pub fn ec_recover_product_div_m(
+    exec_scopes: &mut ExecutionScopes,
+) -> Result<(), VirtualMachineError> {
+    let product = exec_scopes.get_ref::<BigInt>("product")?;
+    let m = exec_scopes.get_ref::<BigInt>("m")?;
+    if m.is_zero() {
+        return Err(VirtualMachineError::DividedByZero);
+    }
+
+    let value = product.div_floor(m);
+    exec_scopes.insert_value("value", value.clone());
+    exec_scopes.insert_value("k", value);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::any_box;
+    use crate::hint_processor::builtin_hint_processor::builtin_hint_processor_definition::BuiltinHintProcessor;
+    use crate::hint_processor::builtin_hint_processor::builtin_hint_processor_definition::HintProcessorData;
+    use crate::hint_processor::builtin_hint_processor::hint_code;
+    use crate::hint_processor::hint_processor_definition::HintProcessor;
+    use crate::{
+        bigint,
+        types::{exec_scope::ExecutionScopes, relocatable::MaybeRelocatable},
+        utils::test_utils::*,
+        vm::{
+            errors::memory_errors::MemoryError, vm_core::VirtualMachine, vm_memory::memory::Memory,
+        },
+    };
+    use num_bigint::Sign;
+    use std::any::Any;
+
+    #[test]
+    fn run_ec_recover_divmod_n_packed_ok() {
+        let hint_code = hint_code::EC_RECOVER_DIV_MOD_N_PACKED;
+        let mut vm = vm!();
+        //ids.n, ids.x and ids.s
+        vm.memory = memory![
+            ((1, 0), 177),
+            ((1, 1), 0),
+            ((1, 2), 0),
+            ((1, 3), 25),
+            ((1, 4), 0),
+            ((1, 5), 0),
+            ((1, 6), 5),
+            ((1, 7), 0),
+            ((1, 8), 0)
+        ];
+        vm.run_context.fp = 3;
+        let ids_data = non_continuous_ids_data![("n", -3), ("x", 0), ("s", 3)];
+        let mut exec_scopes = ExecutionScopes::new();
+        assert_eq!(run_hint!(vm, ids_data, hint_code, &mut exec_scopes), Ok(()));
+        check_scope!(&exec_scopes, [("value", bigint!(5)), ("res", bigint!(5))]);
+    }
+
+    #[test]
+    fn run_ec_recover_divmod_n_packed_zero_n() {
+        let hint_code = hint_code::EC_RECOVER_DIV_MOD_N_PACKED;
+        let mut vm = vm!();
+        vm.memory = memory![
+            ((1, 0), 0),
+            ((1, 1), 0),
+            ((1, 2), 0),
+            ((1, 3), 25),
+            ((1, 4), 0),
+            ((1, 5), 0),
+            ((1, 6), 5),
+            ((1, 7), 0),
+            ((1, 8), 0)
+        ];
+        vm.run_context.fp = 3;
+        let ids_data = non_continuous_ids_data![("n", -3), ("x", 0), ("s", 3)];
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code),
+            Err(VirtualMachineError::DividedByZero)
+        );
+    }
+
+    #[test]
+    fn run_ec_recover_sub_a_b_ok() {
+        let hint_code = hint_code::EC_RECOVER_SUB_A_B;
+        let mut vm = vm!();
+        vm.memory = memory![
+            ((1, 0), 100),
+            ((1, 1), 0),
+            ((1, 2), 0),
+            ((1, 3), 25),
+            ((1, 4), 0),
+            ((1, 5), 0)
+        ];
+        vm.run_context.fp = 3;
+        let ids_data = non_continuous_ids_data![("a", -3), ("b", 0)];
+        let mut exec_scopes = ExecutionScopes::new();
+        assert_eq!(run_hint!(vm, ids_data, hint_code, &mut exec_scopes), Ok(()));
+        check_scope!(&exec_scopes, [("value", bigint!(75)), ("res", bigint!(75))]);
+    }
+
+    #[test]
+    fn run_ec_recover_product_mod_and_div_m_ok() {
+        let mut vm = vm!();
+        //ids.a, ids.b and ids.m
+        vm.memory = memory![
+            ((1, 0), 60),
+            ((1, 1), 0),
+            ((1, 2), 0),
+            ((1, 3), 2),
+            ((1, 4), 0),
+            ((1, 5), 0),
+            ((1, 6), 100),
+            ((1, 7), 0),
+            ((1, 8), 0)
+        ];
+        vm.run_context.fp = 3;
+        let ids_data = non_continuous_ids_data![("a", -3), ("b", 0), ("m", 3)];
+        let mut exec_scopes = ExecutionScopes::new();
+        assert_eq!(
+            run_hint!(
+                vm,
+                ids_data,
+                hint_code::EC_RECOVER_PRODUCT_MOD,
+                &mut exec_scopes
+            ),
+            Ok(())
+        );
+        check_scope!(
+            &exec_scopes,
+            [
+                ("product", bigint!(120)),
+                ("m", bigint!(100)),
+                ("value", bigint!(20)),
+                ("res", bigint!(20))
+            ]
+        );
+
+        assert_eq!(
+            run_hint!(
+                vm,
+                HashMap::new(),
+                hint_code::EC_RECOVER_PRODUCT_DIV_M,
+                &mut exec_scopes
+            ),
+            Ok(())
+        );
+        check_scope!(&exec_scopes, [("value", bigint!(1)), ("k", bigint!(1))]);
+    }
+
+    #[test]
+    fn run_ec_recover_product_div_m_zero_m() {
+        let mut exec_scopes = scope![("product", bigint!(120)), ("m", bigint!(0))];
+        assert_eq!(
+            ec_recover_product_div_m(&mut exec_scopes),
+            Err(VirtualMachineError::DividedByZero)
+        );
+    }
+}
@@ -2,7 +2,7 @@ use crate::bigint;
 use crate::hint_processor::builtin_hint_processor::hint_utils::{
     get_integer_from_var_name, get_relocatable_from_var_name, insert_value_into_ap,
 };
-use crate::hint_processor::builtin_hint_processor::secp::secp_utils::{pack, SECP_REM};
+use crate::hint_processor::builtin_hint_processor::secp::secp_utils::{pack, SecpCurve};
 use crate::hint_processor::hint_processor_definition::HintReference;
 use crate::math_utils::{ec_double_slope, line_slope};
 use crate::serde::deserialize_program::ApTracking;
@@ -12,7 +12,7 @@ use crate::vm::vm_core::VirtualMachine;
 use num_bigint::BigInt;
 use num_integer::Integer;
 use std::collections::HashMap;
-use std::ops::{BitAnd, Shl};
+use std::ops::BitAnd;
 
 use super::secp_utils::pack_from_relocatable;
 
@@ -31,12 +31,9 @@ pub fn ec_negate(
     exec_scopes: &mut ExecutionScopes,
     ids_data: &HashMap<String, HintReference>,
     ap_tracking: &ApTracking,
-    constants: &HashMap<String, BigInt>,
+    curve: &SecpCurve,
 ) -> Result<(), VirtualMachineError> {
-    let secp_p = bigint!(1).shl(256usize)
-        - constants
-            .get(SECP_REM)
-            .ok_or(VirtualMachineError::MissingConstant(SECP_REM))?;
+    let secp_p = curve.p()?;
 
     //ids.point
     let point_y = get_relocatable_from_var_name("point", vm, ids_data, ap_tracking)? + 3;
@@ -63,12 +60,9 @@ pub fn compute_doubling_slope(
     exec_scopes: &mut ExecutionScopes,
     ids_data: &HashMap<String, HintReference>,
     ap_tracking: &ApTracking,
-    constants: &HashMap<String, BigInt>,
+    curve: &SecpCurve,
 ) -> Result<(), VirtualMachineError> {
-    let secp_p = bigint!(1).shl(256usize)
-        - constants
-            .get(SECP_REM)
-            .ok_or(VirtualMachineError::MissingConstant(SECP_REM))?;
+    let secp_p = curve.p()?;
 
     //ids.point
     let point_reloc = get_relocatable_from_var_name("point", vm, ids_data, ap_tracking)?;
@@ -87,7 +81,7 @@ pub fn compute_doubling_slope(
             pack(x_d0.as_ref(), x_d1.as_ref(), x_d2.as_ref(), vm.get_prime()),
             pack(y_d0.as_ref(), y_d1.as_ref(), y_d2.as_ref(), vm.get_prime()),
         ),
-        &bigint!(0),
+        &curve.alpha()?,
         &secp_p,
     );
     exec_scopes.insert_value("value", value.clone());
@@ -114,12 +108,9 @@ pub fn compute_slope(
     exec_scopes: &mut ExecutionScopes,
     ids_data: &HashMap<String, HintReference>,
     ap_tracking: &ApTracking,
-    constants: &HashMap<String, BigInt>,
+    curve: &SecpCurve,
 ) -> Result<(), VirtualMachineError> {
-    let secp_p = bigint!(1).shl(256usize)
-        - constants
-            .get(SECP_REM)
-            .ok_or(VirtualMachineError::MissingConstant(SECP_REM))?;
+    let secp_p = curve.p()?;
 
     //ids.point0
     let point0_reloc = get_relocatable_from_var_name("point0", vm, ids_data, ap_tracking)?;
@@ -198,12 +189,9 @@ pub fn ec_double_assign_new_x(
     exec_scopes: &mut ExecutionScopes,
     ids_data: &HashMap<String, HintReference>,
     ap_tracking: &ApTracking,
-    constants: &HashMap<String, BigInt>,
+    curve: &SecpCurve,
 ) -> Result<(), VirtualMachineError> {
-    let secp_p = bigint!(1).shl(256usize)
-        - constants
-            .get(SECP_REM)
-            .ok_or(VirtualMachineError::MissingConstant(SECP_REM))?;
+    let secp_p = curve.p()?;
 
     //ids.slope
     let slope_reloc = get_relocatable_from_var_name("slope", vm, ids_data, ap_tracking)?;
@@ -252,12 +240,9 @@ Implements hint:
 */
 pub fn ec_double_assign_new_y(
     exec_scopes: &mut ExecutionScopes,
-    constants: &HashMap<String, BigInt>,
+    curve: &SecpCurve,
 ) -> Result<(), VirtualMachineError> {
-    let secp_p = bigint!(1).shl(256usize)
-        - constants
-            .get(SECP_REM)
-            .ok_or(VirtualMachineError::MissingConstant(SECP_REM))?;
+    let secp_p = curve.p()?;
 
     //Get variables from vm scope
     let (slope, x, new_x, y) = (
@@ -291,12 +276,9 @@ pub fn fast_ec_add_assign_new_x(
     exec_scopes: &mut ExecutionScopes,
     ids_data: &HashMap<String, HintReference>,
     ap_tracking: &ApTracking,
-    constants: &HashMap<String, BigInt>,
+    curve: &SecpCurve,
 ) -> Result<(), VirtualMachineError> {
-    let secp_p = bigint!(1).shl(256usize)
-        - constants
-            .get(SECP_REM)
-            .ok_or(VirtualMachineError::MissingConstant(SECP_REM))?;
+    let secp_p = curve.p()?;
 
     //ids.slope
     let slope_reloc = get_relocatable_from_var_name("slope", vm, ids_data, ap_tracking)?;
@@ -371,12 +353,9 @@ Implements hint:
 */
 pub fn fast_ec_add_assign_new_y(
     exec_scopes: &mut ExecutionScopes,
-    constants: &HashMap<String, BigInt>,
+    curve: &SecpCurve,
 ) -> Result<(), VirtualMachineError> {
-    let secp_p = bigint!(1).shl(256usize)
-        - constants
-            .get(SECP_REM)
-            .ok_or(VirtualMachineError::MissingConstant(SECP_REM))?;
+    let secp_p = curve.p()?;
 
     //Get variables from vm scope
     let (slope, x0, new_x, y0) = (
@@ -413,6 +392,8 @@ mod tests {
     use super::*;
     use crate::hint_processor::builtin_hint_processor::builtin_hint_processor_definition::BuiltinHintProcessor;
     use crate::hint_processor::builtin_hint_processor::builtin_hint_processor_definition::HintProcessorData;
+    use crate::hint_processor::builtin_hint_processor::hint_code;
+    use crate::hint_processor::builtin_hint_processor::secp::secp_utils::SECP_REM;
     use crate::hint_processor::hint_processor_definition::HintProcessor;
     use crate::types::exec_scope::ExecutionScopes;
     use crate::types::relocatable::MaybeRelocatable;
@@ -425,6 +406,7 @@ mod tests {
     use crate::{any_box, bigint_str};
     use num_bigint::{BigInt, Sign};
     use std::any::Any;
+    use std::ops::Shl;
 
     #[test]
     fn run_ec_negate_ok() {
@@ -921,4 +903,77 @@ mod tests {
         //Check hint memory inserts
         check_memory![&vm.memory, ((1, 2), 0)];
     }
+
+    #[test]
+    fn run_compute_doubling_slope_secp256r1_ok() {
+        let hint_code = hint_code::EC_DOUBLE_SCOPE_SECP256R1;
+        let mut vm = vm_with_range_check!();
+        //Insert the secp256r1 generator as ids.point
+        vm.memory = memory![
+            ((1, 0), 52227620040540588600771222u128),
+            ((1, 1), 33347259622618539004134583u128),
+            ((1, 2), 8091721874918813684698062u128),
+            ((1, 3), 59685082318776612195095029u128),
+            ((1, 4), 54599710628478995760242092u128),
+            ((1, 5), 6036146923926000695307902u128)
+        ];
+
+        //Initialize fp
+        vm.run_context.fp = 1;
+
+        let ids_data = ids_data!["point"];
+        let mut exec_scopes = ExecutionScopes::new();
+
+        //Execute the hint
+        assert_eq!(run_hint!(vm, ids_data, hint_code, &mut exec_scopes), Ok(()));
+        check_scope!(
+            &exec_scopes,
+            [(
+                "slope",
+                bigint_str!(
+                    b"73404963663004311880882944372748989162084677934852963787452504780932599885725"
+                )
+            )]
+        );
+    }
+
+    #[test]
+    fn run_compute_slope_secp256r1_ok() {
+        let hint_code = hint_code::COMPUTE_SLOPE_SECP256R1;
+        let mut vm = vm_with_range_check!();
+
+        //Insert the secp256r1 generator and its double as ids.point0 and ids.point1
+        vm.memory = memory![
+            ((1, 0), 52227620040540588600771222u128),
+            ((1, 1), 33347259622618539004134583u128),
+            ((1, 2), 8091721874918813684698062u128),
+            ((1, 3), 59685082318776612195095029u128),
+            ((1, 4), 54599710628478995760242092u128),
+            ((1, 5), 6036146923926000695307902u128),
+            ((1, 6), 60574784517941929169033592u128),
+            ((1, 7), 38742641973200156549941727u128),
+            ((1, 8), 9440742814978962916680995u128),
+            ((1, 9), 50180633949907515547874257u128),
+            ((1, 10), 52108912657982010475124979u128),
+            ((1, 11), 564125721045731681407961u128)
+        ];
+
+        //Initialize fp
+        vm.run_context.fp = 14;
+
+        let ids_data = non_continuous_ids_data![("point0", -14), ("point1", -8)];
+        let mut exec_scopes = ExecutionScopes::new();
+
+        //Execute the hint
+        assert_eq!(run_hint!(vm, ids_data, hint_code, &mut exec_scopes), Ok(()));
+        check_scope!(
+            &exec_scopes,
+            [(
+                "slope",
+                bigint_str!(
+                    b"18455939157588970446784565569990294768680858463148985486203132144235521409158"
+                )
+            )]
+        );
+    }
 }
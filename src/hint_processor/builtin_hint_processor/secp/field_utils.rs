@@ -2,7 +2,7 @@ use crate::bigint;
 use crate::hint_processor::builtin_hint_processor::hint_utils::{
     insert_value_from_var_name, insert_value_into_ap,
 };
-use crate::hint_processor::builtin_hint_processor::secp::secp_utils::SECP_REM;
+use crate::hint_processor::builtin_hint_processor::secp::secp_utils::SecpCurve;
 use crate::hint_processor::hint_processor_definition::HintReference;
 use crate::math_utils::div_mod;
 use crate::serde::deserialize_program::ApTracking;
@@ -13,7 +13,6 @@ use num_bigint::BigInt;
 use num_integer::Integer;
 use num_traits::Zero;
 use std::collections::HashMap;
-use std::ops::Shl;
 
 use super::secp_utils::pack_from_var_name;
 
@@ -31,12 +30,9 @@ pub fn verify_zero(
     vm: &mut VirtualMachine,
     ids_data: &HashMap<String, HintReference>,
     ap_tracking: &ApTracking,
-    constants: &HashMap<String, BigInt>,
+    curve: &SecpCurve,
 ) -> Result<(), VirtualMachineError> {
-    let secp_p = bigint!(1).shl(256usize)
-        - constants
-            .get(SECP_REM)
-            .ok_or(VirtualMachineError::MissingConstant(SECP_REM))?;
+    let secp_p = curve.p()?;
 
     let val = pack_from_var_name("val", vm, ids_data, ap_tracking)?;
     let (q, r) = val.div_rem(&secp_p);
@@ -61,12 +57,9 @@ pub fn reduce(
     exec_scopes: &mut ExecutionScopes,
     ids_data: &HashMap<String, HintReference>,
     ap_tracking: &ApTracking,
-    constants: &HashMap<String, BigInt>,
+    curve: &SecpCurve,
 ) -> Result<(), VirtualMachineError> {
-    let secp_p = bigint!(1).shl(256usize)
-        - constants
-            .get(SECP_REM)
-            .ok_or(VirtualMachineError::MissingConstant(SECP_REM))?;
+    let secp_p = curve.p()?;
 
     let value = pack_from_var_name("x", vm, ids_data, ap_tracking)?.mod_floor(&secp_p);
     exec_scopes.insert_value("value", value);
@@ -86,12 +79,9 @@ pub fn is_zero_pack(
     exec_scopes: &mut ExecutionScopes,
     ids_data: &HashMap<String, HintReference>,
     ap_tracking: &ApTracking,
-    constants: &HashMap<String, BigInt>,
+    curve: &SecpCurve,
 ) -> Result<(), VirtualMachineError> {
-    let secp_p = bigint!(1).shl(256usize)
-        - constants
-            .get(SECP_REM)
-            .ok_or(VirtualMachineError::MissingConstant(SECP_REM))?;
+    let secp_p = curve.p()?;
 
     let x_packed = pack_from_var_name("x", vm, ids_data, ap_tracking)?;
     let x = x_packed.mod_floor(&secp_p);
@@ -129,12 +119,9 @@ Implements hint:
 */
 pub fn is_zero_assign_scope_variables(
     exec_scopes: &mut ExecutionScopes,
-    constants: &HashMap<String, BigInt>,
+    curve: &SecpCurve,
 ) -> Result<(), VirtualMachineError> {
-    let secp_p = bigint!(1).shl(256usize)
-        - constants
-            .get(SECP_REM)
-            .ok_or(VirtualMachineError::MissingConstant(SECP_REM))?;
+    let secp_p = curve.p()?;
 
     //Get `x` variable from vm scope
     let x = exec_scopes.get::<BigInt>("x")?;
@@ -153,6 +140,8 @@ mod tests {
     use crate::bigint_str;
     use crate::hint_processor::builtin_hint_processor::builtin_hint_processor_definition::BuiltinHintProcessor;
     use crate::hint_processor::builtin_hint_processor::builtin_hint_processor_definition::HintProcessorData;
+    use crate::hint_processor::builtin_hint_processor::hint_code;
+    use crate::hint_processor::builtin_hint_processor::secp::secp_utils::SECP_REM;
     use crate::hint_processor::hint_processor_definition::HintProcessor;
     use crate::types::exec_scope::ExecutionScopes;
     use crate::types::relocatable::MaybeRelocatable;
@@ -164,6 +153,7 @@ mod tests {
     use crate::vm::vm_memory::memory::Memory;
     use num_bigint::Sign;
     use std::any::Any;
+    use std::ops::Shl;
 
     from_bigint_str![42];
 
@@ -656,4 +646,54 @@ mod tests {
             ))
         );
     }
+
+    #[test]
+    fn run_reduce_secp256r1_ok() {
+        let hint_code = hint_code::REDUCE_SECP256R1;
+        let mut vm = vm_with_range_check!();
+        add_segments!(vm, 3);
+
+        //Initialize fp
+        vm.run_context.fp = 25;
+
+        //Create hint data
+        let ids_data = non_continuous_ids_data![("x", -5)];
+
+        //ids.x is the secp256r1 prime plus 5
+        vm.memory = memory![
+            ((1, 20), 4),
+            ((1, 21), 1024),
+            ((1, 22), 19342813109330467168976896u128)
+        ];
+
+        let mut exec_scopes = ExecutionScopes::new();
+        //Execute the hint
+        assert_eq!(run_hint!(vm, ids_data, hint_code, &mut exec_scopes), Ok(()));
+
+        //Check 'value' is defined in the vm scope
+        assert_eq!(exec_scopes.get::<BigInt>("value"), Ok(bigint!(5)));
+    }
+
+    #[test]
+    fn run_verify_zero_secp256r1_with_secp256k1_value() {
+        let hint_code = hint_code::VERIFY_ZERO_SECP256R1;
+        let mut vm = vm_with_range_check!();
+        //Initialize run_context
+        run_context!(vm, 0, 9, 9);
+        //Create hint data
+        let ids_data = non_continuous_ids_data![("val", -5), ("q", 0)];
+        //ids.val is the secp256k1 prime, which isn't a multiple of the secp256r1 one
+        vm.memory = memory![
+            ((1, 4), 77371252455336262886226991u128),
+            ((1, 5), 77371252455336267181195263u128),
+            ((1, 6), 19342813113834066795298815u128)
+        ];
+        //Execute the hint
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code),
+            Err(VirtualMachineError::SecpVerifyZero(bigint_str!(
+                b"115792089237316195423570985008687907853269984665640564039457584007908834671663"
+            )))
+        );
+    }
 }
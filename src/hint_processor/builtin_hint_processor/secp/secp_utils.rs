@@ -1,15 +1,18 @@
 use crate::hint_processor::hint_processor_definition::HintReference;
 use crate::math_utils::as_int;
 use crate::serde::deserialize_program::ApTracking;
+use crate::types::identifier_manager::IdentifierManager;
 use crate::types::relocatable::Relocatable;
 use crate::vm::errors::vm_errors::VirtualMachineError;
 use crate::vm::vm_core::VirtualMachine;
 use crate::{
-    bigint, hint_processor::builtin_hint_processor::hint_utils::get_relocatable_from_var_name,
+    bigint, bigint_str,
+    hint_processor::builtin_hint_processor::hint_utils::get_relocatable_from_var_name,
 };
 use num_bigint::BigInt;
 use num_traits::{Signed, Zero};
 use std::collections::HashMap;
+use std::ops::Shl;
 
 // Constants in package "starkware.cairo.common.cairo_secp.constants".
 pub const BASE_86: &str = "starkware.cairo.common.cairo_secp.constants.BASE";
@@ -22,6 +25,161 @@ pub const P1: &str = "starkware.cairo.common.cairo_secp.constants.P1";
 pub const P2: &str = "starkware.cairo.common.cairo_secp.constants.P2";
 pub const SECP_REM: &str = "starkware.cairo.common.cairo_secp.constants.SECP_REM";
 
+// Constants in package "starkware.cairo.common.secp256r1.constants", split in limbs of 86 bits.
+pub const SECP256R1_P0: &str = "starkware.cairo.common.secp256r1.constants.P0";
+pub const SECP256R1_P1: &str = "starkware.cairo.common.secp256r1.constants.P1";
+pub const SECP256R1_P2: &str = "starkware.cairo.common.secp256r1.constants.P2";
+pub const SECP256R1_N0: &str = "starkware.cairo.common.secp256r1.constants.N0";
+pub const SECP256R1_N1: &str = "starkware.cairo.common.secp256r1.constants.N1";
+pub const SECP256R1_N2: &str = "starkware.cairo.common.secp256r1.constants.N2";
+pub const SECP256R1_A0: &str = "starkware.cairo.common.secp256r1.constants.A0";
+pub const SECP256R1_A1: &str = "starkware.cairo.common.secp256r1.constants.A1";
+pub const SECP256R1_A2: &str = "starkware.cairo.common.secp256r1.constants.A2";
+pub const SECP256R1_B0: &str = "starkware.cairo.common.secp256r1.constants.B0";
+pub const SECP256R1_B1: &str = "starkware.cairo.common.secp256r1.constants.B1";
+pub const SECP256R1_B2: &str = "starkware.cairo.common.secp256r1.constants.B2";
+
+// secp256r1 (NIST P-256) parameters, as hexadecimal strings. They are only used when the
+// program doesn't define the constants above, as the hints import them from python.
+pub const SECP256R1_P: &[u8] = b"ffffffff00000001000000000000000000000000ffffffffffffffffffffffff";
+pub const SECP256R1_N: &[u8] = b"ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551";
+pub const SECP256R1_ALPHA: &[u8] =
+    b"ffffffff00000001000000000000000000000000fffffffffffffffffffffffc";
+pub const SECP256R1_B: &[u8] = b"5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b";
+
+/// Curve y^2 = x^3 + alpha * x + beta, over the field of order p, used by the secp hints.
+/// The parameters are taken from the program's constants, as accessible from the given scopes
+/// of the hint through the program identifiers (or from the package of the curve's constants)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SecpCurve<'a> {
+    /// secp256k1, from package "starkware.cairo.common.cairo_secp.constants"
+    Secp256k1(
+        &'a HashMap<String, BigInt>,
+        IdentifierManager<'a>,
+        &'a [String],
+    ),
+    /// secp256r1 (NIST P-256), from package "starkware.cairo.common.secp256r1.constants"
+    Secp256r1(
+        &'a HashMap<String, BigInt>,
+        IdentifierManager<'a>,
+        &'a [String],
+    ),
+}
+
+impl SecpCurve<'_> {
+    ///Returns the order of the curve's field
+    pub fn p(&self) -> Result<BigInt, VirtualMachineError> {
+        match self {
+            SecpCurve::Secp256k1(constants, identifiers, accessible_scopes) => Ok(bigint!(1)
+                .shl(256usize)
+                - get_constant(constants, *identifiers, accessible_scopes, SECP_REM)?),
+            SecpCurve::Secp256r1(constants, identifiers, accessible_scopes) => {
+                Ok(get_limbs_constant(
+                    constants,
+                    *identifiers,
+                    accessible_scopes,
+                    [SECP256R1_P0, SECP256R1_P1, SECP256R1_P2],
+                )
+                .unwrap_or_else(|| bigint_str!(SECP256R1_P, 16)))
+            }
+        }
+    }
+
+    ///Returns the order of the curve's generator
+    pub fn n(&self) -> Result<BigInt, VirtualMachineError> {
+        match self {
+            SecpCurve::Secp256k1(constants, identifiers, accessible_scopes) => {
+                let base = get_constant(constants, *identifiers, accessible_scopes, BASE_86)?;
+                let n0 = get_constant(constants, *identifiers, accessible_scopes, N0)?;
+                let n1 = get_constant(constants, *identifiers, accessible_scopes, N1)?;
+                let n2 = get_constant(constants, *identifiers, accessible_scopes, N2)?;
+                Ok(n2 * base * base + n1 * base + n0)
+            }
+            SecpCurve::Secp256r1(constants, identifiers, accessible_scopes) => {
+                Ok(get_limbs_constant(
+                    constants,
+                    *identifiers,
+                    accessible_scopes,
+                    [SECP256R1_N0, SECP256R1_N1, SECP256R1_N2],
+                )
+                .unwrap_or_else(|| bigint_str!(SECP256R1_N, 16)))
+            }
+        }
+    }
+
+    pub fn alpha(&self) -> Result<BigInt, VirtualMachineError> {
+        match self {
+            SecpCurve::Secp256k1(..) => Ok(bigint!(0)),
+            SecpCurve::Secp256r1(constants, identifiers, accessible_scopes) => {
+                Ok(get_limbs_constant(
+                    constants,
+                    *identifiers,
+                    accessible_scopes,
+                    [SECP256R1_A0, SECP256R1_A1, SECP256R1_A2],
+                )
+                .unwrap_or_else(|| bigint_str!(SECP256R1_ALPHA, 16)))
+            }
+        }
+    }
+
+    pub fn beta(&self) -> Result<BigInt, VirtualMachineError> {
+        match self {
+            SecpCurve::Secp256k1(constants, identifiers, accessible_scopes) => {
+                get_constant(constants, *identifiers, accessible_scopes, BETA).cloned()
+            }
+            SecpCurve::Secp256r1(constants, identifiers, accessible_scopes) => {
+                Ok(get_limbs_constant(
+                    constants,
+                    *identifiers,
+                    accessible_scopes,
+                    [SECP256R1_B0, SECP256R1_B1, SECP256R1_B2],
+                )
+                .unwrap_or_else(|| bigint_str!(SECP256R1_B, 16)))
+            }
+        }
+    }
+}
+
+// Packs a constant split in three limbs of 86 bits, None if any of them isn't defined
+fn get_limbs_constant(
+    constants: &HashMap<String, BigInt>,
+    identifiers: IdentifierManager,
+    accessible_scopes: &[String],
+    limbs: [&'static str; 3],
+) -> Option<BigInt> {
+    let [d0, d1, d2] =
+        limbs.map(|limb| get_constant(constants, identifiers, accessible_scopes, limb).ok());
+    let base = bigint!(1).shl(86usize);
+    Some(d2? * &base * &base + d1? * &base + d0?)
+}
+
+// Looks the constant up by its full name in the secp package, then by its short name from the
+// accessible scopes. Constants found by their short name are only used if they resolve into the
+// package of the curve, so that unrelated constants with the same name are ignored
+fn get_constant<'a>(
+    constants: &'a HashMap<String, BigInt>,
+    identifiers: IdentifierManager<'a>,
+    accessible_scopes: &[String],
+    name: &'static str,
+) -> Result<&'a BigInt, VirtualMachineError> {
+    if let Some(value) = constants.get(name) {
+        return Ok(value);
+    }
+    let (package, short_name) = name.rsplit_once('.').unwrap_or_default();
+    match identifiers.search(accessible_scopes, short_name) {
+        Ok((full_name, identifier))
+            if full_name.rsplit_once('.').map(|(prefix, _)| prefix) == Some(package)
+                && identifier.type_.as_deref() == Some("const") =>
+        {
+            identifier
+                .value
+                .as_ref()
+                .ok_or(VirtualMachineError::MissingConstant(name))
+        }
+        _ => Err(VirtualMachineError::MissingConstant(name)),
+    }
+}
+
 /*
 Takes a 256-bit integer and returns its canonical representation as:
 d0 + BASE * d1 + BASE**2 * d2,
@@ -98,6 +256,19 @@ pub fn pack_from_relocatable(
 mod tests {
     use super::*;
     use crate::bigint_str;
+    use crate::serde::deserialize_program::Identifier;
+
+    fn identifier(type_: &str, value: Option<BigInt>, destination: Option<&str>) -> Identifier {
+        Identifier {
+            pc: None,
+            type_: Some(type_.to_string()),
+            value,
+            full_name: None,
+            members: None,
+            cairo_type: None,
+            destination: destination.map(String::from),
+        }
+    }
 
     #[test]
     fn secp_split() {
@@ -160,4 +331,123 @@ mod tests {
             bigint_str!(b"4441762184457963985490320281689802156301430343378457")
         );
     }
+
+    #[test]
+    fn secp256k1_curve_from_constants() {
+        let constants = HashMap::from([
+            (
+                SECP_REM.to_string(),
+                bigint!(1).shl(32_usize) + bigint!(977),
+            ),
+            (BETA.to_string(), bigint!(7)),
+        ]);
+        let identifiers = HashMap::new();
+        let curve = SecpCurve::Secp256k1(&constants, IdentifierManager::new(&identifiers), &[]);
+
+        assert_eq!(
+            curve.p(),
+            Ok(bigint_str!(
+                b"115792089237316195423570985008687907853269984665640564039457584007908834671663"
+            ))
+        );
+        assert_eq!(curve.alpha(), Ok(bigint!(0)));
+        assert_eq!(curve.beta(), Ok(bigint!(7)));
+        assert_eq!(
+            curve.n(),
+            Err(VirtualMachineError::MissingConstant(BASE_86))
+        );
+    }
+
+    #[test]
+    fn secp256k1_curve_from_imported_constants() {
+        // Constants imported from the secp package are found through their aliases, while
+        // unrelated constants with the same short name are ignored
+        let constants = HashMap::new();
+        let identifiers = HashMap::from([
+            (
+                SECP_REM.to_string(),
+                identifier("const", Some(bigint!(1).shl(32_usize) + bigint!(977)), None),
+            ),
+            (
+                "__main__.SECP_REM".to_string(),
+                identifier("alias", None, Some(SECP_REM)),
+            ),
+            (
+                "__main__.verify.BETA".to_string(),
+                identifier("const", Some(bigint!(7)), None),
+            ),
+        ]);
+        let accessible_scopes = ["__main__".to_string(), "__main__.verify".to_string()];
+        let curve = SecpCurve::Secp256k1(
+            &constants,
+            IdentifierManager::new(&identifiers),
+            &accessible_scopes,
+        );
+
+        assert_eq!(
+            curve.p(),
+            Ok(bigint_str!(
+                b"115792089237316195423570985008687907853269984665640564039457584007908834671663"
+            ))
+        );
+        assert_eq!(
+            curve.beta(),
+            Err(VirtualMachineError::MissingConstant(BETA))
+        );
+    }
+
+    #[test]
+    fn secp256r1_curve() {
+        let (constants, identifiers) = (HashMap::new(), HashMap::new());
+        let curve = SecpCurve::Secp256r1(&constants, IdentifierManager::new(&identifiers), &[]);
+        let p = curve.p().unwrap();
+
+        assert_eq!(
+            p,
+            bigint!(1).shl(256_usize) - bigint!(1).shl(224_usize)
+                + bigint!(1).shl(192_usize)
+                + bigint!(1).shl(96_usize)
+                - bigint!(1)
+        );
+        assert_eq!(curve.alpha(), Ok(p - 3));
+        assert_eq!(
+            curve.n(),
+            Ok(bigint_str!(
+                b"115792089210356248762697446949407573529996955224135760342422259061068512044369"
+            ))
+        );
+    }
+
+    #[test]
+    fn secp256r1_curve_from_imported_constants() {
+        // The program's constants take precedence over the default ones, while the secp256k1
+        // limbs imported into the hint's module are ignored
+        let constants = HashMap::from([
+            (SECP256R1_N0.to_string(), bigint!(5)),
+            (SECP256R1_N1.to_string(), bigint!(1)),
+            (SECP256R1_N2.to_string(), bigint!(0)),
+        ]);
+        let mut identifiers = HashMap::new();
+        for (limb, value) in [(P0, 7), (P1, 0), (P2, 1)] {
+            let short_name = limb.rsplit('.').next().unwrap();
+            identifiers.insert(
+                limb.to_string(),
+                identifier("const", Some(bigint!(value)), None),
+            );
+            identifiers.insert(
+                format!("__main__.{}", short_name),
+                identifier("alias", None, Some(limb)),
+            );
+        }
+        let accessible_scopes = ["__main__".to_string()];
+        let curve = SecpCurve::Secp256r1(
+            &constants,
+            IdentifierManager::new(&identifiers),
+            &accessible_scopes,
+        );
+
+        assert_eq!(curve.p(), Ok(bigint_str!(SECP256R1_P, 16)));
+        assert_eq!(curve.n(), Ok(bigint!(1).shl(86_usize) + bigint!(5)));
+        assert_eq!(curve.beta(), Ok(bigint_str!(SECP256R1_B, 16)));
+    }
 }
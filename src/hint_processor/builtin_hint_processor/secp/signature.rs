@@ -1,4 +1,4 @@
-use super::secp_utils::{BASE_86, BETA, N0, N1, N2, SECP_REM};
+use super::secp_utils::SecpCurve;
 use crate::hint_processor::builtin_hint_processor::hint_utils::get_integer_from_var_name;
 use crate::hint_processor::builtin_hint_processor::secp::secp_utils::pack_from_var_name;
 use crate::hint_processor::hint_processor_definition::HintReference;
@@ -7,13 +7,12 @@ use crate::{
     bigint,
     math_utils::{div_mod, safe_div},
     serde::deserialize_program::ApTracking,
-    types::exec_scope::ExecutionScopes,
+    types::{exec_scope::ExecutionScopes, identifier_manager::IdentifierManager},
     vm::errors::vm_errors::VirtualMachineError,
 };
 use num_bigint::BigInt;
 use num_integer::Integer;
 use std::collections::HashMap;
-use std::ops::Shl;
 
 /* Implements hint:
 from starkware.cairo.common.cairo_secp.secp_utils import N, pack
@@ -28,31 +27,16 @@ pub fn div_mod_n_packed_divmod(
     exec_scopes: &mut ExecutionScopes,
     ids_data: &HashMap<String, HintReference>,
     ap_tracking: &ApTracking,
-    constants: &HashMap<String, BigInt>,
+    curve: &SecpCurve,
 ) -> Result<(), VirtualMachineError> {
     let a = pack_from_var_name("a", vm, ids_data, ap_tracking)?;
     let b = pack_from_var_name("b", vm, ids_data, ap_tracking)?;
-
-    let n = {
-        let base = constants
-            .get(BASE_86)
-            .ok_or(VirtualMachineError::MissingConstant(BASE_86))?;
-        let n0 = constants
-            .get(N0)
-            .ok_or(VirtualMachineError::MissingConstant(N0))?;
-        let n1 = constants
-            .get(N1)
-            .ok_or(VirtualMachineError::MissingConstant(N1))?;
-        let n2 = constants
-            .get(N2)
-            .ok_or(VirtualMachineError::MissingConstant(N2))?;
-
-        (n2 * base * base) | (n1 * base) | n0
-    };
+    let n = curve.n()?;
 
     let value = div_mod(&a, &b, &n);
     exec_scopes.insert_value("a", a);
     exec_scopes.insert_value("b", b);
+    exec_scopes.insert_value("N", n);
     exec_scopes.insert_value("value", value.clone());
     exec_scopes.insert_value("res", value);
     Ok(())
@@ -60,62 +44,108 @@ pub fn div_mod_n_packed_divmod(
 
 // Implements hint:
 // value = k = safe_div(res * b - a, N)
+// N is imported into the scope by the div_mod_n_packed_divmod hint of the curve in use, it is
+// taken from the secp256k1 constants otherwise
 pub fn div_mod_n_safe_div(
     exec_scopes: &mut ExecutionScopes,
     constants: &HashMap<String, BigInt>,
+    identifiers: IdentifierManager,
+    accessible_scopes: &[String],
 ) -> Result<(), VirtualMachineError> {
+    let n = match exec_scopes.get::<BigInt>("N") {
+        Ok(n) => n,
+        Err(_) => SecpCurve::Secp256k1(constants, identifiers, accessible_scopes).n()?,
+    };
     let a = exec_scopes.get_ref::<BigInt>("a")?;
     let b = exec_scopes.get_ref::<BigInt>("b")?;
     let res = exec_scopes.get_ref::<BigInt>("res")?;
 
-    let n = {
-        let base = constants
-            .get(BASE_86)
-            .ok_or(VirtualMachineError::MissingConstant(BASE_86))?;
-        let n0 = constants
-            .get(N0)
-            .ok_or(VirtualMachineError::MissingConstant(N0))?;
-        let n1 = constants
-            .get(N1)
-            .ok_or(VirtualMachineError::MissingConstant(N1))?;
-        let n2 = constants
-            .get(N2)
-            .ok_or(VirtualMachineError::MissingConstant(N2))?;
-
-        n2 * base * base + n1 * base + n0
-    };
-
     let value = safe_div(&(res * b - a), &n)?;
 
     exec_scopes.insert_value("value", value);
     Ok(())
 }
 
+/* Implements hint:
+from starkware.cairo.common.cairo_secp.secp_utils import SECP_P, pack
+
+x_cube_int = pack(ids.x_cube, PRIME) % SECP_P
+y_square_int = (x_cube_int + ids.BETA) % SECP_P
+y = pow(y_square_int, (SECP_P + 1) // 4, SECP_P)
+
+# We need to decide whether to take y or SECP_P - y.
+if ids.v % 2 == y % 2:
+    value = y
+else:
+    value = (-y) % SECP_P
+*/
 pub fn get_point_from_x(
     vm: &mut VirtualMachine,
     exec_scopes: &mut ExecutionScopes,
     ids_data: &HashMap<String, HintReference>,
     ap_tracking: &ApTracking,
-    constants: &HashMap<String, BigInt>,
+    curve: &SecpCurve,
 ) -> Result<(), VirtualMachineError> {
-    let beta = constants
-        .get(BETA)
-        .ok_or(VirtualMachineError::MissingConstant(BETA))?;
-    let secp_p = bigint!(1).shl(256usize)
-        - constants
-            .get(SECP_REM)
-            .ok_or(VirtualMachineError::MissingConstant(SECP_REM))?;
+    let beta = curve.beta()?;
+    let secp_p = curve.p()?;
 
     let x_cube_int = pack_from_var_name("x_cube", vm, ids_data, ap_tracking)?.mod_floor(&secp_p);
-    let y_cube_int = (x_cube_int + beta).mod_floor(&secp_p);
-    let mut y = y_cube_int.modpow(&((&secp_p + 1) / 4), &secp_p);
+    let y_square_int = (x_cube_int + beta).mod_floor(&secp_p);
+    let y = y_from_y_square(y_square_int, vm, ids_data, ap_tracking, &secp_p)?;
+    exec_scopes.insert_value("value", y);
+    Ok(())
+}
+
+/* Implements hint:
+from starkware.cairo.common.cairo_secp.secp256r1_utils import SECP256R1_ALPHA as ALPHA
+from starkware.cairo.common.cairo_secp.secp256r1_utils import SECP256R1_B as BETA
+from starkware.cairo.common.cairo_secp.secp256r1_utils import SECP256R1_P as SECP_P
+from starkware.cairo.common.cairo_secp.secp_utils import pack
+from starkware.python.math_utils import y_squared_from_x
+
+x = pack(ids.x, PRIME) % SECP_P
+y_square_int = y_squared_from_x(x=x, alpha=ALPHA, beta=BETA, field_prime=SECP_P)
+y = pow(y_square_int, (SECP_P + 1) // 4, SECP_P)
+
+# We need to decide whether to take y or SECP_P - y.
+if ids.v % 2 == y % 2:
+    value = y
+else:
+    value = (-y) % SECP_P
+*/
+pub fn get_point_from_x_with_alpha(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+    curve: &SecpCurve,
+) -> Result<(), VirtualMachineError> {
+    let secp_p = curve.p()?;
+
+    let x = pack_from_var_name("x", vm, ids_data, ap_tracking)?.mod_floor(&secp_p);
+    let y_square_int =
+        (x.modpow(&bigint!(3), &secp_p) + curve.alpha()? * &x + curve.beta()?).mod_floor(&secp_p);
+    let y = y_from_y_square(y_square_int, vm, ids_data, ap_tracking, &secp_p)?;
+    exec_scopes.insert_value("value", y);
+    Ok(())
+}
+
+// Computes the square root of y_square, taking the one with the same parity as ids.v.
+// Only valid for primes congruent to 3 modulo 4, as both secp256k1 and secp256r1 primes are
+fn y_from_y_square(
+    y_square: BigInt,
+    vm: &VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+    secp_p: &BigInt,
+) -> Result<BigInt, VirtualMachineError> {
+    let y = y_square.modpow(&((secp_p + 1) / 4), secp_p);
 
     let v = get_integer_from_var_name("v", vm, ids_data, ap_tracking)?;
     if v.mod_floor(&bigint!(2)) != y.mod_floor(&bigint!(2)) {
-        y = (-y).mod_floor(&secp_p);
+        return Ok((-y).mod_floor(secp_p));
     }
-    exec_scopes.insert_value("value", y);
-    Ok(())
+    Ok(y)
 }
 
 #[cfg(test)]
@@ -125,6 +155,9 @@ mod tests {
     use crate::hint_processor::builtin_hint_processor::builtin_hint_processor_definition::BuiltinHintProcessor;
     use crate::hint_processor::builtin_hint_processor::builtin_hint_processor_definition::HintProcessorData;
     use crate::hint_processor::builtin_hint_processor::hint_code;
+    use crate::hint_processor::builtin_hint_processor::secp::secp_utils::{
+        BASE_86, BETA, N0, N1, N2, SECP_REM,
+    };
     use crate::hint_processor::hint_processor_definition::HintProcessor;
     use crate::{
         bigint, bigint_str,
@@ -137,6 +170,7 @@ mod tests {
     use num_bigint::BigInt;
     use num_bigint::Sign;
     use std::any::Any;
+    use std::ops::Shl;
 
     #[test]
     fn safe_div_ok() {
@@ -167,7 +201,15 @@ mod tests {
             run_hint!(vm, ids_data, hint_code, &mut exec_scopes, &constants),
             Ok(())
         );
-        assert_eq!(div_mod_n_safe_div(&mut exec_scopes, &constants), Ok(()));
+        assert_eq!(
+            div_mod_n_safe_div(
+                &mut exec_scopes,
+                &constants,
+                IdentifierManager::new(&HashMap::new()),
+                &[]
+            ),
+            Ok(())
+        );
     }
 
     #[test]
@@ -190,7 +232,9 @@ mod tests {
                 ]
                 .into_iter()
                 .map(|(k, v)| (k.to_string(), v))
-                .collect()
+                .collect(),
+                IdentifierManager::new(&HashMap::new()),
+                &[]
             ),
         );
     }
@@ -228,7 +272,8 @@ mod tests {
                 ]
                 .into_iter()
                 .map(|(k, v)| (k.to_string(), v))
-                .collect()
+                .collect(),
+                &[]
             ),
             Ok(())
         )
@@ -269,7 +314,8 @@ mod tests {
                 ]
                 .into_iter()
                 .map(|(k, v)| (k.to_string(), v))
-                .collect()
+                .collect(),
+                &[]
             ),
             Ok(())
         );
@@ -284,4 +330,72 @@ mod tests {
             )]
         );
     }
+
+    #[test]
+    fn get_point_from_x_secp256r1_ok() {
+        let hint_code = hint_code::GET_POINT_FROM_X_SECP256R1;
+        let mut vm = vm!();
+        let mut exec_scopes = ExecutionScopes::new();
+        //ids.v and ids.x, the x coordinate of the secp256r1 generator
+        vm.memory = memory![
+            ((1, 0), 1),
+            ((1, 1), 52227620040540588600771222u128),
+            ((1, 2), 33347259622618539004134583u128),
+            ((1, 3), 8091721874918813684698062u128)
+        ];
+        vm.run_context.fp = 2;
+
+        let ids_data = ids_data!["v", "x"];
+        assert_eq!(run_hint!(vm, ids_data, hint_code, &mut exec_scopes), Ok(()));
+
+        //The y coordinate of the generator, which is odd
+        check_scope!(
+            &exec_scopes,
+            [(
+                "value",
+                bigint_str!(
+                    b"36134250956749795798585127919587881956611106672985015071877198253568414405109"
+                )
+            )]
+        );
+    }
+
+    #[test]
+    fn div_mod_n_secp256r1_ok() {
+        let hint_code = hint_code::DIV_MOD_N_PACKED_DIVMOD_SECP256R1;
+        let mut vm = vm!();
+        vm.memory = memory![
+            ((1, 0), 12345),
+            ((1, 1), 0),
+            ((1, 2), 0),
+            ((1, 3), 6789),
+            ((1, 4), 0),
+            ((1, 5), 0)
+        ];
+        vm.run_context.fp = 3;
+        let ids_data = non_continuous_ids_data![("a", -3), ("b", 0)];
+        let mut exec_scopes = ExecutionScopes::new();
+        assert_eq!(run_hint!(vm, ids_data, hint_code, &mut exec_scopes), Ok(()));
+        check_scope!(
+            &exec_scopes,
+            [(
+                "res",
+                bigint_str!(
+                    b"25072082948773558061741824571458113579186260742300716998580162147557919090475"
+                )
+            )]
+        );
+
+        //The secp256r1 order is taken from the scope instead of the constants
+        assert_eq!(
+            div_mod_n_safe_div(
+                &mut exec_scopes,
+                &HashMap::new(),
+                IdentifierManager::new(&HashMap::new()),
+                &[]
+            ),
+            Ok(())
+        );
+        check_scope!(&exec_scopes, [("value", bigint!(1470))]);
+    }
 }
@@ -1,22 +1,32 @@
 use crate::bigint;
 use crate::hint_processor::builtin_hint_processor::hint_utils::{
-    get_integer_from_var_name, get_relocatable_from_var_name,
+    get_integer_from_var_name, get_member_addr_from_var_name, get_relocatable_from_var_name,
+    get_struct_from_var_name,
 };
 use crate::math_utils::isqrt;
 use crate::serde::deserialize_program::ApTracking;
+use crate::types::relocatable::Relocatable;
 use crate::vm::errors::vm_errors::VirtualMachineError;
 use crate::vm::vm_core::VirtualMachine;
 use num_bigint::BigInt;
 use num_integer::{div_rem, Integer};
-use num_traits::Signed;
+use num_traits::{Signed, Zero};
 use std::collections::HashMap;
-use std::ops::{Shl, Shr};
+use std::ops::{Shl, Shr, Sub};
 
 use crate::hint_processor::builtin_hint_processor::hint_utils::{
     insert_value_from_var_name, insert_value_into_ap,
 };
 use crate::hint_processor::hint_processor_definition::HintReference;
 
+crate::cairo_struct! {
+    /// starkware.cairo.common.uint256.Uint256, made of two 128-bit limbs
+    pub(crate) struct Uint256 = "Uint256" {
+        pub(crate) low: BigInt,
+        pub(crate) high: BigInt,
+    }
+}
+
 /*
 Implements hint:
 %{
@@ -33,16 +43,8 @@ pub fn uint256_add(
 ) -> Result<(), VirtualMachineError> {
     let shift: BigInt = bigint!(2).pow(128);
 
-    let a_relocatable = get_relocatable_from_var_name("a", vm, ids_data, ap_tracking)?;
-    let b_relocatable = get_relocatable_from_var_name("b", vm, ids_data, ap_tracking)?;
-    let a_low = vm.get_integer(&a_relocatable)?;
-    let a_high = vm.get_integer(&(a_relocatable + 1))?;
-    let b_low = vm.get_integer(&b_relocatable)?;
-    let b_high = vm.get_integer(&(b_relocatable + 1))?;
-    let a_low = a_low.as_ref();
-    let a_high = a_high.as_ref();
-    let b_low = b_low.as_ref();
-    let b_high = b_high.as_ref();
+    let a = get_struct_from_var_name::<Uint256>("a", vm, ids_data, ap_tracking)?;
+    let b = get_struct_from_var_name::<Uint256>("b", vm, ids_data, ap_tracking)?;
 
     //Main logic
     //sum_low = ids.a.low + ids.b.low
@@ -50,13 +52,13 @@ pub fn uint256_add(
     //sum_high = ids.a.high + ids.b.high + ids.carry_low
     //ids.carry_high = 1 if sum_high >= ids.SHIFT else 0
 
-    let carry_low = if a_low + b_low >= shift {
+    let carry_low = if &a.low + &b.low >= shift {
         bigint!(1)
     } else {
         bigint!(0)
     };
 
-    let carry_high = if a_high + b_high + &carry_low >= shift {
+    let carry_high = if a.high + b.high + &carry_low >= shift {
         bigint!(1)
     } else {
         bigint!(0)
@@ -105,12 +107,8 @@ pub fn uint256_sqrt(
     ids_data: &HashMap<String, HintReference>,
     ap_tracking: &ApTracking,
 ) -> Result<(), VirtualMachineError> {
-    let n_addr = get_relocatable_from_var_name("n", vm, ids_data, ap_tracking)?;
+    let n = get_uint256("n", vm, ids_data, ap_tracking)?;
     let root_addr = get_relocatable_from_var_name("root", vm, ids_data, ap_tracking)?;
-    let n_low = vm.get_integer(&n_addr)?;
-    let n_high = vm.get_integer(&(n_addr + 1))?;
-    let n_low = n_low.as_ref();
-    let n_high = n_high.as_ref();
 
     //Main logic
     //from starkware.python.math_utils import isqrt
@@ -120,7 +118,7 @@ pub fn uint256_sqrt(
     //ids.root.low = root
     //ids.root.high = 0
 
-    let root = isqrt(&(n_high.shl(128_usize) + n_low))?;
+    let root = isqrt(&n)?;
 
     if root.is_negative() || root >= bigint!(1).shl(128) {
         return Err(VirtualMachineError::AssertionFailed(format!(
@@ -141,8 +139,8 @@ pub fn uint256_signed_nn(
     ids_data: &HashMap<String, HintReference>,
     ap_tracking: &ApTracking,
 ) -> Result<(), VirtualMachineError> {
-    let a_addr = get_relocatable_from_var_name("a", vm, ids_data, ap_tracking)?;
-    let a_high = vm.get_integer(&(a_addr + 1))?;
+    let a_high_addr = get_member_addr_from_var_name("a", "high", vm, ids_data, ap_tracking)?;
+    let a_high = vm.get_integer(&a_high_addr)?;
     //Main logic
     //memory[ap] = 1 if 0 <= (ids.a.high % PRIME) < 2 ** 127 else 0
     let result: BigInt =
@@ -172,20 +170,11 @@ pub fn uint256_unsigned_div_rem(
     ids_data: &HashMap<String, HintReference>,
     ap_tracking: &ApTracking,
 ) -> Result<(), VirtualMachineError> {
-    let a_addr = get_relocatable_from_var_name("a", vm, ids_data, ap_tracking)?;
-    let div_addr = get_relocatable_from_var_name("div", vm, ids_data, ap_tracking)?;
+    let a = get_uint256("a", vm, ids_data, ap_tracking)?;
+    let div = get_uint256("div", vm, ids_data, ap_tracking)?;
     let quotient_addr = get_relocatable_from_var_name("quotient", vm, ids_data, ap_tracking)?;
     let remainder_addr = get_relocatable_from_var_name("remainder", vm, ids_data, ap_tracking)?;
 
-    let a_low = vm.get_integer(&a_addr)?;
-    let a_high = vm.get_integer(&(a_addr + 1))?;
-    let div_low = vm.get_integer(&div_addr)?;
-    let div_high = vm.get_integer(&(div_addr + 1))?;
-    let a_low = a_low.as_ref();
-    let a_high = a_high.as_ref();
-    let div_low = div_low.as_ref();
-    let div_high = div_high.as_ref();
-
     //Main logic
     //a = (ids.a.high << 128) + ids.a.low
     //div = (ids.div.high << 128) + ids.div.low
@@ -196,8 +185,6 @@ pub fn uint256_unsigned_div_rem(
     //ids.remainder.low = remainder & ((1 << 128) - 1)
     //ids.remainder.high = remainder >> 128
 
-    let a = a_high.shl(128_usize) + a_low;
-    let div = div_high.shl(128_usize) + div_low;
     //a and div will always be positive numbers
     //Then, Rust div_rem equals Python divmod
     let (quotient, remainder) = div_rem(a, div);
@@ -218,6 +205,184 @@ pub fn uint256_unsigned_div_rem(
     vm.insert_value(&(remainder_addr + 1), remainder_high)
 }
 
+/*
+Implements hint:
+%{
+    a = (ids.a.high << 128) + ids.a.low
+    div = (ids.div.b23 << 128) + ids.div.b01
+    quotient, remainder = divmod(a, div)
+
+    ids.quotient.low = quotient & ((1 << 128) - 1)
+    ids.quotient.high = quotient >> 128
+    ids.remainder.low = remainder & ((1 << 128) - 1)
+    ids.remainder.high = remainder >> 128
+%}
+*/
+pub fn uint256_expanded_unsigned_div_rem(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), VirtualMachineError> {
+    let div_addr = get_relocatable_from_var_name("div", vm, ids_data, ap_tracking)?;
+    let quotient_addr = get_relocatable_from_var_name("quotient", vm, ids_data, ap_tracking)?;
+    let remainder_addr = get_relocatable_from_var_name("remainder", vm, ids_data, ap_tracking)?;
+
+    let a = get_uint256("a", vm, ids_data, ap_tracking)?;
+    //Uint256Expanded is (B0, b01, b12, b23, b3), b01 and b23 hold the low and high limbs
+    let div_b01 = vm.get_integer(&(&div_addr + 1))?;
+    let div_b23 = vm.get_integer(&(div_addr + 3))?;
+    let div = div_b23.as_ref().shl(128_usize) + div_b01.as_ref();
+    if div.is_zero() {
+        return Err(VirtualMachineError::DividedByZero);
+    }
+
+    //a and div will always be positive numbers
+    //Then, Rust div_rem equals Python divmod
+    let (quotient, remainder) = div_rem(a, div);
+
+    insert_uint256(vm, &quotient_addr, quotient)?;
+    insert_uint256(vm, &remainder_addr, remainder)
+}
+
+/*
+Implements hint:
+%{
+    a = (ids.a.high << 128) + ids.a.low
+    b = (ids.b.high << 128) + ids.b.low
+    div = (ids.div.high << 128) + ids.div.low
+    quotient, remainder = divmod(a * b, div)
+
+    ids.quotient_low.low = quotient & ((1 << 128) - 1)
+    ids.quotient_low.high = (quotient >> 128) & ((1 << 128) - 1)
+    ids.quotient_high.low = (quotient >> 256) & ((1 << 128) - 1)
+    ids.quotient_high.high = quotient >> 384
+    ids.remainder.low = remainder & ((1 << 128) - 1)
+    ids.remainder.high = remainder >> 128
+%}
+*/
+pub fn uint256_mul_div_mod(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), VirtualMachineError> {
+    let quotient_low_addr =
+        get_relocatable_from_var_name("quotient_low", vm, ids_data, ap_tracking)?;
+    let quotient_high_addr =
+        get_relocatable_from_var_name("quotient_high", vm, ids_data, ap_tracking)?;
+    let remainder_addr = get_relocatable_from_var_name("remainder", vm, ids_data, ap_tracking)?;
+
+    let a = get_uint256("a", vm, ids_data, ap_tracking)?;
+    let b = get_uint256("b", vm, ids_data, ap_tracking)?;
+    let div = get_uint256("div", vm, ids_data, ap_tracking)?;
+    if div.is_zero() {
+        return Err(VirtualMachineError::DividedByZero);
+    }
+
+    //The 512-bit quotient is split into two Uint256
+    let (quotient, remainder) = div_rem(a * b, div);
+    let quotient_low = &quotient & bigint!(1).shl(256_usize).sub(1);
+    let quotient_high = quotient.shr(256_usize);
+
+    insert_uint256(vm, &quotient_low_addr, quotient_low)?;
+    insert_uint256(vm, &quotient_high_addr, quotient_high)?;
+    insert_uint256(vm, &remainder_addr, remainder)
+}
+
+/*
+Implements hint:
+%{
+    def split(num: int, num_bits_shift: int = 128, length: int = 2):
+        a = []
+        for _ in range(length):
+            a.append( num & ((1 << num_bits_shift) - 1) )
+            num = num >> num_bits_shift
+        return tuple(a)
+
+    def pack(z, num_bits_shift: int = 128) -> int:
+        limbs = (z.low, z.high)
+        return sum(limb << (num_bits_shift * i) for i, limb in enumerate(limbs))
+
+    a = pack(ids.a)
+    b = pack(ids.b)
+    res = (a - b)%2**256
+    res_split = split(res)
+    ids.res.low = res_split[0]
+    ids.res.high = res_split[1]
+%}
+*/
+pub fn uint256_sub(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), VirtualMachineError> {
+    let res_addr = get_relocatable_from_var_name("res", vm, ids_data, ap_tracking)?;
+
+    let a = get_uint256("a", vm, ids_data, ap_tracking)?;
+    let b = get_uint256("b", vm, ids_data, ap_tracking)?;
+    let res = (a - b).mod_floor(&bigint!(1).shl(256_usize));
+
+    insert_uint256(vm, &res_addr, res)
+}
+
+/*
+Implements hint:
+%{
+    PRIME = 2**255 - 19
+    II = pow(2, (PRIME - 1) // 4, PRIME)
+
+    xx = ids.xx.low + (ids.xx.high<<128)
+    x = pow(xx, (PRIME + 3) // 8, PRIME)
+    if (x * x - xx) % PRIME != 0:
+        x = (x * II) % PRIME
+    if x % 2 != 0:
+        x = PRIME - x
+    ids.x.low = x & ((1<<128)-1)
+    ids.x.high = x >> 128
+%}
+*/
+pub fn split_xx(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), VirtualMachineError> {
+    let x_addr = get_relocatable_from_var_name("x", vm, ids_data, ap_tracking)?;
+
+    //Computes the square root of xx modulo the ed25519 prime, picking the even root
+    let prime = bigint!(1).shl(255_usize).sub(19);
+    let ii = bigint!(2).modpow(&((&prime - 1_u32) / 4_u32), &prime);
+    let xx = get_uint256("xx", vm, ids_data, ap_tracking)?;
+    let mut x = xx.modpow(&((&prime + 3_u32) / 8_u32), &prime);
+    if !(&x * &x - &xx).mod_floor(&prime).is_zero() {
+        x = (x * ii).mod_floor(&prime);
+    }
+    if x.is_odd() {
+        x = &prime - x;
+    }
+
+    insert_uint256(vm, &x_addr, x)
+}
+
+//Reads the Uint256 held by the given ids variable as a single integer
+fn get_uint256(
+    var_name: &str,
+    vm: &VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<BigInt, VirtualMachineError> {
+    let value = get_struct_from_var_name::<Uint256>(var_name, vm, ids_data, ap_tracking)?;
+    Ok(value.high.shl(128_usize) + value.low)
+}
+
+//Writes value into the Uint256 located at addr
+fn insert_uint256(
+    vm: &mut VirtualMachine,
+    addr: &Relocatable,
+    value: BigInt,
+) -> Result<(), VirtualMachineError> {
+    vm.insert_value(addr, &value & bigint!(u128::MAX))?;
+    vm.insert_value(&(addr + 1), value.shr(128_usize))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -225,7 +390,9 @@ mod tests {
     use crate::bigint_str;
     use crate::hint_processor::builtin_hint_processor::builtin_hint_processor_definition::BuiltinHintProcessor;
     use crate::hint_processor::builtin_hint_processor::builtin_hint_processor_definition::HintProcessorData;
+    use crate::hint_processor::builtin_hint_processor::hint_code;
     use crate::hint_processor::hint_processor_definition::HintProcessor;
+    use crate::serde::deserialize_program::Member;
     use crate::types::exec_scope::ExecutionScopes;
     use crate::types::relocatable::MaybeRelocatable;
     use crate::types::relocatable::Relocatable;
@@ -237,7 +404,35 @@ mod tests {
     use num_bigint::{BigInt, Sign};
     use std::any::Any;
 
-    from_bigint_str![33];
+    //Gives the given ids variables the Uint256 type, as the program references would
+    fn uint256_ids_data(
+        mut ids_data: HashMap<String, HintReference>,
+        uint256_names: &[&str],
+    ) -> HashMap<String, HintReference> {
+        for name in uint256_names {
+            let hint_ref = ids_data.get_mut(*name).unwrap();
+            hint_ref.cairo_type = Some("starkware.cairo.common.uint256.Uint256".to_string());
+            hint_ref.members = Some(HashMap::from([
+                (
+                    "low".to_string(),
+                    Member {
+                        cairo_type: "felt".to_string(),
+                        offset: 0,
+                    },
+                ),
+                (
+                    "high".to_string(),
+                    Member {
+                        cairo_type: "felt".to_string(),
+                        offset: 1,
+                    },
+                ),
+            ]));
+        }
+        ids_data
+    }
+
+    from_bigint_str![21, 33];
 
     #[test]
     fn run_uint256_add_ok() {
@@ -246,8 +441,10 @@ mod tests {
         //Initialize fp
         vm.run_context.fp = 10;
         //Create hint_data
-        let ids_data =
-            non_continuous_ids_data![("a", -6), ("b", -4), ("carry_high", 3), ("carry_low", 2)];
+        let ids_data = uint256_ids_data(
+            non_continuous_ids_data![("a", -6), ("b", -4), ("carry_high", 3), ("carry_low", 2)],
+            &["a", "b"],
+        );
         vm.memory = memory![
             ((1, 4), 2),
             ((1, 5), 3),
@@ -267,8 +464,10 @@ mod tests {
         //Initialize fp
         vm.run_context.fp = 10;
         //Create hint_data
-        let ids_data =
-            non_continuous_ids_data![("a", -6), ("b", -4), ("carry_high", 3), ("carry_low", 2)];
+        let ids_data = uint256_ids_data(
+            non_continuous_ids_data![("a", -6), ("b", -4), ("carry_high", 3), ("carry_low", 2)],
+            &["a", "b"],
+        );
         //Insert ids into memory
         vm.memory = memory![
             ((1, 4), 2),
@@ -366,7 +565,7 @@ mod tests {
         //Initialize fp
         vm.run_context.fp = 5;
         //Create hint_data
-        let ids_data = non_continuous_ids_data![("n", -5), ("root", 0)];
+        let ids_data = uint256_ids_data(non_continuous_ids_data![("n", -5), ("root", 0)], &["n"]);
         vm.memory = memory![((1, 0), 17), ((1, 1), 7)];
         //Execute the hint
         assert_eq!(run_hint!(vm, ids_data, hint_code), Ok(()));
@@ -382,7 +581,7 @@ mod tests {
         //Initialize fp
         vm.run_context.fp = 5;
         //Create hint_data
-        let ids_data = non_continuous_ids_data![("n", -5), ("root", 0)];
+        let ids_data = uint256_ids_data(non_continuous_ids_data![("n", -5), ("root", 0)], &["n"]);
         vm.memory = memory![
             ((1, 0), 0),
             ((1, 1), (b"340282366920938463463374607431768211458", 10))
@@ -403,7 +602,7 @@ mod tests {
         //Initialize fp
         vm.run_context.fp = 5;
         //Create hint_data
-        let ids_data = non_continuous_ids_data![("n", -5), ("root", 0)];
+        let ids_data = uint256_ids_data(non_continuous_ids_data![("n", -5), ("root", 0)], &["n"]);
         //Insert  ids.n.low into memory
         vm.memory = memory![((1, 0), 17), ((1, 1), 7), ((1, 5), 1)];
         //Execute the hint
@@ -426,7 +625,7 @@ mod tests {
         //Initialize run_context
         run_context!(vm, 0, 5, 4);
         //Create hint_data
-        let ids_data = non_continuous_ids_data![("a", -4)];
+        let ids_data = uint256_ids_data(non_continuous_ids_data![("a", -4)], &["a"]);
         //Insert ids.a.high into memory
         vm.memory = memory![(
             (1, 1),
@@ -449,7 +648,7 @@ mod tests {
         //Initialize run_context
         run_context!(vm, 0, 5, 4);
         //Create hint_data
-        let ids_data = non_continuous_ids_data![("a", -4)];
+        let ids_data = uint256_ids_data(non_continuous_ids_data![("a", -4)], &["a"]);
         //Insert ids.a.high into memory
         vm.memory = memory![(
             (1, 1),
@@ -472,7 +671,7 @@ mod tests {
         //Initialize run_context
         run_context!(vm, 0, 5, 4);
         //Create hint_data
-        let ids_data = non_continuous_ids_data![("a", -4)];
+        let ids_data = uint256_ids_data(non_continuous_ids_data![("a", -4)], &["a"]);
         vm.memory = memory![((1, 1), 1), ((1, 5), 55)];
         //Execute the hint
         assert_eq!(
@@ -494,8 +693,10 @@ mod tests {
         //Initialize fp
         vm.run_context.fp = 10;
         //Create hint_data
-        let ids_data =
-            non_continuous_ids_data![("a", -6), ("div", -4), ("quotient", 0), ("remainder", 2)];
+        let ids_data = uint256_ids_data(
+            non_continuous_ids_data![("a", -6), ("div", -4), ("quotient", 0), ("remainder", 2)],
+            &["a", "div"],
+        );
         //Insert ids into memory
         vm.memory = memory![((1, 4), 89), ((1, 5), 72), ((1, 6), 3), ((1, 7), 7)];
         //Execute the hint
@@ -518,8 +719,10 @@ mod tests {
         //Initialize fp
         vm.run_context.fp = 10;
         //Create hint_data
-        let ids_data =
-            non_continuous_ids_data![("a", -6), ("div", -4), ("quotient", 0), ("remainder", 2)];
+        let ids_data = uint256_ids_data(
+            non_continuous_ids_data![("a", -6), ("div", -4), ("quotient", 0), ("remainder", 2)],
+            &["a", "div"],
+        );
         //Insert ids into memory
         vm.memory = memory![
             ((1, 4), 89),
@@ -540,4 +743,187 @@ mod tests {
             ))
         );
     }
+
+    #[test]
+    fn run_expanded_unsigned_div_rem_ok() {
+        let hint_code = hint_code::UINT256_EXPANDED_UNSIGNED_DIV_REM;
+        let mut vm = vm_with_range_check!();
+        //Initialize fp
+        vm.run_context.fp = 10;
+        //Create hint_data
+        let ids_data = uint256_ids_data(
+            non_continuous_ids_data![("a", -7), ("div", -5), ("quotient", 0), ("remainder", 2)],
+            &["a"],
+        );
+        //Insert ids into memory
+        //ids.div is Uint256Expanded(B0, b01, b12, b23, b3)
+        vm.memory = memory![
+            ((1, 3), 89),
+            ((1, 4), 72),
+            ((1, 5), 3),
+            ((1, 6), 3),
+            ((1, 7), (b"129127208515966861312", 10)),
+            ((1, 8), 7),
+            ((1, 9), 0)
+        ];
+        //Execute the hint
+        assert_eq!(run_hint!(vm, ids_data, hint_code), Ok(()));
+        //Check hint memory inserts
+        //ids.quotient.low, ids.quotient.high, ids.remainder.low, ids.remainder.high
+        check_memory![
+            &vm.memory,
+            ((1, 10), 10),
+            ((1, 11), 0),
+            ((1, 12), 59),
+            ((1, 13), 2)
+        ];
+    }
+
+    #[test]
+    fn run_expanded_unsigned_div_rem_zero_divisor() {
+        let hint_code = hint_code::UINT256_EXPANDED_UNSIGNED_DIV_REM;
+        let mut vm = vm_with_range_check!();
+        vm.run_context.fp = 10;
+        let ids_data = uint256_ids_data(
+            non_continuous_ids_data![("a", -7), ("div", -5), ("quotient", 0), ("remainder", 2)],
+            &["a"],
+        );
+        vm.memory = memory![
+            ((1, 3), 89),
+            ((1, 4), 72),
+            ((1, 5), 0),
+            ((1, 6), 0),
+            ((1, 7), 0),
+            ((1, 8), 0),
+            ((1, 9), 0)
+        ];
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code),
+            Err(VirtualMachineError::DividedByZero)
+        );
+    }
+
+    #[test]
+    fn run_mul_div_mod_ok() {
+        let hint_code = hint_code::UINT256_MUL_DIV_MOD;
+        let mut vm = vm_with_range_check!();
+        //Initialize fp
+        vm.run_context.fp = 10;
+        //Create hint_data
+        let ids_data = uint256_ids_data(
+            non_continuous_ids_data![
+                ("a", -8),
+                ("b", -6),
+                ("div", -4),
+                ("quotient_low", 0),
+                ("quotient_high", 2),
+                ("remainder", 4)
+            ],
+            &["a", "b", "div"],
+        );
+        //Insert ids into memory
+        vm.memory = memory![
+            ((1, 2), 89),
+            ((1, 3), 72),
+            ((1, 4), 3),
+            ((1, 5), 7),
+            ((1, 6), 107),
+            ((1, 7), 114)
+        ];
+        //Execute the hint
+        assert_eq!(run_hint!(vm, ids_data, hint_code), Ok(()));
+        //Check hint memory inserts
+        //ids.quotient_low, ids.quotient_high, ids.remainder
+        check_memory![
+            &vm.memory,
+            ((1, 10), (b"143276786071974089879315624181797141668", 10)),
+            ((1, 11), 4),
+            ((1, 12), 0),
+            ((1, 13), 0),
+            ((1, 14), (b"322372768661941702228460154409043568767", 10)),
+            ((1, 15), 101)
+        ];
+    }
+
+    #[test]
+    fn run_mul_div_mod_invalid_memory_insert() {
+        let hint_code = hint_code::UINT256_MUL_DIV_MOD;
+        let mut vm = vm_with_range_check!();
+        vm.run_context.fp = 10;
+        let ids_data = uint256_ids_data(
+            non_continuous_ids_data![
+                ("a", -8),
+                ("b", -6),
+                ("div", -4),
+                ("quotient_low", 0),
+                ("quotient_high", 2),
+                ("remainder", 4)
+            ],
+            &["a", "b", "div"],
+        );
+        vm.memory = memory![
+            ((1, 2), 89),
+            ((1, 3), 72),
+            ((1, 4), 3),
+            ((1, 5), 7),
+            ((1, 6), 107),
+            ((1, 7), 114),
+            ((1, 10), 0)
+        ];
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code),
+            Err(VirtualMachineError::MemoryError(
+                MemoryError::InconsistentMemory(
+                    MaybeRelocatable::from((1, 10)),
+                    MaybeRelocatable::from(bigint!(0)),
+                    MaybeRelocatable::from(bigint_str!(b"143276786071974089879315624181797141668"))
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn run_uint256_sub_ok() {
+        let hint_code = hint_code::UINT256_SUB;
+        let mut vm = vm_with_range_check!();
+        //Initialize fp
+        vm.run_context.fp = 10;
+        //Create hint_data
+        let ids_data = uint256_ids_data(
+            non_continuous_ids_data![("a", -6), ("b", -4), ("res", 0)],
+            &["a", "b"],
+        );
+        //Insert ids into memory
+        vm.memory = memory![((1, 4), 5), ((1, 5), 2), ((1, 6), 3), ((1, 7), 7)];
+        //Execute the hint
+        assert_eq!(run_hint!(vm, ids_data, hint_code), Ok(()));
+        //Check hint memory inserts
+        //ids.res.low, ids.res.high, wrapping around 2**256
+        check_memory![
+            &vm.memory,
+            ((1, 10), 2),
+            ((1, 11), (b"340282366920938463463374607431768211451", 10))
+        ];
+    }
+
+    #[test]
+    fn run_split_xx_ok() {
+        let hint_code = hint_code::SPLIT_XX;
+        let mut vm = vm_with_range_check!();
+        //Initialize fp
+        vm.run_context.fp = 10;
+        //Create hint_data
+        let ids_data = uint256_ids_data(non_continuous_ids_data![("xx", -2), ("x", 0)], &["xx"]);
+        //Insert ids into memory
+        vm.memory = memory![((1, 8), 25), ((1, 9), 0)];
+        //Execute the hint
+        assert_eq!(run_hint!(vm, ids_data, hint_code), Ok(()));
+        //Check hint memory inserts
+        //ids.x is the even square root of 25, 2**255 - 19 - 5
+        check_memory![
+            &vm.memory,
+            ((1, 10), (b"340282366920938463463374607431768211432", 10)),
+            ((1, 11), (b"170141183460469231731687303715884105727", 10))
+        ];
+    }
 }
@@ -0,0 +1,665 @@
+use crate::bigint;
+use crate::hint_processor::builtin_hint_processor::hint_utils::{
+    get_integer_from_var_name, get_relocatable_from_var_name, insert_value_from_var_name,
+    insert_value_into_ap,
+};
+use crate::hint_processor::hint_processor_definition::HintReference;
+use crate::math_utils::isqrt;
+use crate::serde::deserialize_program::ApTracking;
+use crate::types::identifier_manager::IdentifierManager;
+use crate::types::relocatable::Relocatable;
+use crate::vm::errors::vm_errors::VirtualMachineError;
+use crate::vm::vm_core::VirtualMachine;
+use num_bigint::BigInt;
+use num_integer::{div_rem, Integer};
+use num_traits::{Signed, Zero};
+use std::collections::HashMap;
+use std::ops::{Shl, Shr};
+
+/*
+Implements hint:
+%{
+    def split(num: int, num_bits_shift: int, length: int):
+        a = []
+        for _ in range(length):
+            a.append( num & ((1 << num_bits_shift) - 1) )
+            num = num >> num_bits_shift
+        return tuple(a)
+
+    def pack(z, num_bits_shift: int) -> int:
+        limbs = (z.d0, z.d1, z.d2)
+        return sum(limb << (num_bits_shift * i) for i, limb in enumerate(limbs))
+
+    a = pack(ids.a, num_bits_shift = 128)
+    div = pack(ids.div, num_bits_shift = 128)
+    quotient, remainder = divmod(a, div)
+
+    quotient_split = split(quotient, num_bits_shift=128, length=3)
+    assert len(quotient_split) == 3
+
+    ids.quotient.d0 = quotient_split[0]
+    ids.quotient.d1 = quotient_split[1]
+    ids.quotient.d2 = quotient_split[2]
+
+    remainder_split = split(remainder, num_bits_shift=128, length=3)
+    ids.remainder.d0 = remainder_split[0]
+    ids.remainder.d1 = remainder_split[1]
+    ids.remainder.d2 = remainder_split[2]
+%}
+*/
+pub fn uint384_unsigned_div_rem(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), VirtualMachineError> {
+    let a_addr = get_relocatable_from_var_name("a", vm, ids_data, ap_tracking)?;
+    let div_addr = get_relocatable_from_var_name("div", vm, ids_data, ap_tracking)?;
+    let quotient_addr = get_relocatable_from_var_name("quotient", vm, ids_data, ap_tracking)?;
+    let remainder_addr = get_relocatable_from_var_name("remainder", vm, ids_data, ap_tracking)?;
+
+    let a = get_limbs(vm, &a_addr, 3, 1)?;
+    let div = get_limbs(vm, &div_addr, 3, 1)?;
+    if div.is_zero() {
+        return Err(VirtualMachineError::DividedByZero);
+    }
+
+    //a and div will always be positive numbers
+    //Then, Rust div_rem equals Python divmod
+    let (quotient, remainder) = div_rem(a, div);
+
+    insert_limbs(vm, &quotient_addr, quotient, 3)?;
+    insert_limbs(vm, &remainder_addr, remainder, 3)
+}
+
+/*
+Implements hint:
+%{
+    ids.low = ids.a & ((1<<128) - 1)
+    ids.high = ids.a >> 128
+%}
+*/
+pub fn uint384_split_128(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), VirtualMachineError> {
+    let a = get_integer_from_var_name("a", vm, ids_data, ap_tracking)?;
+    let low = a.as_ref() & bigint!(u128::MAX);
+    let high = a.as_ref().shr(128_usize);
+    insert_value_from_var_name("low", low, vm, ids_data, ap_tracking)?;
+    insert_value_from_var_name("high", high, vm, ids_data, ap_tracking)
+}
+
+/*
+Implements hint:
+%{
+    sum_d0 = ids.a.d0 + ids.b.d0
+    ids.carry_d0 = 1 if sum_d0 >= ids.SHIFT else 0
+    sum_d1 = ids.a.d1 + ids.b.d1 + ids.carry_d0
+    ids.carry_d1 = 1 if sum_d1 >= ids.SHIFT else 0
+    sum_d2 = ids.a.d2 + ids.b.d2 + ids.carry_d1
+    ids.carry_d2 = 1 if sum_d2 >= ids.SHIFT else 0
+%}
+*/
+pub fn add_no_uint384_check(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+    accessible_scopes: &[String],
+) -> Result<(), VirtualMachineError> {
+    let identifiers = vm.get_identifiers();
+    let shift = IdentifierManager::new(&identifiers)
+        .get_const(accessible_scopes, "SHIFT")?
+        .clone();
+
+    let a_addr = get_relocatable_from_var_name("a", vm, ids_data, ap_tracking)?;
+    let b_addr = get_relocatable_from_var_name("b", vm, ids_data, ap_tracking)?;
+
+    let mut carry = bigint!(0);
+    for (i, carry_name) in ["carry_d0", "carry_d1", "carry_d2"].iter().enumerate() {
+        let a_limb = vm.get_integer(&(&a_addr + i))?;
+        let b_limb = vm.get_integer(&(&b_addr + i))?;
+        carry = if a_limb.as_ref() + b_limb.as_ref() + &carry >= shift {
+            bigint!(1)
+        } else {
+            bigint!(0)
+        };
+        insert_value_from_var_name(carry_name, carry.clone(), vm, ids_data, ap_tracking)?;
+    }
+    Ok(())
+}
+
+/*
+Implements hint:
+%{
+    from starkware.python.math_utils import isqrt
+
+    def split(num: int, num_bits_shift: int, length: int):
+        a = []
+        for _ in range(length):
+            a.append( num & ((1 << num_bits_shift) - 1) )
+            num = num >> num_bits_shift
+        return tuple(a)
+
+    def pack(z, num_bits_shift: int) -> int:
+        limbs = (z.d0, z.d1, z.d2)
+        return sum(limb << (num_bits_shift * i) for i, limb in enumerate(limbs))
+
+    a = pack(ids.a, num_bits_shift=128)
+    root = isqrt(a)
+    assert 0 <= root < 2 ** 192
+    root_split = split(root, num_bits_shift=128, length=3)
+    ids.root.d0 = root_split[0]
+    ids.root.d1 = root_split[1]
+    ids.root.d2 = root_split[2]
+%}
+*/
+pub fn uint384_sqrt(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), VirtualMachineError> {
+    let a_addr = get_relocatable_from_var_name("a", vm, ids_data, ap_tracking)?;
+    let root_addr = get_relocatable_from_var_name("root", vm, ids_data, ap_tracking)?;
+
+    let root = isqrt(&get_limbs(vm, &a_addr, 3, 1)?)?;
+
+    if root.is_negative() || root >= bigint!(1).shl(192_usize) {
+        return Err(VirtualMachineError::AssertionFailed(format!(
+            "assert 0 <= {} < 2 ** 192",
+            &root
+        )));
+    }
+    insert_limbs(vm, &root_addr, root, 3)
+}
+
+/*
+Implements hint:
+%{ memory[ap] = 1 if 0 <= (ids.a.d2 % PRIME) < 2 ** 127 else 0 %}
+*/
+pub fn uint384_signed_nn(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), VirtualMachineError> {
+    let a_addr = get_relocatable_from_var_name("a", vm, ids_data, ap_tracking)?;
+    let a_d2 = vm.get_integer(&(a_addr + 2))?;
+    let result: BigInt =
+        if !a_d2.is_negative() && (a_d2.mod_floor(vm.get_prime())) <= bigint!(i128::MAX) {
+            bigint!(1)
+        } else {
+            bigint!(0)
+        };
+    insert_value_into_ap(vm, result)
+}
+
+/*
+Implements hint:
+%{
+    def split(num: int, num_bits_shift: int, length: int):
+        a = []
+        for _ in range(length):
+            a.append( num & ((1 << num_bits_shift) - 1) )
+            num = num >> num_bits_shift
+        return tuple(a)
+
+    def pack(z, num_bits_shift: int) -> int:
+        limbs = (z.d0, z.d1, z.d2)
+        return sum(limb << (num_bits_shift * i) for i, limb in enumerate(limbs))
+
+    def pack2(z, num_bits_shift: int) -> int:
+        limbs = (z.b01, z.b23, z.b45)
+        return sum(limb << (num_bits_shift * i) for i, limb in enumerate(limbs))
+
+    a = pack(ids.a, num_bits_shift = 128)
+    div = pack2(ids.div, num_bits_shift = 128)
+    quotient, remainder = divmod(a, div)
+
+    quotient_split = split(quotient, num_bits_shift=128, length=3)
+    assert len(quotient_split) == 3
+
+    ids.quotient.d0 = quotient_split[0]
+    ids.quotient.d1 = quotient_split[1]
+    ids.quotient.d2 = quotient_split[2]
+
+    remainder_split = split(remainder, num_bits_shift=128, length=3)
+    ids.remainder.d0 = remainder_split[0]
+    ids.remainder.d1 = remainder_split[1]
+    ids.remainder.d2 = remainder_split[2]
+%}
+*/
+pub fn uint384_unsigned_div_rem_expanded(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), VirtualMachineError> {
+    let a_addr = get_relocatable_from_var_name("a", vm, ids_data, ap_tracking)?;
+    let div_addr = get_relocatable_from_var_name("div", vm, ids_data, ap_tracking)?;
+    let quotient_addr = get_relocatable_from_var_name("quotient", vm, ids_data, ap_tracking)?;
+    let remainder_addr = get_relocatable_from_var_name("remainder", vm, ids_data, ap_tracking)?;
+
+    let a = get_limbs(vm, &a_addr, 3, 1)?;
+    //Uint384Expanded is (B0, b01, b12, b23, b34, b45, b5), b01, b23 and b45 hold the limbs
+    let div = get_limbs(vm, &(div_addr + 1), 3, 2)?;
+    if div.is_zero() {
+        return Err(VirtualMachineError::DividedByZero);
+    }
+
+    //a and div will always be positive numbers
+    //Then, Rust div_rem equals Python divmod
+    let (quotient, remainder) = div_rem(a, div);
+
+    insert_limbs(vm, &quotient_addr, quotient, 3)?;
+    insert_limbs(vm, &remainder_addr, remainder, 3)
+}
+
+//Reads n_limbs 128-bit limbs, placed step cells apart starting at addr, as a single integer
+pub(crate) fn get_limbs(
+    vm: &VirtualMachine,
+    addr: &Relocatable,
+    n_limbs: usize,
+    step: usize,
+) -> Result<BigInt, VirtualMachineError> {
+    let mut value = bigint!(0);
+    for i in (0..n_limbs).rev() {
+        let limb = vm.get_integer(&(addr + i * step))?;
+        value = value.shl(128_usize) + limb.as_ref();
+    }
+    Ok(value)
+}
+
+//Writes value split into n_limbs 128-bit limbs starting at addr, the last limb holds the
+//remaining high bits
+pub(crate) fn insert_limbs(
+    vm: &mut VirtualMachine,
+    addr: &Relocatable,
+    mut value: BigInt,
+    n_limbs: usize,
+) -> Result<(), VirtualMachineError> {
+    for i in 0..n_limbs - 1 {
+        vm.insert_value(&(addr + i), &value & bigint!(u128::MAX))?;
+        value = value.shr(128_usize);
+    }
+    vm.insert_value(&(addr + (n_limbs - 1)), value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::any_box;
+    use crate::hint_processor::builtin_hint_processor::builtin_hint_processor_definition::BuiltinHintProcessor;
+    use crate::hint_processor::builtin_hint_processor::builtin_hint_processor_definition::HintProcessorData;
+    use crate::hint_processor::builtin_hint_processor::hint_code;
+    use crate::hint_processor::hint_processor_definition::HintProcessor;
+    use crate::serde::deserialize_program::Identifier;
+    use crate::types::exec_scope::ExecutionScopes;
+    use crate::types::relocatable::MaybeRelocatable;
+    use crate::utils::test_utils::*;
+    use crate::vm::errors::memory_errors::MemoryError;
+    use crate::vm::vm_core::VirtualMachine;
+    use crate::vm::vm_memory::memory::Memory;
+    use crate::{bigint, vm::runners::builtin_runner::RangeCheckBuiltinRunner};
+    use num_bigint::{BigInt, Sign};
+    use std::any::Any;
+    use std::rc::Rc;
+
+    #[test]
+    fn run_uint384_unsigned_div_rem_ok() {
+        let mut vm = vm_with_range_check!();
+        //Initialize fp
+        vm.run_context.fp = 10;
+        //Create ids_data
+        let ids_data =
+            non_continuous_ids_data![("a", -9), ("div", -6), ("quotient", -3), ("remainder", 0)];
+        //Insert ids into memory
+        vm.memory = memory![
+            //a
+            ((1, 1), 83434123481193248_u64),
+            ((1, 2), 82349321849739284_u64),
+            ((1, 3), 839243219401320423_u64),
+            //div
+            ((1, 4), 9283430921839492319493_u128),
+            ((1, 5), 313248123482483248_u64),
+            ((1, 6), 3790328402913840_u64)
+        ];
+        //Execute the hint
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::UINT384_UNSIGNED_DIV_REM),
+            Ok(())
+        );
+        //Check hint memory inserts
+        //ids.quotient.d0, ids.quotient.d1, ids.quotient.d2
+        //ids.remainder.d0, ids.remainder.d1, ids.remainder.d2
+        check_memory![
+            &vm.memory,
+            ((1, 7), 221),
+            ((1, 8), 0),
+            ((1, 9), 0),
+            ((1, 10), (b"340282366920936411825224315027446796751", 10)),
+            ((1, 11), (b"340282366920938463394229121463989152931", 10)),
+            ((1, 12), 1580642357361782_u64)
+        ];
+    }
+
+    #[test]
+    fn run_uint384_unsigned_div_rem_zero_divisor() {
+        let mut vm = vm_with_range_check!();
+        vm.run_context.fp = 10;
+        let ids_data =
+            non_continuous_ids_data![("a", -9), ("div", -6), ("quotient", -3), ("remainder", 0)];
+        vm.memory = memory![
+            ((1, 1), 83434123481193248_u64),
+            ((1, 2), 82349321849739284_u64),
+            ((1, 3), 839243219401320423_u64),
+            ((1, 4), 0),
+            ((1, 5), 0),
+            ((1, 6), 0)
+        ];
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::UINT384_UNSIGNED_DIV_REM),
+            Err(VirtualMachineError::DividedByZero)
+        );
+    }
+
+    #[test]
+    fn run_uint384_split_128_ok() {
+        let mut vm = vm_with_range_check!();
+        vm.run_context.fp = 3;
+        let ids_data = non_continuous_ids_data![("a", -1), ("low", 0), ("high", 1)];
+        vm.memory = memory![((1, 2), 34895349583295832495320945304_u128)];
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::UINT384_SPLIT_128),
+            Ok(())
+        );
+        //ids.low, ids.high
+        check_memory![
+            &vm.memory,
+            ((1, 3), 34895349583295832495320945304_u128),
+            ((1, 4), 0)
+        ];
+    }
+
+    #[test]
+    fn run_uint384_split_128_big_a() {
+        let mut vm = vm_with_range_check!();
+        vm.run_context.fp = 3;
+        let ids_data = non_continuous_ids_data![("a", -1), ("low", 0), ("high", 1)];
+        vm.memory = memory![(
+            (1, 2),
+            (
+                b"3618502788666131213697322783095070105623107215331596699973092056135872020480",
+                10
+            )
+        )];
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::UINT384_SPLIT_128),
+            Ok(())
+        );
+        //ids.low, ids.high
+        check_memory![
+            &vm.memory,
+            ((1, 3), 0),
+            ((1, 4), (b"10633823966279327296825105735305134080", 10))
+        ];
+    }
+
+    // Runs the hint from the main scope, which defines SHIFT
+    fn run_add_no_uint384_check(
+        vm: &mut VirtualMachine,
+        ids_data: HashMap<String, HintReference>,
+        shift: BigInt,
+    ) -> Result<(), VirtualMachineError> {
+        vm.identifiers = Rc::new(HashMap::from([(
+            "__main__.SHIFT".to_string(),
+            Identifier {
+                pc: None,
+                type_: Some("const".to_string()),
+                value: Some(shift),
+                full_name: None,
+                members: None,
+                cairo_type: None,
+                destination: None,
+            },
+        )]));
+        let mut hint_data =
+            HintProcessorData::new_default(hint_code::ADD_NO_UINT384_CHECK.to_string(), ids_data);
+        hint_data.accessible_scopes = vec!["__main__".to_string()];
+        BuiltinHintProcessor::new_empty().execute_hint(
+            vm,
+            exec_scopes_ref!(),
+            &any_box!(hint_data),
+            &HashMap::new(),
+        )
+    }
+
+    #[test]
+    fn run_add_no_uint384_check_ok() {
+        let mut vm = vm_with_range_check!();
+        vm.run_context.fp = 10;
+        let ids_data = non_continuous_ids_data![
+            ("a", -10),
+            ("b", -7),
+            ("carry_d0", -4),
+            ("carry_d1", -3),
+            ("carry_d2", -2)
+        ];
+        vm.memory = memory![
+            //a
+            ((1, 0), (b"340282366920938463463374607431768211455", 10)),
+            ((1, 1), 2),
+            ((1, 2), (b"340282366920938463463374607431768211455", 10)),
+            //b
+            ((1, 3), 1),
+            ((1, 4), 2),
+            ((1, 5), 1)
+        ];
+        assert_eq!(
+            run_add_no_uint384_check(&mut vm, ids_data, bigint!(1).shl(128_usize)),
+            Ok(())
+        );
+        //ids.carry_d0, ids.carry_d1, ids.carry_d2
+        check_memory![&vm.memory, ((1, 6), 1), ((1, 7), 0), ((1, 8), 1)];
+    }
+
+    #[test]
+    fn run_add_no_uint384_check_with_program_shift() {
+        let mut vm = vm_with_range_check!();
+        vm.run_context.fp = 10;
+        let ids_data = non_continuous_ids_data![
+            ("a", -10),
+            ("b", -7),
+            ("carry_d0", -4),
+            ("carry_d1", -3),
+            ("carry_d2", -2)
+        ];
+        vm.memory = memory![
+            ((1, 0), 6),
+            ((1, 1), 2),
+            ((1, 2), 7),
+            ((1, 3), 3),
+            ((1, 4), 5),
+            ((1, 5), 1)
+        ];
+        assert_eq!(
+            run_add_no_uint384_check(&mut vm, ids_data, bigint!(8)),
+            Ok(())
+        );
+        check_memory![&vm.memory, ((1, 6), 1), ((1, 7), 1), ((1, 8), 1)];
+    }
+
+    #[test]
+    fn run_add_no_uint384_check_missing_shift() {
+        let mut vm = vm_with_range_check!();
+        let ids_data = non_continuous_ids_data![("a", -10), ("b", -7)];
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::ADD_NO_UINT384_CHECK),
+            Err(VirtualMachineError::UnknownIdentifier("SHIFT".to_string()))
+        );
+    }
+
+    #[test]
+    fn run_add_no_uint384_check_invalid_memory_insert() {
+        let mut vm = vm_with_range_check!();
+        vm.run_context.fp = 10;
+        let ids_data = non_continuous_ids_data![
+            ("a", -10),
+            ("b", -7),
+            ("carry_d0", -4),
+            ("carry_d1", -3),
+            ("carry_d2", -2)
+        ];
+        vm.memory = memory![
+            ((1, 0), 3),
+            ((1, 1), 2),
+            ((1, 2), 1),
+            ((1, 3), 1),
+            ((1, 4), 2),
+            ((1, 5), 1),
+            ((1, 6), 2)
+        ];
+        assert_eq!(
+            run_add_no_uint384_check(&mut vm, ids_data, bigint!(1).shl(128_usize)),
+            Err(VirtualMachineError::MemoryError(
+                MemoryError::InconsistentMemory(
+                    MaybeRelocatable::from((1, 6)),
+                    MaybeRelocatable::from(bigint!(2)),
+                    MaybeRelocatable::from(bigint!(0))
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn run_uint384_sqrt_ok() {
+        let mut vm = vm_with_range_check!();
+        vm.run_context.fp = 5;
+        let ids_data = non_continuous_ids_data![("a", -5), ("root", 0)];
+        vm.memory = memory![
+            ((1, 0), 83434123481193248_u64),
+            ((1, 1), 82349321849739284_u64),
+            ((1, 2), 1)
+        ];
+        assert_eq!(run_hint!(vm, ids_data, hint_code::UINT384_SQRT), Ok(()));
+        //ids.root.d0, ids.root.d1, ids.root.d2
+        check_memory![
+            &vm.memory,
+            ((1, 5), 41174660924869641_u64),
+            ((1, 6), 1),
+            ((1, 7), 0)
+        ];
+    }
+
+    #[test]
+    fn run_uint384_signed_nn_ok_result_one() {
+        let mut vm = vm_with_range_check!();
+        vm.run_context.fp = 3;
+        vm.run_context.ap = 5;
+        let ids_data = non_continuous_ids_data![("a", -3)];
+        vm.memory = memory![
+            ((1, 0), 1),
+            ((1, 1), 1),
+            ((1, 2), (b"170141183460469231731687303715884105727", 10))
+        ];
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::UINT384_SIGNED_NN),
+            Ok(())
+        );
+        check_memory![&vm.memory, ((1, 5), 1)];
+    }
+
+    #[test]
+    fn run_uint384_signed_nn_ok_result_zero() {
+        let mut vm = vm_with_range_check!();
+        vm.run_context.fp = 3;
+        vm.run_context.ap = 5;
+        let ids_data = non_continuous_ids_data![("a", -3)];
+        vm.memory = memory![
+            ((1, 0), 1),
+            ((1, 1), 1),
+            ((1, 2), (b"170141183460469231731687303715884105728", 10))
+        ];
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::UINT384_SIGNED_NN),
+            Ok(())
+        );
+        check_memory![&vm.memory, ((1, 5), 0)];
+    }
+
+    #[test]
+    fn run_uint384_unsigned_div_rem_expanded_ok() {
+        let mut vm = vm_with_range_check!();
+        vm.run_context.fp = 13;
+        let ids_data =
+            non_continuous_ids_data![("a", -13), ("div", -10), ("quotient", -3), ("remainder", 0)];
+        vm.memory = memory![
+            //a
+            ((1, 0), 1000),
+            ((1, 1), 0),
+            ((1, 2), 1),
+            //div, only b01, b23 and b45 are read
+            ((1, 3), 0),
+            ((1, 4), 7),
+            ((1, 5), 0),
+            ((1, 6), 0),
+            ((1, 7), 0),
+            ((1, 8), 1),
+            ((1, 9), 0)
+        ];
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::UINT384_UNSIGNED_DIV_REM_EXPANDED),
+            Ok(())
+        );
+        //ids.quotient.d0, ids.quotient.d1, ids.quotient.d2
+        //ids.remainder.d0, ids.remainder.d1, ids.remainder.d2
+        check_memory![
+            &vm.memory,
+            ((1, 10), 1),
+            ((1, 11), 0),
+            ((1, 12), 0),
+            ((1, 13), 993),
+            ((1, 14), 0),
+            ((1, 15), 0)
+        ];
+    }
+
+    #[test]
+    fn run_uint384_unsigned_div_rem_expanded_zero_divisor() {
+        let mut vm = vm_with_range_check!();
+        vm.run_context.fp = 13;
+        let ids_data =
+            non_continuous_ids_data![("a", -13), ("div", -10), ("quotient", -3), ("remainder", 0)];
+        vm.memory = memory![
+            ((1, 0), 1000),
+            ((1, 1), 0),
+            ((1, 2), 1),
+            ((1, 3), 5),
+            ((1, 4), 0),
+            ((1, 5), 5),
+            ((1, 6), 0),
+            ((1, 7), 5),
+            ((1, 8), 0),
+            ((1, 9), 5)
+        ];
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::UINT384_UNSIGNED_DIV_REM_EXPANDED),
+            Err(VirtualMachineError::DividedByZero)
+        );
+    }
+
+    #[test]
+    fn get_and_insert_limbs() {
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), 3), ((1, 1), 2), ((1, 2), 1)];
+        let value = get_limbs(&vm, &Relocatable::from((1, 0)), 3, 1).unwrap();
+        assert_eq!(
+            value,
+            bigint!(1).shl(256_usize) + bigint!(2).shl(128_usize) + bigint!(3)
+        );
+        insert_limbs(&mut vm, &Relocatable::from((1, 3)), value, 2).unwrap();
+        check_memory![
+            &vm.memory,
+            ((1, 3), 3),
+            ((1, 4), (b"340282366920938463463374607431768211458", 10))
+        ];
+    }
+}
@@ -0,0 +1,249 @@
+use crate::hint_processor::builtin_hint_processor::hint_utils::get_relocatable_from_var_name;
+use crate::hint_processor::builtin_hint_processor::uint384::{get_limbs, insert_limbs};
+use crate::hint_processor::hint_processor_definition::HintReference;
+use crate::serde::deserialize_program::ApTracking;
+use crate::vm::errors::vm_errors::VirtualMachineError;
+use crate::vm::vm_core::VirtualMachine;
+use num_bigint::BigInt;
+use num_integer::{div_rem, Integer};
+use num_traits::{One, Zero};
+use std::collections::HashMap;
+
+/*
+Implements hint:
+%{
+    def split(num: int, num_bits_shift: int, length: int):
+        a = []
+        for _ in range(length):
+            a.append( num & ((1 << num_bits_shift) - 1) )
+            num = num >> num_bits_shift
+        return tuple(a)
+
+    def pack(z, num_bits_shift: int) -> int:
+        limbs = (z.d0, z.d1, z.d2)
+        return sum(limb << (num_bits_shift * i) for i, limb in enumerate(limbs))
+
+    def pack_extended(z, num_bits_shift: int) -> int:
+        limbs = (z.d0, z.d1, z.d2, z.d3, z.d4, z.d5)
+        return sum(limb << (num_bits_shift * i) for i, limb in enumerate(limbs))
+
+    a = pack_extended(ids.a, num_bits_shift = 128)
+    div = pack(ids.div, num_bits_shift = 128)
+
+    quotient, remainder = divmod(a, div)
+
+    quotient_split = split(quotient, num_bits_shift=128, length=6)
+
+    ids.quotient.d0 = quotient_split[0]
+    ids.quotient.d1 = quotient_split[1]
+    ids.quotient.d2 = quotient_split[2]
+    ids.quotient.d3 = quotient_split[3]
+    ids.quotient.d4 = quotient_split[4]
+    ids.quotient.d5 = quotient_split[5]
+
+    remainder_split = split(remainder, num_bits_shift=128, length=3)
+    ids.remainder.d0 = remainder_split[0]
+    ids.remainder.d1 = remainder_split[1]
+    ids.remainder.d2 = remainder_split[2]
+%}
+*/
+pub fn unsigned_div_rem_uint768_by_uint384(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), VirtualMachineError> {
+    let a_addr = get_relocatable_from_var_name("a", vm, ids_data, ap_tracking)?;
+    let div_addr = get_relocatable_from_var_name("div", vm, ids_data, ap_tracking)?;
+    let quotient_addr = get_relocatable_from_var_name("quotient", vm, ids_data, ap_tracking)?;
+    let remainder_addr = get_relocatable_from_var_name("remainder", vm, ids_data, ap_tracking)?;
+
+    let a = get_limbs(vm, &a_addr, 6, 1)?;
+    let div = get_limbs(vm, &div_addr, 3, 1)?;
+    if div.is_zero() {
+        return Err(VirtualMachineError::DividedByZero);
+    }
+
+    //a and div will always be positive numbers
+    //Then, Rust div_rem equals Python divmod
+    let (quotient, remainder) = div_rem(a, div);
+
+    insert_limbs(vm, &quotient_addr, quotient, 6)?;
+    insert_limbs(vm, &remainder_addr, remainder, 3)
+}
+
+/*
+Implements hint:
+%{
+    def pack_512(u, num_bits_shift: int) -> int:
+        limbs = (u.d0, u.d1, u.d2, u.d3)
+        return sum(limb << (num_bits_shift * i) for i, limb in enumerate(limbs))
+
+    x = pack_512(ids.x, num_bits_shift = 128)
+    p = ids.p.low + (ids.p.high << 128)
+    x_inverse_mod_p = pow(x,-1, p)
+
+    x_inverse_mod_p_split = (x_inverse_mod_p & ((1 << 128) - 1), x_inverse_mod_p >> 128)
+
+    ids.x_inverse_mod_p.low = x_inverse_mod_p_split[0]
+    ids.x_inverse_mod_p.high = x_inverse_mod_p_split[1]
+%}
+*/
+pub fn inv_mod_p_uint512(
+    vm: &mut VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+) -> Result<(), VirtualMachineError> {
+    let x_addr = get_relocatable_from_var_name("x", vm, ids_data, ap_tracking)?;
+    let p_addr = get_relocatable_from_var_name("p", vm, ids_data, ap_tracking)?;
+    let x_inverse_mod_p_addr =
+        get_relocatable_from_var_name("x_inverse_mod_p", vm, ids_data, ap_tracking)?;
+
+    let x = get_limbs(vm, &x_addr, 4, 1)?;
+    let p = get_limbs(vm, &p_addr, 2, 1)?;
+    if p.is_zero() {
+        return Err(VirtualMachineError::DividedByZero);
+    }
+
+    //Python's pow(x, -1, p) fails if x has no inverse modulo p
+    let gcd = x.extended_gcd(&p);
+    if !gcd.gcd.is_one() {
+        return Err(VirtualMachineError::NoModularInverse(x, p));
+    }
+    let x_inverse_mod_p = gcd.x.mod_floor(&p);
+
+    insert_limbs(vm, &x_inverse_mod_p_addr, x_inverse_mod_p, 2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::any_box;
+    use crate::hint_processor::builtin_hint_processor::builtin_hint_processor_definition::BuiltinHintProcessor;
+    use crate::hint_processor::builtin_hint_processor::builtin_hint_processor_definition::HintProcessorData;
+    use crate::hint_processor::builtin_hint_processor::hint_code;
+    use crate::hint_processor::hint_processor_definition::HintProcessor;
+    use crate::types::exec_scope::ExecutionScopes;
+    use crate::types::relocatable::MaybeRelocatable;
+    use crate::utils::test_utils::*;
+    use crate::vm::errors::memory_errors::MemoryError;
+    use crate::vm::vm_core::VirtualMachine;
+    use crate::vm::vm_memory::memory::Memory;
+    use crate::{bigint, vm::runners::builtin_runner::RangeCheckBuiltinRunner};
+    use num_bigint::{BigInt, Sign};
+    use std::any::Any;
+
+    #[test]
+    fn run_unsigned_div_rem_uint768_by_uint384_ok() {
+        let mut vm = vm_with_range_check!();
+        vm.run_context.fp = 17;
+        let ids_data = non_continuous_ids_data![
+            ("a", -17),
+            ("div", -11),
+            ("quotient", -8),
+            ("remainder", -2)
+        ];
+        vm.memory = memory![
+            //a
+            ((1, 0), 1000),
+            ((1, 1), 0),
+            ((1, 2), 0),
+            ((1, 3), 0),
+            ((1, 4), 0),
+            ((1, 5), 7),
+            //div
+            ((1, 6), 3),
+            ((1, 7), 0),
+            ((1, 8), 1)
+        ];
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::UNSIGNED_DIV_REM_UINT768_BY_UINT384),
+            Ok(())
+        );
+        //ids.quotient.d0 ... ids.quotient.d5
+        //ids.remainder.d0, ids.remainder.d1, ids.remainder.d2
+        check_memory![
+            &vm.memory,
+            ((1, 9), 0),
+            ((1, 10), (b"340282366920938463463374607431768211435", 10)),
+            ((1, 11), (b"340282366920938463463374607431768211455", 10)),
+            ((1, 12), 6),
+            ((1, 13), 0),
+            ((1, 14), 0),
+            ((1, 15), 1000),
+            ((1, 16), 63),
+            ((1, 17), 0)
+        ];
+    }
+
+    #[test]
+    fn run_unsigned_div_rem_uint768_by_uint384_zero_divisor() {
+        let mut vm = vm_with_range_check!();
+        vm.run_context.fp = 17;
+        let ids_data = non_continuous_ids_data![
+            ("a", -17),
+            ("div", -11),
+            ("quotient", -8),
+            ("remainder", -2)
+        ];
+        vm.memory = memory![
+            ((1, 0), 1000),
+            ((1, 1), 0),
+            ((1, 2), 0),
+            ((1, 3), 0),
+            ((1, 4), 0),
+            ((1, 5), 7),
+            ((1, 6), 0),
+            ((1, 7), 0),
+            ((1, 8), 0)
+        ];
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::UNSIGNED_DIV_REM_UINT768_BY_UINT384),
+            Err(VirtualMachineError::DividedByZero)
+        );
+    }
+
+    #[test]
+    fn run_inv_mod_p_uint512_ok() {
+        let mut vm = vm_with_range_check!();
+        vm.run_context.fp = 6;
+        let ids_data = non_continuous_ids_data![("x", -6), ("p", -2), ("x_inverse_mod_p", 0)];
+        vm.memory = memory![
+            //x
+            ((1, 0), 3),
+            ((1, 1), 0),
+            ((1, 2), 0),
+            ((1, 3), 0),
+            //p
+            ((1, 4), 7),
+            ((1, 5), 0)
+        ];
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::INV_MOD_P_UINT512),
+            Ok(())
+        );
+        //ids.x_inverse_mod_p.low, ids.x_inverse_mod_p.high
+        check_memory![&vm.memory, ((1, 6), 5), ((1, 7), 0)];
+    }
+
+    #[test]
+    fn run_inv_mod_p_uint512_no_inverse() {
+        let mut vm = vm_with_range_check!();
+        vm.run_context.fp = 6;
+        let ids_data = non_continuous_ids_data![("x", -6), ("p", -2), ("x_inverse_mod_p", 0)];
+        vm.memory = memory![
+            ((1, 0), 4),
+            ((1, 1), 0),
+            ((1, 2), 0),
+            ((1, 3), 0),
+            ((1, 4), 6),
+            ((1, 5), 0)
+        ];
+        assert_eq!(
+            run_hint!(vm, ids_data, hint_code::INV_MOD_P_UINT512),
+            Err(VirtualMachineError::NoModularInverse(
+                bigint!(4),
+                bigint!(6)
+            ))
+        );
+    }
+}
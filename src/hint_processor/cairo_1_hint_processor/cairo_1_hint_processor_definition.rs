@@ -0,0 +1,764 @@
+use super::cairo_1_hints::{Cairo1Hint, CellRef, DerefOrImmediate, Operation, ResOperand};
+use super::dict_manager::{DictManagerExecScope, DICT_MANAGER_EXEC_SCOPE};
+use crate::any_box;
+use crate::hint_processor::hint_processor_definition::{HintProcessor, HintReference};
+use crate::hint_processor::hint_processor_utils::bigint_to_usize;
+use crate::math_utils::isqrt;
+use crate::serde::deserialize_program::ApTracking;
+use crate::types::exec_scope::ExecutionScopes;
+use crate::types::instruction::Register;
+use crate::types::relocatable::{MaybeRelocatable, Relocatable};
+use crate::vm::errors::vm_errors::VirtualMachineError;
+use crate::vm::vm_core::VirtualMachine;
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{One, Zero};
+use std::any::Any;
+use std::collections::HashMap;
+
+/// Hint processor running the structured hints of Cairo 1 programs compiled to CASM.
+/// The program loader stores each hint as its JSON encoding, which compile_hint parses back,
+/// so the processor can be combined with Cairo 0 processors in a `CompositeHintProcessor`.
+#[derive(Default)]
+pub struct Cairo1HintProcessor {}
+
+impl Cairo1HintProcessor {
+    pub fn new() -> Self {
+        Cairo1HintProcessor {}
+    }
+
+    fn alloc_segment(
+        &self,
+        vm: &mut VirtualMachine,
+        dst: &CellRef,
+    ) -> Result<(), VirtualMachineError> {
+        let segment = vm.add_memory_segment();
+        vm.insert_value(&cell_ref_to_relocatable(dst, vm)?, segment)
+    }
+
+    fn test_less_than(
+        &self,
+        vm: &mut VirtualMachine,
+        lhs: &ResOperand,
+        rhs: &ResOperand,
+        dst: &CellRef,
+        or_equal: bool,
+    ) -> Result<(), VirtualMachineError> {
+        let lhs = get_int(vm, lhs)?;
+        let rhs = get_int(vm, rhs)?;
+        let result = if or_equal { lhs <= rhs } else { lhs < rhs };
+        vm.insert_value(
+            &cell_ref_to_relocatable(dst, vm)?,
+            BigInt::from(result as u8),
+        )
+    }
+
+    fn wide_mul_128(
+        &self,
+        vm: &mut VirtualMachine,
+        lhs: &ResOperand,
+        rhs: &ResOperand,
+        high: &CellRef,
+        low: &CellRef,
+    ) -> Result<(), VirtualMachineError> {
+        let mask = (BigInt::one() << 128_usize) - 1_u32;
+        let product = get_int(vm, lhs)? * get_int(vm, rhs)?;
+        vm.insert_value(&cell_ref_to_relocatable(high, vm)?, &product >> 128_usize)?;
+        vm.insert_value(&cell_ref_to_relocatable(low, vm)?, product & mask)
+    }
+
+    fn div_mod(
+        &self,
+        vm: &mut VirtualMachine,
+        lhs: &ResOperand,
+        rhs: &ResOperand,
+        quotient: &CellRef,
+        remainder: &CellRef,
+    ) -> Result<(), VirtualMachineError> {
+        let lhs = get_int(vm, lhs)?;
+        let rhs = get_int(vm, rhs)?;
+        if rhs.is_zero() {
+            return Err(VirtualMachineError::DividedByZero);
+        }
+        let (q, r) = lhs.div_mod_floor(&rhs);
+        vm.insert_value(&cell_ref_to_relocatable(quotient, vm)?, q)?;
+        vm.insert_value(&cell_ref_to_relocatable(remainder, vm)?, r)
+    }
+
+    fn square_root(
+        &self,
+        vm: &mut VirtualMachine,
+        value: &ResOperand,
+        dst: &CellRef,
+    ) -> Result<(), VirtualMachineError> {
+        let root = isqrt(&get_int(vm, value)?)?;
+        vm.insert_value(&cell_ref_to_relocatable(dst, vm)?, root)
+    }
+
+    // Splits value into x * scalar + y, with x as large as possible without exceeding max_x
+    fn linear_split(
+        &self,
+        vm: &mut VirtualMachine,
+        value: &ResOperand,
+        scalar: &ResOperand,
+        max_x: &ResOperand,
+        x: &CellRef,
+        y: &CellRef,
+    ) -> Result<(), VirtualMachineError> {
+        let value = get_int(vm, value)?;
+        let scalar = get_int(vm, scalar)?;
+        let max_x = get_int(vm, max_x)?;
+        if scalar.is_zero() {
+            return Err(VirtualMachineError::DividedByZero);
+        }
+        let x_value = value.div_floor(&scalar).min(max_x);
+        let y_value = value - &x_value * scalar;
+        vm.insert_value(&cell_ref_to_relocatable(x, vm)?, x_value)?;
+        vm.insert_value(&cell_ref_to_relocatable(y, vm)?, y_value)
+    }
+
+    // The segment arena pointer is preceded by the number of dictionaries created so far and,
+    // before it, by the pointer to the dictionary infos, which take three cells each.
+    fn alloc_felt252_dict(
+        &self,
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        segment_arena_ptr: &ResOperand,
+    ) -> Result<(), VirtualMachineError> {
+        let segment_arena_ptr = get_ptr(vm, segment_arena_ptr)?;
+        let n_dicts = bigint_to_usize(&vm.get_integer(&segment_arena_ptr.sub(2)?)?)?;
+        let dict_infos_base = vm.get_relocatable(&segment_arena_ptr.sub(3)?)?.into_owned();
+
+        if exec_scopes
+            .get_ref::<DictManagerExecScope>(DICT_MANAGER_EXEC_SCOPE)
+            .is_err()
+        {
+            exec_scopes.insert_value(DICT_MANAGER_EXEC_SCOPE, DictManagerExecScope::new());
+        }
+        let dict_manager =
+            exec_scopes.get_mut_ref::<DictManagerExecScope>(DICT_MANAGER_EXEC_SCOPE)?;
+        let dict_segment = dict_manager.new_default_dict(vm);
+        vm.insert_value(&(dict_infos_base + 3 * n_dicts), dict_segment)
+    }
+
+    // Writes the previous value of the key right after it, in the dictionary entry being added
+    fn felt252_dict_entry_init(
+        &self,
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        dict_ptr: &ResOperand,
+        key: &ResOperand,
+    ) -> Result<(), VirtualMachineError> {
+        let dict_address = get_ptr(vm, dict_ptr)?;
+        let key = get_int(vm, key)?;
+        let dict_manager = exec_scopes.get_ref::<DictManagerExecScope>(DICT_MANAGER_EXEC_SCOPE)?;
+        let prev_value = dict_manager
+            .get_from_tracker(&dict_address, &key)?
+            .cloned()
+            .unwrap_or_else(|| MaybeRelocatable::from(BigInt::zero()));
+        vm.insert_value(&(dict_address + 1_usize), prev_value)
+    }
+
+    // Called once the entry was written, so the key is three cells before the dictionary end
+    fn felt252_dict_entry_update(
+        &self,
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        dict_ptr: &ResOperand,
+        value: &ResOperand,
+    ) -> Result<(), VirtualMachineError> {
+        let dict_address = get_ptr(vm, dict_ptr)?;
+        let key = vm.get_integer(&dict_address.sub(3)?)?.into_owned();
+        let value = get_val(vm, value)?;
+        let dict_manager =
+            exec_scopes.get_mut_ref::<DictManagerExecScope>(DICT_MANAGER_EXEC_SCOPE)?;
+        dict_manager.insert_to_tracker(&dict_address, key, value)
+    }
+
+    fn get_segment_arena_index(
+        &self,
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        dict_end_ptr: &ResOperand,
+        dict_index: &CellRef,
+    ) -> Result<(), VirtualMachineError> {
+        let dict_end_ptr = get_ptr(vm, dict_end_ptr)?;
+        let dict_manager = exec_scopes.get_ref::<DictManagerExecScope>(DICT_MANAGER_EXEC_SCOPE)?;
+        let index = dict_manager.get_dict_index(&dict_end_ptr)?;
+        vm.insert_value(
+            &cell_ref_to_relocatable(dict_index, vm)?,
+            BigInt::from(index),
+        )
+    }
+}
+
+impl HintProcessor for Cairo1HintProcessor {
+    fn execute_hint(
+        &self,
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        hint_data: &Box<dyn Any>,
+        _constants: &HashMap<String, BigInt>,
+    ) -> Result<(), VirtualMachineError> {
+        let hint = hint_data
+            .downcast_ref::<Cairo1Hint>()
+            .ok_or(VirtualMachineError::WrongHintData)?;
+        match hint {
+            Cairo1Hint::AllocSegment { dst } => self.alloc_segment(vm, dst),
+            Cairo1Hint::TestLessThan { lhs, rhs, dst } => {
+                self.test_less_than(vm, lhs, rhs, dst, false)
+            }
+            Cairo1Hint::TestLessThanOrEqual { lhs, rhs, dst } => {
+                self.test_less_than(vm, lhs, rhs, dst, true)
+            }
+            Cairo1Hint::WideMul128 {
+                lhs,
+                rhs,
+                high,
+                low,
+            } => self.wide_mul_128(vm, lhs, rhs, high, low),
+            Cairo1Hint::DivMod {
+                lhs,
+                rhs,
+                quotient,
+                remainder,
+            } => self.div_mod(vm, lhs, rhs, quotient, remainder),
+            Cairo1Hint::SquareRoot { value, dst } => self.square_root(vm, value, dst),
+            Cairo1Hint::LinearSplit {
+                value,
+                scalar,
+                max_x,
+                x,
+                y,
+            } => self.linear_split(vm, value, scalar, max_x, x, y),
+            Cairo1Hint::AllocFelt252Dict { segment_arena_ptr } => {
+                self.alloc_felt252_dict(vm, exec_scopes, segment_arena_ptr)
+            }
+            Cairo1Hint::Felt252DictEntryInit { dict_ptr, key } => {
+                self.felt252_dict_entry_init(vm, exec_scopes, dict_ptr, key)
+            }
+            Cairo1Hint::Felt252DictEntryUpdate { dict_ptr, value } => {
+                self.felt252_dict_entry_update(vm, exec_scopes, dict_ptr, value)
+            }
+            Cairo1Hint::GetSegmentArenaIndex {
+                dict_end_ptr,
+                dict_index,
+            } => self.get_segment_arena_index(vm, exec_scopes, dict_end_ptr, dict_index),
+        }
+    }
+
+    fn compile_hint(
+        &self,
+        hint_code: &str,
+        _accessible_scopes: &[String],
+        _ap_tracking_data: &ApTracking,
+        _reference_ids: &HashMap<String, usize>,
+        _references: &HashMap<usize, HintReference>,
+    ) -> Result<Box<dyn Any>, VirtualMachineError> {
+        let hint: Cairo1Hint = serde_json::from_str(hint_code)
+            .map_err(|_| VirtualMachineError::UnknownHint(hint_code.to_string()))?;
+        Ok(any_box!(hint))
+    }
+
+    fn supports_hint(&self, hint_code: &str) -> bool {
+        serde_json::from_str::<Cairo1Hint>(hint_code).is_ok()
+    }
+}
+
+fn cell_ref_to_relocatable(
+    cell_ref: &CellRef,
+    vm: &VirtualMachine,
+) -> Result<Relocatable, VirtualMachineError> {
+    let base = match cell_ref.register {
+        Register::AP => vm.get_ap(),
+        Register::FP => vm.get_fp(),
+    };
+    add_offset(&base, cell_ref.offset)
+}
+
+// Applies a signed cell offset, failing instead of wrapping below the start of the segment
+fn add_offset(base: &Relocatable, offset: i16) -> Result<Relocatable, VirtualMachineError> {
+    let new_offset = if offset.is_negative() {
+        base.offset.checked_sub(offset.unsigned_abs() as usize)
+    } else {
+        base.offset.checked_add(offset as usize)
+    };
+    new_offset
+        .map(|offset| Relocatable {
+            segment_index: base.segment_index,
+            offset,
+        })
+        .ok_or(VirtualMachineError::FailedToGetIds)
+}
+
+fn get_cell_val(
+    vm: &VirtualMachine,
+    cell_ref: &CellRef,
+) -> Result<MaybeRelocatable, VirtualMachineError> {
+    let address = cell_ref_to_relocatable(cell_ref, vm)?;
+    vm.get_maybe(&address)?
+        .ok_or_else(|| VirtualMachineError::MemoryGet(address.into()))
+}
+
+// Evaluates an operand, reducing the immediates and the products modulo the prime
+fn get_val(
+    vm: &VirtualMachine,
+    res_operand: &ResOperand,
+) -> Result<MaybeRelocatable, VirtualMachineError> {
+    let prime = vm.get_prime();
+    match res_operand {
+        ResOperand::Deref(cell_ref) => get_cell_val(vm, cell_ref),
+        ResOperand::DoubleDeref(cell_ref, offset) => {
+            let address = add_offset(get_cell_val(vm, cell_ref)?.get_relocatable()?, *offset)?;
+            vm.get_maybe(&address)?
+                .ok_or_else(|| VirtualMachineError::MemoryGet(address.into()))
+        }
+        ResOperand::Immediate(value) => Ok(MaybeRelocatable::Int(value.mod_floor(prime))),
+        ResOperand::BinOp(bin_op) => {
+            let a = get_cell_val(vm, &bin_op.a)?;
+            let b = match &bin_op.b {
+                DerefOrImmediate::Deref(cell_ref) => get_cell_val(vm, cell_ref)?,
+                DerefOrImmediate::Immediate(value) => MaybeRelocatable::Int(value.mod_floor(prime)),
+            };
+            match bin_op.op {
+                Operation::Add => a.add_mod(&b, prime),
+                Operation::Mul => Ok(MaybeRelocatable::Int(
+                    (a.get_int_ref()? * b.get_int_ref()?).mod_floor(prime),
+                )),
+            }
+        }
+    }
+}
+
+fn get_int(vm: &VirtualMachine, res_operand: &ResOperand) -> Result<BigInt, VirtualMachineError> {
+    Ok(get_val(vm, res_operand)?.get_int_ref()?.clone())
+}
+
+fn get_ptr(
+    vm: &VirtualMachine,
+    res_operand: &ResOperand,
+) -> Result<Relocatable, VirtualMachineError> {
+    Ok(get_val(vm, res_operand)?.get_relocatable()?.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hint_processor::builtin_hint_processor::builtin_hint_processor_definition::BuiltinHintProcessor;
+    use crate::hint_processor::cairo_1_hint_processor::cairo_1_hints::BinOpOperand;
+    use crate::hint_processor::composite_hint_processor::CompositeHintProcessor;
+    use crate::starknet::contract_class::EntryPointType;
+    use crate::types::program::Program;
+    use crate::utils::test_utils::*;
+    use crate::vm::errors::memory_errors::MemoryError;
+    use crate::vm::runners::cairo_runner::CairoRunner;
+    use crate::vm::vm_memory::memory::Memory;
+    use crate::{bigint, bigint_str, relocatable};
+    use num_bigint::Sign;
+    use std::path::Path;
+
+    fn run_cairo_1_hint(
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        hint_code: &str,
+    ) -> Result<(), VirtualMachineError> {
+        let hint_processor = Cairo1HintProcessor::new();
+        let hint_data = hint_processor.compile_hint(
+            hint_code,
+            &[],
+            &ApTracking::new(),
+            &HashMap::new(),
+            &HashMap::new(),
+        )?;
+        hint_processor.execute_hint(vm, exec_scopes, &hint_data, &HashMap::new())
+    }
+
+    #[test]
+    fn run_alloc_segment() {
+        let mut vm = vm!();
+        add_segments!(vm, 2);
+        let mut exec_scopes = ExecutionScopes::new();
+
+        run_cairo_1_hint(
+            &mut vm,
+            &mut exec_scopes,
+            r#"{"AllocSegment": {"dst": {"register": "AP", "offset": 0}}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(vm.segments.num_segments, 3);
+        check_memory![vm.memory, ((1, 0), (2, 0))];
+    }
+
+    #[test]
+    fn run_test_less_than() {
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), 7)];
+        vm.run_context.ap = 1;
+        vm.run_context.fp = 1;
+        let mut exec_scopes = ExecutionScopes::new();
+
+        run_cairo_1_hint(
+            &mut vm,
+            &mut exec_scopes,
+            r#"{"TestLessThan": {
+                "lhs": {"Deref": {"register": "FP", "offset": -1}},
+                "rhs": {"Immediate": "0x7"},
+                "dst": {"register": "AP", "offset": 0}
+            }}"#,
+        )
+        .unwrap();
+        run_cairo_1_hint(
+            &mut vm,
+            &mut exec_scopes,
+            r#"{"TestLessThanOrEqual": {
+                "lhs": {"Deref": {"register": "FP", "offset": -1}},
+                "rhs": {"Immediate": "0x7"},
+                "dst": {"register": "AP", "offset": 1}
+            }}"#,
+        )
+        .unwrap();
+
+        check_memory![vm.memory, ((1, 1), 0), ((1, 2), 1)];
+    }
+
+    #[test]
+    fn run_wide_mul_128() {
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), 0)];
+        let mut exec_scopes = ExecutionScopes::new();
+
+        run_cairo_1_hint(
+            &mut vm,
+            &mut exec_scopes,
+            r#"{"WideMul128": {
+                "lhs": {"Immediate": "0xffffffffffffffffffffffffffffffff"},
+                "rhs": {"Immediate": "0x3"},
+                "high": {"register": "AP", "offset": 1},
+                "low": {"register": "AP", "offset": 2}
+            }}"#,
+        )
+        .unwrap();
+
+        check_memory![
+            vm.memory,
+            ((1, 1), 2),
+            (
+                (1, 2),
+                (bigint_str!(b"340282366920938463463374607431768211453"))
+            )
+        ];
+    }
+
+    #[test]
+    fn run_div_mod() {
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), 17), ((1, 1), 5)];
+        vm.run_context.ap = 2;
+        let mut exec_scopes = ExecutionScopes::new();
+
+        run_cairo_1_hint(
+            &mut vm,
+            &mut exec_scopes,
+            r#"{"DivMod": {
+                "lhs": {"Deref": {"register": "AP", "offset": -2}},
+                "rhs": {"Deref": {"register": "AP", "offset": -1}},
+                "quotient": {"register": "AP", "offset": 0},
+                "remainder": {"register": "AP", "offset": 1}
+            }}"#,
+        )
+        .unwrap();
+
+        check_memory![vm.memory, ((1, 2), 3), ((1, 3), 2)];
+    }
+
+    #[test]
+    fn run_div_mod_by_zero() {
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), 17)];
+        let mut exec_scopes = ExecutionScopes::new();
+
+        assert_eq!(
+            run_cairo_1_hint(
+                &mut vm,
+                &mut exec_scopes,
+                r#"{"DivMod": {
+                    "lhs": {"Deref": {"register": "AP", "offset": 0}},
+                    "rhs": {"Immediate": "0x0"},
+                    "quotient": {"register": "AP", "offset": 1},
+                    "remainder": {"register": "AP", "offset": 2}
+                }}"#,
+            ),
+            Err(VirtualMachineError::DividedByZero)
+        );
+    }
+
+    #[test]
+    fn run_hint_with_cell_ref_before_segment_start() {
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), (1, 0))];
+        vm.run_context.ap = 1;
+        vm.run_context.fp = 1;
+        let mut exec_scopes = ExecutionScopes::new();
+
+        assert_eq!(
+            run_cairo_1_hint(
+                &mut vm,
+                &mut exec_scopes,
+                r#"{"SquareRoot": {
+                    "value": {"Deref": {"register": "FP", "offset": -3}},
+                    "dst": {"register": "AP", "offset": 0}
+                }}"#,
+            ),
+            Err(VirtualMachineError::FailedToGetIds)
+        );
+        assert_eq!(
+            run_cairo_1_hint(
+                &mut vm,
+                &mut exec_scopes,
+                r#"{"SquareRoot": {
+                    "value": {"DoubleDeref": [{"register": "FP", "offset": -1}, -2]},
+                    "dst": {"register": "AP", "offset": 0}
+                }}"#,
+            ),
+            Err(VirtualMachineError::FailedToGetIds)
+        );
+        assert_eq!(
+            run_cairo_1_hint(
+                &mut vm,
+                &mut exec_scopes,
+                r#"{"AllocSegment": {"dst": {"register": "AP", "offset": -2}}}"#,
+            ),
+            Err(VirtualMachineError::FailedToGetIds)
+        );
+    }
+
+    #[test]
+    fn run_square_root() {
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), 0)];
+        let mut exec_scopes = ExecutionScopes::new();
+
+        run_cairo_1_hint(
+            &mut vm,
+            &mut exec_scopes,
+            r#"{"SquareRoot": {
+                "value": {"Immediate": "0x52"},
+                "dst": {"register": "AP", "offset": 1}
+            }}"#,
+        )
+        .unwrap();
+
+        check_memory![vm.memory, ((1, 1), 9)];
+    }
+
+    #[test]
+    fn run_linear_split() {
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), 0)];
+        let mut exec_scopes = ExecutionScopes::new();
+
+        run_cairo_1_hint(
+            &mut vm,
+            &mut exec_scopes,
+            r#"{"LinearSplit": {
+                "value": {"Immediate": "0x64"},
+                "scalar": {"Immediate": "0x7"},
+                "max_x": {"Immediate": "0xa"},
+                "x": {"register": "AP", "offset": 1},
+                "y": {"register": "AP", "offset": 2}
+            }}"#,
+        )
+        .unwrap();
+
+        check_memory![vm.memory, ((1, 1), 10), ((1, 2), 30)];
+    }
+
+    #[test]
+    fn run_bin_op_operand() {
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), (2, 3)), ((1, 1), 4)];
+        vm.run_context.ap = 2;
+        let mut exec_scopes = ExecutionScopes::new();
+
+        assert_eq!(
+            get_ptr(
+                &vm,
+                &ResOperand::BinOp(BinOpOperand {
+                    op: Operation::Add,
+                    a: CellRef {
+                        register: Register::AP,
+                        offset: -2
+                    },
+                    b: DerefOrImmediate::Immediate(bigint!(2)),
+                })
+            ),
+            Ok(relocatable!(2, 5))
+        );
+        run_cairo_1_hint(
+            &mut vm,
+            &mut exec_scopes,
+            r#"{"SquareRoot": {
+                "value": {"BinOp": {
+                    "op": "Mul",
+                    "a": {"register": "AP", "offset": -1},
+                    "b": {"Deref": {"register": "AP", "offset": -1}}
+                }},
+                "dst": {"register": "AP", "offset": 0}
+            }}"#,
+        )
+        .unwrap();
+
+        check_memory![vm.memory, ((1, 2), 4)];
+    }
+
+    #[test]
+    fn run_felt252_dict_hints() {
+        let mut vm = vm!();
+        add_segments!(vm, 3);
+        // Segment arena: the dict infos pointer, the number of dicts and the arena end
+        vm.insert_value(&relocatable!(1, 0), relocatable!(2, 0))
+            .unwrap();
+        vm.insert_value(&relocatable!(1, 1), bigint!(0)).unwrap();
+        vm.insert_value(&relocatable!(1, 2), relocatable!(1, 3))
+            .unwrap();
+        vm.run_context.ap = 3;
+        let mut exec_scopes = ExecutionScopes::new();
+
+        run_cairo_1_hint(
+            &mut vm,
+            &mut exec_scopes,
+            r#"{"AllocFelt252Dict": {
+                "segment_arena_ptr": {"Deref": {"register": "AP", "offset": -1}}
+            }}"#,
+        )
+        .unwrap();
+        // The new dict segment is stored in the dict infos
+        check_memory![vm.memory, ((2, 0), (3, 0))];
+
+        // Write the first entry of the dict: key 5, updated to the value 8
+        vm.insert_value(&relocatable!(3, 0), bigint!(5)).unwrap();
+        vm.insert_value(&relocatable!(1, 3), relocatable!(3, 0))
+            .unwrap();
+        vm.run_context.ap = 4;
+        run_cairo_1_hint(
+            &mut vm,
+            &mut exec_scopes,
+            r#"{"Felt252DictEntryInit": {
+                "dict_ptr": {"Deref": {"register": "AP", "offset": -1}},
+                "key": {"Immediate": "0x5"}
+            }}"#,
+        )
+        .unwrap();
+        check_memory![vm.memory, ((3, 1), 0)];
+
+        vm.insert_value(&relocatable!(3, 2), bigint!(8)).unwrap();
+        vm.insert_value(&relocatable!(1, 4), relocatable!(3, 3))
+            .unwrap();
+        vm.run_context.ap = 5;
+        run_cairo_1_hint(
+            &mut vm,
+            &mut exec_scopes,
+            r#"{"Felt252DictEntryUpdate": {
+                "dict_ptr": {"Deref": {"register": "AP", "offset": -1}},
+                "value": {"Immediate": "0x8"}
+            }}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            exec_scopes
+                .get_ref::<DictManagerExecScope>(DICT_MANAGER_EXEC_SCOPE)
+                .unwrap()
+                .get_from_tracker(&relocatable!(3, 3), &bigint!(5)),
+            Ok(Some(&MaybeRelocatable::from(bigint!(8))))
+        );
+
+        run_cairo_1_hint(
+            &mut vm,
+            &mut exec_scopes,
+            r#"{"GetSegmentArenaIndex": {
+                "dict_end_ptr": {"Deref": {"register": "AP", "offset": -1}},
+                "dict_index": {"register": "AP", "offset": 0}
+            }}"#,
+        )
+        .unwrap();
+        check_memory![vm.memory, ((1, 5), 0)];
+    }
+
+    #[test]
+    fn run_dict_hint_without_dict() {
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), (2, 0))];
+        vm.run_context.ap = 1;
+        let mut exec_scopes = ExecutionScopes::new();
+        exec_scopes.insert_value(DICT_MANAGER_EXEC_SCOPE, DictManagerExecScope::new());
+
+        assert_eq!(
+            run_cairo_1_hint(
+                &mut vm,
+                &mut exec_scopes,
+                r#"{"Felt252DictEntryInit": {
+                    "dict_ptr": {"Deref": {"register": "AP", "offset": -1}},
+                    "key": {"Immediate": "0x5"}
+                }}"#,
+            ),
+            Err(VirtualMachineError::NoDictTracker(2))
+        );
+    }
+
+    #[test]
+    fn compile_unknown_hint() {
+        let hint_processor = Cairo1HintProcessor::new();
+        let hint_code = "memory[ap] = segments.add()";
+
+        assert!(!hint_processor.supports_hint(hint_code));
+        assert_eq!(
+            hint_processor
+                .compile_hint(
+                    hint_code,
+                    &[],
+                    &ApTracking::new(),
+                    &HashMap::new(),
+                    &HashMap::new()
+                )
+                .err(),
+            Some(VirtualMachineError::UnknownHint(hint_code.to_string()))
+        );
+    }
+
+    #[test]
+    fn run_casm_program_entry_point() {
+        let program = Program::from_casm_file(
+            Path::new("cairo_programs/manually_compiled/alloc_segment_casm.json"),
+            Some((EntryPointType::External, &bigint!(1))),
+        )
+        .unwrap();
+        let mut hint_processor = CompositeHintProcessor::new();
+        hint_processor.add_processor(Cairo1HintProcessor::new());
+        hint_processor.add_processor(BuiltinHintProcessor::new_empty());
+
+        let mut cairo_runner = CairoRunner::new(&program, "all", false).unwrap();
+        let mut vm = VirtualMachine::new(program.prime.clone(), false, Vec::new());
+        cairo_runner.initialize_function_runner(&mut vm).unwrap();
+        cairo_runner
+            .run_from_entrypoint(
+                program.main.unwrap(),
+                vec![],
+                false,
+                false,
+                true,
+                &mut vm,
+                &hint_processor,
+            )
+            .unwrap();
+
+        // Both hints wrote to the cells consumed by the two instructions before the return
+        let ap = vm.get_ap();
+        let segment = vm.get_relocatable(&(&ap + -2)).unwrap().into_owned();
+        assert_eq!(segment.offset, 0);
+        assert_eq!(
+            vm.get_integer(&(&ap + -1)).unwrap().into_owned(),
+            bigint!(1)
+        );
+    }
+}
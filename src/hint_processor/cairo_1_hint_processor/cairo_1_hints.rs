@@ -0,0 +1,192 @@
+use crate::types::instruction::Register;
+use num_bigint::BigInt;
+use serde::{de, Deserialize, Deserializer};
+use serde_json::Value;
+
+/// A memory cell given by its offset from one of the registers
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct CellRef {
+    pub register: Register,
+    pub offset: i16,
+}
+
+/// An operand of a Cairo 1 hint, evaluated to a value when the hint runs
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum ResOperand {
+    Deref(CellRef),
+    DoubleDeref(CellRef, i16),
+    Immediate(#[serde(deserialize_with = "deserialize_immediate")] BigInt),
+    BinOp(BinOpOperand),
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct BinOpOperand {
+    pub op: Operation,
+    pub a: CellRef,
+    pub b: DerefOrImmediate,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Add,
+    Mul,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum DerefOrImmediate {
+    Deref(CellRef),
+    Immediate(#[serde(deserialize_with = "deserialize_immediate")] BigInt),
+}
+
+/// The hints emitted by the Sierra to CASM compiler. Unlike Cairo 0 hints, they are structured
+/// data instead of Python code.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum Cairo1Hint {
+    AllocSegment {
+        dst: CellRef,
+    },
+    TestLessThan {
+        lhs: ResOperand,
+        rhs: ResOperand,
+        dst: CellRef,
+    },
+    TestLessThanOrEqual {
+        lhs: ResOperand,
+        rhs: ResOperand,
+        dst: CellRef,
+    },
+    WideMul128 {
+        lhs: ResOperand,
+        rhs: ResOperand,
+        high: CellRef,
+        low: CellRef,
+    },
+    DivMod {
+        lhs: ResOperand,
+        rhs: ResOperand,
+        quotient: CellRef,
+        remainder: CellRef,
+    },
+    SquareRoot {
+        value: ResOperand,
+        dst: CellRef,
+    },
+    LinearSplit {
+        value: ResOperand,
+        scalar: ResOperand,
+        max_x: ResOperand,
+        x: CellRef,
+        y: CellRef,
+    },
+    AllocFelt252Dict {
+        segment_arena_ptr: ResOperand,
+    },
+    Felt252DictEntryInit {
+        dict_ptr: ResOperand,
+        key: ResOperand,
+    },
+    Felt252DictEntryUpdate {
+        dict_ptr: ResOperand,
+        value: ResOperand,
+    },
+    GetSegmentArenaIndex {
+        dict_end_ptr: ResOperand,
+        dict_index: CellRef,
+    },
+}
+
+// Immediates are encoded as (possibly negative) hex strings, such as "0x10" or "-0x1"
+fn deserialize_immediate<'de, D: Deserializer<'de>>(d: D) -> Result<BigInt, D::Error> {
+    match Value::deserialize(d)? {
+        Value::Number(number) => BigInt::parse_bytes(number.to_string().as_bytes(), 10)
+            .ok_or_else(|| de::Error::custom(format!("invalid immediate {number}"))),
+        Value::String(string) => {
+            let (negative, hex) = match string.strip_prefix('-') {
+                Some(hex) => (true, hex),
+                None => (false, string.as_str()),
+            };
+            let hex = hex
+                .strip_prefix("0x")
+                .ok_or_else(|| de::Error::custom("hex prefix error"))?;
+            let value = BigInt::parse_bytes(hex.as_bytes(), 16)
+                .ok_or_else(|| de::Error::custom(format!("invalid immediate {string}")))?;
+            Ok(if negative { -value } else { value })
+        }
+        value => Err(de::Error::custom(format!("invalid immediate {value}"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bigint;
+
+    #[test]
+    fn deserialize_hint_with_immediate_operands() {
+        let hint: Cairo1Hint = serde_json::from_str(
+            r#"{"TestLessThanOrEqual": {
+                "lhs": {"Immediate": "0x10"},
+                "rhs": {"Immediate": "-0x1"},
+                "dst": {"register": "AP", "offset": 0}
+            }}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            hint,
+            Cairo1Hint::TestLessThanOrEqual {
+                lhs: ResOperand::Immediate(bigint!(16)),
+                rhs: ResOperand::Immediate(bigint!(-1)),
+                dst: CellRef {
+                    register: Register::AP,
+                    offset: 0
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn deserialize_hint_with_memory_operands() {
+        let hint: Cairo1Hint = serde_json::from_str(
+            r#"{"Felt252DictEntryUpdate": {
+                "dict_ptr": {"DoubleDeref": [{"register": "FP", "offset": -3}, 2]},
+                "value": {"BinOp": {
+                    "op": "Add",
+                    "a": {"register": "AP", "offset": -1},
+                    "b": {"Immediate": "0x1"}
+                }}
+            }}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            hint,
+            Cairo1Hint::Felt252DictEntryUpdate {
+                dict_ptr: ResOperand::DoubleDeref(
+                    CellRef {
+                        register: Register::FP,
+                        offset: -3
+                    },
+                    2
+                ),
+                value: ResOperand::BinOp(BinOpOperand {
+                    op: Operation::Add,
+                    a: CellRef {
+                        register: Register::AP,
+                        offset: -1
+                    },
+                    b: DerefOrImmediate::Immediate(bigint!(1)),
+                }),
+            }
+        );
+    }
+
+    #[test]
+    fn deserialize_unknown_hint() {
+        assert!(serde_json::from_str::<Cairo1Hint>(
+            r#"{"Uint512DivModByUint256": {"dst": {"register": "AP", "offset": 0}}}"#
+        )
+        .is_err());
+        assert!(serde_json::from_str::<Cairo1Hint>("memory[ap] = segments.add()").is_err());
+    }
+}
@@ -0,0 +1,81 @@
+use crate::types::relocatable::{MaybeRelocatable, Relocatable};
+use crate::vm::errors::vm_errors::VirtualMachineError;
+use crate::vm::vm_core::VirtualMachine;
+use num_bigint::BigInt;
+use std::collections::HashMap;
+
+/// Name of the execution scope variable holding the `DictManagerExecScope`
+pub const DICT_MANAGER_EXEC_SCOPE: &str = "dict_manager_exec_scope";
+
+#[derive(PartialEq, Debug, Clone, Default)]
+///Manages the dictionaries of a Cairo 1 program.
+///Dictionaries are identified by their index in the segment arena, which is the order in which
+///they were created.
+pub struct DictManagerExecScope {
+    trackers: Vec<DictTrackerExecScope>,
+    //Maps the segment of each dictionary to its index
+    segment_to_tracker: HashMap<isize, usize>,
+}
+
+#[derive(PartialEq, Debug, Clone)]
+///Tracks the values of a Cairo 1 dictionary, which default to zero.
+pub struct DictTrackerExecScope {
+    pub data: HashMap<BigInt, MaybeRelocatable>,
+    //Start of the dictionary segment.
+    pub start: Relocatable,
+}
+
+impl DictManagerExecScope {
+    pub fn new() -> Self {
+        DictManagerExecScope {
+            trackers: Vec::new(),
+            segment_to_tracker: HashMap::new(),
+        }
+    }
+
+    ///Creates an empty dictionary in a new segment and returns the segment base.
+    pub fn new_default_dict(&mut self, vm: &mut VirtualMachine) -> Relocatable {
+        let start = vm.add_memory_segment();
+        self.segment_to_tracker
+            .insert(start.segment_index, self.trackers.len());
+        self.trackers.push(DictTrackerExecScope {
+            data: HashMap::new(),
+            start: start.clone(),
+        });
+        start
+    }
+
+    ///Returns the index of the dictionary the pointer belongs to.
+    pub fn get_dict_index(&self, dict_ptr: &Relocatable) -> Result<usize, VirtualMachineError> {
+        self.segment_to_tracker
+            .get(&dict_ptr.segment_index)
+            .copied()
+            .ok_or(VirtualMachineError::NoDictTracker(dict_ptr.segment_index))
+    }
+
+    pub fn get_tracker(
+        &self,
+        dict_ptr: &Relocatable,
+    ) -> Result<&DictTrackerExecScope, VirtualMachineError> {
+        Ok(&self.trackers[self.get_dict_index(dict_ptr)?])
+    }
+
+    pub fn get_from_tracker(
+        &self,
+        dict_ptr: &Relocatable,
+        key: &BigInt,
+    ) -> Result<Option<&MaybeRelocatable>, VirtualMachineError> {
+        Ok(self.get_tracker(dict_ptr)?.data.get(key))
+    }
+
+    pub fn insert_to_tracker(
+        &mut self,
+        dict_ptr: &Relocatable,
+        key: BigInt,
+        value: MaybeRelocatable,
+    ) -> Result<(), VirtualMachineError> {
+        let index = self.get_dict_index(dict_ptr)?;
+        self.trackers[index].data.insert(key, value);
+        Ok(())
+    }
+}
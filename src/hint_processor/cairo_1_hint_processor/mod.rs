@@ -0,0 +1,3 @@
+pub mod cairo_1_hint_processor_definition;
+pub mod cairo_1_hints;
+pub mod dict_manager;
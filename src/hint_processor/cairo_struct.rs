@@ -0,0 +1,179 @@
+use crate::serde::deserialize_program::Member;
+use crate::types::relocatable::{MaybeRelocatable, Relocatable};
+use crate::vm::errors::vm_errors::VirtualMachineError;
+use crate::vm::vm_core::VirtualMachine;
+use num_bigint::BigInt;
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+/// Rust representation of a Cairo struct, read from memory using the member offsets of the
+/// struct identifier instead of hard-coded ones.
+/// Usually implemented through the `cairo_struct!` macro.
+pub trait CairoStruct: Sized {
+    /// Full name of the Cairo struct (e.g. `starkware.cairo.common.uint256.Uint256`) or its
+    /// last component (e.g. `Uint256`).
+    const CAIRO_TYPE: &'static str;
+
+    /// Reads the struct located at `base`, which belongs to the ids variable `var_name`.
+    fn from_memory(
+        vm: &VirtualMachine,
+        var_name: &str,
+        base: &Relocatable,
+        members: &HashMap<String, Member>,
+    ) -> Result<Self, VirtualMachineError>;
+}
+
+/// Types that can be used as members of a `CairoStruct`.
+pub trait CairoStructMember: Sized {
+    fn get_member(vm: &VirtualMachine, addr: &Relocatable) -> Result<Self, VirtualMachineError>;
+}
+
+impl CairoStructMember for BigInt {
+    fn get_member(vm: &VirtualMachine, addr: &Relocatable) -> Result<Self, VirtualMachineError> {
+        vm.get_integer(addr).map(Cow::into_owned)
+    }
+}
+
+impl CairoStructMember for Relocatable {
+    fn get_member(vm: &VirtualMachine, addr: &Relocatable) -> Result<Self, VirtualMachineError> {
+        vm.get_relocatable(addr).map(Cow::into_owned)
+    }
+}
+
+impl CairoStructMember for MaybeRelocatable {
+    fn get_member(vm: &VirtualMachine, addr: &Relocatable) -> Result<Self, VirtualMachineError> {
+        vm.get_maybe(addr)?
+            .ok_or_else(|| VirtualMachineError::MemoryGet(addr.into()))
+    }
+}
+
+///Returns the address of the member `member_name` of the struct located at `base`
+pub fn get_member_addr(
+    var_name: &str,
+    base: &Relocatable,
+    members: &HashMap<String, Member>,
+    member_name: &str,
+) -> Result<Relocatable, VirtualMachineError> {
+    members
+        .get(member_name)
+        .map(|member| base + member.offset)
+        .ok_or_else(|| {
+            VirtualMachineError::MissingStructMember(var_name.to_string(), member_name.to_string())
+        })
+}
+
+///Returns true if `cairo_type`, the type of an ids variable, is the struct `struct_name` or a
+///pointer to it (pointers to pointers are rejected)
+pub(crate) fn is_struct_type(cairo_type: &str, struct_name: &str) -> bool {
+    let struct_type = cairo_type.strip_suffix('*').unwrap_or(cairo_type);
+    struct_type == struct_name
+        || matches!(struct_type.strip_suffix(struct_name), Some(prefix) if prefix.ends_with('.'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bigint;
+    use crate::relocatable;
+    use crate::utils::test_utils::*;
+    use crate::vm::errors::memory_errors::MemoryError;
+    use crate::vm::vm_memory::memory::Memory;
+    use num_bigint::Sign;
+
+    crate::cairo_struct! {
+        #[derive(Debug, PartialEq)]
+        struct Pair = "Pair" {
+            first: BigInt,
+            second: Relocatable,
+        }
+    }
+
+    fn pair_members() -> HashMap<String, Member> {
+        HashMap::from([
+            (
+                "first".to_string(),
+                Member {
+                    cairo_type: "felt".to_string(),
+                    offset: 1,
+                },
+            ),
+            (
+                "second".to_string(),
+                Member {
+                    cairo_type: "felt*".to_string(),
+                    offset: 0,
+                },
+            ),
+        ])
+    }
+
+    #[test]
+    fn read_struct_from_memory() {
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), (2, 3)), ((1, 1), 5)];
+
+        assert_eq!(
+            Pair::from_memory(&vm, "pair", &relocatable!(1, 0), &pair_members()),
+            Ok(Pair {
+                first: bigint!(5),
+                second: relocatable!(2, 3),
+            })
+        );
+    }
+
+    #[test]
+    fn read_struct_missing_member() {
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), (2, 3)), ((1, 1), 5)];
+        let mut members = pair_members();
+        members.remove("second");
+
+        assert_eq!(
+            Pair::from_memory(&vm, "pair", &relocatable!(1, 0), &members),
+            Err(VirtualMachineError::MissingStructMember(
+                "pair".to_string(),
+                "second".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn read_maybe_relocatable_member() {
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), 3)];
+
+        assert_eq!(
+            MaybeRelocatable::get_member(&vm, &relocatable!(1, 0)),
+            Ok(MaybeRelocatable::from(bigint!(3)))
+        );
+        assert_eq!(
+            MaybeRelocatable::get_member(&vm, &relocatable!(1, 1)),
+            Err(VirtualMachineError::MemoryGet(MaybeRelocatable::from((
+                1, 1
+            ))))
+        );
+    }
+
+    #[test]
+    fn struct_type_names() {
+        assert!(is_struct_type("Uint256", "Uint256"));
+        assert!(is_struct_type(
+            "starkware.cairo.common.uint256.Uint256*",
+            "Uint256"
+        ));
+        assert!(is_struct_type(
+            "starkware.cairo.common.uint256.Uint256",
+            "starkware.cairo.common.uint256.Uint256"
+        ));
+        assert!(!is_struct_type(
+            "starkware.cairo.common.MyUint256",
+            "Uint256"
+        ));
+        assert!(!is_struct_type("felt", "Uint256"));
+        assert!(!is_struct_type(
+            "starkware.cairo.common.uint256.Uint256**",
+            "Uint256"
+        ));
+        assert!(!is_struct_type("Uint256**", "Uint256"));
+    }
+}
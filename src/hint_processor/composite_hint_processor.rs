@@ -0,0 +1,283 @@
+use crate::any_box;
+use crate::hint_processor::hint_processor_definition::{HintProcessor, HintReference};
+use crate::serde::deserialize_program::ApTracking;
+use crate::types::exec_scope::ExecutionScopes;
+use crate::vm::errors::vm_errors::VirtualMachineError;
+use crate::vm::vm_core::VirtualMachine;
+use num_bigint::BigInt;
+use std::any::Any;
+use std::collections::HashMap;
+
+/// Hint processor combining an ordered list of hint processors.
+/// Each hint is compiled and executed by the first processor that supports it, so processors
+/// overriding hints of later ones should be added first.
+#[derive(Default)]
+pub struct CompositeHintProcessor<'a> {
+    processors: Vec<(String, Box<dyn HintProcessor + 'a>)>,
+}
+
+// Hint data produced by compile_hint: the processor that claimed the hint and its own data
+struct CompositeHintData {
+    processor_index: usize,
+    hint_data: Box<dyn Any>,
+}
+
+impl<'a> CompositeHintProcessor<'a> {
+    pub fn new() -> Self {
+        CompositeHintProcessor {
+            processors: Vec::new(),
+        }
+    }
+
+    pub fn add_processor<P: HintProcessor + 'a>(&mut self, processor: P) {
+        self.processors
+            .push((std::any::type_name::<P>().to_string(), Box::new(processor)));
+    }
+
+    fn processor_names(&self) -> Vec<String> {
+        self.processors
+            .iter()
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+}
+
+impl<'a> HintProcessor for CompositeHintProcessor<'a> {
+    fn execute_hint(
+        &self,
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        hint_data: &Box<dyn Any>,
+        constants: &HashMap<String, BigInt>,
+    ) -> Result<(), VirtualMachineError> {
+        let hint_data = hint_data
+            .downcast_ref::<CompositeHintData>()
+            .ok_or(VirtualMachineError::WrongHintData)?;
+        let (_, processor) = self
+            .processors
+            .get(hint_data.processor_index)
+            .ok_or(VirtualMachineError::WrongHintData)?;
+
+        processor.execute_hint(vm, exec_scopes, &hint_data.hint_data, constants)
+    }
+
+    fn compile_hint(
+        &self,
+        hint_code: &str,
+        accessible_scopes: &[String],
+        ap_tracking_data: &ApTracking,
+        reference_ids: &HashMap<String, usize>,
+        references: &HashMap<usize, HintReference>,
+    ) -> Result<Box<dyn Any>, VirtualMachineError> {
+        let (processor_index, (_, processor)) = self
+            .processors
+            .iter()
+            .enumerate()
+            .find(|(_, (_, processor))| processor.supports_hint(hint_code))
+            .ok_or_else(|| {
+                VirtualMachineError::UnknownHintInProcessors(
+                    hint_code.to_string(),
+                    self.processor_names(),
+                )
+            })?;
+
+        Ok(any_box!(CompositeHintData {
+            processor_index,
+            hint_data: processor.compile_hint(
+                hint_code,
+                accessible_scopes,
+                ap_tracking_data,
+                reference_ids,
+                references
+            )?,
+        }))
+    }
+
+    fn supports_hint(&self, hint_code: &str) -> bool {
+        self.processors
+            .iter()
+            .any(|(_, processor)| processor.supports_hint(hint_code))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bigint;
+    use crate::hint_processor::builtin_hint_processor::builtin_hint_processor_definition::BuiltinHintProcessor;
+    use crate::types::relocatable::MaybeRelocatable;
+    use crate::utils::test_utils::*;
+    use num_bigint::Sign;
+
+    // Supports a single hint, which writes `value` into [ap]
+    struct ConstantHintProcessor {
+        hint_code: &'static str,
+        value: usize,
+    }
+
+    impl HintProcessor for ConstantHintProcessor {
+        fn execute_hint(
+            &self,
+            vm: &mut VirtualMachine,
+            _exec_scopes: &mut ExecutionScopes,
+            hint_data: &Box<dyn Any>,
+            _constants: &HashMap<String, BigInt>,
+        ) -> Result<(), VirtualMachineError> {
+            let hint_code = hint_data
+                .downcast_ref::<String>()
+                .ok_or(VirtualMachineError::WrongHintData)?;
+            assert_eq!(hint_code, self.hint_code);
+            vm.insert_value(&vm.get_ap(), BigInt::from(self.value))
+        }
+
+        fn compile_hint(
+            &self,
+            hint_code: &str,
+            _accessible_scopes: &[String],
+            _ap_tracking_data: &ApTracking,
+            _reference_ids: &HashMap<String, usize>,
+            _references: &HashMap<usize, HintReference>,
+        ) -> Result<Box<dyn Any>, VirtualMachineError> {
+            Ok(any_box!(hint_code.to_string()))
+        }
+
+        fn supports_hint(&self, hint_code: &str) -> bool {
+            hint_code == self.hint_code
+        }
+    }
+
+    // Compiles only the hint "c", relying on the default supports_hint
+    struct CompilingHintProcessor;
+
+    impl HintProcessor for CompilingHintProcessor {
+        fn execute_hint(
+            &self,
+            vm: &mut VirtualMachine,
+            _exec_scopes: &mut ExecutionScopes,
+            _hint_data: &Box<dyn Any>,
+            _constants: &HashMap<String, BigInt>,
+        ) -> Result<(), VirtualMachineError> {
+            vm.insert_value(&vm.get_ap(), bigint!(4))
+        }
+
+        fn compile_hint(
+            &self,
+            hint_code: &str,
+            _accessible_scopes: &[String],
+            _ap_tracking_data: &ApTracking,
+            _reference_ids: &HashMap<String, usize>,
+            _references: &HashMap<usize, HintReference>,
+        ) -> Result<Box<dyn Any>, VirtualMachineError> {
+            match hint_code {
+                "c" => Ok(any_box!(())),
+                _ => Err(VirtualMachineError::UnknownHint(hint_code.to_string())),
+            }
+        }
+    }
+
+    fn compile_and_execute(
+        hint_processor: &CompositeHintProcessor,
+        vm: &mut VirtualMachine,
+        hint_code: &str,
+    ) -> Result<(), VirtualMachineError> {
+        let hint_data = hint_processor.compile_hint(
+            hint_code,
+            &[],
+            &ApTracking::new(),
+            &HashMap::new(),
+            &HashMap::new(),
+        )?;
+        hint_processor.execute_hint(vm, exec_scopes_ref!(), &hint_data, &HashMap::new())
+    }
+
+    #[test]
+    fn dispatches_to_first_supporting_processor() {
+        let mut hint_processor = CompositeHintProcessor::new();
+        hint_processor.add_processor(ConstantHintProcessor {
+            hint_code: "a",
+            value: 1,
+        });
+        hint_processor.add_processor(ConstantHintProcessor {
+            hint_code: "b",
+            value: 2,
+        });
+        hint_processor.add_processor(ConstantHintProcessor {
+            hint_code: "b",
+            value: 3,
+        });
+        let mut vm = vm!();
+        add_segments!(vm, 2);
+
+        assert_eq!(compile_and_execute(&hint_processor, &mut vm, "b"), Ok(()));
+        check_memory![vm.memory, ((1, 0), 2)];
+    }
+
+    #[test]
+    fn falls_back_to_builtin_hint_processor() {
+        let mut hint_processor = CompositeHintProcessor::new();
+        hint_processor.add_processor(ConstantHintProcessor {
+            hint_code: "a",
+            value: 1,
+        });
+        hint_processor.add_processor(BuiltinHintProcessor::new_empty());
+        let mut vm = vm!();
+        add_segments!(vm, 2);
+
+        assert_eq!(
+            compile_and_execute(&hint_processor, &mut vm, "memory[ap] = segments.add()"),
+            Ok(())
+        );
+        check_memory![vm.memory, ((1, 0), (2, 0))];
+    }
+
+    #[test]
+    fn default_supports_hint_compiles_the_hint() {
+        let mut hint_processor = CompositeHintProcessor::new();
+        hint_processor.add_processor(CompilingHintProcessor);
+        hint_processor.add_processor(ConstantHintProcessor {
+            hint_code: "d",
+            value: 5,
+        });
+        let mut vm = vm!();
+        add_segments!(vm, 2);
+
+        assert!(CompilingHintProcessor.supports_hint("c"));
+        assert!(!CompilingHintProcessor.supports_hint("d"));
+        assert_eq!(compile_and_execute(&hint_processor, &mut vm, "d"), Ok(()));
+        check_memory![vm.memory, ((1, 0), 5)];
+    }
+
+    #[test]
+    fn unknown_hint_lists_processors() {
+        let mut hint_processor = CompositeHintProcessor::new();
+        hint_processor.add_processor(ConstantHintProcessor {
+            hint_code: "a",
+            value: 1,
+        });
+        let name = std::any::type_name::<ConstantHintProcessor>().to_string();
+
+        assert!(!hint_processor.supports_hint("b"));
+        assert_eq!(
+            compile_and_execute(&hint_processor, &mut vm!(), "b"),
+            Err(VirtualMachineError::UnknownHintInProcessors(
+                "b".to_string(),
+                vec![name]
+            ))
+        );
+    }
+
+    #[test]
+    fn execute_hint_wrong_hint_data() {
+        let hint_processor = CompositeHintProcessor::new();
+
+        assert_eq!(
+            hint_processor.execute_hint(
+                &mut vm!(),
+                exec_scopes_ref!(),
+                &any_box!("a".to_string()),
+                &HashMap::new()
+            ),
+            Err(VirtualMachineError::WrongHintData)
+        );
+    }
+}
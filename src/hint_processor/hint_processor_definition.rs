@@ -1,4 +1,5 @@
-use crate::serde::deserialize_program::ApTracking;
+use crate::serde::deserialize_program::{ApTracking, Member};
+use crate::serde::reference_expression::ReferenceExpression;
 use crate::types::{exec_scope::ExecutionScopes, instruction::Register};
 use crate::vm::errors::vm_errors::VirtualMachineError;
 use crate::vm::vm_core::VirtualMachine;
@@ -27,6 +28,9 @@ pub trait HintProcessor {
         &self,
         //Block of hint code as String
         hint_code: &str,
+        //Scopes from which the hint code can access identifiers, from the outermost to the
+        //innermost one
+        accessible_scopes: &[String],
         //Ap Tracking Data corresponding to the Hint
         ap_tracking_data: &ApTracking,
         //Map from variable name to reference id number
@@ -35,6 +39,20 @@ pub trait HintProcessor {
         //List of all references (key corresponds to element of the previous dictionary)
         references: &HashMap<usize, HintReference>,
     ) -> Result<Box<dyn Any>, VirtualMachineError>;
+
+    //Returns whether this processor can execute the given block of hint code. Used to choose
+    //which processor compiles and executes each hint when several of them are combined.
+    //Defaults to whether the hint code compiles without any ids or scopes
+    fn supports_hint(&self, hint_code: &str) -> bool {
+        self.compile_hint(
+            hint_code,
+            &[],
+            &ApTracking::new(),
+            &HashMap::new(),
+            &HashMap::new(),
+        )
+        .is_ok()
+    }
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -47,6 +65,12 @@ pub struct HintReference {
     pub ap_tracking_data: Option<ApTracking>,
     pub immediate: Option<BigInt>,
     pub cairo_type: Option<String>,
+    //Members of the struct given by cairo_type (or pointed to by it), resolved from the
+    //program identifiers
+    pub members: Option<HashMap<String, Member>>,
+    //Expression of references that don't fit the register/offsets/immediate form, with its
+    //struct member accesses already resolved. When present, the other location fields are unused
+    pub expression: Option<ReferenceExpression>,
 }
 
 impl HintReference {
@@ -60,6 +84,8 @@ impl HintReference {
             immediate: None,
             dereference: true,
             cairo_type: None,
+            members: None,
+            expression: None,
         }
     }
 
@@ -73,6 +99,8 @@ impl HintReference {
             immediate: None,
             dereference,
             cairo_type: None,
+            members: None,
+            expression: None,
         }
     }
 }
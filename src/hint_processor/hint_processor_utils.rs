@@ -1,18 +1,20 @@
-use std::borrow::Cow;
+use std::{any::Any, borrow::Cow, collections::HashMap};
 
 use num_bigint::BigInt;
+use num_integer::Integer;
 use num_traits::ToPrimitive;
 
 use crate::{
-    serde::deserialize_program::ApTracking,
+    serde::{deserialize_program::ApTracking, reference_expression::ReferenceExpression},
     types::{
         instruction::Register,
+        program::Program,
         relocatable::{MaybeRelocatable, Relocatable},
     },
     vm::{errors::vm_errors::VirtualMachineError, vm_core::VirtualMachine},
 };
 
-use super::hint_processor_definition::HintReference;
+use super::hint_processor_definition::{HintProcessor, HintReference};
 
 ///Inserts value into the address of the given ids variable
 pub fn insert_value_from_reference(
@@ -31,6 +33,13 @@ pub fn get_integer_from_reference<'a>(
     hint_reference: &'a HintReference,
     ap_tracking: &ApTracking,
 ) -> Result<Cow<'a, BigInt>, VirtualMachineError> {
+    // references that are not a dereference hold their value in the expression itself
+    if let Some(expression) = &hint_reference.expression {
+        if expression.dereferenced_address().is_none() {
+            let value = evaluate_reference_expression(expression, hint_reference, vm, ap_tracking)?;
+            return Ok(Cow::Owned(value.get_int_ref()?.clone()));
+        }
+    }
     // if the reference register is none, this means it is an immediate value and we
     // should return that value.
     if hint_reference.register.is_none() && hint_reference.immediate.is_some() {
@@ -71,6 +80,18 @@ pub fn compute_addr_from_reference(
     //ApTracking of the Hint itself
     hint_ap_tracking: &ApTracking,
 ) -> Result<Relocatable, VirtualMachineError> {
+    if let Some(expression) = &hint_reference.expression {
+        let addr_expression = expression.dereferenced_address().unwrap_or(expression);
+        return match evaluate_reference_expression(
+            addr_expression,
+            hint_reference,
+            vm,
+            hint_ap_tracking,
+        )? {
+            MaybeRelocatable::RelocatableValue(addr) => Ok(addr),
+            value => Err(VirtualMachineError::ExpectedRelocatable(value)),
+        };
+    }
     let base_addr = match hint_reference.register {
         //This should never fail
         Some(Register::FP) => vm.get_fp(),
@@ -105,6 +126,60 @@ pub fn compute_addr_from_reference(
     }
 }
 
+///Evaluates a reference expression, reading the dereferenced values from memory
+pub fn evaluate_reference_expression(
+    expression: &ReferenceExpression,
+    //Reference data of the ids variable, used for its ap tracking
+    hint_reference: &HintReference,
+    vm: &VirtualMachine,
+    //ApTracking of the Hint itself
+    hint_ap_tracking: &ApTracking,
+) -> Result<MaybeRelocatable, VirtualMachineError> {
+    let evaluate = |expression: &ReferenceExpression| {
+        evaluate_reference_expression(expression, hint_reference, vm, hint_ap_tracking)
+    };
+    let prime = vm.get_prime();
+    match expression {
+        ReferenceExpression::Register(Register::FP) => Ok(vm.get_fp().into()),
+        ReferenceExpression::Register(Register::AP) => {
+            let var_ap_trackig = hint_reference
+                .ap_tracking_data
+                .as_ref()
+                .ok_or(VirtualMachineError::NoneApTrackingData)?;
+
+            Ok(
+                apply_ap_tracking_correction(&vm.get_ap(), var_ap_trackig, hint_ap_tracking)?
+                    .into(),
+            )
+        }
+        ReferenceExpression::Immediate(value) => Ok(MaybeRelocatable::from(value.mod_floor(prime))),
+        ReferenceExpression::Deref(inner) => {
+            let addr = match evaluate(inner)? {
+                MaybeRelocatable::RelocatableValue(addr) => addr,
+                value => return Err(VirtualMachineError::ExpectedRelocatable(value)),
+            };
+            vm.get_maybe(&addr)
+                .map_err(|_| VirtualMachineError::FailedToGetIds)?
+                .ok_or(VirtualMachineError::FailedToGetIds)
+        }
+        ReferenceExpression::Add(lhs, rhs) => evaluate(lhs)?.add_mod(&evaluate(rhs)?, prime),
+        ReferenceExpression::Sub(lhs, rhs) => evaluate(lhs)?.sub(&evaluate(rhs)?, prime),
+        ReferenceExpression::Mul(lhs, rhs) => {
+            let (lhs, rhs) = (evaluate(lhs)?, evaluate(rhs)?);
+            Ok(MaybeRelocatable::from(
+                (lhs.get_int_ref()? * rhs.get_int_ref()?).mod_floor(prime),
+            ))
+        }
+        ReferenceExpression::Neg(inner) => Ok(MaybeRelocatable::from(
+            (-evaluate(inner)?.get_int_ref()?).mod_floor(prime),
+        )),
+        ReferenceExpression::Cast(inner, _) => evaluate(inner),
+        ReferenceExpression::MemberAccess(_, member_name) => Err(
+            VirtualMachineError::UnresolvedReferenceMember(member_name.clone()),
+        ),
+    }
+}
+
 fn apply_ap_tracking_correction(
     ap: &Relocatable,
     ref_ap_tracking: &ApTracking,
@@ -133,11 +208,93 @@ pub fn bigint_to_u32(bigint: &BigInt) -> Result<u32, VirtualMachineError> {
     bigint.to_u32().ok_or(VirtualMachineError::BigintToU32Fail)
 }
 
+/// Builds the hint references of a program, resolving the struct members of their types from
+/// the program identifiers
+pub fn get_program_reference_list(program: &Program) -> HashMap<usize, HintReference> {
+    let mut references = HashMap::<usize, HintReference>::new();
+
+    for (i, reference) in program.reference_manager.references.iter().enumerate() {
+        // member accesses can only be resolved once the struct definitions are known
+        let expression = reference
+            .value_address
+            .expression
+            .clone()
+            .map(|expression| expression.resolve_members(&program.identifiers));
+        let (cairo_type, dereference) = match &expression {
+            Some(expression) => (
+                expression
+                    .cairo_type()
+                    .unwrap_or_else(|| reference.value_address.value_type.clone()),
+                expression.dereferenced_address().is_some(),
+            ),
+            None => (
+                reference.value_address.value_type.clone(),
+                reference.value_address.dereference,
+            ),
+        };
+        references.insert(
+            i,
+            HintReference {
+                register: reference.value_address.register.clone(),
+                offset1: reference.value_address.offset1,
+                offset2: reference.value_address.offset2,
+                inner_dereference: reference.value_address.inner_dereference,
+                dereference,
+                immediate: reference.value_address.immediate.clone(),
+                // only store `ap` tracking data if the reference is referred to it
+                ap_tracking_data: if reference.value_address.register == Some(Register::FP) {
+                    None
+                } else {
+                    Some(reference.ap_tracking_data.clone())
+                },
+                // members of a struct or of a pointer to it, pointers to pointers have none
+                members: program
+                    .identifiers
+                    .get(cairo_type.strip_suffix('*').unwrap_or(cairo_type.as_str()))
+                    .and_then(|identifier| identifier.members.clone()),
+                cairo_type: Some(cairo_type),
+                expression,
+            },
+        );
+    }
+    references
+}
+
+/// Compiles the hints of a program with the given hint processor, grouped by pc offset
+pub fn compile_program_hints(
+    program: &Program,
+    references: &HashMap<usize, HintReference>,
+    hint_executor: &dyn HintProcessor,
+) -> Result<HashMap<usize, Vec<Box<dyn Any>>>, VirtualMachineError> {
+    let mut hint_data_dictionary = HashMap::<usize, Vec<Box<dyn Any>>>::new();
+    for (hint_index, hints) in program.hints.iter() {
+        for hint in hints {
+            let hint_data = hint_executor.compile_hint(
+                &hint.code,
+                &hint.accessible_scopes,
+                &hint.flow_tracking_data.ap_tracking,
+                &hint.flow_tracking_data.reference_ids,
+                references,
+            );
+            hint_data_dictionary
+                .entry(*hint_index)
+                .or_insert(vec![])
+                .push(hint_data.map_err(|err| match err {
+                    VirtualMachineError::UnknownHint(_)
+                    | VirtualMachineError::UnknownHintInProcessors(_, _) => err,
+                    _ => VirtualMachineError::CompileHintFail(hint.code.clone()),
+                })?);
+        }
+    }
+    Ok(hint_data_dictionary)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
         bigint, relocatable,
+        serde::reference_expression::parse_reference_expression,
         utils::test_utils::*,
         vm::{
             errors::memory_errors::MemoryError, vm_core::VirtualMachine, vm_memory::memory::Memory,
@@ -230,6 +387,79 @@ mod tests {
         );
     }
 
+    fn expression_reference(value: &str) -> HintReference {
+        let (_, expression) = parse_reference_expression(value).unwrap();
+        let mut hint_reference =
+            HintReference::new(0, 0, false, expression.dereferenced_address().is_some());
+        hint_reference.register = None;
+        hint_reference.ap_tracking_data = Some(ApTracking::new());
+        hint_reference.expression = Some(expression);
+        hint_reference
+    }
+
+    #[test]
+    fn compute_addr_from_reference_with_nested_dereferences() {
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), (2, 0)), ((2, 1), (3, 0))];
+
+        assert_eq!(
+            compute_addr_from_reference(
+                &expression_reference("[cast([[fp] + 1] + 2, felt*)]"),
+                &vm,
+                &ApTracking::new()
+            ),
+            Ok(relocatable!(3, 2))
+        );
+    }
+
+    #[test]
+    fn compute_addr_from_reference_with_ap_expression() {
+        let mut vm = vm!();
+        vm.memory = memory![((1, 1), (2, 0))];
+
+        assert_eq!(
+            compute_addr_from_reference(
+                &expression_reference("[cast([ap + 1] + 1, felt*)]"),
+                &vm,
+                &ApTracking::new()
+            ),
+            Ok(relocatable!(2, 1))
+        );
+    }
+
+    #[test]
+    fn get_integer_from_reference_with_arithmetic_expression() {
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), 5), ((1, 1), 2)];
+
+        assert_eq!(
+            get_integer_from_reference(
+                &vm,
+                &expression_reference("cast([fp] * 3 + [fp + 1] - 1, felt)"),
+                &ApTracking::new()
+            )
+            .expect("Unexpected get integer fail")
+            .into_owned(),
+            bigint!(16)
+        );
+    }
+
+    #[test]
+    fn compute_addr_from_reference_with_unresolved_member() {
+        let vm = vm!();
+
+        assert_eq!(
+            compute_addr_from_reference(
+                &expression_reference("[cast(fp, a.Point*)].x"),
+                &vm,
+                &ApTracking::new()
+            ),
+            Err(VirtualMachineError::UnresolvedReferenceMember(
+                "x".to_string()
+            ))
+        );
+    }
+
     #[test]
     fn tracking_correction_invalid_group() {
         let mut ref_ap_tracking = ApTracking::new();
@@ -1,3 +1,7 @@
 pub mod builtin_hint_processor;
+pub mod cairo_1_hint_processor;
+pub mod cairo_struct;
+pub mod composite_hint_processor;
 pub mod hint_processor_definition;
 pub mod hint_processor_utils;
+pub mod python_hint_processor;
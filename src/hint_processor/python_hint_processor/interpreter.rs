@@ -0,0 +1,1366 @@
+use super::parser::{BinaryOp, CompareOp, Expr, Stmt, UnaryOp};
+use crate::hint_processor::hint_processor_definition::HintReference;
+use crate::hint_processor::hint_processor_utils::{
+    bigint_to_usize, compute_addr_from_reference, get_ptr_from_reference,
+};
+use crate::math_utils::{as_int, div_mod, isqrt, safe_div};
+use crate::serde::deserialize_program::{ApTracking, Member};
+use crate::types::exec_scope::ExecutionScopes;
+use crate::types::relocatable::{MaybeRelocatable, Relocatable};
+use crate::vm::errors::python_hint_errors::PythonHintError;
+use crate::vm::errors::vm_errors::VirtualMachineError;
+use crate::vm::vm_core::VirtualMachine;
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{One, Signed, ToPrimitive, Zero};
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::fmt;
+use std::rc::Rc;
+
+/// Value handled by the interpreter.
+/// Values other than integers, relocatables and booleans are stored in the execution scopes as
+/// they are, so they can be shared between hints run by the interpreter.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Value {
+    None,
+    Bool(bool),
+    Int(BigInt),
+    Str(String),
+    Relocatable(Relocatable),
+    Tuple(Vec<Value>),
+    List(Rc<RefCell<Vec<Value>>>),
+    Dict(Rc<RefCell<Vec<(Value, Value)>>>),
+    // Ranges are iterated lazily, as they are often larger than the loops that use them
+    Range {
+        start: BigInt,
+        stop: BigInt,
+        step: BigInt,
+    },
+    // Struct located at base. is_pointer is set when it was reached through a pointer, in which
+    // case using it as a value yields its address
+    Struct {
+        base: Relocatable,
+        members: Rc<HashMap<String, Member>>,
+        is_pointer: bool,
+    },
+    Function(&'static str),
+    Method(Box<Value>, String),
+    Ids,
+    Memory,
+    Segments,
+}
+
+const BUILTIN_FUNCTIONS: [&str; 16] = [
+    "abs",
+    "as_int",
+    "div_mod",
+    "divmod",
+    "int",
+    "isqrt",
+    "len",
+    "list",
+    "max",
+    "min",
+    "pow",
+    "range",
+    "safe_div",
+    "sum",
+    "vm_enter_scope",
+    "vm_exit_scope",
+];
+
+// Number of statements, loop iterations and iterated elements a single hint may go through, so
+// that hints can't stall the vm
+pub(crate) const MAX_OPERATIONS: usize = 1_000_000;
+
+// Size of the largest integer that products, powers and left shifts may produce
+const MAX_INT_BITS: u64 = 1 << 16;
+
+// Length of the longest string, tuple or list that concatenations may produce
+const MAX_SEQUENCE_LEN: usize = 1 << 20;
+
+impl Value {
+    fn type_name(&self) -> &'static str {
+        match self {
+            Value::None => "NoneType",
+            Value::Bool(_) => "bool",
+            Value::Int(_) => "int",
+            Value::Str(_) => "str",
+            Value::Relocatable(_) => "RelocatableValue",
+            Value::Tuple(_) => "tuple",
+            Value::List(_) => "list",
+            Value::Dict(_) => "dict",
+            Value::Range { .. } => "range",
+            Value::Struct { .. } => "struct",
+            Value::Function(_) | Value::Method(_, _) => "function",
+            Value::Ids => "ids",
+            Value::Memory => "memory",
+            Value::Segments => "segments",
+        }
+    }
+
+    fn is_truthy(&self) -> bool {
+        match self {
+            Value::None => false,
+            Value::Bool(value) => *value,
+            Value::Int(value) => !value.is_zero(),
+            Value::Str(value) => !value.is_empty(),
+            Value::Tuple(elements) => !elements.is_empty(),
+            Value::List(elements) => !elements.borrow().is_empty(),
+            Value::Dict(items) => !items.borrow().is_empty(),
+            Value::Range { start, stop, step } => !range_len(start, stop, step).is_zero(),
+            _ => true,
+        }
+    }
+
+    // Booleans behave as integers in arithmetic and comparisons
+    fn as_int(&self) -> Option<BigInt> {
+        match self {
+            Value::Int(value) => Some(value.clone()),
+            Value::Bool(value) => Some(BigInt::from(*value as u8)),
+            _ => None,
+        }
+    }
+
+    // Structs reached through pointers are used as their address
+    fn as_address(&self) -> Option<Relocatable> {
+        match self {
+            Value::Relocatable(addr) => Some(addr.clone()),
+            Value::Struct {
+                base,
+                is_pointer: true,
+                ..
+            } => Some(base.clone()),
+            _ => None,
+        }
+    }
+
+    fn equals(&self, other: &Value) -> bool {
+        match (self.as_int(), other.as_int()) {
+            (Some(a), Some(b)) => a == b,
+            _ => match (self.as_address(), other.as_address()) {
+                (Some(a), Some(b)) => a == b,
+                _ => self == other,
+            },
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Value::None => write!(f, "None"),
+            Value::Bool(true) => write!(f, "True"),
+            Value::Bool(false) => write!(f, "False"),
+            Value::Int(value) => write!(f, "{}", value),
+            Value::Str(value) => write!(f, "{}", value),
+            Value::Relocatable(addr) => write!(f, "{}:{}", addr.segment_index, addr.offset),
+            Value::Tuple(elements) => write!(f, "({})", join_values(elements)),
+            Value::List(elements) => write!(f, "[{}]", join_values(&elements.borrow())),
+            Value::Dict(items) => {
+                let items: Vec<String> = items
+                    .borrow()
+                    .iter()
+                    .map(|(key, value)| format!("{}: {}", key, value))
+                    .collect();
+                write!(f, "{{{}}}", items.join(", "))
+            }
+            Value::Range { start, stop, step } if step.is_one() => {
+                write!(f, "range({}, {})", start, stop)
+            }
+            Value::Range { start, stop, step } => write!(f, "range({}, {}, {})", start, stop, step),
+            Value::Struct { base, .. } => {
+                write!(f, "<struct at {}:{}>", base.segment_index, base.offset)
+            }
+            Value::Function(name) => write!(f, "<function {}>", name),
+            Value::Method(_, name) => write!(f, "<function {}>", name),
+            _ => write!(f, "{}", self.type_name()),
+        }
+    }
+}
+
+fn join_values(values: &[Value]) -> String {
+    values
+        .iter()
+        .map(Value::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+impl From<MaybeRelocatable> for Value {
+    fn from(value: MaybeRelocatable) -> Self {
+        match value {
+            MaybeRelocatable::Int(value) => Value::Int(value),
+            MaybeRelocatable::RelocatableValue(addr) => Value::Relocatable(addr),
+        }
+    }
+}
+
+fn type_error<T>(message: String) -> Result<T, VirtualMachineError> {
+    Err(PythonHintError::TypeError(message).into())
+}
+
+// Result of executing a statement, used to unwind loops
+enum Flow {
+    Normal,
+    Break,
+    Continue,
+}
+
+/// Executes parsed hint code against the vm, keeping local variables in the current
+/// execution scope.
+pub(crate) struct Interpreter<'a> {
+    vm: &'a mut VirtualMachine,
+    exec_scopes: &'a mut ExecutionScopes,
+    ids_data: &'a HashMap<String, HintReference>,
+    ap_tracking: &'a ApTracking,
+    constants: &'a HashMap<String, BigInt>,
+    // Operations left before the hint is aborted
+    remaining_operations: Cell<usize>,
+}
+
+impl<'a> Interpreter<'a> {
+    pub(crate) fn new(
+        vm: &'a mut VirtualMachine,
+        exec_scopes: &'a mut ExecutionScopes,
+        ids_data: &'a HashMap<String, HintReference>,
+        ap_tracking: &'a ApTracking,
+        constants: &'a HashMap<String, BigInt>,
+    ) -> Self {
+        Interpreter {
+            vm,
+            exec_scopes,
+            ids_data,
+            ap_tracking,
+            constants,
+            remaining_operations: Cell::new(MAX_OPERATIONS),
+        }
+    }
+
+    // Counts the given number of operations against the budget of the hint
+    fn charge(&self, operations: usize) -> Result<(), VirtualMachineError> {
+        match self.remaining_operations.get().checked_sub(operations) {
+            Some(remaining_operations) => {
+                self.remaining_operations.set(remaining_operations);
+                Ok(())
+            }
+            None => Err(PythonHintError::OperationLimitExceeded(MAX_OPERATIONS).into()),
+        }
+    }
+
+    pub(crate) fn run(&mut self, statements: &[Stmt]) -> Result<(), VirtualMachineError> {
+        // The parser rejects break and continue statements outside of loops
+        self.execute_block(statements).map(|_| ())
+    }
+
+    fn execute_block(&mut self, statements: &[Stmt]) -> Result<Flow, VirtualMachineError> {
+        for statement in statements {
+            match self.execute(statement)? {
+                Flow::Normal => (),
+                flow => return Ok(flow),
+            }
+        }
+        Ok(Flow::Normal)
+    }
+
+    fn execute(&mut self, statement: &Stmt) -> Result<Flow, VirtualMachineError> {
+        self.charge(1)?;
+        match statement {
+            Stmt::Expr(expression) => {
+                self.evaluate(expression)?;
+            }
+            Stmt::Assign(targets, expression) => {
+                let value = self.evaluate(expression)?;
+                for target in targets {
+                    self.assign(target, value.clone())?;
+                }
+            }
+            Stmt::AugAssign(target, op, expression) => {
+                let current = self.evaluate(target)?;
+                let operand = self.evaluate(expression)?;
+                let value = self.binary_op(*op, current, operand)?;
+                self.assign(target, value)?;
+            }
+            Stmt::If(branches, orelse) => {
+                for (condition, body) in branches {
+                    if self.evaluate(condition)?.is_truthy() {
+                        return self.execute_block(body);
+                    }
+                }
+                return self.execute_block(orelse);
+            }
+            Stmt::While(condition, body) => {
+                while self.evaluate(condition)?.is_truthy() {
+                    if let Flow::Break = self.execute_block(body)? {
+                        break;
+                    }
+                }
+            }
+            Stmt::For(target, iterable, body) => match self.evaluate(iterable)? {
+                Value::Range { start, stop, step } => {
+                    let mut current = start;
+                    while (step.is_positive() && current < stop)
+                        || (step.is_negative() && current > stop)
+                    {
+                        self.assign(target, Value::Int(current.clone()))?;
+                        if let Flow::Break = self.execute_block(body)? {
+                            break;
+                        }
+                        current += &step;
+                    }
+                }
+                iterable => {
+                    for value in self.iterate(&iterable)? {
+                        self.assign(target, value)?;
+                        if let Flow::Break = self.execute_block(body)? {
+                            break;
+                        }
+                    }
+                }
+            },
+            Stmt::Assert(condition, message) => {
+                if !self.evaluate(condition)?.is_truthy() {
+                    let message = match message {
+                        Some(message) => self.evaluate(message)?.to_string(),
+                        None => String::new(),
+                    };
+                    return Err(PythonHintError::AssertionFailed(message).into());
+                }
+            }
+            Stmt::Import(names) => {
+                if let Some(name) = names
+                    .iter()
+                    .find(|name| !BUILTIN_FUNCTIONS.contains(&name.as_str()))
+                {
+                    return Err(PythonHintError::Unsupported(format!("import of {}", name)).into());
+                }
+            }
+            Stmt::Break => return Ok(Flow::Break),
+            Stmt::Continue => return Ok(Flow::Continue),
+            Stmt::Pass => (),
+        }
+        Ok(Flow::Normal)
+    }
+
+    fn evaluate(&mut self, expression: &Expr) -> Result<Value, VirtualMachineError> {
+        match expression {
+            Expr::Int(value) => Ok(Value::Int(value.clone())),
+            Expr::Str(value) => Ok(Value::Str(value.clone())),
+            Expr::Bool(value) => Ok(Value::Bool(*value)),
+            Expr::None => Ok(Value::None),
+            Expr::Name(name) => self.get_name(name),
+            Expr::Attribute(object, attribute) => {
+                let object = self.evaluate(object)?;
+                self.get_attribute(object, attribute)
+            }
+            Expr::Subscript(object, index) => {
+                let object = self.evaluate(object)?;
+                let index = self.evaluate(index)?;
+                self.get_item(&object, &index)
+            }
+            Expr::Call(function, args, kwargs) => {
+                let function = self.evaluate(function)?;
+                let args = args
+                    .iter()
+                    .map(|arg| self.evaluate(arg))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let kwargs = kwargs
+                    .iter()
+                    .map(|(name, arg)| Ok((name.clone(), self.evaluate(arg)?)))
+                    .collect::<Result<Vec<_>, VirtualMachineError>>()?;
+                self.call(function, args, kwargs)
+            }
+            Expr::Unary(op, operand) => {
+                let operand = self.evaluate(operand)?;
+                if let UnaryOp::Not = op {
+                    return Ok(Value::Bool(!operand.is_truthy()));
+                }
+                let value = operand.as_int().ok_or_else(|| {
+                    PythonHintError::TypeError(format!(
+                        "bad operand type for unary operator: '{}'",
+                        operand.type_name()
+                    ))
+                })?;
+                Ok(Value::Int(match op {
+                    UnaryOp::Neg => -value,
+                    UnaryOp::Invert => -value - 1,
+                    _ => value,
+                }))
+            }
+            Expr::Binary(op, left, right) => {
+                let left = self.evaluate(left)?;
+                let right = self.evaluate(right)?;
+                self.binary_op(*op, left, right)
+            }
+            Expr::Compare(left, comparisons) => {
+                let mut left = self.evaluate(left)?;
+                for (op, right) in comparisons {
+                    let right = self.evaluate(right)?;
+                    if !compare(*op, &left, &right)? {
+                        return Ok(Value::Bool(false));
+                    }
+                    left = right;
+                }
+                Ok(Value::Bool(true))
+            }
+            Expr::And(left, right) => {
+                let left = self.evaluate(left)?;
+                if !left.is_truthy() {
+                    return Ok(left);
+                }
+                self.evaluate(right)
+            }
+            Expr::Or(left, right) => {
+                let left = self.evaluate(left)?;
+                if left.is_truthy() {
+                    return Ok(left);
+                }
+                self.evaluate(right)
+            }
+            Expr::IfElse(condition, body, orelse) => {
+                if self.evaluate(condition)?.is_truthy() {
+                    self.evaluate(body)
+                } else {
+                    self.evaluate(orelse)
+                }
+            }
+            Expr::Tuple(elements) => Ok(Value::Tuple(self.evaluate_all(elements)?)),
+            Expr::List(elements) => Ok(Value::List(Rc::new(RefCell::new(
+                self.evaluate_all(elements)?,
+            )))),
+            Expr::Dict(items) => {
+                let mut dict: Vec<(Value, Value)> = Vec::new();
+                for (key, value) in items {
+                    let key = self.evaluate(key)?;
+                    let value = self.evaluate(value)?;
+                    dict_insert(&mut dict, key, value);
+                }
+                Ok(Value::Dict(Rc::new(RefCell::new(dict))))
+            }
+        }
+    }
+
+    fn evaluate_all(&mut self, expressions: &[Expr]) -> Result<Vec<Value>, VirtualMachineError> {
+        expressions
+            .iter()
+            .map(|expression| self.evaluate(expression))
+            .collect()
+    }
+
+    fn get_name(&self, name: &str) -> Result<Value, VirtualMachineError> {
+        if let Some(value) = self.exec_scopes.get_local_variables()?.get(name) {
+            return load_value(name, value.as_ref());
+        }
+        match name {
+            "ids" => Ok(Value::Ids),
+            "memory" => Ok(Value::Memory),
+            "segments" => Ok(Value::Segments),
+            "PRIME" => Ok(Value::Int(self.vm.get_prime().clone())),
+            "ap" => Ok(Value::Relocatable(self.vm.get_ap())),
+            "fp" => Ok(Value::Relocatable(self.vm.get_fp())),
+            "pc" => Ok(Value::Relocatable(self.vm.get_pc().clone())),
+            _ => match BUILTIN_FUNCTIONS.iter().find(|function| **function == name) {
+                Some(function) => Ok(Value::Function(function)),
+                None => Err(PythonHintError::NameError(name.to_string()).into()),
+            },
+        }
+    }
+
+    fn get_attribute(&self, object: Value, attribute: &str) -> Result<Value, VirtualMachineError> {
+        match (&object, attribute) {
+            (Value::Ids, _) => self.get_ids_variable(attribute),
+            (Value::Struct { base, .. }, "address_") => Ok(Value::Relocatable(base.clone())),
+            (Value::Struct { base, members, .. }, _) => {
+                let member = members.get(attribute).ok_or_else(|| {
+                    PythonHintError::TypeError(format!("struct has no member '{}'", attribute))
+                })?;
+                self.read_memory(&(base + member.offset))
+            }
+            (Value::Relocatable(addr), "segment_index") => {
+                Ok(Value::Int(BigInt::from(addr.segment_index)))
+            }
+            (Value::Relocatable(addr), "offset") => Ok(Value::Int(BigInt::from(addr.offset))),
+            (Value::Segments, "add" | "add_temp_segment" | "write_arg")
+            | (Value::List(_), "append" | "pop")
+            | (Value::Dict(_), "get") => Ok(Value::Method(Box::new(object), attribute.to_string())),
+            _ => type_error(format!(
+                "'{}' object has no attribute '{}'",
+                object.type_name(),
+                attribute
+            )),
+        }
+    }
+
+    fn get_ids_variable(&self, name: &str) -> Result<Value, VirtualMachineError> {
+        let hint_reference = match self.ids_data.get(name) {
+            Some(hint_reference) => hint_reference,
+            // Constants of the program can also be accessed through ids
+            None => {
+                let suffix = format!(".{}", name);
+                return self
+                    .constants
+                    .iter()
+                    .find(|(constant_name, _)| constant_name.ends_with(&suffix))
+                    .map(|(_, value)| Value::Int(value.clone()))
+                    .ok_or_else(|| PythonHintError::NameError(format!("ids.{}", name)).into());
+            }
+        };
+        if hint_reference.register.is_none() {
+            if let Some(immediate) = &hint_reference.immediate {
+                return Ok(Value::Int(immediate.clone()));
+            }
+        }
+        if let Some(members) = &hint_reference.members {
+            let is_pointer =
+                matches!(&hint_reference.cairo_type, Some(cairo_type) if cairo_type.ends_with('*'));
+            let base = if is_pointer {
+                get_ptr_from_reference(self.vm, hint_reference, self.ap_tracking)?
+            } else {
+                compute_addr_from_reference(hint_reference, self.vm, self.ap_tracking)?
+            };
+            return Ok(Value::Struct {
+                base,
+                members: Rc::new(members.clone()),
+                is_pointer,
+            });
+        }
+        if !hint_reference.dereference {
+            return Ok(Value::Relocatable(get_ptr_from_reference(
+                self.vm,
+                hint_reference,
+                self.ap_tracking,
+            )?));
+        }
+        let addr = compute_addr_from_reference(hint_reference, self.vm, self.ap_tracking)?;
+        self.read_memory(&addr)
+    }
+
+    fn read_memory(&self, addr: &Relocatable) -> Result<Value, VirtualMachineError> {
+        self.vm
+            .get_maybe(addr)?
+            .map(Value::from)
+            .ok_or_else(|| VirtualMachineError::MemoryGet(addr.into()))
+    }
+
+    fn get_item(&self, object: &Value, index: &Value) -> Result<Value, VirtualMachineError> {
+        match object {
+            Value::Memory => {
+                let addr = index.as_address().ok_or_else(|| {
+                    PythonHintError::TypeError(format!(
+                        "memory address must be a relocatable, not '{}'",
+                        index.type_name()
+                    ))
+                })?;
+                self.read_memory(&addr)
+            }
+            Value::Tuple(elements) => {
+                let position = sequence_index(elements.len(), index)?;
+                Ok(elements[position].clone())
+            }
+            Value::List(elements) => {
+                let elements = elements.borrow();
+                let position = sequence_index(elements.len(), index)?;
+                Ok(elements[position].clone())
+            }
+            Value::Dict(items) => items
+                .borrow()
+                .iter()
+                .find(|(key, _)| key.equals(index))
+                .map(|(_, value)| value.clone())
+                .ok_or_else(|| PythonHintError::KeyError(index.to_string()).into()),
+            _ => type_error(format!(
+                "'{}' object is not subscriptable",
+                object.type_name()
+            )),
+        }
+    }
+
+    fn assign(&mut self, target: &Expr, value: Value) -> Result<(), VirtualMachineError> {
+        match target {
+            Expr::Name(name) => {
+                self.exec_scopes
+                    .assign_or_update_variable(name, store_value(value));
+                Ok(())
+            }
+            Expr::Attribute(object, attribute) => {
+                let addr = match self.evaluate(object)? {
+                    Value::Ids => self.get_ids_variable_addr(attribute)?,
+                    Value::Struct { base, members, .. } => {
+                        let member = members.get(attribute.as_str()).ok_or_else(|| {
+                            PythonHintError::TypeError(format!(
+                                "struct has no member '{}'",
+                                attribute
+                            ))
+                        })?;
+                        base + member.offset
+                    }
+                    object => {
+                        return type_error(format!(
+                            "cannot assign attribute '{}' of '{}' object",
+                            attribute,
+                            object.type_name()
+                        ))
+                    }
+                };
+                self.write_memory(&addr, value)
+            }
+            Expr::Subscript(object, index) => {
+                let object = self.evaluate(object)?;
+                let index = self.evaluate(index)?;
+                match object {
+                    Value::Memory => {
+                        let addr = index.as_address().ok_or_else(|| {
+                            PythonHintError::TypeError(format!(
+                                "memory address must be a relocatable, not '{}'",
+                                index.type_name()
+                            ))
+                        })?;
+                        self.write_memory(&addr, value)
+                    }
+                    Value::List(elements) => {
+                        let mut elements = elements.borrow_mut();
+                        let position = sequence_index(elements.len(), &index)?;
+                        elements[position] = value;
+                        Ok(())
+                    }
+                    Value::Dict(items) => {
+                        dict_insert(&mut items.borrow_mut(), index, value);
+                        Ok(())
+                    }
+                    object => type_error(format!(
+                        "'{}' object does not support item assignment",
+                        object.type_name()
+                    )),
+                }
+            }
+            Expr::Tuple(targets) | Expr::List(targets) => {
+                let values = self.iterate(&value)?;
+                if values.len() != targets.len() {
+                    return type_error(format!(
+                        "cannot unpack {} values into {} targets",
+                        values.len(),
+                        targets.len()
+                    ));
+                }
+                for (target, value) in targets.iter().zip(values) {
+                    self.assign(target, value)?;
+                }
+                Ok(())
+            }
+            // The parser only accepts the targets above
+            _ => type_error("cannot assign to expression".to_string()),
+        }
+    }
+
+    fn get_ids_variable_addr(&self, name: &str) -> Result<Relocatable, VirtualMachineError> {
+        let hint_reference = self
+            .ids_data
+            .get(name)
+            .ok_or_else(|| PythonHintError::NameError(format!("ids.{}", name)))?;
+        compute_addr_from_reference(hint_reference, self.vm, self.ap_tracking)
+    }
+
+    fn write_memory(
+        &mut self,
+        addr: &Relocatable,
+        value: Value,
+    ) -> Result<(), VirtualMachineError> {
+        let value = match (value.as_int(), value.as_address()) {
+            (Some(value), _) => MaybeRelocatable::Int(value.mod_floor(self.vm.get_prime())),
+            (_, Some(value)) => MaybeRelocatable::RelocatableValue(value),
+            _ => {
+                return type_error(format!(
+                    "cannot write '{}' object into memory",
+                    value.type_name()
+                ))
+            }
+        };
+        self.vm.insert_value(addr, value)
+    }
+
+    // Collects the elements of the iterable, each of them counting as an operation
+    fn iterate(&self, iterable: &Value) -> Result<Vec<Value>, VirtualMachineError> {
+        if let Value::Range { start, stop, step } = iterable {
+            let len = range_len(start, stop, step)
+                .to_usize()
+                .ok_or(PythonHintError::OperationLimitExceeded(MAX_OPERATIONS))?;
+            self.charge(len)?;
+            let mut values = Vec::with_capacity(len);
+            let mut current = start.clone();
+            for _ in 0..len {
+                values.push(Value::Int(current.clone()));
+                current += step;
+            }
+            return Ok(values);
+        }
+        let values = match iterable {
+            Value::Tuple(elements) => elements.clone(),
+            Value::List(elements) => elements.borrow().clone(),
+            Value::Dict(items) => items.borrow().iter().map(|(key, _)| key.clone()).collect(),
+            Value::Str(string) => string.chars().map(|c| Value::Str(c.to_string())).collect(),
+            _ => return type_error(format!("'{}' object is not iterable", iterable.type_name())),
+        };
+        self.charge(values.len())?;
+        Ok(values)
+    }
+
+    fn binary_op(
+        &self,
+        op: BinaryOp,
+        left: Value,
+        right: Value,
+    ) -> Result<Value, VirtualMachineError> {
+        if let (Some(a), Some(b)) = (left.as_int(), right.as_int()) {
+            return int_binary_op(op, a, b).map(Value::Int);
+        }
+        match (op, &left, &right) {
+            (BinaryOp::Add, Value::Str(a), Value::Str(b)) => {
+                check_concatenation_len(a.len() + b.len())?;
+                Ok(Value::Str(format!("{}{}", a, b)))
+            }
+            (BinaryOp::Add, Value::Tuple(a), Value::Tuple(b)) => {
+                check_concatenation_len(a.len() + b.len())?;
+                Ok(Value::Tuple(a.iter().chain(b.iter()).cloned().collect()))
+            }
+            (BinaryOp::Add, Value::List(a), Value::List(b)) => {
+                check_concatenation_len(a.borrow().len() + b.borrow().len())?;
+                Ok(Value::List(Rc::new(RefCell::new(
+                    a.borrow()
+                        .iter()
+                        .chain(b.borrow().iter())
+                        .cloned()
+                        .collect(),
+                ))))
+            }
+            (BinaryOp::Add | BinaryOp::Sub, _, _) => {
+                match (
+                    left.as_address(),
+                    right.as_address(),
+                    left.as_int(),
+                    right.as_int(),
+                ) {
+                    (Some(addr), None, _, Some(offset)) => {
+                        let offset = if op == BinaryOp::Sub { -offset } else { offset };
+                        relocatable_add(&addr, &offset).map(Value::Relocatable)
+                    }
+                    (None, Some(addr), Some(offset), _) if op == BinaryOp::Add => {
+                        relocatable_add(&addr, &offset).map(Value::Relocatable)
+                    }
+                    (Some(a), Some(b), _, _) if op == BinaryOp::Sub => {
+                        if a.segment_index != b.segment_index {
+                            return type_error(format!(
+                                "cannot subtract relocatables of different segments {} and {}",
+                                a.segment_index, b.segment_index
+                            ));
+                        }
+                        Ok(Value::Int(BigInt::from(a.offset) - BigInt::from(b.offset)))
+                    }
+                    _ => unsupported_operands(op, &left, &right),
+                }
+            }
+            _ => unsupported_operands(op, &left, &right),
+        }
+    }
+
+    fn call(
+        &mut self,
+        function: Value,
+        args: Vec<Value>,
+        kwargs: Vec<(String, Value)>,
+    ) -> Result<Value, VirtualMachineError> {
+        match function {
+            Value::Function(name) => self.call_builtin(name, args, kwargs),
+            Value::Method(object, name) => {
+                if !kwargs.is_empty() {
+                    return type_error(format!("{}() takes no keyword arguments", name));
+                }
+                self.call_method(*object, &name, args)
+            }
+            _ => type_error(format!("'{}' object is not callable", function.type_name())),
+        }
+    }
+
+    fn call_builtin(
+        &mut self,
+        name: &str,
+        args: Vec<Value>,
+        kwargs: Vec<(String, Value)>,
+    ) -> Result<Value, VirtualMachineError> {
+        if name == "vm_enter_scope" {
+            let new_scope_locals = match (args.as_slice(), kwargs.as_slice()) {
+                ([], []) => None,
+                ([locals], []) => Some(locals),
+                ([], [(kwarg, locals)]) if kwarg == "new_scope_locals" => Some(locals),
+                _ => return type_error("vm_enter_scope() takes a single argument".to_string()),
+            };
+            let mut scope = HashMap::new();
+            match new_scope_locals {
+                Some(Value::Dict(items)) => {
+                    for (key, value) in items.borrow().iter() {
+                        match key {
+                            Value::Str(key) => {
+                                scope.insert(key.clone(), store_value(value.clone()));
+                            }
+                            _ => {
+                                return type_error(
+                                    "scope variable names must be strings".to_string(),
+                                )
+                            }
+                        }
+                    }
+                }
+                Some(Value::None) | None => (),
+                Some(value) => {
+                    return type_error(format!(
+                        "new_scope_locals must be a dict, not '{}'",
+                        value.type_name()
+                    ))
+                }
+            }
+            self.exec_scopes.enter_scope(scope);
+            return Ok(Value::None);
+        }
+        if !kwargs.is_empty() {
+            return type_error(format!("{}() takes no keyword arguments", name));
+        }
+        match (name, args.as_slice()) {
+            ("vm_exit_scope", []) => {
+                self.exec_scopes.exit_scope()?;
+                Ok(Value::None)
+            }
+            ("len", [value]) => Ok(Value::Int(match value {
+                Value::Str(string) => BigInt::from(string.chars().count()),
+                Value::Range { start, stop, step } => range_len(start, stop, step),
+                _ => BigInt::from(self.iterate(value)?.len()),
+            })),
+            ("list", [value]) => Ok(Value::List(Rc::new(RefCell::new(self.iterate(value)?)))),
+            ("range", _) => {
+                let (start, stop, step) = match int_args(name, &args)?.as_slice() {
+                    [stop] => (BigInt::zero(), stop.clone(), BigInt::one()),
+                    [start, stop] => (start.clone(), stop.clone(), BigInt::one()),
+                    [start, stop, step] if !step.is_zero() => {
+                        (start.clone(), stop.clone(), step.clone())
+                    }
+                    _ => return type_error("invalid arguments for range()".to_string()),
+                };
+                Ok(Value::Range { start, stop, step })
+            }
+            ("min" | "max", _) if !args.is_empty() => {
+                let values = if args.len() == 1 {
+                    self.iterate(&args[0])?
+                } else {
+                    args.clone()
+                };
+                let mut values = int_args(name, &values)?.into_iter();
+                let first = values.next().ok_or_else(|| {
+                    PythonHintError::TypeError(format!("{}() arg is an empty sequence", name))
+                })?;
+                Ok(Value::Int(values.fold(first, |current, value| {
+                    if (name == "min") == (value < current) {
+                        value
+                    } else {
+                        current
+                    }
+                })))
+            }
+            ("sum", [values]) => Ok(Value::Int(
+                int_args(name, &self.iterate(values)?)?.into_iter().sum(),
+            )),
+            ("int", [Value::Str(string)]) => string
+                .trim()
+                .parse::<BigInt>()
+                .map(Value::Int)
+                .map_err(|_| {
+                    PythonHintError::TypeError(format!("invalid literal for int(): '{}'", string))
+                        .into()
+                }),
+            _ => {
+                let args = int_args(name, &args)?;
+                let result = match (name, args.as_slice()) {
+                    ("abs", [value]) => value.abs(),
+                    ("int", [value]) => value.clone(),
+                    ("pow", [base, exponent]) => {
+                        int_binary_op(BinaryOp::Pow, base.clone(), exponent.clone())?
+                    }
+                    ("pow", [base, exponent, modulus]) => {
+                        if modulus.is_zero() {
+                            return Err(PythonHintError::ZeroDivision.into());
+                        }
+                        if exponent.is_negative() {
+                            return Err(PythonHintError::Unsupported(
+                                "pow() with a negative exponent".to_string(),
+                            )
+                            .into());
+                        }
+                        base.modpow(exponent, modulus).mod_floor(modulus)
+                    }
+                    ("divmod", [a, b]) => {
+                        if b.is_zero() {
+                            return Err(PythonHintError::ZeroDivision.into());
+                        }
+                        let (quotient, remainder) = a.div_mod_floor(b);
+                        return Ok(Value::Tuple(vec![
+                            Value::Int(quotient),
+                            Value::Int(remainder),
+                        ]));
+                    }
+                    ("as_int", [value, prime]) => as_int(value, prime),
+                    ("div_mod", [n, m, p]) => {
+                        if p.is_zero() || m.mod_floor(p).is_zero() {
+                            return Err(PythonHintError::ZeroDivision.into());
+                        }
+                        div_mod(n, m, p)
+                    }
+                    ("isqrt", [value]) => isqrt(value)?,
+                    ("safe_div", [x, y]) => safe_div(x, y)?,
+                    _ => return type_error(format!("invalid arguments for {}()", name)),
+                };
+                Ok(Value::Int(result))
+            }
+        }
+    }
+
+    fn call_method(
+        &mut self,
+        object: Value,
+        name: &str,
+        args: Vec<Value>,
+    ) -> Result<Value, VirtualMachineError> {
+        match (&object, name, args.as_slice()) {
+            (Value::Segments, "add", []) => Ok(Value::Relocatable(self.vm.add_memory_segment())),
+            (Value::Segments, "add_temp_segment", []) => {
+                Ok(Value::Relocatable(self.vm.add_temporary_segment()))
+            }
+            (Value::Segments, "write_arg", [ptr, data]) => {
+                let ptr = ptr.as_address().ok_or_else(|| {
+                    PythonHintError::TypeError(
+                        "write_arg() expects a relocatable pointer".to_string(),
+                    )
+                })?;
+                let data = self
+                    .iterate(data)?
+                    .into_iter()
+                    .map(|value| match (value.as_int(), value.as_address()) {
+                        (Some(value), _) => Ok(MaybeRelocatable::Int(value)),
+                        (_, Some(addr)) => Ok(MaybeRelocatable::RelocatableValue(addr)),
+                        _ => type_error(format!(
+                            "cannot write '{}' object into memory",
+                            value.type_name()
+                        )),
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(self.vm.write_arg(&ptr, &data)?.into())
+            }
+            (Value::List(elements), "append", [value]) => {
+                elements.borrow_mut().push(value.clone());
+                Ok(Value::None)
+            }
+            (Value::List(elements), "pop", _) if args.len() <= 1 => {
+                let mut elements = elements.borrow_mut();
+                let position = match args.first() {
+                    Some(index) => sequence_index(elements.len(), index)?,
+                    None if elements.is_empty() => {
+                        return Err(
+                            PythonHintError::IndexError("pop from empty list".to_string()).into(),
+                        )
+                    }
+                    None => elements.len() - 1,
+                };
+                Ok(elements.remove(position))
+            }
+            (Value::Dict(items), "get", [key]) | (Value::Dict(items), "get", [key, _]) => Ok(items
+                .borrow()
+                .iter()
+                .find(|(item_key, _)| item_key.equals(key))
+                .map(|(_, value)| value.clone())
+                .unwrap_or_else(|| args.get(1).cloned().unwrap_or(Value::None))),
+            _ => type_error(format!(
+                "invalid arguments for {}.{}()",
+                object.type_name(),
+                name
+            )),
+        }
+    }
+}
+
+fn check_concatenation_len(len: usize) -> Result<(), VirtualMachineError> {
+    if len > MAX_SEQUENCE_LEN {
+        return Err(PythonHintError::SequenceTooLong(MAX_SEQUENCE_LEN).into());
+    }
+    Ok(())
+}
+
+fn int_binary_op(op: BinaryOp, a: BigInt, b: BigInt) -> Result<BigInt, VirtualMachineError> {
+    Ok(match op {
+        BinaryOp::Add => a + b,
+        BinaryOp::Sub => a - b,
+        BinaryOp::Mul => {
+            if a.bits() + b.bits() > MAX_INT_BITS {
+                return Err(PythonHintError::IntegerTooLarge("*", MAX_INT_BITS).into());
+            }
+            a * b
+        }
+        BinaryOp::Div => {
+            return Err(PythonHintError::Unsupported(
+                "true division, use // or div_mod instead".to_string(),
+            )
+            .into())
+        }
+        BinaryOp::FloorDiv | BinaryOp::Mod if b.is_zero() => {
+            return Err(PythonHintError::ZeroDivision.into())
+        }
+        BinaryOp::FloorDiv => a.div_floor(&b),
+        BinaryOp::Mod => a.mod_floor(&b),
+        BinaryOp::Pow => {
+            let exponent = b
+                .to_u32()
+                .ok_or_else(|| PythonHintError::Unsupported(format!("exponent {}", b)))?;
+            // 0, 1 and -1 stay small whatever the exponent
+            if a.magnitude() > &One::one() && a.bits() * exponent as u64 > MAX_INT_BITS {
+                return Err(PythonHintError::IntegerTooLarge("**", MAX_INT_BITS).into());
+            }
+            a.pow(exponent)
+        }
+        BinaryOp::BitAnd => a & b,
+        BinaryOp::BitOr => a | b,
+        BinaryOp::BitXor => a ^ b,
+        BinaryOp::Shl | BinaryOp::Shr => {
+            let shift = b
+                .to_usize()
+                .ok_or_else(|| PythonHintError::TypeError(format!("invalid shift count {}", b)))?;
+            if op == BinaryOp::Shl {
+                if !a.is_zero() && a.bits() + shift as u64 > MAX_INT_BITS {
+                    return Err(PythonHintError::IntegerTooLarge("<<", MAX_INT_BITS).into());
+                }
+                a << shift
+            } else {
+                a >> shift
+            }
+        }
+    })
+}
+
+fn compare(op: CompareOp, left: &Value, right: &Value) -> Result<bool, VirtualMachineError> {
+    match op {
+        CompareOp::Eq | CompareOp::Is => Ok(left.equals(right)),
+        CompareOp::NotEq | CompareOp::IsNot => Ok(!left.equals(right)),
+        CompareOp::In | CompareOp::NotIn => {
+            let contained = match right {
+                Value::Tuple(elements) => elements.iter().any(|element| element.equals(left)),
+                Value::List(elements) => {
+                    elements.borrow().iter().any(|element| element.equals(left))
+                }
+                Value::Dict(items) => items.borrow().iter().any(|(key, _)| key.equals(left)),
+                Value::Range { start, stop, step } => match left.as_int() {
+                    Some(value) => {
+                        let in_bounds = if step.is_positive() {
+                            start <= &value && &value < stop
+                        } else {
+                            stop < &value && &value <= start
+                        };
+                        in_bounds && (value - start).mod_floor(step).is_zero()
+                    }
+                    None => false,
+                },
+                Value::Str(string) => match left {
+                    Value::Str(substring) => string.contains(substring.as_str()),
+                    _ => {
+                        return type_error(
+                            "'in <string>' requires string as left operand".to_string(),
+                        )
+                    }
+                },
+                _ => {
+                    return type_error(format!(
+                        "argument of type '{}' is not iterable",
+                        right.type_name()
+                    ))
+                }
+            };
+            Ok(contained == (op == CompareOp::In))
+        }
+        CompareOp::Lt | CompareOp::LtE | CompareOp::Gt | CompareOp::GtE => {
+            let ordering = match (left.as_int(), right.as_int()) {
+                (Some(a), Some(b)) => a.cmp(&b),
+                _ => match (left.as_address(), right.as_address()) {
+                    (Some(a), Some(b)) if a.segment_index == b.segment_index => {
+                        a.offset.cmp(&b.offset)
+                    }
+                    _ => {
+                        return type_error(format!(
+                            "cannot compare '{}' and '{}'",
+                            left.type_name(),
+                            right.type_name()
+                        ))
+                    }
+                },
+            };
+            Ok(match op {
+                CompareOp::Lt => ordering.is_lt(),
+                CompareOp::LtE => ordering.is_le(),
+                CompareOp::Gt => ordering.is_gt(),
+                _ => ordering.is_ge(),
+            })
+        }
+    }
+}
+
+// Number of elements of range(start, stop, step), step is never zero
+fn range_len(start: &BigInt, stop: &BigInt, step: &BigInt) -> BigInt {
+    let (distance, step) = if step.is_positive() {
+        (stop - start, step.clone())
+    } else {
+        (start - stop, -step)
+    };
+    if distance.is_positive() {
+        (distance + &step - 1).div_floor(&step)
+    } else {
+        BigInt::zero()
+    }
+}
+
+fn unsupported_operands<T>(
+    op: BinaryOp,
+    left: &Value,
+    right: &Value,
+) -> Result<T, VirtualMachineError> {
+    type_error(format!(
+        "unsupported operand types for {:?}: '{}' and '{}'",
+        op,
+        left.type_name(),
+        right.type_name()
+    ))
+}
+
+fn relocatable_add(
+    addr: &Relocatable,
+    offset: &BigInt,
+) -> Result<Relocatable, VirtualMachineError> {
+    let offset = (BigInt::from(addr.offset) + offset)
+        .to_usize()
+        .ok_or_else(|| PythonHintError::TypeError("relocatable offset out of range".to_string()))?;
+    Ok(Relocatable {
+        segment_index: addr.segment_index,
+        offset,
+    })
+}
+
+fn int_args(name: &str, args: &[Value]) -> Result<Vec<BigInt>, VirtualMachineError> {
+    args.iter()
+        .map(|arg| {
+            arg.as_int().ok_or_else(|| {
+                PythonHintError::TypeError(format!(
+                    "{}() expects integer arguments, got '{}'",
+                    name,
+                    arg.type_name()
+                ))
+                .into()
+            })
+        })
+        .collect()
+}
+
+// Returns the position in a sequence of length len given by a (possibly negative) index
+fn sequence_index(len: usize, index: &Value) -> Result<usize, VirtualMachineError> {
+    let index = index.as_int().ok_or_else(|| {
+        PythonHintError::TypeError(format!(
+            "indices must be integers, not '{}'",
+            index.type_name()
+        ))
+    })?;
+    let position = if index.is_negative() {
+        index + BigInt::from(len)
+    } else {
+        index
+    };
+    match bigint_to_usize(&position) {
+        Ok(position) if position < len => Ok(position),
+        _ => Err(PythonHintError::IndexError("index out of range".to_string()).into()),
+    }
+}
+
+fn dict_insert(dict: &mut Vec<(Value, Value)>, key: Value, value: Value) {
+    match dict.iter_mut().find(|(item_key, _)| item_key.equals(&key)) {
+        Some(item) => item.1 = value,
+        None => dict.push((key, value)),
+    }
+}
+
+// Converts a value into the representation stored in the execution scopes, which uses the types
+// the builtin hints expect for integers and relocatables
+fn store_value(value: Value) -> Box<dyn Any> {
+    match value {
+        Value::Int(value) => Box::new(value),
+        Value::Bool(value) => Box::new(value),
+        Value::Relocatable(addr) => Box::new(addr),
+        value => Box::new(value),
+    }
+}
+
+fn load_value(name: &str, value: &dyn Any) -> Result<Value, VirtualMachineError> {
+    if let Some(value) = value.downcast_ref::<Value>() {
+        Ok(value.clone())
+    } else if let Some(value) = value.downcast_ref::<BigInt>() {
+        Ok(Value::Int(value.clone()))
+    } else if let Some(value) = value.downcast_ref::<usize>() {
+        Ok(Value::Int(BigInt::from(*value)))
+    } else if let Some(value) = value.downcast_ref::<bool>() {
+        Ok(Value::Bool(*value))
+    } else if let Some(addr) = value.downcast_ref::<Relocatable>() {
+        Ok(Value::Relocatable(addr.clone()))
+    } else if let Some(value) = value.downcast_ref::<MaybeRelocatable>() {
+        Ok(value.clone().into())
+    } else if let Some(values) = value.downcast_ref::<Vec<BigInt>>() {
+        Ok(Value::List(Rc::new(RefCell::new(
+            values.iter().cloned().map(Value::Int).collect(),
+        ))))
+    } else {
+        type_error(format!(
+            "scope variable {} has a type unsupported by the interpreter",
+            name
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bigint;
+    use crate::relocatable;
+
+    fn int(value: i32) -> Value {
+        Value::Int(bigint!(value))
+    }
+
+    #[test]
+    fn int_binary_ops_use_floor_semantics() {
+        assert_eq!(
+            int_binary_op(BinaryOp::FloorDiv, bigint!(-7), bigint!(2)),
+            Ok(bigint!(-4))
+        );
+        assert_eq!(
+            int_binary_op(BinaryOp::Mod, bigint!(-7), bigint!(2)),
+            Ok(bigint!(1))
+        );
+        assert_eq!(
+            int_binary_op(BinaryOp::Mod, bigint!(7), bigint!(0)),
+            Err(VirtualMachineError::PythonHint(
+                PythonHintError::ZeroDivision
+            ))
+        );
+        assert_eq!(
+            int_binary_op(BinaryOp::Div, bigint!(7), bigint!(2)),
+            Err(VirtualMachineError::PythonHint(
+                PythonHintError::Unsupported(
+                    "true division, use // or div_mod instead".to_string()
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn int_binary_ops_cap_result_sizes() {
+        assert_eq!(
+            int_binary_op(BinaryOp::Pow, bigint!(-1), bigint!(1_000_000_001)),
+            Ok(bigint!(-1))
+        );
+        assert_eq!(
+            int_binary_op(BinaryOp::Pow, bigint!(2), bigint!(1_000_000_000)),
+            Err(VirtualMachineError::PythonHint(
+                PythonHintError::IntegerTooLarge("**", MAX_INT_BITS)
+            ))
+        );
+        assert_eq!(
+            int_binary_op(BinaryOp::Shl, bigint!(0), bigint!(1_000_000_000)),
+            Ok(bigint!(0))
+        );
+        assert_eq!(
+            int_binary_op(BinaryOp::Shl, bigint!(1), bigint!(1_000_000_000)),
+            Err(VirtualMachineError::PythonHint(
+                PythonHintError::IntegerTooLarge("<<", MAX_INT_BITS)
+            ))
+        );
+        let large = bigint!(1) << 40_000_usize;
+        assert_eq!(
+            int_binary_op(BinaryOp::Mul, large.clone(), bigint!(0)),
+            Ok(bigint!(0))
+        );
+        assert_eq!(
+            int_binary_op(BinaryOp::Mul, large.clone(), large),
+            Err(VirtualMachineError::PythonHint(
+                PythonHintError::IntegerTooLarge("*", MAX_INT_BITS)
+            ))
+        );
+    }
+
+    #[test]
+    fn range_lengths() {
+        assert_eq!(
+            range_len(&bigint!(0), &bigint!(10), &bigint!(3)),
+            bigint!(4)
+        );
+        assert_eq!(
+            range_len(&bigint!(10), &bigint!(0), &bigint!(-3)),
+            bigint!(4)
+        );
+        assert_eq!(range_len(&bigint!(5), &bigint!(0), &bigint!(1)), bigint!(0));
+    }
+
+    #[test]
+    fn compare_values() {
+        let addr = Value::Relocatable(relocatable!(1, 2));
+        assert_eq!(
+            compare(CompareOp::Eq, &Value::Bool(true), &int(1)),
+            Ok(true)
+        );
+        assert_eq!(
+            compare(
+                CompareOp::Lt,
+                &addr,
+                &Value::Relocatable(relocatable!(1, 3))
+            ),
+            Ok(true)
+        );
+        assert_eq!(
+            compare(CompareOp::In, &int(2), &Value::Tuple(vec![int(1), int(2)])),
+            Ok(true)
+        );
+        assert_eq!(
+            compare(
+                CompareOp::Lt,
+                &addr,
+                &Value::Relocatable(relocatable!(2, 3))
+            ),
+            Err(VirtualMachineError::PythonHint(PythonHintError::TypeError(
+                "cannot compare 'RelocatableValue' and 'RelocatableValue'".to_string()
+            )))
+        );
+    }
+
+    #[test]
+    fn sequence_indices() {
+        assert_eq!(sequence_index(3, &int(-1)), Ok(2));
+        assert_eq!(
+            sequence_index(3, &int(3)),
+            Err(VirtualMachineError::PythonHint(
+                PythonHintError::IndexError("index out of range".to_string())
+            ))
+        );
+    }
+
+    #[test]
+    fn relocatable_offsets() {
+        assert_eq!(
+            relocatable_add(&relocatable!(1, 2), &bigint!(-2)),
+            Ok(relocatable!(1, 0))
+        );
+        assert_eq!(
+            relocatable_add(&relocatable!(1, 2), &bigint!(-3)),
+            Err(VirtualMachineError::PythonHint(PythonHintError::TypeError(
+                "relocatable offset out of range".to_string()
+            )))
+        );
+    }
+}
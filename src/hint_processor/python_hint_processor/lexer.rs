@@ -0,0 +1,295 @@
+use crate::vm::errors::python_hint_errors::PythonHintError;
+use num_bigint::BigInt;
+use num_traits::Num;
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Token {
+    Name(String),
+    Int(BigInt),
+    Str(String),
+    Op(&'static str),
+    Newline,
+    Indent,
+    Dedent,
+    EndOfFile,
+}
+
+// Sorted so that longer operators are matched first
+const OPERATORS: &[&str] = &[
+    "**=", "//=", ">>=", "<<=", "**", "//", "==", "!=", "<=", ">=", "<<", ">>", "+=", "-=", "*=",
+    "%=", "&=", "|=", "^=", "->", "+", "-", "*", "/", "%", "<", ">", "=", "(", ")", "[", "]", "{",
+    "}", ",", ":", ".", ";", "&", "|", "^", "~",
+];
+
+/// Splits a block of hint code into tokens, each one paired with the line it was found in.
+/// Indentation changes are reported with `Indent` and `Dedent` tokens, as Python does.
+pub(crate) fn tokenize(code: &str) -> Result<Vec<(Token, usize)>, PythonHintError> {
+    let chars: Vec<char> = code.chars().collect();
+    let mut tokens = Vec::new();
+    let mut indent_stack = vec![0_usize];
+    let mut nesting_level = 0_usize;
+    let mut line = 1;
+    let mut at_line_start = true;
+    let mut i = 0;
+
+    while i < chars.len() {
+        if at_line_start {
+            let mut indent = 0;
+            while i < chars.len() && (chars[i] == ' ' || chars[i] == '\t') {
+                indent += if chars[i] == '\t' { 8 - indent % 8 } else { 1 };
+                i += 1;
+            }
+            // Blank and comment-only lines don't affect indentation
+            if i >= chars.len() || chars[i] == '\n' || chars[i] == '#' || chars[i] == '\r' {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+                i += 1;
+                line += 1;
+                continue;
+            }
+            at_line_start = false;
+            let current_indent = *indent_stack.last().unwrap_or(&0);
+            if indent > current_indent {
+                indent_stack.push(indent);
+                tokens.push((Token::Indent, line));
+            }
+            while indent < *indent_stack.last().unwrap_or(&0) {
+                indent_stack.pop();
+                tokens.push((Token::Dedent, line));
+            }
+            if indent != *indent_stack.last().unwrap_or(&0) {
+                return Err(PythonHintError::Syntax(
+                    line,
+                    "unindent does not match any outer indentation level".to_string(),
+                ));
+            }
+        }
+
+        let c = chars[i];
+        match c {
+            '\n' => {
+                if nesting_level == 0 {
+                    tokens.push((Token::Newline, line));
+                    at_line_start = true;
+                }
+                line += 1;
+                i += 1;
+            }
+            ' ' | '\t' | '\r' => i += 1,
+            '#' => {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+            }
+            '\\' if chars.get(i + 1) == Some(&'\n') => {
+                line += 1;
+                i += 2;
+            }
+            '0'..='9' => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                let literal: String = chars[start..i].iter().filter(|c| **c != '_').collect();
+                tokens.push((Token::Int(parse_int(&literal, line)?), line));
+            }
+            '\'' | '"' => {
+                let (string, end) = parse_string(&chars, i, line)?;
+                tokens.push((Token::Str(string), line));
+                i = end;
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                let name: String = chars[start..i].iter().collect();
+                if matches!(chars.get(i), Some('\'') | Some('"')) {
+                    return Err(PythonHintError::Unsupported(format!(
+                        "string prefix {}",
+                        name
+                    )));
+                }
+                tokens.push((Token::Name(name), line));
+            }
+            _ => {
+                let operator = OPERATORS
+                    .iter()
+                    .find(|operator| {
+                        operator
+                            .chars()
+                            .enumerate()
+                            .all(|(offset, c)| chars.get(i + offset) == Some(&c))
+                    })
+                    .ok_or_else(|| {
+                        PythonHintError::Syntax(line, format!("unexpected character {:?}", c))
+                    })?;
+                match *operator {
+                    "(" | "[" | "{" => nesting_level += 1,
+                    ")" | "]" | "}" => nesting_level = nesting_level.saturating_sub(1),
+                    _ => (),
+                }
+                tokens.push((Token::Op(operator), line));
+                i += operator.len();
+            }
+        }
+    }
+
+    if !at_line_start {
+        tokens.push((Token::Newline, line));
+    }
+    for _ in 1..indent_stack.len() {
+        tokens.push((Token::Dedent, line));
+    }
+    tokens.push((Token::EndOfFile, line));
+    Ok(tokens)
+}
+
+fn parse_int(literal: &str, line: usize) -> Result<BigInt, PythonHintError> {
+    let lowercase = literal.to_lowercase();
+    let (digits, radix) = if let Some(digits) = lowercase.strip_prefix("0x") {
+        (digits, 16)
+    } else if let Some(digits) = lowercase.strip_prefix("0o") {
+        (digits, 8)
+    } else if let Some(digits) = lowercase.strip_prefix("0b") {
+        (digits, 2)
+    } else {
+        (lowercase.as_str(), 10)
+    };
+    BigInt::from_str_radix(digits, radix)
+        .map_err(|_| PythonHintError::Syntax(line, format!("invalid number {}", literal)))
+}
+
+// Parses the string literal starting at chars[start], returning its value and the index
+// following its closing quote
+fn parse_string(
+    chars: &[char],
+    start: usize,
+    line: usize,
+) -> Result<(String, usize), PythonHintError> {
+    let quote = chars[start];
+    let mut string = String::new();
+    let mut i = start + 1;
+    loop {
+        match chars.get(i) {
+            None | Some('\n') => {
+                return Err(PythonHintError::Syntax(
+                    line,
+                    "unterminated string literal".to_string(),
+                ))
+            }
+            Some('\\') => {
+                let escaped = match chars.get(i + 1) {
+                    Some('n') => '\n',
+                    Some('t') => '\t',
+                    Some('\\') => '\\',
+                    Some('\'') => '\'',
+                    Some('"') => '"',
+                    _ => {
+                        return Err(PythonHintError::Unsupported(
+                            "escape sequence in string literal".to_string(),
+                        ))
+                    }
+                };
+                string.push(escaped);
+                i += 2;
+            }
+            Some(c) if *c == quote => return Ok((string, i + 1)),
+            Some(c) => {
+                string.push(*c);
+                i += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bigint;
+
+    fn tokens(code: &str) -> Vec<Token> {
+        tokenize(code)
+            .unwrap()
+            .into_iter()
+            .map(|(token, _)| token)
+            .collect()
+    }
+
+    #[test]
+    fn tokenize_assignment() {
+        assert_eq!(
+            tokens("ids.a = 0x10 // 2"),
+            vec![
+                Token::Name("ids".to_string()),
+                Token::Op("."),
+                Token::Name("a".to_string()),
+                Token::Op("="),
+                Token::Int(bigint!(16)),
+                Token::Op("//"),
+                Token::Int(bigint!(2)),
+                Token::Newline,
+                Token::EndOfFile
+            ]
+        );
+    }
+
+    #[test]
+    fn tokenize_indented_block() {
+        assert_eq!(
+            tokens("if x:\n    # comment\n\n    y = 'a'\nz = (1,\n 2)"),
+            vec![
+                Token::Name("if".to_string()),
+                Token::Name("x".to_string()),
+                Token::Op(":"),
+                Token::Newline,
+                Token::Indent,
+                Token::Name("y".to_string()),
+                Token::Op("="),
+                Token::Str("a".to_string()),
+                Token::Newline,
+                Token::Dedent,
+                Token::Name("z".to_string()),
+                Token::Op("="),
+                Token::Op("("),
+                Token::Int(bigint!(1)),
+                Token::Op(","),
+                Token::Int(bigint!(2)),
+                Token::Op(")"),
+                Token::Newline,
+                Token::EndOfFile
+            ]
+        );
+    }
+
+    #[test]
+    fn tokenize_inconsistent_dedent() {
+        assert_eq!(
+            tokenize("if x:\n    y = 1\n  z = 2"),
+            Err(PythonHintError::Syntax(
+                3,
+                "unindent does not match any outer indentation level".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn tokenize_unterminated_string() {
+        assert_eq!(
+            tokenize("x = 'abc"),
+            Err(PythonHintError::Syntax(
+                1,
+                "unterminated string literal".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn tokenize_f_string() {
+        assert_eq!(
+            tokenize("print(f'{x}')"),
+            Err(PythonHintError::Unsupported("string prefix f".to_string()))
+        );
+    }
+}
@@ -0,0 +1,4 @@
+pub(crate) mod interpreter;
+mod lexer;
+mod parser;
+pub mod python_hint_processor_definition;
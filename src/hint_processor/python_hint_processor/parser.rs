@@ -0,0 +1,827 @@
+use super::lexer::{tokenize, Token};
+use crate::vm::errors::python_hint_errors::PythonHintError;
+use num_bigint::BigInt;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    FloorDiv,
+    Mod,
+    Pow,
+    BitAnd,
+    BitOr,
+    BitXor,
+    Shl,
+    Shr,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum UnaryOp {
+    Neg,
+    Pos,
+    Not,
+    Invert,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum CompareOp {
+    Eq,
+    NotEq,
+    Lt,
+    LtE,
+    Gt,
+    GtE,
+    In,
+    NotIn,
+    Is,
+    IsNot,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Expr {
+    Int(BigInt),
+    Str(String),
+    Bool(bool),
+    None,
+    Name(String),
+    Attribute(Box<Expr>, String),
+    Subscript(Box<Expr>, Box<Expr>),
+    Call(Box<Expr>, Vec<Expr>, Vec<(String, Expr)>),
+    Unary(UnaryOp, Box<Expr>),
+    Binary(BinaryOp, Box<Expr>, Box<Expr>),
+    Compare(Box<Expr>, Vec<(CompareOp, Expr)>),
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+    IfElse(Box<Expr>, Box<Expr>, Box<Expr>),
+    Tuple(Vec<Expr>),
+    List(Vec<Expr>),
+    Dict(Vec<(Expr, Expr)>),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Stmt {
+    Expr(Expr),
+    Assign(Vec<Expr>, Expr),
+    AugAssign(Expr, BinaryOp, Expr),
+    If(Vec<(Expr, Vec<Stmt>)>, Vec<Stmt>),
+    While(Expr, Vec<Stmt>),
+    For(Expr, Expr, Vec<Stmt>),
+    Assert(Expr, Option<Expr>),
+    Import(Vec<String>),
+    Break,
+    Continue,
+    Pass,
+}
+
+const KEYWORDS: [&str; 23] = [
+    "and", "as", "assert", "break", "class", "continue", "def", "del", "elif", "else", "for",
+    "from", "if", "import", "in", "is", "lambda", "not", "or", "pass", "return", "while", "with",
+];
+
+/// Parses a block of hint code into its statements.
+pub(crate) fn parse(code: &str) -> Result<Vec<Stmt>, PythonHintError> {
+    let mut parser = Parser {
+        tokens: tokenize(code)?,
+        position: 0,
+        loop_depth: 0,
+    };
+    let mut statements = Vec::new();
+    while parser.peek() != &Token::EndOfFile {
+        statements.extend(parser.parse_statement()?);
+    }
+    Ok(statements)
+}
+
+// Positional and keyword arguments of a call
+type CallArguments = (Vec<Expr>, Vec<(String, Expr)>);
+
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    position: usize,
+    // Number of loops enclosing the statement being parsed
+    loop_depth: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> &Token {
+        // The token list always ends with EndOfFile, which is never consumed
+        &self.tokens[self.position].0
+    }
+
+    fn line(&self) -> usize {
+        self.tokens[self.position].1
+    }
+
+    fn advance(&mut self) -> Token {
+        let token = self.tokens[self.position].0.clone();
+        if token != Token::EndOfFile {
+            self.position += 1;
+        }
+        token
+    }
+
+    fn syntax_error<T>(&self, message: &str) -> Result<T, PythonHintError> {
+        Err(PythonHintError::Syntax(
+            self.line(),
+            format!("{}, found {:?}", message, self.peek()),
+        ))
+    }
+
+    fn is_op(&self, op: &str) -> bool {
+        matches!(self.peek(), Token::Op(found) if *found == op)
+    }
+
+    fn is_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Token::Name(found) if found == keyword)
+    }
+
+    fn eat_op(&mut self, op: &str) -> bool {
+        if self.is_op(op) {
+            self.advance();
+            return true;
+        }
+        false
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        if self.is_keyword(keyword) {
+            self.advance();
+            return true;
+        }
+        false
+    }
+
+    fn expect_op(&mut self, op: &str) -> Result<(), PythonHintError> {
+        if !self.eat_op(op) {
+            return self.syntax_error(&format!("expected '{}'", op));
+        }
+        Ok(())
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> Result<(), PythonHintError> {
+        if !self.eat_keyword(keyword) {
+            return self.syntax_error(&format!("expected '{}'", keyword));
+        }
+        Ok(())
+    }
+
+    fn expect_name(&mut self) -> Result<String, PythonHintError> {
+        match self.peek() {
+            Token::Name(name) if !KEYWORDS.contains(&name.as_str()) => {
+                let name = name.clone();
+                self.advance();
+                Ok(name)
+            }
+            _ => self.syntax_error("expected a name"),
+        }
+    }
+
+    fn expect_newline(&mut self) -> Result<(), PythonHintError> {
+        match self.peek() {
+            Token::Newline => {
+                self.advance();
+                Ok(())
+            }
+            Token::EndOfFile => Ok(()),
+            _ => self.syntax_error("expected end of line"),
+        }
+    }
+
+    fn parse_statement(&mut self) -> Result<Vec<Stmt>, PythonHintError> {
+        if self.eat_keyword("if") {
+            return Ok(vec![self.parse_if()?]);
+        }
+        if self.eat_keyword("while") {
+            let condition = self.parse_expression()?;
+            let body = self.parse_loop_body()?;
+            return Ok(vec![Stmt::While(condition, body)]);
+        }
+        if self.eat_keyword("for") {
+            let target = self.parse_target_list()?;
+            self.expect_keyword("in")?;
+            let iterable = self.parse_expression_list()?;
+            let body = self.parse_loop_body()?;
+            return Ok(vec![Stmt::For(target, iterable, body)]);
+        }
+        if let Token::Name(name) = self.peek() {
+            if ["def", "class", "with", "lambda", "return", "del"].contains(&name.as_str()) {
+                return Err(PythonHintError::Unsupported(format!(
+                    "'{}' statement",
+                    name
+                )));
+            }
+        }
+        self.parse_simple_statements()
+    }
+
+    // Parses the statements following an if, after the if keyword itself
+    fn parse_if(&mut self) -> Result<Stmt, PythonHintError> {
+        let mut branches = Vec::new();
+        loop {
+            let condition = self.parse_expression()?;
+            let body = self.parse_suite()?;
+            branches.push((condition, body));
+            if !self.eat_keyword("elif") {
+                break;
+            }
+        }
+        let orelse = if self.eat_keyword("else") {
+            self.parse_suite()?
+        } else {
+            Vec::new()
+        };
+        Ok(Stmt::If(branches, orelse))
+    }
+
+    fn parse_loop_body(&mut self) -> Result<Vec<Stmt>, PythonHintError> {
+        self.loop_depth += 1;
+        let body = self.parse_suite();
+        self.loop_depth -= 1;
+        body
+    }
+
+    // Parses the ':' and body of a compound statement, either an indented block or statements
+    // in the same line
+    fn parse_suite(&mut self) -> Result<Vec<Stmt>, PythonHintError> {
+        self.expect_op(":")?;
+        if self.peek() != &Token::Newline {
+            return self.parse_simple_statements();
+        }
+        self.advance();
+        if self.advance() != Token::Indent {
+            return Err(PythonHintError::Syntax(
+                self.line(),
+                "expected an indented block".to_string(),
+            ));
+        }
+        let mut body = Vec::new();
+        while !matches!(self.peek(), Token::Dedent | Token::EndOfFile) {
+            body.extend(self.parse_statement()?);
+        }
+        self.advance();
+        Ok(body)
+    }
+
+    fn parse_simple_statements(&mut self) -> Result<Vec<Stmt>, PythonHintError> {
+        let mut statements = vec![self.parse_simple_statement()?];
+        while self.eat_op(";") {
+            if matches!(self.peek(), Token::Newline | Token::EndOfFile) {
+                break;
+            }
+            statements.push(self.parse_simple_statement()?);
+        }
+        self.expect_newline()?;
+        Ok(statements)
+    }
+
+    fn parse_simple_statement(&mut self) -> Result<Stmt, PythonHintError> {
+        if self.eat_keyword("pass") {
+            return Ok(Stmt::Pass);
+        }
+        if self.is_keyword("break") || self.is_keyword("continue") {
+            if self.loop_depth == 0 {
+                return self.syntax_error("'break' and 'continue' must be inside a loop");
+            }
+            return Ok(if self.advance() == Token::Name("break".to_string()) {
+                Stmt::Break
+            } else {
+                Stmt::Continue
+            });
+        }
+        if self.eat_keyword("assert") {
+            let condition = self.parse_expression()?;
+            let message = if self.eat_op(",") {
+                Some(self.parse_expression()?)
+            } else {
+                None
+            };
+            return Ok(Stmt::Assert(condition, message));
+        }
+        if self.eat_keyword("from") {
+            // Only the imported names matter, the interpreter checks they are available
+            self.expect_name()?;
+            while self.eat_op(".") {
+                self.expect_name()?;
+            }
+            self.expect_keyword("import")?;
+            let parenthesized = self.eat_op("(");
+            let mut names = vec![self.expect_name()?];
+            while self.eat_op(",") {
+                if parenthesized && self.is_op(")") {
+                    break;
+                }
+                names.push(self.expect_name()?);
+            }
+            if parenthesized {
+                self.expect_op(")")?;
+            }
+            return Ok(Stmt::Import(names));
+        }
+        if self.is_keyword("import") {
+            return Err(PythonHintError::Unsupported(
+                "'import' statement".to_string(),
+            ));
+        }
+
+        let expression = self.parse_expression_list()?;
+        if self.is_op("=") {
+            let mut targets = vec![expression];
+            while self.eat_op("=") {
+                targets.push(self.parse_expression_list()?);
+            }
+            // The last expression of the chain is the assigned value
+            let value = targets.pop().unwrap_or(Expr::None);
+            for target in targets.iter() {
+                check_target(target, self.line())?;
+            }
+            return Ok(Stmt::Assign(targets, value));
+        }
+        if let Token::Op(op) = self.peek() {
+            let op = match *op {
+                "+=" => Some(BinaryOp::Add),
+                "-=" => Some(BinaryOp::Sub),
+                "*=" => Some(BinaryOp::Mul),
+                "//=" => Some(BinaryOp::FloorDiv),
+                "%=" => Some(BinaryOp::Mod),
+                "**=" => Some(BinaryOp::Pow),
+                "&=" => Some(BinaryOp::BitAnd),
+                "|=" => Some(BinaryOp::BitOr),
+                "^=" => Some(BinaryOp::BitXor),
+                "<<=" => Some(BinaryOp::Shl),
+                ">>=" => Some(BinaryOp::Shr),
+                _ => None,
+            };
+            if let Some(op) = op {
+                self.advance();
+                check_target(&expression, self.line())?;
+                let value = self.parse_expression_list()?;
+                return Ok(Stmt::AugAssign(expression, op, value));
+            }
+        }
+        Ok(Stmt::Expr(expression))
+    }
+
+    // Parses the target of a for loop, stopping before the 'in' keyword
+    fn parse_target_list(&mut self) -> Result<Expr, PythonHintError> {
+        let mut targets = vec![self.parse_or_operand()?];
+        let mut is_tuple = false;
+        while self.eat_op(",") {
+            is_tuple = true;
+            if self.is_keyword("in") {
+                break;
+            }
+            targets.push(self.parse_or_operand()?);
+        }
+        let target = if is_tuple {
+            Expr::Tuple(targets)
+        } else {
+            targets.remove(0)
+        };
+        check_target(&target, self.line())?;
+        Ok(target)
+    }
+
+    // Parses a comma separated list of expressions, which builds a tuple if there is more
+    // than one of them or a trailing comma
+    fn parse_expression_list(&mut self) -> Result<Expr, PythonHintError> {
+        let first = self.parse_expression()?;
+        if !self.is_op(",") {
+            return Ok(first);
+        }
+        let mut elements = vec![first];
+        while self.eat_op(",") {
+            if self.ends_expression_list() {
+                break;
+            }
+            elements.push(self.parse_expression()?);
+        }
+        Ok(Expr::Tuple(elements))
+    }
+
+    fn ends_expression_list(&self) -> bool {
+        matches!(self.peek(), Token::Newline | Token::EndOfFile)
+            || [")", "]", "}", "=", ";", ":"]
+                .iter()
+                .any(|op| self.is_op(op))
+    }
+
+    fn parse_expression(&mut self) -> Result<Expr, PythonHintError> {
+        let expression = self.parse_or()?;
+        if self.eat_keyword("if") {
+            let condition = self.parse_or()?;
+            self.expect_keyword("else")?;
+            let orelse = self.parse_expression()?;
+            return Ok(Expr::IfElse(
+                Box::new(condition),
+                Box::new(expression),
+                Box::new(orelse),
+            ));
+        }
+        Ok(expression)
+    }
+
+    fn parse_or(&mut self) -> Result<Expr, PythonHintError> {
+        let mut expression = self.parse_and()?;
+        while self.eat_keyword("or") {
+            expression = Expr::Or(Box::new(expression), Box::new(self.parse_and()?));
+        }
+        Ok(expression)
+    }
+
+    fn parse_and(&mut self) -> Result<Expr, PythonHintError> {
+        let mut expression = self.parse_not()?;
+        while self.eat_keyword("and") {
+            expression = Expr::And(Box::new(expression), Box::new(self.parse_not()?));
+        }
+        Ok(expression)
+    }
+
+    fn parse_not(&mut self) -> Result<Expr, PythonHintError> {
+        if self.eat_keyword("not") {
+            return Ok(Expr::Unary(UnaryOp::Not, Box::new(self.parse_not()?)));
+        }
+        self.parse_comparison()
+    }
+
+    fn parse_comparison(&mut self) -> Result<Expr, PythonHintError> {
+        let left = self.parse_or_operand()?;
+        let mut comparisons = Vec::new();
+        loop {
+            let op = match self.peek() {
+                Token::Op("==") => CompareOp::Eq,
+                Token::Op("!=") => CompareOp::NotEq,
+                Token::Op("<") => CompareOp::Lt,
+                Token::Op("<=") => CompareOp::LtE,
+                Token::Op(">") => CompareOp::Gt,
+                Token::Op(">=") => CompareOp::GtE,
+                Token::Name(name) if name == "in" => CompareOp::In,
+                Token::Name(name) if name == "is" => {
+                    self.advance();
+                    let op = if self.eat_keyword("not") {
+                        CompareOp::IsNot
+                    } else {
+                        CompareOp::Is
+                    };
+                    comparisons.push((op, self.parse_or_operand()?));
+                    continue;
+                }
+                Token::Name(name) if name == "not" => {
+                    self.advance();
+                    self.expect_keyword("in")?;
+                    comparisons.push((CompareOp::NotIn, self.parse_or_operand()?));
+                    continue;
+                }
+                _ => break,
+            };
+            self.advance();
+            comparisons.push((op, self.parse_or_operand()?));
+        }
+        if comparisons.is_empty() {
+            return Ok(left);
+        }
+        Ok(Expr::Compare(Box::new(left), comparisons))
+    }
+
+    // Parses the operands of comparisons, from bitwise or down to the primary expressions
+    fn parse_or_operand(&mut self) -> Result<Expr, PythonHintError> {
+        self.parse_binary(0)
+    }
+
+    fn parse_binary(&mut self, level: usize) -> Result<Expr, PythonHintError> {
+        const LEVELS: [&[(&str, BinaryOp)]; 6] = [
+            &[("|", BinaryOp::BitOr)],
+            &[("^", BinaryOp::BitXor)],
+            &[("&", BinaryOp::BitAnd)],
+            &[("<<", BinaryOp::Shl), (">>", BinaryOp::Shr)],
+            &[("+", BinaryOp::Add), ("-", BinaryOp::Sub)],
+            &[
+                ("*", BinaryOp::Mul),
+                ("/", BinaryOp::Div),
+                ("//", BinaryOp::FloorDiv),
+                ("%", BinaryOp::Mod),
+            ],
+        ];
+        if level == LEVELS.len() {
+            return self.parse_unary();
+        }
+        let mut expression = self.parse_binary(level + 1)?;
+        'operators: loop {
+            for (op_str, op) in LEVELS[level] {
+                if self.eat_op(op_str) {
+                    let right = self.parse_binary(level + 1)?;
+                    expression = Expr::Binary(*op, Box::new(expression), Box::new(right));
+                    continue 'operators;
+                }
+            }
+            break;
+        }
+        Ok(expression)
+    }
+
+    fn parse_unary(&mut self) -> Result<Expr, PythonHintError> {
+        let op = if self.eat_op("-") {
+            UnaryOp::Neg
+        } else if self.eat_op("+") {
+            UnaryOp::Pos
+        } else if self.eat_op("~") {
+            UnaryOp::Invert
+        } else {
+            return self.parse_power();
+        };
+        Ok(Expr::Unary(op, Box::new(self.parse_unary()?)))
+    }
+
+    fn parse_power(&mut self) -> Result<Expr, PythonHintError> {
+        let base = self.parse_primary()?;
+        if self.eat_op("**") {
+            let exponent = self.parse_unary()?;
+            return Ok(Expr::Binary(
+                BinaryOp::Pow,
+                Box::new(base),
+                Box::new(exponent),
+            ));
+        }
+        Ok(base)
+    }
+
+    fn parse_primary(&mut self) -> Result<Expr, PythonHintError> {
+        let mut expression = self.parse_atom()?;
+        loop {
+            if self.eat_op(".") {
+                expression = Expr::Attribute(Box::new(expression), self.expect_name()?);
+            } else if self.eat_op("[") {
+                let index = self.parse_expression_list()?;
+                self.expect_op("]")?;
+                expression = Expr::Subscript(Box::new(expression), Box::new(index));
+            } else if self.eat_op("(") {
+                let (args, kwargs) = self.parse_call_arguments()?;
+                expression = Expr::Call(Box::new(expression), args, kwargs);
+            } else {
+                return Ok(expression);
+            }
+        }
+    }
+
+    fn parse_call_arguments(&mut self) -> Result<CallArguments, PythonHintError> {
+        let mut args = Vec::new();
+        let mut kwargs = Vec::new();
+        while !self.eat_op(")") {
+            let is_keyword_argument = matches!(self.peek(), Token::Name(_))
+                && matches!(
+                    self.tokens.get(self.position + 1),
+                    Some((Token::Op("="), _))
+                );
+            if is_keyword_argument {
+                let name = self.expect_name()?;
+                self.expect_op("=")?;
+                kwargs.push((name, self.parse_expression()?));
+            } else if kwargs.is_empty() {
+                args.push(self.parse_expression()?);
+            } else {
+                return self.syntax_error("positional argument follows keyword argument");
+            }
+            if !self.eat_op(",") {
+                self.expect_op(")")?;
+                break;
+            }
+        }
+        Ok((args, kwargs))
+    }
+
+    fn parse_atom(&mut self) -> Result<Expr, PythonHintError> {
+        match self.peek().clone() {
+            Token::Int(value) => {
+                self.advance();
+                Ok(Expr::Int(value))
+            }
+            Token::Str(mut value) => {
+                self.advance();
+                // Adjacent string literals are concatenated
+                while let Token::Str(next) = self.peek() {
+                    value.push_str(next);
+                    self.advance();
+                }
+                Ok(Expr::Str(value))
+            }
+            Token::Name(name) => match name.as_str() {
+                "True" => {
+                    self.advance();
+                    Ok(Expr::Bool(true))
+                }
+                "False" => {
+                    self.advance();
+                    Ok(Expr::Bool(false))
+                }
+                "None" => {
+                    self.advance();
+                    Ok(Expr::None)
+                }
+                _ => Ok(Expr::Name(self.expect_name()?)),
+            },
+            Token::Op("(") => {
+                self.advance();
+                if self.eat_op(")") {
+                    return Ok(Expr::Tuple(Vec::new()));
+                }
+                let expression = self.parse_expression_list()?;
+                self.expect_op(")")?;
+                Ok(expression)
+            }
+            Token::Op("[") => {
+                self.advance();
+                let mut elements = Vec::new();
+                while !self.eat_op("]") {
+                    elements.push(self.parse_expression()?);
+                    if self.is_keyword("for") {
+                        return Err(PythonHintError::Unsupported(
+                            "list comprehension".to_string(),
+                        ));
+                    }
+                    if !self.eat_op(",") {
+                        self.expect_op("]")?;
+                        break;
+                    }
+                }
+                Ok(Expr::List(elements))
+            }
+            Token::Op("{") => {
+                self.advance();
+                let mut items = Vec::new();
+                while !self.eat_op("}") {
+                    let key = self.parse_expression()?;
+                    self.expect_op(":")?;
+                    items.push((key, self.parse_expression()?));
+                    if !self.eat_op(",") {
+                        self.expect_op("}")?;
+                        break;
+                    }
+                }
+                Ok(Expr::Dict(items))
+            }
+            _ => self.syntax_error("expected an expression"),
+        }
+    }
+}
+
+// Checks that an expression can be assigned to
+fn check_target(target: &Expr, line: usize) -> Result<(), PythonHintError> {
+    match target {
+        Expr::Name(_) | Expr::Attribute(_, _) | Expr::Subscript(_, _) => Ok(()),
+        Expr::Tuple(targets) | Expr::List(targets) => targets
+            .iter()
+            .try_for_each(|target| check_target(target, line)),
+        _ => Err(PythonHintError::Syntax(
+            line,
+            "cannot assign to expression".to_string(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bigint;
+
+    fn name(name: &str) -> Expr {
+        Expr::Name(name.to_string())
+    }
+
+    fn ids(member: &str) -> Expr {
+        Expr::Attribute(Box::new(name("ids")), member.to_string())
+    }
+
+    fn int(value: i32) -> Expr {
+        Expr::Int(bigint!(value))
+    }
+
+    #[test]
+    fn parse_operator_precedence() {
+        assert_eq!(
+            parse("x = -2 ** 2 + 3 * ids.a % 5"),
+            Ok(vec![Stmt::Assign(
+                vec![name("x")],
+                Expr::Binary(
+                    BinaryOp::Add,
+                    Box::new(Expr::Unary(
+                        UnaryOp::Neg,
+                        Box::new(Expr::Binary(
+                            BinaryOp::Pow,
+                            Box::new(int(2)),
+                            Box::new(int(2))
+                        ))
+                    )),
+                    Box::new(Expr::Binary(
+                        BinaryOp::Mod,
+                        Box::new(Expr::Binary(
+                            BinaryOp::Mul,
+                            Box::new(int(3)),
+                            Box::new(ids("a"))
+                        )),
+                        Box::new(int(5))
+                    ))
+                )
+            )])
+        );
+    }
+
+    #[test]
+    fn parse_tuple_assignment_and_call() {
+        assert_eq!(
+            parse("ids.q, ids.r = divmod(ids.a, 2)"),
+            Ok(vec![Stmt::Assign(
+                vec![Expr::Tuple(vec![ids("q"), ids("r")])],
+                Expr::Call(Box::new(name("divmod")), vec![ids("a"), int(2)], Vec::new())
+            )])
+        );
+    }
+
+    #[test]
+    fn parse_compound_statements() {
+        let code = "if x < 1 and not y:\n    z = 1\nelif x in l:\n    pass\nelse:\n    for i in range(3): z += i\nwhile True:\n    break";
+        assert_eq!(
+            parse(code),
+            Ok(vec![
+                Stmt::If(
+                    vec![
+                        (
+                            Expr::And(
+                                Box::new(Expr::Compare(
+                                    Box::new(name("x")),
+                                    vec![(CompareOp::Lt, int(1))]
+                                )),
+                                Box::new(Expr::Unary(UnaryOp::Not, Box::new(name("y"))))
+                            ),
+                            vec![Stmt::Assign(vec![name("z")], int(1))]
+                        ),
+                        (
+                            Expr::Compare(Box::new(name("x")), vec![(CompareOp::In, name("l"))]),
+                            vec![Stmt::Pass]
+                        )
+                    ],
+                    vec![Stmt::For(
+                        name("i"),
+                        Expr::Call(Box::new(name("range")), vec![int(3)], Vec::new()),
+                        vec![Stmt::AugAssign(name("z"), BinaryOp::Add, name("i"))]
+                    )]
+                ),
+                Stmt::While(Expr::Bool(true), vec![Stmt::Break])
+            ])
+        );
+    }
+
+    #[test]
+    fn parse_scope_and_memory_statements() {
+        assert_eq!(
+            parse("from starkware.python.math_utils import div_mod, isqrt\nvm_enter_scope({'n': ids.len})\nmemory[ap] = segments.add()"),
+            Ok(vec![
+                Stmt::Import(vec!["div_mod".to_string(), "isqrt".to_string()]),
+                Stmt::Expr(Expr::Call(
+                    Box::new(name("vm_enter_scope")),
+                    vec![Expr::Dict(vec![(Expr::Str("n".to_string()), ids("len"))])],
+                    Vec::new()
+                )),
+                Stmt::Assign(
+                    vec![Expr::Subscript(Box::new(name("memory")), Box::new(name("ap")))],
+                    Expr::Call(
+                        Box::new(Expr::Attribute(Box::new(name("segments")), "add".to_string())),
+                        Vec::new(),
+                        Vec::new()
+                    )
+                )
+            ])
+        );
+    }
+
+    #[test]
+    fn parse_invalid_target() {
+        assert_eq!(
+            parse("1 = x"),
+            Err(PythonHintError::Syntax(
+                1,
+                "cannot assign to expression".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_missing_indented_block() {
+        assert_eq!(
+            parse("if x:\ny = 1"),
+            Err(PythonHintError::Syntax(
+                2,
+                "expected an indented block".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_unsupported_statement() {
+        assert_eq!(
+            parse("def f():\n    pass"),
+            Err(PythonHintError::Unsupported("'def' statement".to_string()))
+        );
+    }
+}
@@ -0,0 +1,419 @@
+use super::interpreter::Interpreter;
+use super::parser::{parse, Stmt};
+use crate::any_box;
+use crate::hint_processor::builtin_hint_processor::builtin_hint_processor_definition::get_ids_data;
+use crate::hint_processor::hint_processor_definition::{HintProcessor, HintReference};
+use crate::serde::deserialize_program::ApTracking;
+use crate::types::exec_scope::ExecutionScopes;
+use crate::vm::errors::vm_errors::VirtualMachineError;
+use crate::vm::vm_core::VirtualMachine;
+use num_bigint::BigInt;
+use std::any::Any;
+use std::collections::HashMap;
+
+/// Hint processor interpreting hints written in a restricted subset of Python: assignments,
+/// integer arithmetic, `if`/`while`/`for` statements, `ids` and `memory` accesses, `segments`
+/// and scope management.
+/// It is meant to be used as the last processor of a `CompositeHintProcessor`, running the
+/// hints which are not implemented natively by the processors before it.
+#[derive(Default)]
+pub struct PythonHintProcessor {}
+
+// Hint data produced by compile_hint, holding the parsed hint code
+struct PythonHintData {
+    statements: Vec<Stmt>,
+    ids_data: HashMap<String, HintReference>,
+    ap_tracking: ApTracking,
+}
+
+impl PythonHintProcessor {
+    pub fn new() -> Self {
+        PythonHintProcessor {}
+    }
+}
+
+impl HintProcessor for PythonHintProcessor {
+    fn execute_hint(
+        &self,
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        hint_data: &Box<dyn Any>,
+        constants: &HashMap<String, BigInt>,
+    ) -> Result<(), VirtualMachineError> {
+        let hint_data = hint_data
+            .downcast_ref::<PythonHintData>()
+            .ok_or(VirtualMachineError::WrongHintData)?;
+        Interpreter::new(
+            vm,
+            exec_scopes,
+            &hint_data.ids_data,
+            &hint_data.ap_tracking,
+            constants,
+        )
+        .run(&hint_data.statements)
+    }
+
+    fn compile_hint(
+        &self,
+        hint_code: &str,
+        _accessible_scopes: &[String],
+        ap_tracking_data: &ApTracking,
+        reference_ids: &HashMap<String, usize>,
+        references: &HashMap<usize, HintReference>,
+    ) -> Result<Box<dyn Any>, VirtualMachineError> {
+        Ok(any_box!(PythonHintData {
+            statements: parse(hint_code)?,
+            ids_data: get_ids_data(reference_ids, references)?,
+            ap_tracking: ap_tracking_data.clone(),
+        }))
+    }
+
+    fn supports_hint(&self, hint_code: &str) -> bool {
+        parse(hint_code).is_ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bigint;
+    use crate::hint_processor::builtin_hint_processor::builtin_hint_processor_definition::BuiltinHintProcessor;
+    use crate::hint_processor::composite_hint_processor::CompositeHintProcessor;
+    use crate::relocatable;
+    use crate::serde::deserialize_program::Member;
+    use crate::types::relocatable::{MaybeRelocatable, Relocatable};
+    use crate::utils::test_utils::*;
+    use crate::vm::errors::memory_errors::MemoryError;
+    use crate::vm::errors::python_hint_errors::PythonHintError;
+    use crate::vm::vm_memory::memory::Memory;
+    use num_bigint::Sign;
+
+    // Compiles the hint with the ids variables ids_names, located at fp - n .. fp - 1
+    fn run_python_hint(
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        ids_names: &[&str],
+        hint_code: &str,
+    ) -> Result<(), VirtualMachineError> {
+        let reference_ids = ids_names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| (format!("__main__.{}", name), i))
+            .collect();
+        let references = references!(ids_names.len() as i32);
+        run_python_hint_with_references(vm, exec_scopes, reference_ids, references, hint_code)
+    }
+
+    fn run_python_hint_with_references(
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        reference_ids: HashMap<String, usize>,
+        references: HashMap<usize, HintReference>,
+        hint_code: &str,
+    ) -> Result<(), VirtualMachineError> {
+        let hint_processor = PythonHintProcessor::new();
+        let hint_data = hint_processor.compile_hint(
+            hint_code,
+            &[],
+            &ApTracking::new(),
+            &reference_ids,
+            &references,
+        )?;
+        let constants = HashMap::from([("__main__.SHIFT".to_string(), bigint!(1_u128 << 64))]);
+        hint_processor.execute_hint(vm, exec_scopes, &hint_data, &constants)
+    }
+
+    #[test]
+    fn run_arithmetic_on_ids() {
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), 17), ((1, 1), (-3))];
+        vm.set_fp(5);
+        let hint_code = "q, r = divmod(ids.a, ids.SHIFT // 2 ** 60)\nids.q = q\nids.r = r ** 2 - ids.b\nids.c = -1";
+
+        assert_eq!(
+            run_python_hint(
+                &mut vm,
+                exec_scopes_ref!(),
+                &["a", "b", "q", "r", "c"],
+                hint_code
+            ),
+            Ok(())
+        );
+        let prime = vm.get_prime().clone();
+        check_memory![
+            vm.memory,
+            ((1, 2), 1),
+            ((1, 3), 4),
+            ((1, 4), (prime - 1_u32))
+        ];
+    }
+
+    #[test]
+    fn run_memory_and_segments() {
+        let mut vm = vm!();
+        add_segments!(vm, 2);
+        let hint_code = "ptr = segments.add()\nfor i in range(3):\n    memory[ptr + i] = i * i\nmemory[ap] = ptr\nmemory[ap + 1] = segments.write_arg(ptr + 3, [memory[ptr + 2], ap])";
+
+        assert_eq!(
+            run_python_hint(&mut vm, exec_scopes_ref!(), &[], hint_code),
+            Ok(())
+        );
+        check_memory![
+            vm.memory,
+            ((2, 0), 0),
+            ((2, 1), 1),
+            ((2, 2), 4),
+            ((2, 3), 4),
+            ((2, 4), (1, 0)),
+            ((1, 0), (2, 0)),
+            ((1, 1), (2, 5))
+        ];
+    }
+
+    #[test]
+    fn run_loops_and_scopes() {
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), 10)];
+        vm.set_fp(1);
+        let mut exec_scopes = ExecutionScopes::new();
+        let hint_code = "vm_enter_scope({'n': ids.n, 'found': []})";
+        assert_eq!(
+            run_python_hint(&mut vm, &mut exec_scopes, &["n"], hint_code),
+            Ok(())
+        );
+        let hint_code = "i = 0\nwhile True:\n    i += 1\n    if i % 2 == 0:\n        continue\n    elif i > n:\n        break\n    found.append(i)\ntotal = sum(found)";
+        assert_eq!(
+            run_python_hint(&mut vm, &mut exec_scopes, &[], hint_code),
+            Ok(())
+        );
+        assert_eq!(exec_scopes.get::<BigInt>("total"), Ok(bigint!(25)));
+        assert_eq!(exec_scopes.get::<BigInt>("i"), Ok(bigint!(11)));
+        assert_eq!(
+            run_python_hint(&mut vm, &mut exec_scopes, &[], "vm_exit_scope()"),
+            Ok(())
+        );
+        assert_eq!(exec_scopes.data.len(), 1);
+    }
+
+    #[test]
+    fn run_struct_members() {
+        let mut vm = vm!();
+        vm.memory = memory![((1, 0), (1, 2)), ((1, 2), 3), ((1, 3), 5)];
+        vm.set_fp(1);
+        let mut reference = HintReference::new_simple(-1);
+        reference.cairo_type = Some("__main__.Pair*".to_string());
+        reference.members = Some(HashMap::from([
+            (
+                "low".to_string(),
+                Member {
+                    cairo_type: "felt".to_string(),
+                    offset: 0,
+                },
+            ),
+            (
+                "high".to_string(),
+                Member {
+                    cairo_type: "felt".to_string(),
+                    offset: 1,
+                },
+            ),
+        ]));
+        let hint_code = "assert ids.pair.address_ == ids.pair and ids.pair.low < ids.pair.high\nmemory[ids.pair + 2] = ids.pair.low + ids.pair.high";
+
+        assert_eq!(
+            run_python_hint_with_references(
+                &mut vm,
+                exec_scopes_ref!(),
+                HashMap::from([("__main__.pair".to_string(), 0)]),
+                HashMap::from([(0, reference)]),
+                hint_code
+            ),
+            Ok(())
+        );
+        check_memory![vm.memory, ((1, 4), 8)];
+    }
+
+    #[test]
+    fn run_failing_hints() {
+        let mut vm = vm!();
+        add_segments!(vm, 2);
+
+        assert_eq!(
+            run_python_hint(&mut vm, exec_scopes_ref!(), &[], "x = y"),
+            Err(VirtualMachineError::PythonHint(PythonHintError::NameError(
+                "y".to_string()
+            )))
+        );
+        assert_eq!(
+            run_python_hint(&mut vm, exec_scopes_ref!(), &[], "x = 1 % 0"),
+            Err(VirtualMachineError::PythonHint(
+                PythonHintError::ZeroDivision
+            ))
+        );
+        assert_eq!(
+            run_python_hint(
+                &mut vm,
+                exec_scopes_ref!(),
+                &[],
+                "assert 1 > 2, 'bad order'"
+            ),
+            Err(VirtualMachineError::PythonHint(
+                PythonHintError::AssertionFailed("bad order".to_string())
+            ))
+        );
+        assert_eq!(
+            run_python_hint(
+                &mut vm,
+                exec_scopes_ref!(),
+                &[],
+                "memory[ap] = 1\nmemory[ap] = 2"
+            ),
+            Err(VirtualMachineError::MemoryError(
+                MemoryError::InconsistentMemory(
+                    MaybeRelocatable::from((1, 0)),
+                    MaybeRelocatable::from(bigint!(1)),
+                    MaybeRelocatable::from(bigint!(2))
+                )
+            ))
+        );
+        assert_eq!(
+            run_python_hint(&mut vm, exec_scopes_ref!(), &[], "x = [1][1]"),
+            Err(VirtualMachineError::PythonHint(
+                PythonHintError::IndexError("index out of range".to_string())
+            ))
+        );
+    }
+
+    #[test]
+    fn run_unbounded_hints() {
+        let mut vm = vm!();
+        add_segments!(vm, 2);
+
+        assert_eq!(
+            run_python_hint(&mut vm, exec_scopes_ref!(), &[], "while True:\n    pass"),
+            Err(VirtualMachineError::PythonHint(
+                PythonHintError::OperationLimitExceeded(1_000_000)
+            ))
+        );
+        assert_eq!(
+            run_python_hint(
+                &mut vm,
+                exec_scopes_ref!(),
+                &[],
+                "for i in range(10 ** 30):\n    pass"
+            ),
+            Err(VirtualMachineError::PythonHint(
+                PythonHintError::OperationLimitExceeded(1_000_000)
+            ))
+        );
+        assert_eq!(
+            run_python_hint(
+                &mut vm,
+                exec_scopes_ref!(),
+                &[],
+                "assert len(range(10 ** 30)) == 10 ** 30\nassert 10 ** 29 in range(0, 10 ** 30, 2)"
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            run_python_hint(&mut vm, exec_scopes_ref!(), &[], "x = 2 ** (10 ** 9)"),
+            Err(VirtualMachineError::PythonHint(
+                PythonHintError::IntegerTooLarge("**", 65536)
+            ))
+        );
+        assert_eq!(
+            run_python_hint(&mut vm, exec_scopes_ref!(), &[], "x = 1 << 10 ** 9"),
+            Err(VirtualMachineError::PythonHint(
+                PythonHintError::IntegerTooLarge("<<", 65536)
+            ))
+        );
+        assert_eq!(
+            run_python_hint(
+                &mut vm,
+                exec_scopes_ref!(),
+                &[],
+                "x = 2 ** 30000\nwhile True:\n    x = x * x"
+            ),
+            Err(VirtualMachineError::PythonHint(
+                PythonHintError::IntegerTooLarge("*", 65536)
+            ))
+        );
+        assert_eq!(
+            run_python_hint(
+                &mut vm,
+                exec_scopes_ref!(),
+                &[],
+                "s = 'a'\nwhile True:\n    s = s + s"
+            ),
+            Err(VirtualMachineError::PythonHint(
+                PythonHintError::SequenceTooLong(1 << 20)
+            ))
+        );
+    }
+
+    #[test]
+    fn read_builtin_hint_scope_variables() {
+        let mut vm = vm!();
+        add_segments!(vm, 2);
+        let mut exec_scopes = scope!(
+            ("n", 3_usize),
+            ("ptr", relocatable!(1, 5)),
+            ("value", bigint!(7))
+        );
+
+        assert_eq!(
+            run_python_hint(
+                &mut vm,
+                &mut exec_scopes,
+                &[],
+                "memory[ptr] = value * n\nptr = ptr + 1"
+            ),
+            Ok(())
+        );
+        check_memory![vm.memory, ((1, 5), 21)];
+        assert_eq!(
+            exec_scopes.get::<Relocatable>("ptr"),
+            Ok(relocatable!(1, 6))
+        );
+    }
+
+    #[test]
+    fn supports_parseable_hints_only() {
+        let hint_processor = PythonHintProcessor::new();
+
+        assert!(hint_processor.supports_hint("x = (1 + 2) * 3"));
+        assert!(!hint_processor.supports_hint("x = (1 + 2"));
+        assert!(!hint_processor.supports_hint("def f():\n    return 1"));
+    }
+
+    #[test]
+    fn runs_after_builtin_hint_processor() {
+        let mut hint_processor = CompositeHintProcessor::new();
+        hint_processor.add_processor(BuiltinHintProcessor::new_empty());
+        hint_processor.add_processor(PythonHintProcessor::new());
+        let mut vm = vm!();
+        add_segments!(vm, 2);
+
+        for hint_code in ["memory[ap] = segments.add()", "memory[ap + 1] = 2 ** 3"].iter() {
+            let hint_data = hint_processor
+                .compile_hint(
+                    hint_code,
+                    &[],
+                    &ApTracking::new(),
+                    &HashMap::new(),
+                    &HashMap::new(),
+                )
+                .unwrap();
+            assert_eq!(
+                hint_processor.execute_hint(
+                    &mut vm,
+                    exec_scopes_ref!(),
+                    &hint_data,
+                    &HashMap::new()
+                ),
+                Ok(())
+            );
+        }
+        check_memory![vm.memory, ((1, 0), (2, 0)), ((1, 1), 8)];
+    }
+}
@@ -3,6 +3,7 @@ pub mod cairo_run;
 pub mod hint_processor;
 pub mod math_utils;
 pub mod serde;
+pub mod starknet;
 pub mod types;
 pub mod utils;
 pub mod vm;
@@ -1,11 +1,17 @@
 #![deny(warnings)]
-use cairo_rs::cairo_run;
+use cairo_rs::cairo_run::{self, FuncArgs};
 use cairo_rs::hint_processor::builtin_hint_processor::builtin_hint_processor_definition::BuiltinHintProcessor;
+use cairo_rs::types::abi::decode_return_values;
 use cairo_rs::vm::errors::cairo_run_errors::CairoRunError;
 use cairo_rs::vm::errors::runner_errors::RunnerError;
 use cairo_rs::vm::errors::trace_errors::TraceError;
+use cairo_rs::vm::errors::vm_errors::VirtualMachineError;
+use cairo_rs::vm::runners::run_resources::RunResources;
 use clap::{Parser, ValueHint};
+use std::cell::RefCell;
 use std::path::PathBuf;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
 
 #[cfg(feature = "with_mimalloc")]
 use mimalloc::MiMalloc;
@@ -28,10 +34,36 @@ struct Args {
     trace: Option<PathBuf>,
     #[structopt(long = "--memory_file")]
     memory_file: Option<PathBuf>,
+    /// File to write the fact topology of the program output to, requires proof mode
+    #[structopt(long = "--fact_topologies_file", requires = "proof_mode")]
+    fact_topologies_file: Option<PathBuf>,
     #[clap(long = "--layout", default_value = "plain", validator=validate_layout)]
     layout: String,
     #[structopt(long = "--proof_mode")]
     proof_mode: bool,
+    /// Verify that the run is safe to be relocated and used by other Cairo programs
+    #[structopt(long = "--secure_run")]
+    secure_run: bool,
+    #[structopt(long = "--print_program_hash")]
+    print_program_hash: bool,
+    #[clap(long = "--max_steps", value_parser)]
+    max_steps: Option<usize>,
+    #[clap(long = "--max_memory_cells", value_parser)]
+    max_memory_cells: Option<usize>,
+    /// Maximum run time in seconds
+    #[clap(long = "--timeout", value_parser)]
+    timeout: Option<u64>,
+    /// Maximum gas consumed, one per step and the layout ratio per builtin instance
+    #[clap(long = "--gas_budget", value_parser)]
+    gas_budget: Option<u64>,
+    /// Arguments of the entrypoint: felts separated by spaces or commas and arrays in brackets,
+    /// e.g. "1 [2, 3] [[4] [5 6]]". Builtin pointers are passed implicitly and the return values
+    /// are printed once the run ends
+    #[clap(long = "--args", value_parser, conflicts_with = "proof_mode")]
+    args: Option<FuncArgs>,
+    /// File to write a json report of the run to, also written if the run fails
+    #[clap(long = "--json_report", value_parser)]
+    json_report: Option<PathBuf>,
 }
 
 fn validate_layout(value: &str) -> Result<(), String> {
@@ -46,19 +78,85 @@ fn validate_layout(value: &str) -> Result<(), String> {
 fn main() -> Result<(), CairoRunError> {
     let args = Args::parse();
     let trace_enabled = args.trace_file.is_some();
-    let hint_executor = BuiltinHintProcessor::new_empty();
-    let cairo_runner = match cairo_run::cairo_run(
-        &args.filename,
-        &args.entrypoint,
-        trace_enabled,
-        args.print_output,
-        &args.layout,
-        args.proof_mode,
-        &hint_executor,
-    ) {
-        Ok(runner) => runner,
-        Err(error) => return Err(error),
+    let mut hint_executor = BuiltinHintProcessor::new_empty();
+    // Keep the output of print hints apart from the program output
+    hint_executor.set_print_writer(Rc::new(RefCell::new(std::io::stderr())));
+    let run_resources = RunResources {
+        max_steps: args.max_steps,
+        max_memory_cells: args.max_memory_cells,
+        max_segments: None,
+        deadline: args
+            .timeout
+            .map(|timeout| Instant::now() + Duration::from_secs(timeout)),
+        gas_budget: args.gas_budget,
     };
+    let run_result = match &args.args {
+        Some(func_args) => cairo_run::cairo_run_with_args(
+            &args.filename,
+            &args.entrypoint,
+            &func_args.0,
+            trace_enabled,
+            args.print_output,
+            &args.layout,
+            args.secure_run,
+            &run_resources,
+            &hint_executor,
+        ),
+        None => cairo_run::cairo_run_with_vm(
+            &args.filename,
+            &args.entrypoint,
+            trace_enabled,
+            args.print_output,
+            &args.layout,
+            args.proof_mode,
+            args.secure_run,
+            &run_resources,
+            &hint_executor,
+        ),
+    };
+    let (cairo_runner, vm) = match run_result {
+        Ok(result) => result,
+        Err(error) => {
+            if let Some(report_path) = args.json_report {
+                let report = cairo_run::RunReport::from_error(&args.layout, &error);
+                if cairo_run::write_json_report(&report, &report_path).is_err() {
+                    return Err(CairoRunError::Runner(RunnerError::WriteFail));
+                }
+            }
+            return Err(error);
+        }
+    };
+
+    if let Some(report_path) = args.json_report {
+        let report = cairo_run::RunReport::new(&args.layout, &args.entrypoint, &cairo_runner, &vm)?;
+        match cairo_run::write_json_report(&report, &report_path) {
+            Ok(()) => (),
+            Err(_e) => return Err(CairoRunError::Runner(RunnerError::WriteFail)),
+        }
+    }
+
+    if args.args.is_some() {
+        match decode_return_values(cairo_runner.get_program(), &args.entrypoint, &vm) {
+            Ok(return_values) => println!("Return values: {}", serde_json::json!(return_values)),
+            // Functions without a return type are run for their side effects
+            Err(VirtualMachineError::RunnerError(RunnerError::UnknownReturnType(_))) => (),
+            Err(error) => return Err(error.into()),
+        }
+    }
+
+    if args.gas_budget.is_some() {
+        if let Some(consumed_gas) = cairo_runner.consumed_gas {
+            println!("Consumed gas: {}", consumed_gas);
+        }
+    }
+
+    if args.print_program_hash {
+        let program_hash = cairo_runner
+            .get_program()
+            .compute_hash(0)
+            .map_err(CairoRunError::Program)?;
+        println!("Program hash: {:#x}", program_hash);
+    }
 
     if let Some(trace_path) = args.trace_file {
         let relocated_trace = cairo_runner
@@ -78,5 +176,17 @@ fn main() -> Result<(), CairoRunError> {
         }
     }
 
+    if let Some(fact_topologies_path) = args.fact_topologies_file {
+        let fact_topology = match &cairo_runner.fact_topology {
+            Some(fact_topology) => Some(fact_topology.clone()),
+            None => cairo_runner.get_fact_topology(&vm)?,
+        };
+        let fact_topologies: Vec<_> = fact_topology.into_iter().collect();
+        match cairo_run::write_fact_topologies(&fact_topologies, &fact_topologies_path) {
+            Ok(()) => (),
+            Err(_e) => return Err(CairoRunError::Runner(RunnerError::WriteFail)),
+        }
+    }
+
     Ok(())
 }
@@ -0,0 +1,151 @@
+use crate::serde::deserialize_program::{
+    deserialize_array_of_bigint_hex, deserialize_bigint_hex, ApTracking, FlowTrackingData,
+    HintParams, ReferenceManager,
+};
+use crate::starknet::contract_class::{deserialize_entry_point_offset, EntryPointType};
+use crate::types::relocatable::MaybeRelocatable;
+use crate::types::{errors::program_errors::ProgramError, program::Program};
+use num_bigint::BigInt;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::io::Read;
+
+/// A Cairo 1 contract compiled from Sierra to CASM, as output by `starknet-sierra-compile`.
+#[derive(Deserialize, Debug)]
+pub struct CasmContractClassJson {
+    #[serde(deserialize_with = "deserialize_bigint_hex")]
+    pub prime: BigInt,
+    #[serde(deserialize_with = "deserialize_array_of_bigint_hex")]
+    pub bytecode: Vec<MaybeRelocatable>,
+    // Hints are kept in their JSON form, as they are only interpreted by the hint processor
+    pub hints: Vec<(usize, Vec<Value>)>,
+    pub entry_points_by_type: HashMap<EntryPointType, Vec<CasmEntryPoint>>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct CasmEntryPoint {
+    #[serde(deserialize_with = "deserialize_bigint_hex")]
+    pub selector: BigInt,
+    #[serde(deserialize_with = "deserialize_entry_point_offset")]
+    pub offset: usize,
+    pub builtins: Vec<String>,
+}
+
+pub fn deserialize_casm_program(
+    reader: impl Read,
+    entry_point: Option<(EntryPointType, &BigInt)>,
+) -> Result<Program, ProgramError> {
+    let casm_json: CasmContractClassJson = serde_json::from_reader(reader)?;
+    program_from_casm_json(casm_json, entry_point)
+}
+
+/// Builds a program out of the CASM bytecode. When an entry point is given, it becomes the
+/// program main and its builtins the program builtins.
+/// Each hint is stored as its JSON encoding, to be compiled by the `Cairo1HintProcessor`.
+pub fn program_from_casm_json(
+    casm_json: CasmContractClassJson,
+    entry_point: Option<(EntryPointType, &BigInt)>,
+) -> Result<Program, ProgramError> {
+    let entry_point = match entry_point {
+        Some((entry_point_type, selector)) => Some(
+            casm_json
+                .entry_points_by_type
+                .get(&entry_point_type)
+                .and_then(|entry_points| {
+                    entry_points
+                        .iter()
+                        .find(|entry_point| &entry_point.selector == selector)
+                })
+                .ok_or_else(|| ProgramError::EntryPointSelectorNotFound(selector.clone()))?,
+        ),
+        None => None,
+    };
+
+    let hints = casm_json
+        .hints
+        .into_iter()
+        .map(|(pc, hints)| {
+            let hints = hints
+                .into_iter()
+                .map(|hint| HintParams {
+                    code: hint.to_string(),
+                    accessible_scopes: Vec::new(),
+                    flow_tracking_data: FlowTrackingData {
+                        ap_tracking: ApTracking::new(),
+                        reference_ids: HashMap::new(),
+                    },
+                })
+                .collect();
+            (pc, hints)
+        })
+        .collect();
+
+    Ok(Program {
+        builtins: entry_point
+            .map(|entry_point| entry_point.builtins.clone())
+            .unwrap_or_default(),
+        prime: casm_json.prime,
+        data: casm_json.bytecode,
+        constants: HashMap::new(),
+        main: entry_point.map(|entry_point| entry_point.offset),
+        start: None,
+        end: None,
+        hints,
+        reference_manager: ReferenceManager {
+            references: Vec::new(),
+        },
+        identifiers: HashMap::new(),
+        error_message_attributes: Vec::new(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bigint;
+    use std::path::Path;
+
+    #[test]
+    fn deserialize_casm_program_with_entry_point() {
+        let program = Program::from_casm_file(
+            Path::new("cairo_programs/manually_compiled/alloc_segment_casm.json"),
+            Some((EntryPointType::External, &bigint!(1))),
+        )
+        .expect("Failed to deserialize casm program");
+
+        assert_eq!(program.builtins, vec!["range_check"]);
+        assert_eq!(program.main, Some(0));
+        assert_eq!(program.data.len(), 3);
+        assert_eq!(program.hints[&0].len(), 2);
+        assert_eq!(
+            serde_json::from_str::<Value>(&program.hints[&0][0].code).unwrap(),
+            serde_json::json!({"AllocSegment": {"dst": {"register": "AP", "offset": 0}}})
+        );
+    }
+
+    #[test]
+    fn deserialize_casm_program_without_entry_point() {
+        let program = Program::from_casm_file(
+            Path::new("cairo_programs/manually_compiled/alloc_segment_casm.json"),
+            None,
+        )
+        .expect("Failed to deserialize casm program");
+
+        assert!(program.builtins.is_empty());
+        assert_eq!(program.main, None);
+    }
+
+    #[test]
+    fn deserialize_casm_program_missing_entry_point() {
+        let program = Program::from_casm_file(
+            Path::new("cairo_programs/manually_compiled/alloc_segment_casm.json"),
+            Some((EntryPointType::L1Handler, &bigint!(1))),
+        );
+
+        assert!(matches!(
+            program,
+            Err(ProgramError::EntryPointSelectorNotFound(selector)) if selector == bigint!(1)
+        ));
+    }
+}
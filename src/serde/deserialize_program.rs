@@ -1,8 +1,11 @@
 use crate::bigint;
 use crate::serde::deserialize_utils;
+use crate::serde::reference_expression::{parse_reference_expression, ReferenceExpression};
 use crate::types::instruction::Register;
 use crate::types::{
-    errors::program_errors::ProgramError, program::Program, relocatable::MaybeRelocatable,
+    errors::program_errors::ProgramError,
+    program::{extract_constants, Program},
+    relocatable::MaybeRelocatable,
 };
 use num_bigint::{BigInt, Sign};
 use serde::{de, de::MapAccess, de::SeqAccess, Deserialize, Deserializer};
@@ -69,6 +72,9 @@ pub struct Identifier {
 
     pub full_name: Option<String>,
     pub members: Option<HashMap<String, Member>>,
+    pub cairo_type: Option<String>,
+    //Full name of the identifier an alias refers to
+    pub destination: Option<String>,
 }
 
 #[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
@@ -116,6 +122,10 @@ pub struct ValueAddress {
     pub dereference: bool,
     pub inner_dereference: bool,
     pub value_type: String,
+    //Full expression of the reference, only present when it doesn't fit the simple
+    //register/offsets/immediate form described by the other fields
+    #[serde(skip)]
+    pub expression: Option<ReferenceExpression>,
 }
 
 impl ValueAddress {
@@ -135,6 +145,24 @@ impl ValueAddress {
             dereference: false,
             inner_dereference: false,
             value_type: String::from("felt"),
+            expression: None,
+        }
+    }
+
+    // Builds a ValueAddress from a reference that could only be parsed as a full expression.
+    // The value is then computed by evaluating the expression instead of using the offsets.
+    pub fn from_expression(expression: ReferenceExpression) -> ValueAddress {
+        ValueAddress {
+            register: None,
+            offset1: 0,
+            offset2: 0,
+            immediate: None,
+            dereference: expression.dereferenced_address().is_some(),
+            inner_dereference: false,
+            value_type: expression
+                .cairo_type()
+                .unwrap_or_else(|| String::from("felt")),
+            expression: Some(expression),
         }
     }
 }
@@ -241,12 +269,16 @@ impl<'de> de::Visitor<'de> for ValueAddressVisitor {
     where
         E: de::Error,
     {
-        let parse_res = deserialize_utils::parse_value(value);
-
-        if let Ok((_, res)) = parse_res {
+        // Simple references are stored as register and offsets, anything else is kept as an
+        // expression to be evaluated when the reference is used
+        if let Ok(("", res)) = deserialize_utils::parse_value(value) {
             return Ok(res);
         }
 
+        if let Ok(("", expression)) = parse_reference_expression(value) {
+            return Ok(ValueAddress::from_expression(expression));
+        }
+
         Ok(ValueAddress::no_hint_reference_default())
     }
 }
@@ -284,7 +316,13 @@ pub fn deserialize_program(
     entrypoint: Option<&str>,
 ) -> Result<Program, ProgramError> {
     let program_json: ProgramJson = deserialize_program_json(reader)?;
+    program_from_program_json(program_json, entrypoint)
+}
 
+pub fn program_from_program_json(
+    program_json: ProgramJson,
+    entrypoint: Option<&str>,
+) -> Result<Program, ProgramError> {
     let entrypoint_pc = match entrypoint {
         Some(entrypoint) => match program_json
             .identifiers
@@ -309,20 +347,7 @@ pub fn deserialize_program(
         builtins: program_json.builtins,
         prime: program_json.prime,
         data: program_json.data,
-        constants: {
-            let mut constants = HashMap::new();
-            for (key, value) in program_json.identifiers.iter() {
-                if value.type_.as_deref() == Some("const") {
-                    let value = value
-                        .value
-                        .clone()
-                        .ok_or_else(|| ProgramError::ConstWithoutValue(key.to_owned()))?;
-                    constants.insert(key.to_owned(), value);
-                }
-            }
-
-            constants
-        },
+        constants: extract_constants(&program_json.identifiers)?,
         main: entrypoint_pc,
         start,
         end,
@@ -554,6 +579,7 @@ mod tests {
                         dereference: true,
                         inner_dereference: false,
                         value_type: "felt".to_string(),
+                        expression: None,
                     },
                 },
                 Reference {
@@ -570,6 +596,7 @@ mod tests {
                         dereference: true,
                         inner_dereference: false,
                         value_type: "felt".to_string(),
+                        expression: None,
                     },
                 },
                 Reference {
@@ -586,6 +613,7 @@ mod tests {
                         dereference: false,
                         inner_dereference: true,
                         value_type: "felt".to_string(),
+                        expression: None,
                     },
                 },
                 Reference {
@@ -602,6 +630,7 @@ mod tests {
                         dereference: true,
                         inner_dereference: false,
                         value_type: "felt*".to_string(),
+                        expression: None,
                     },
                 },
             ],
@@ -844,6 +873,8 @@ mod tests {
                 value: None,
                 full_name: None,
                 members: None,
+                cairo_type: None,
+                destination: None,
             },
         );
         identifiers.insert(
@@ -854,6 +885,8 @@ mod tests {
                 value: Some(bigint_str!(b"-3618502788666131213697322783095070105623107215331596699973092056135872020481")),
                 full_name: None,
                 members: None,
+                cairo_type: None,
+                destination: None,
             },
         );
         identifiers.insert(
@@ -864,6 +897,8 @@ mod tests {
                 value: None,
                 full_name: None,
                 members: None,
+                cairo_type: None,
+                destination: Some(String::from("starkware.cairo.common.math.unsigned_div_rem")),
             },
         );
         identifiers.insert(
@@ -876,6 +911,8 @@ mod tests {
                 )),
                 full_name: None,
                 members: None,
+                cairo_type: None,
+                destination: None,
             },
         );
         identifiers.insert(
@@ -886,6 +923,8 @@ mod tests {
                 value: Some(bigint!(3)),
                 full_name: None,
                 members: None,
+                cairo_type: None,
+                destination: None,
             },
         );
         identifiers.insert(
@@ -896,6 +935,8 @@ mod tests {
                 value: Some(bigint!(0)),
                 full_name: None,
                 members: None,
+                cairo_type: None,
+                destination: None,
             },
         );
         identifiers.insert(
@@ -906,6 +947,8 @@ mod tests {
                 value: Some(bigint_str!(b"340282366920938463463374607431768211456")),
                 full_name: None,
                 members: None,
+                cairo_type: None,
+                destination: None,
             },
         );
 
@@ -955,6 +998,66 @@ mod tests {
         assert_eq!(program_json.reference_manager, reference_manager);
     }
 
+    #[test]
+    fn value_address_from_expression_test() {
+        let valid_json = r#"
+            {
+                "prime": "0x000A",
+                "attributes": [],
+                "builtins": [],
+                "data": [
+                ],
+                "identifiers": {
+                },
+                "hints": {
+                },
+                "reference_manager": {
+                    "references": [
+                        {
+                            "ap_tracking_data": {
+                                "group": 0,
+                                "offset": 0
+                            },
+                            "pc": 0,
+                            "value": "[cast([[ap + (-1)] + 3] * 2, felt*)]"
+                        }
+                    ]
+                }
+            }"#;
+
+        let program_json: ProgramJson = serde_json::from_str(valid_json).unwrap();
+        let value_address = &program_json.reference_manager.references[0].value_address;
+
+        assert_eq!(
+            value_address,
+            &ValueAddress::from_expression(
+                parse_reference_expression("[cast([[ap + (-1)] + 3] * 2, felt*)]")
+                    .unwrap()
+                    .1
+            )
+        );
+        assert_eq!(value_address.register, None);
+        assert!(value_address.dereference);
+        assert_eq!(value_address.value_type, "felt");
+    }
+
+    #[test]
+    fn value_address_from_expression_with_trailing_input_test() {
+        let reference: Reference = serde_json::from_str(
+            r#"{
+                "ap_tracking_data": {"group": 0, "offset": 0},
+                "pc": 0,
+                "value": "[cast(ap + 1, felt*)]}"
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            reference.value_address,
+            ValueAddress::no_hint_reference_default()
+        );
+    }
+
     #[test]
     fn deserialize_attributes_test() {
         let valid_json = r#"
@@ -191,6 +191,7 @@ pub fn parse_value(input: &str) -> IResult<&str, ValueAddress> {
             dereference,
             inner_dereference: inner_deref,
             value_type: type_,
+            expression: None,
         }
     } else {
         ValueAddress {
@@ -201,6 +202,7 @@ pub fn parse_value(input: &str) -> IResult<&str, ValueAddress> {
             dereference,
             inner_dereference: inner_deref,
             value_type: type_,
+            expression: None,
         }
     };
 
@@ -307,6 +309,7 @@ mod tests {
                     dereference: true,
                     inner_dereference: true,
                     value_type: "felt".to_string(),
+                    expression: None,
                 }
             ))
         );
@@ -329,6 +332,7 @@ mod tests {
                     dereference: false,
                     inner_dereference: false,
                     value_type: "felt".to_string(),
+                    expression: None,
                 }
             ))
         );
@@ -350,6 +354,7 @@ mod tests {
                     dereference: false,
                     inner_dereference: false,
                     value_type: "felt".to_string(),
+                    expression: None,
                 }
             ))
         );
@@ -372,6 +377,7 @@ mod tests {
                     dereference: true,
                     inner_dereference: false,
                     value_type: "felt".to_string(),
+                    expression: None,
                 }
             ))
         );
@@ -394,6 +400,7 @@ mod tests {
                     dereference: true,
                     inner_dereference: true,
                     value_type: "__main__.felt".to_string(),
+                    expression: None,
                 }
             ))
         );
@@ -416,6 +423,7 @@ mod tests {
                     dereference: false,
                     inner_dereference: false,
                     value_type: "felt".to_string(),
+                    expression: None,
                 }
             ))
         );
@@ -438,6 +446,7 @@ mod tests {
                     dereference: true,
                     inner_dereference: true,
                     value_type: "starkware.cairo.common.cairo_secp.ec.EcPoint".to_string(),
+                    expression: None,
                 }
             ))
         );
@@ -460,6 +469,7 @@ mod tests {
                     dereference: true,
                     inner_dereference: true,
                     value_type: "starkware.cairo.common.cairo_secp.ec.EcPoint*".to_string(),
+                    expression: None,
                 }
             ))
         );
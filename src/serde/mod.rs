@@ -1,2 +1,4 @@
+pub mod deserialize_casm;
 pub mod deserialize_program;
 pub mod deserialize_utils;
+pub mod reference_expression;
@@ -0,0 +1,439 @@
+use crate::serde::deserialize_program::Identifier;
+use crate::types::instruction::Register;
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_while1},
+    character::complete::{char, digit1, multispace0},
+    combinator::{all_consuming, map, map_res, value},
+    multi::fold_many0,
+    sequence::{delimited, pair, preceded, tuple},
+    IResult,
+};
+use num_bigint::BigInt;
+use parse_hyperlinks::take_until_unbalanced;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// Expression tree of a reference value, as emitted by the Cairo compiler
+/// (e.g. `[cast([[ap + (-1)] + 3] * 2, felt*)]` or `[cast(fp + (-3), MyStruct*)].x`)
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ReferenceExpression {
+    Register(Register),
+    Immediate(BigInt),
+    Deref(Box<ReferenceExpression>),
+    Add(Box<ReferenceExpression>, Box<ReferenceExpression>),
+    Sub(Box<ReferenceExpression>, Box<ReferenceExpression>),
+    Mul(Box<ReferenceExpression>, Box<ReferenceExpression>),
+    Neg(Box<ReferenceExpression>),
+    Cast(Box<ReferenceExpression>, String),
+    // Member access is replaced by pointer arithmetic once the program identifiers are known,
+    // see `ReferenceExpression::resolve_members`
+    MemberAccess(Box<ReferenceExpression>, String),
+}
+
+impl ReferenceExpression {
+    /// Returns the cairo type of the expression, if it can be deduced from its casts
+    pub fn cairo_type(&self) -> Option<String> {
+        match self {
+            ReferenceExpression::Cast(_, cairo_type) => Some(cairo_type.clone()),
+            ReferenceExpression::Deref(inner) => inner
+                .cairo_type()
+                .and_then(|cairo_type| cairo_type.strip_suffix('*').map(String::from)),
+            _ => None,
+        }
+    }
+
+    /// If the expression is a (possibly cast) dereference, returns the expression of the
+    /// dereferenced address
+    pub fn dereferenced_address(&self) -> Option<&ReferenceExpression> {
+        match self {
+            ReferenceExpression::Deref(inner) => Some(inner),
+            ReferenceExpression::Cast(inner, _) => inner.dereferenced_address(),
+            _ => None,
+        }
+    }
+
+    /// Replaces every struct member access with the dereference of the member address, using
+    /// the struct definitions found in the program identifiers.
+    /// Member accesses whose struct can't be determined are left untouched.
+    pub fn resolve_members(self, identifiers: &HashMap<String, Identifier>) -> Self {
+        let resolve = |expression: Box<ReferenceExpression>| {
+            Box::new(expression.resolve_members(identifiers))
+        };
+        match self {
+            ReferenceExpression::Deref(inner) => ReferenceExpression::Deref(resolve(inner)),
+            ReferenceExpression::Add(lhs, rhs) => {
+                ReferenceExpression::Add(resolve(lhs), resolve(rhs))
+            }
+            ReferenceExpression::Sub(lhs, rhs) => {
+                ReferenceExpression::Sub(resolve(lhs), resolve(rhs))
+            }
+            ReferenceExpression::Mul(lhs, rhs) => {
+                ReferenceExpression::Mul(resolve(lhs), resolve(rhs))
+            }
+            ReferenceExpression::Neg(inner) => ReferenceExpression::Neg(resolve(inner)),
+            ReferenceExpression::Cast(inner, cairo_type) => {
+                ReferenceExpression::Cast(resolve(inner), cairo_type)
+            }
+            ReferenceExpression::MemberAccess(base, member_name) => {
+                let base = resolve(base);
+                match resolve_member_access(&base, &member_name, identifiers) {
+                    Some(resolved) => resolved,
+                    None => ReferenceExpression::MemberAccess(base, member_name),
+                }
+            }
+            expression => expression,
+        }
+    }
+}
+
+// Builds `[cast(ptr + offset, member_type*)]` from `base.member`, where `ptr` is either the
+// address of the struct value `base` or `base` itself when it is a pointer to the struct
+fn resolve_member_access(
+    base: &ReferenceExpression,
+    member_name: &str,
+    identifiers: &HashMap<String, Identifier>,
+) -> Option<ReferenceExpression> {
+    let base_type = base.cairo_type()?;
+    let (struct_ptr, struct_name) = match base_type.strip_suffix('*') {
+        Some(struct_name) => (base.clone(), struct_name.to_string()),
+        None => (base.dereferenced_address()?.clone(), base_type),
+    };
+    let member = identifiers
+        .get(&struct_name)?
+        .members
+        .as_ref()?
+        .get(member_name)?;
+
+    Some(ReferenceExpression::Deref(Box::new(
+        ReferenceExpression::Cast(
+            Box::new(ReferenceExpression::Add(
+                Box::new(struct_ptr),
+                Box::new(ReferenceExpression::Immediate(BigInt::from(member.offset))),
+            )),
+            format!("{}*", member.cairo_type),
+        ),
+    )))
+}
+
+/*
+NOM PARSERS
+Grammar of the reference expressions, from lowest to highest precedence:
+    expression := product (('+' | '-') product)*
+    product    := unary ('*' unary)*
+    unary      := '-' unary | postfix
+    postfix    := atom ('.' member_name)*
+    atom       := '[' expression ']' | '(' expression ')' | 'cast(' expression ',' type ')'
+                | 'ap' | 'fp' | integer
+*/
+
+// Skips the whitespace surrounding the given parser
+fn ws<'a, O>(
+    parser: impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, O> {
+    delimited(multispace0, parser, multispace0)
+}
+
+fn register(input: &str) -> IResult<&str, ReferenceExpression> {
+    map(
+        alt((
+            value(Register::AP, tag("ap")),
+            value(Register::FP, tag("fp")),
+        )),
+        ReferenceExpression::Register,
+    )(input)
+}
+
+fn immediate(input: &str) -> IResult<&str, ReferenceExpression> {
+    map(
+        map_res(digit1, BigInt::from_str),
+        ReferenceExpression::Immediate,
+    )(input)
+}
+
+fn cast(input: &str) -> IResult<&str, ReferenceExpression> {
+    let (rem_input, (expression, _, cairo_type, _)) = preceded(
+        pair(tag("cast"), ws(char('('))),
+        tuple((
+            expression,
+            char(','),
+            take_until_unbalanced('(', ')'),
+            char(')'),
+        )),
+    )(input)?;
+
+    Ok((
+        rem_input,
+        ReferenceExpression::Cast(Box::new(expression), cairo_type.trim().to_string()),
+    ))
+}
+
+fn atom(input: &str) -> IResult<&str, ReferenceExpression> {
+    ws(alt((
+        map(delimited(char('['), expression, char(']')), |inner| {
+            ReferenceExpression::Deref(Box::new(inner))
+        }),
+        delimited(char('('), expression, char(')')),
+        cast,
+        register,
+        immediate,
+    )))(input)
+}
+
+fn postfix(input: &str) -> IResult<&str, ReferenceExpression> {
+    let (rem_input, base) = atom(input)?;
+
+    fold_many0(
+        ws(preceded(
+            char('.'),
+            take_while1(|c: char| c.is_alphanumeric() || c == '_'),
+        )),
+        move || base.clone(),
+        |base, member_name: &str| {
+            ReferenceExpression::MemberAccess(Box::new(base), member_name.to_string())
+        },
+    )(rem_input)
+}
+
+fn unary(input: &str) -> IResult<&str, ReferenceExpression> {
+    alt((
+        map(preceded(ws(char('-')), unary), |inner| {
+            ReferenceExpression::Neg(Box::new(inner))
+        }),
+        postfix,
+    ))(input)
+}
+
+fn product(input: &str) -> IResult<&str, ReferenceExpression> {
+    let (rem_input, first) = unary(input)?;
+
+    fold_many0(
+        preceded(ws(char('*')), unary),
+        move || first.clone(),
+        |lhs, rhs| ReferenceExpression::Mul(Box::new(lhs), Box::new(rhs)),
+    )(rem_input)
+}
+
+fn expression(input: &str) -> IResult<&str, ReferenceExpression> {
+    let (rem_input, first) = product(input)?;
+
+    fold_many0(
+        pair(ws(alt((char('+'), char('-')))), product),
+        move || first.clone(),
+        |lhs, (operator, rhs)| match operator {
+            '+' => ReferenceExpression::Add(Box::new(lhs), Box::new(rhs)),
+            _ => ReferenceExpression::Sub(Box::new(lhs), Box::new(rhs)),
+        },
+    )(rem_input)
+}
+
+/// Parses a whole reference value into its expression tree
+pub fn parse_reference_expression(input: &str) -> IResult<&str, ReferenceExpression> {
+    all_consuming(expression)(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bigint;
+    use crate::serde::deserialize_program::Member;
+
+    fn reg(register: Register) -> Box<ReferenceExpression> {
+        Box::new(ReferenceExpression::Register(register))
+    }
+
+    fn imm(value: i32) -> Box<ReferenceExpression> {
+        Box::new(ReferenceExpression::Immediate(bigint!(value)))
+    }
+
+    fn struct_identifiers() -> HashMap<String, Identifier> {
+        HashMap::from([(
+            "__main__.MyStruct".to_string(),
+            Identifier {
+                pc: None,
+                type_: Some(String::from("struct")),
+                value: None,
+                full_name: Some("__main__.MyStruct".to_string()),
+                members: Some(HashMap::from([
+                    (
+                        "x".to_string(),
+                        Member {
+                            cairo_type: "felt".to_string(),
+                            offset: 0,
+                        },
+                    ),
+                    (
+                        "y".to_string(),
+                        Member {
+                            cairo_type: "felt*".to_string(),
+                            offset: 1,
+                        },
+                    ),
+                ])),
+                cairo_type: None,
+                destination: None,
+            },
+        )])
+    }
+
+    #[test]
+    fn parse_simple_reference() {
+        assert_eq!(
+            parse_reference_expression("[cast(fp + (-3), felt*)]"),
+            Ok((
+                "",
+                ReferenceExpression::Deref(Box::new(ReferenceExpression::Cast(
+                    Box::new(ReferenceExpression::Add(
+                        reg(Register::FP),
+                        Box::new(ReferenceExpression::Neg(imm(3)))
+                    )),
+                    "felt*".to_string()
+                )))
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_nested_dereferences() {
+        assert_eq!(
+            parse_reference_expression("[cast([[ap - 1] + 3], felt*)]"),
+            Ok((
+                "",
+                ReferenceExpression::Deref(Box::new(ReferenceExpression::Cast(
+                    Box::new(ReferenceExpression::Deref(Box::new(
+                        ReferenceExpression::Add(
+                            Box::new(ReferenceExpression::Deref(Box::new(
+                                ReferenceExpression::Sub(reg(Register::AP), imm(1))
+                            ))),
+                            imm(3)
+                        )
+                    ))),
+                    "felt*".to_string()
+                )))
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_multiplication_precedence() {
+        assert_eq!(
+            parse_reference_expression("cast([fp] + [fp + 1] * 2, felt)"),
+            Ok((
+                "",
+                ReferenceExpression::Cast(
+                    Box::new(ReferenceExpression::Add(
+                        Box::new(ReferenceExpression::Deref(reg(Register::FP))),
+                        Box::new(ReferenceExpression::Mul(
+                            Box::new(ReferenceExpression::Deref(Box::new(
+                                ReferenceExpression::Add(reg(Register::FP), imm(1))
+                            ))),
+                            imm(2)
+                        ))
+                    )),
+                    "felt".to_string()
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_cast_to_tuple() {
+        let (_, expression) =
+            parse_reference_expression("[cast(ap + (-2), (felt, felt*)*)]").unwrap();
+        assert_eq!(expression.cairo_type(), Some("(felt, felt*)".to_string()));
+        assert_eq!(
+            expression.dereferenced_address(),
+            Some(&ReferenceExpression::Cast(
+                Box::new(ReferenceExpression::Add(
+                    reg(Register::AP),
+                    Box::new(ReferenceExpression::Neg(imm(2)))
+                )),
+                "(felt, felt*)*".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_member_access() {
+        assert_eq!(
+            parse_reference_expression("[cast(fp, __main__.MyStruct*)].y"),
+            Ok((
+                "",
+                ReferenceExpression::MemberAccess(
+                    Box::new(ReferenceExpression::Deref(Box::new(
+                        ReferenceExpression::Cast(
+                            reg(Register::FP),
+                            "__main__.MyStruct*".to_string()
+                        )
+                    ))),
+                    "y".to_string()
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_invalid_expression() {
+        assert!(parse_reference_expression("[cast(fp + , felt*)]").is_err());
+        assert!(parse_reference_expression("[fp] + 1)").is_err());
+    }
+
+    #[test]
+    fn resolve_member_access_of_struct_value() {
+        let (_, expression) =
+            parse_reference_expression("[cast(fp, __main__.MyStruct*)].y").unwrap();
+        let resolved = expression.resolve_members(&struct_identifiers());
+
+        assert_eq!(
+            resolved,
+            ReferenceExpression::Deref(Box::new(ReferenceExpression::Cast(
+                Box::new(ReferenceExpression::Add(
+                    Box::new(ReferenceExpression::Cast(
+                        reg(Register::FP),
+                        "__main__.MyStruct*".to_string()
+                    )),
+                    imm(1)
+                )),
+                "felt**".to_string()
+            )))
+        );
+        assert_eq!(resolved.cairo_type(), Some("felt*".to_string()));
+    }
+
+    #[test]
+    fn resolve_member_access_of_struct_pointer() {
+        let (_, expression) =
+            parse_reference_expression("cast([fp + (-3)], __main__.MyStruct*).x").unwrap();
+        let resolved = expression.resolve_members(&struct_identifiers());
+
+        assert_eq!(
+            resolved.dereferenced_address(),
+            Some(&ReferenceExpression::Cast(
+                Box::new(ReferenceExpression::Add(
+                    Box::new(ReferenceExpression::Cast(
+                        Box::new(ReferenceExpression::Deref(Box::new(
+                            ReferenceExpression::Add(
+                                reg(Register::FP),
+                                Box::new(ReferenceExpression::Neg(imm(3)))
+                            )
+                        ))),
+                        "__main__.MyStruct*".to_string()
+                    )),
+                    imm(0)
+                )),
+                "felt*".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn resolve_unknown_member_is_left_untouched() {
+        let (_, expression) =
+            parse_reference_expression("[cast(fp, __main__.MyStruct*)].z").unwrap();
+
+        assert_eq!(
+            expression.clone().resolve_members(&struct_identifiers()),
+            expression
+        );
+    }
+}
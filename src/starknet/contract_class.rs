@@ -0,0 +1,231 @@
+use crate::serde::deserialize_program::{
+    deserialize_bigint_hex, program_from_program_json, ProgramJson,
+};
+use crate::types::{errors::program_errors::ProgramError, program::Program};
+use num_bigint::BigInt;
+use num_traits::Zero;
+use serde::{de, Deserialize, Deserializer};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufReader, Read};
+use std::path::Path;
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Hash)]
+pub enum EntryPointType {
+    #[serde(rename = "EXTERNAL")]
+    External,
+    #[serde(rename = "L1_HANDLER")]
+    L1Handler,
+    #[serde(rename = "CONSTRUCTOR")]
+    Constructor,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct ContractEntryPoint {
+    #[serde(deserialize_with = "deserialize_bigint_hex")]
+    pub selector: BigInt,
+    #[serde(deserialize_with = "deserialize_entry_point_offset")]
+    pub offset: usize,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ContractClassJson {
+    pub program: ProgramJson,
+    pub entry_points_by_type: HashMap<EntryPointType, Vec<ContractEntryPoint>>,
+    #[serde(default)]
+    pub abi: Option<Value>,
+}
+
+/// A (deprecated, Cairo 0) StarkNet contract class: a program together with the offsets of
+/// its entry points.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContractClass {
+    pub program: Program,
+    pub entry_points_by_type: HashMap<EntryPointType, Vec<ContractEntryPoint>>,
+    pub abi: Option<Value>,
+}
+
+impl ContractClass {
+    pub fn from_file(path: &Path) -> Result<ContractClass, ProgramError> {
+        let file = File::open(path)?;
+        let reader = BufReader::new(file);
+
+        ContractClass::from_reader(reader)
+    }
+
+    pub fn from_reader(reader: impl Read) -> Result<ContractClass, ProgramError> {
+        let contract_class_json: ContractClassJson = serde_json::from_reader(reader)?;
+
+        Ok(ContractClass {
+            program: program_from_program_json(contract_class_json.program, None)?,
+            entry_points_by_type: contract_class_json.entry_points_by_type,
+            abi: contract_class_json.abi,
+        })
+    }
+
+    /// Returns the entry point of the given type matching the selector. As in StarkNet, the
+    /// default entry point (selector 0) is used as a fallback when no entry point matches.
+    pub fn get_entry_point(
+        &self,
+        entry_point_type: EntryPointType,
+        selector: &BigInt,
+    ) -> Result<&ContractEntryPoint, ProgramError> {
+        let entry_points = self
+            .entry_points_by_type
+            .get(&entry_point_type)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+
+        entry_points
+            .iter()
+            .find(|entry_point| &entry_point.selector == selector)
+            .or_else(|| {
+                entry_points
+                    .iter()
+                    .find(|entry_point| entry_point.selector.is_zero())
+            })
+            .ok_or_else(|| ProgramError::EntryPointSelectorNotFound(selector.clone()))
+    }
+}
+
+// Entry point offsets are encoded as hex strings by recent compilers, and as plain numbers
+// by older ones.
+pub(crate) fn deserialize_entry_point_offset<'de, D: Deserializer<'de>>(
+    d: D,
+) -> Result<usize, D::Error> {
+    match Value::deserialize(d)? {
+        Value::Number(number) => number
+            .as_u64()
+            .and_then(|offset| usize::try_from(offset).ok())
+            .ok_or_else(|| de::Error::custom(format!("invalid entry point offset {number}"))),
+        Value::String(string) => {
+            let offset = string
+                .strip_prefix("0x")
+                .ok_or_else(|| de::Error::custom("hex prefix error"))?;
+            usize::from_str_radix(offset, 16).map_err(de::Error::custom)
+        }
+        value => Err(de::Error::custom(format!(
+            "invalid entry point offset {value}"
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bigint_str;
+
+    #[test]
+    fn deserialize_contract_class() {
+        let contract_class = ContractClass::from_file(Path::new(
+            "cairo_programs/manually_compiled/echo_contract.json",
+        ))
+        .expect("Failed to deserialize contract class");
+
+        assert_eq!(contract_class.program.builtins, vec!["range_check"]);
+        assert_eq!(contract_class.program.data.len(), 5);
+        assert_eq!(
+            contract_class.entry_points_by_type[&EntryPointType::External],
+            vec![ContractEntryPoint {
+                selector: bigint_str!(
+                    b"301710206996413058962444592991928216677664597027184567149740246420992804240"
+                ),
+                offset: 0,
+            }]
+        );
+        assert!(contract_class.entry_points_by_type[&EntryPointType::Constructor].is_empty());
+        assert!(contract_class.entry_points_by_type[&EntryPointType::L1Handler].is_empty());
+        assert!(contract_class.abi.is_some());
+    }
+
+    #[test]
+    fn deserialize_entry_point_with_numeric_offset() {
+        let entry_point: ContractEntryPoint =
+            serde_json::from_str(r#"{"selector": "0x1", "offset": 12}"#).unwrap();
+
+        assert_eq!(
+            entry_point,
+            ContractEntryPoint {
+                selector: BigInt::from(1),
+                offset: 12,
+            }
+        );
+    }
+
+    #[test]
+    fn deserialize_entry_point_with_hex_offset() {
+        let entry_point: ContractEntryPoint =
+            serde_json::from_str(r#"{"selector": "0x1", "offset": "0x1a"}"#).unwrap();
+
+        assert_eq!(entry_point.offset, 26);
+    }
+
+    #[test]
+    fn deserialize_entry_point_with_invalid_offset() {
+        let entry_point: Result<ContractEntryPoint, _> =
+            serde_json::from_str(r#"{"selector": "0x1", "offset": "1a"}"#);
+
+        assert!(entry_point.is_err());
+    }
+
+    #[test]
+    fn get_entry_point_by_selector() {
+        let entry_point = ContractEntryPoint {
+            selector: BigInt::from(7),
+            offset: 3,
+        };
+        let contract_class = ContractClass {
+            program: Program::default(),
+            entry_points_by_type: HashMap::from([(
+                EntryPointType::External,
+                vec![entry_point.clone()],
+            )]),
+            abi: None,
+        };
+
+        assert_eq!(
+            contract_class
+                .get_entry_point(EntryPointType::External, &BigInt::from(7))
+                .unwrap(),
+            &entry_point
+        );
+        assert!(matches!(
+            contract_class.get_entry_point(EntryPointType::External, &BigInt::from(8)),
+            Err(ProgramError::EntryPointSelectorNotFound(selector)) if selector == BigInt::from(8)
+        ));
+        assert!(matches!(
+            contract_class.get_entry_point(EntryPointType::L1Handler, &BigInt::from(7)),
+            Err(ProgramError::EntryPointSelectorNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn get_entry_point_falls_back_to_default() {
+        let default_entry_point = ContractEntryPoint {
+            selector: BigInt::zero(),
+            offset: 5,
+        };
+        let contract_class = ContractClass {
+            program: Program::default(),
+            entry_points_by_type: HashMap::from([(
+                EntryPointType::External,
+                vec![
+                    ContractEntryPoint {
+                        selector: BigInt::from(7),
+                        offset: 3,
+                    },
+                    default_entry_point.clone(),
+                ],
+            )]),
+            abi: None,
+        };
+
+        assert_eq!(
+            contract_class
+                .get_entry_point(EntryPointType::External, &BigInt::from(8))
+                .unwrap(),
+            &default_entry_point
+        );
+    }
+}
@@ -0,0 +1,2 @@
+pub mod state_errors;
+pub mod syscall_handler_errors;
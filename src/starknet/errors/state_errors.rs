@@ -0,0 +1,12 @@
+use num_bigint::BigInt;
+use thiserror::Error;
+
+#[derive(Debug, PartialEq, Eq, Error)]
+pub enum StateError {
+    #[error("No contract deployed at address {0:#x}")]
+    NoClassHashForAddress(BigInt),
+    #[error("Contract class with hash {0:#x} is not declared")]
+    MissingContractClass(BigInt),
+    #[error("A contract is already deployed at address {0:#x}")]
+    ContractAddressUnavailable(BigInt),
+}
@@ -0,0 +1,13 @@
+use super::state_errors::StateError;
+use num_bigint::BigInt;
+use thiserror::Error;
+
+#[derive(Debug, PartialEq, Eq, Error)]
+pub enum SyscallHandlerError {
+    #[error(transparent)]
+    State(#[from] StateError),
+    #[error("Expected a {0} syscall request, found selector {1}")]
+    UnexpectedSyscallSelector(&'static str, BigInt),
+    #[error("Call to contract {0:#x} failed: {1}")]
+    CallFailed(BigInt, String),
+}
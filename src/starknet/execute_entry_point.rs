@@ -0,0 +1,213 @@
+use crate::hint_processor::hint_processor_definition::HintProcessor;
+use crate::hint_processor::hint_processor_utils::bigint_to_usize;
+use crate::starknet::contract_class::{ContractClass, ContractEntryPoint, EntryPointType};
+use crate::types::relocatable::MaybeRelocatable;
+use crate::vm::errors::{
+    cairo_run_errors::CairoRunError, runner_errors::RunnerError, vm_errors::VirtualMachineError,
+};
+use crate::vm::runners::cairo_runner::CairoRunner;
+use crate::vm::vm_core::VirtualMachine;
+use num_bigint::BigInt;
+use std::any::Any;
+use std::borrow::Cow;
+
+/// Builds the os context passed to every StarkNet entry point: a pointer to a new syscall
+/// segment followed by the initial stack of each builtin used by the program.
+pub fn prepare_os_context(
+    cairo_runner: &CairoRunner,
+    vm: &mut VirtualMachine,
+) -> Result<Vec<MaybeRelocatable>, RunnerError> {
+    let syscall_segment = vm.add_memory_segment();
+    let mut os_context = vec![MaybeRelocatable::from(syscall_segment)];
+    for builtin_name in cairo_runner.get_program_builtins() {
+        let (_, builtin_runner) = vm
+            .get_builtin_runners()
+            .iter()
+            .find(|(name, _)| name == builtin_name)
+            .ok_or_else(|| RunnerError::MissingBuiltin(builtin_name.to_string()))?;
+        os_context.extend(builtin_runner.initial_stack());
+    }
+    Ok(os_context)
+}
+
+/// Runs a contract entry point with the given calldata and returns its retdata.
+/// The runner must have been initialized with `initialize_function_runner`.
+///
+/// Entry point wrappers receive the os context, the calldata size and a pointer to the
+/// calldata, and return the updated os context followed by the retdata size and a pointer
+/// to the retdata.
+pub fn run_entry_point(
+    cairo_runner: &mut CairoRunner,
+    vm: &mut VirtualMachine,
+    entry_point: &ContractEntryPoint,
+    calldata: &[BigInt],
+    hint_processor: &dyn HintProcessor,
+) -> Result<Vec<BigInt>, VirtualMachineError> {
+    let os_context = prepare_os_context(cairo_runner, vm)?;
+    let calldata_size = MaybeRelocatable::from(BigInt::from(calldata.len()));
+    let calldata: Vec<MaybeRelocatable> = calldata
+        .iter()
+        .map(|value| MaybeRelocatable::from(value.clone()))
+        .collect();
+
+    let mut args: Vec<&dyn Any> = os_context.iter().map(|arg| arg as &dyn Any).collect();
+    args.push(&calldata_size);
+    args.push(&calldata);
+
+    cairo_runner.run_from_entrypoint(
+        entry_point.offset,
+        args,
+        false,
+        true,
+        true,
+        vm,
+        hint_processor,
+    )?;
+
+    get_retdata(vm)
+}
+
+/// Loads `contract_class` into a new runner and executes the entry point of the given type
+/// matching `selector`, returning its retdata.
+pub fn execute_entry_point(
+    contract_class: &ContractClass,
+    entry_point_type: EntryPointType,
+    selector: &BigInt,
+    calldata: &[BigInt],
+    hint_processor: &dyn HintProcessor,
+) -> Result<Vec<BigInt>, CairoRunError> {
+    let entry_point = contract_class.get_entry_point(entry_point_type, selector)?;
+    let program = &contract_class.program;
+
+    let mut cairo_runner = CairoRunner::new(program, "all", false)?;
+    let mut vm = VirtualMachine::new(
+        program.prime.clone(),
+        false,
+        program.error_message_attributes.clone(),
+    );
+    cairo_runner.initialize_function_runner(&mut vm)?;
+
+    Ok(run_entry_point(
+        &mut cairo_runner,
+        &mut vm,
+        entry_point,
+        calldata,
+        hint_processor,
+    )?)
+}
+
+fn get_retdata(vm: &VirtualMachine) -> Result<Vec<BigInt>, VirtualMachineError> {
+    let retdata_size_addr = vm.get_ap().sub(2)?;
+    let retdata_size = bigint_to_usize(vm.get_integer(&retdata_size_addr)?.as_ref())?;
+    let retdata_ptr = vm.get_relocatable(&(retdata_size_addr + 1))?;
+
+    Ok(vm
+        .get_integer_range(&retdata_ptr, retdata_size)?
+        .into_iter()
+        .map(Cow::into_owned)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bigint_str;
+    use crate::hint_processor::builtin_hint_processor::builtin_hint_processor_definition::BuiltinHintProcessor;
+    use crate::types::errors::program_errors::ProgramError;
+    use std::path::Path;
+
+    fn echo_contract() -> ContractClass {
+        ContractClass::from_file(Path::new(
+            "cairo_programs/manually_compiled/echo_contract.json",
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn execute_echo_entry_point() {
+        let hint_processor = BuiltinHintProcessor::new_empty();
+        let selector = bigint_str!(
+            b"301710206996413058962444592991928216677664597027184567149740246420992804240"
+        );
+        let calldata = vec![BigInt::from(3), BigInt::from(-1), BigInt::from(42)];
+
+        let retdata = execute_entry_point(
+            &echo_contract(),
+            EntryPointType::External,
+            &selector,
+            &calldata,
+            &hint_processor,
+        )
+        .unwrap();
+
+        // Calldata is reduced modulo the prime when written into memory.
+        let prime = echo_contract().program.prime;
+        assert_eq!(
+            retdata,
+            vec![BigInt::from(3), prime - 1_u32, BigInt::from(42)]
+        );
+    }
+
+    #[test]
+    fn execute_echo_entry_point_empty_calldata() {
+        let hint_processor = BuiltinHintProcessor::new_empty();
+        let selector = bigint_str!(
+            b"301710206996413058962444592991928216677664597027184567149740246420992804240"
+        );
+
+        let retdata = execute_entry_point(
+            &echo_contract(),
+            EntryPointType::External,
+            &selector,
+            &[],
+            &hint_processor,
+        )
+        .unwrap();
+
+        assert!(retdata.is_empty());
+    }
+
+    #[test]
+    fn execute_missing_entry_point() {
+        let hint_processor = BuiltinHintProcessor::new_empty();
+
+        assert!(matches!(
+            execute_entry_point(
+                &echo_contract(),
+                EntryPointType::L1Handler,
+                &BigInt::from(1),
+                &[],
+                &hint_processor,
+            ),
+            Err(CairoRunError::Program(
+                ProgramError::EntryPointSelectorNotFound(_)
+            ))
+        ));
+    }
+
+    #[test]
+    fn prepare_os_context_with_builtins() {
+        let contract_class = echo_contract();
+        let mut cairo_runner = CairoRunner::new(&contract_class.program, "all", false).unwrap();
+        let mut vm = VirtualMachine::new(contract_class.program.prime.clone(), false, Vec::new());
+        cairo_runner.initialize_function_runner(&mut vm).unwrap();
+
+        let os_context = prepare_os_context(&cairo_runner, &mut vm).unwrap();
+
+        // Program, execution and one segment per builtin come first.
+        let range_check_base = vm
+            .get_builtin_runners()
+            .iter()
+            .find(|(name, _)| name == "range_check")
+            .unwrap()
+            .1
+            .base();
+        assert_eq!(
+            os_context,
+            vec![
+                MaybeRelocatable::from((vm.segments.num_segments as isize - 1, 0)),
+                MaybeRelocatable::from((range_check_base, 0)),
+            ]
+        );
+    }
+}
@@ -0,0 +1,7 @@
+pub mod contract_class;
+pub mod errors;
+pub mod execute_entry_point;
+pub(crate) mod hint_code;
+pub mod state;
+pub mod syscall_handler;
+pub mod syscall_hint_processor;
@@ -0,0 +1,167 @@
+use crate::starknet::contract_class::ContractClass;
+use crate::starknet::errors::state_errors::StateError;
+use num_bigint::BigInt;
+use num_traits::Zero;
+use std::collections::HashMap;
+
+/// Read access to the StarkNet state needed to execute contracts.
+pub trait StateReader {
+    /// Returns the value stored at `key` in the storage of `contract_address`.
+    /// Uninitialized storage cells hold zero.
+    fn get_storage_at(&self, contract_address: &BigInt, key: &BigInt)
+        -> Result<BigInt, StateError>;
+
+    /// Returns the class hash of the contract deployed at `contract_address`.
+    fn get_class_hash_at(&self, contract_address: &BigInt) -> Result<BigInt, StateError>;
+
+    /// Returns the contract class declared with `class_hash`.
+    fn get_contract_class(&self, class_hash: &BigInt) -> Result<ContractClass, StateError>;
+}
+
+/// A StarkNet state that can be modified by contract execution.
+pub trait State: StateReader {
+    fn set_storage_at(&mut self, contract_address: &BigInt, key: &BigInt, value: BigInt);
+
+    fn deploy_contract(
+        &mut self,
+        contract_address: BigInt,
+        class_hash: BigInt,
+    ) -> Result<(), StateError>;
+}
+
+/// A state kept entirely in memory, mostly useful for testing contracts.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InMemoryState {
+    pub storage: HashMap<(BigInt, BigInt), BigInt>,
+    pub class_hashes: HashMap<BigInt, BigInt>,
+    pub contract_classes: HashMap<BigInt, ContractClass>,
+}
+
+impl InMemoryState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn declare_class(&mut self, class_hash: BigInt, contract_class: ContractClass) {
+        self.contract_classes.insert(class_hash, contract_class);
+    }
+}
+
+impl StateReader for InMemoryState {
+    fn get_storage_at(
+        &self,
+        contract_address: &BigInt,
+        key: &BigInt,
+    ) -> Result<BigInt, StateError> {
+        Ok(self
+            .storage
+            .get(&(contract_address.clone(), key.clone()))
+            .cloned()
+            .unwrap_or_else(BigInt::zero))
+    }
+
+    fn get_class_hash_at(&self, contract_address: &BigInt) -> Result<BigInt, StateError> {
+        self.class_hashes
+            .get(contract_address)
+            .cloned()
+            .ok_or_else(|| StateError::NoClassHashForAddress(contract_address.clone()))
+    }
+
+    fn get_contract_class(&self, class_hash: &BigInt) -> Result<ContractClass, StateError> {
+        self.contract_classes
+            .get(class_hash)
+            .cloned()
+            .ok_or_else(|| StateError::MissingContractClass(class_hash.clone()))
+    }
+}
+
+impl State for InMemoryState {
+    fn set_storage_at(&mut self, contract_address: &BigInt, key: &BigInt, value: BigInt) {
+        self.storage
+            .insert((contract_address.clone(), key.clone()), value);
+    }
+
+    fn deploy_contract(
+        &mut self,
+        contract_address: BigInt,
+        class_hash: BigInt,
+    ) -> Result<(), StateError> {
+        if self.class_hashes.contains_key(&contract_address) {
+            return Err(StateError::ContractAddressUnavailable(contract_address));
+        }
+        self.class_hashes.insert(contract_address, class_hash);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bigint;
+    use crate::types::program::Program;
+
+    #[test]
+    fn get_storage_at_uninitialized_key() {
+        let state = InMemoryState::new();
+
+        assert_eq!(
+            state.get_storage_at(&bigint!(1), &bigint!(2)),
+            Ok(bigint!(0))
+        );
+    }
+
+    #[test]
+    fn set_and_get_storage_at() {
+        let mut state = InMemoryState::new();
+        state.set_storage_at(&bigint!(1), &bigint!(2), bigint!(3));
+
+        assert_eq!(
+            state.get_storage_at(&bigint!(1), &bigint!(2)),
+            Ok(bigint!(3))
+        );
+        assert_eq!(
+            state.get_storage_at(&bigint!(2), &bigint!(2)),
+            Ok(bigint!(0))
+        );
+    }
+
+    #[test]
+    fn deploy_contract_and_get_class() {
+        let mut state = InMemoryState::new();
+        let contract_class = ContractClass {
+            program: Program::default(),
+            entry_points_by_type: HashMap::new(),
+            abi: None,
+        };
+        state.declare_class(bigint!(10), contract_class.clone());
+
+        assert_eq!(state.deploy_contract(bigint!(1), bigint!(10)), Ok(()));
+        assert_eq!(state.get_class_hash_at(&bigint!(1)), Ok(bigint!(10)));
+        assert_eq!(state.get_contract_class(&bigint!(10)), Ok(contract_class));
+    }
+
+    #[test]
+    fn deploy_contract_twice() {
+        let mut state = InMemoryState::new();
+
+        assert_eq!(state.deploy_contract(bigint!(1), bigint!(10)), Ok(()));
+        assert_eq!(
+            state.deploy_contract(bigint!(1), bigint!(11)),
+            Err(StateError::ContractAddressUnavailable(bigint!(1)))
+        );
+    }
+
+    #[test]
+    fn get_missing_class_hash_and_contract_class() {
+        let state = InMemoryState::new();
+
+        assert_eq!(
+            state.get_class_hash_at(&bigint!(1)),
+            Err(StateError::NoClassHashForAddress(bigint!(1)))
+        );
+        assert_eq!(
+            state.get_contract_class(&bigint!(1)),
+            Err(StateError::MissingContractClass(bigint!(1)))
+        );
+    }
+}
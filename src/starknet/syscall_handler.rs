@@ -0,0 +1,439 @@
+use crate::starknet::contract_class::EntryPointType;
+use crate::starknet::errors::syscall_handler_errors::SyscallHandlerError;
+use crate::starknet::execute_entry_point::execute_entry_point;
+use crate::starknet::state::State;
+use crate::starknet::syscall_hint_processor::SyscallHintProcessor;
+use num_bigint::BigInt;
+
+/// Semantic operations backing the StarkNet syscalls. `SyscallHintProcessor` decodes the
+/// syscall requests from memory and forwards them to an implementor of this trait.
+pub trait SyscallHandler {
+    fn storage_read(&mut self, address: &BigInt) -> Result<BigInt, SyscallHandlerError>;
+
+    fn storage_write(&mut self, address: &BigInt, value: BigInt)
+        -> Result<(), SyscallHandlerError>;
+
+    /// Calls the external function `function_selector` of the contract deployed at
+    /// `contract_address` and returns its retdata.
+    fn call_contract(
+        &mut self,
+        contract_address: &BigInt,
+        function_selector: &BigInt,
+        calldata: &[BigInt],
+    ) -> Result<Vec<BigInt>, SyscallHandlerError>;
+
+    /// Runs the external function `function_selector` of the class `class_hash` in the
+    /// context of the current contract and returns its retdata.
+    fn library_call(
+        &mut self,
+        class_hash: &BigInt,
+        function_selector: &BigInt,
+        calldata: &[BigInt],
+    ) -> Result<Vec<BigInt>, SyscallHandlerError>;
+
+    fn get_caller_address(&self) -> BigInt;
+
+    fn get_contract_address(&self) -> BigInt;
+
+    fn get_sequencer_address(&self) -> BigInt;
+
+    fn get_block_number(&self) -> BigInt;
+
+    fn get_block_timestamp(&self) -> BigInt;
+
+    fn get_tx_info(&self) -> &TxInfo;
+
+    fn emit_event(&mut self, keys: Vec<BigInt>, data: Vec<BigInt>);
+
+    fn send_message_to_l1(&mut self, to_address: BigInt, payload: Vec<BigInt>);
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BlockInfo {
+    pub block_number: BigInt,
+    pub block_timestamp: BigInt,
+    pub sequencer_address: BigInt,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TxInfo {
+    pub version: BigInt,
+    pub account_contract_address: BigInt,
+    pub max_fee: BigInt,
+    pub signature: Vec<BigInt>,
+    pub transaction_hash: BigInt,
+    pub chain_id: BigInt,
+    pub nonce: BigInt,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Event {
+    pub from_address: BigInt,
+    pub keys: Vec<BigInt>,
+    pub data: Vec<BigInt>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct L2ToL1Message {
+    pub from_address: BigInt,
+    pub to_address: BigInt,
+    pub payload: Vec<BigInt>,
+}
+
+/// Syscall handler executing the syscalls of a contract against a `State`.
+/// Events and messages emitted by the contract, including those emitted by the contracts it
+/// calls, are collected in `events` and `l2_to_l1_messages`.
+/// The storage writes of a failed call, including those of the contracts it called, are reverted.
+pub struct BusinessLogicSyscallHandler<'a, S: State> {
+    state: &'a mut S,
+    pub contract_address: BigInt,
+    pub caller_address: BigInt,
+    pub block_info: BlockInfo,
+    pub tx_info: TxInfo,
+    pub events: Vec<Event>,
+    pub l2_to_l1_messages: Vec<L2ToL1Message>,
+    // Contract address, key and previous value of every storage cell written so far
+    storage_writes: Vec<(BigInt, BigInt, BigInt)>,
+}
+
+impl<'a, S: State> BusinessLogicSyscallHandler<'a, S> {
+    pub fn new(
+        state: &'a mut S,
+        contract_address: BigInt,
+        caller_address: BigInt,
+        block_info: BlockInfo,
+        tx_info: TxInfo,
+    ) -> Self {
+        BusinessLogicSyscallHandler {
+            state,
+            contract_address,
+            caller_address,
+            block_info,
+            tx_info,
+            events: Vec::new(),
+            l2_to_l1_messages: Vec::new(),
+            storage_writes: Vec::new(),
+        }
+    }
+
+    pub fn state(&self) -> &S {
+        self.state
+    }
+
+    fn execute_call(
+        &mut self,
+        class_hash: &BigInt,
+        contract_address: BigInt,
+        caller_address: BigInt,
+        function_selector: &BigInt,
+        calldata: &[BigInt],
+    ) -> Result<Vec<BigInt>, SyscallHandlerError> {
+        let contract_class = self.state.get_contract_class(class_hash)?;
+        let failed_address = contract_address.clone();
+        let hint_processor = SyscallHintProcessor::new(BusinessLogicSyscallHandler::new(
+            &mut *self.state,
+            contract_address,
+            caller_address,
+            self.block_info.clone(),
+            self.tx_info.clone(),
+        ));
+
+        let result = execute_entry_point(
+            &contract_class,
+            EntryPointType::External,
+            function_selector,
+            calldata,
+            &hint_processor,
+        );
+
+        let callee = hint_processor.syscall_handler.into_inner();
+        match result {
+            Ok(retdata) => {
+                self.events.extend(callee.events);
+                self.l2_to_l1_messages.extend(callee.l2_to_l1_messages);
+                self.storage_writes.extend(callee.storage_writes);
+                Ok(retdata)
+            }
+            Err(err) => {
+                callee.revert_storage_writes();
+                Err(SyscallHandlerError::CallFailed(
+                    failed_address,
+                    err.to_string(),
+                ))
+            }
+        }
+    }
+
+    // Restores the storage cells written by the call to their previous values, latest first
+    fn revert_storage_writes(self) {
+        for (contract_address, key, value) in self.storage_writes.into_iter().rev() {
+            self.state.set_storage_at(&contract_address, &key, value);
+        }
+    }
+}
+
+impl<'a, S: State> SyscallHandler for BusinessLogicSyscallHandler<'a, S> {
+    fn storage_read(&mut self, address: &BigInt) -> Result<BigInt, SyscallHandlerError> {
+        Ok(self.state.get_storage_at(&self.contract_address, address)?)
+    }
+
+    fn storage_write(
+        &mut self,
+        address: &BigInt,
+        value: BigInt,
+    ) -> Result<(), SyscallHandlerError> {
+        let previous_value = self.state.get_storage_at(&self.contract_address, address)?;
+        self.storage_writes.push((
+            self.contract_address.clone(),
+            address.clone(),
+            previous_value,
+        ));
+        self.state
+            .set_storage_at(&self.contract_address, address, value);
+        Ok(())
+    }
+
+    fn call_contract(
+        &mut self,
+        contract_address: &BigInt,
+        function_selector: &BigInt,
+        calldata: &[BigInt],
+    ) -> Result<Vec<BigInt>, SyscallHandlerError> {
+        let class_hash = self.state.get_class_hash_at(contract_address)?;
+        self.execute_call(
+            &class_hash,
+            contract_address.clone(),
+            self.contract_address.clone(),
+            function_selector,
+            calldata,
+        )
+    }
+
+    fn library_call(
+        &mut self,
+        class_hash: &BigInt,
+        function_selector: &BigInt,
+        calldata: &[BigInt],
+    ) -> Result<Vec<BigInt>, SyscallHandlerError> {
+        self.execute_call(
+            class_hash,
+            self.contract_address.clone(),
+            self.caller_address.clone(),
+            function_selector,
+            calldata,
+        )
+    }
+
+    fn get_caller_address(&self) -> BigInt {
+        self.caller_address.clone()
+    }
+
+    fn get_contract_address(&self) -> BigInt {
+        self.contract_address.clone()
+    }
+
+    fn get_sequencer_address(&self) -> BigInt {
+        self.block_info.sequencer_address.clone()
+    }
+
+    fn get_block_number(&self) -> BigInt {
+        self.block_info.block_number.clone()
+    }
+
+    fn get_block_timestamp(&self) -> BigInt {
+        self.block_info.block_timestamp.clone()
+    }
+
+    fn get_tx_info(&self) -> &TxInfo {
+        &self.tx_info
+    }
+
+    fn emit_event(&mut self, keys: Vec<BigInt>, data: Vec<BigInt>) {
+        self.events.push(Event {
+            from_address: self.contract_address.clone(),
+            keys,
+            data,
+        });
+    }
+
+    fn send_message_to_l1(&mut self, to_address: BigInt, payload: Vec<BigInt>) {
+        self.l2_to_l1_messages.push(L2ToL1Message {
+            from_address: self.contract_address.clone(),
+            to_address,
+            payload,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bigint;
+    use crate::bigint_str;
+    use crate::starknet::contract_class::ContractClass;
+    use crate::starknet::errors::state_errors::StateError;
+    use crate::starknet::state::{InMemoryState, StateReader};
+    use std::path::Path;
+
+    fn echo_selector() -> BigInt {
+        bigint_str!(b"301710206996413058962444592991928216677664597027184567149740246420992804240")
+    }
+
+    fn state_with_echo_contract() -> InMemoryState {
+        let mut state = InMemoryState::new();
+        let contract_class = ContractClass::from_file(Path::new(
+            "cairo_programs/manually_compiled/echo_contract.json",
+        ))
+        .unwrap();
+        state.declare_class(bigint!(100), contract_class);
+        state.deploy_contract(bigint!(7), bigint!(100)).unwrap();
+        state
+    }
+
+    #[test]
+    fn storage_write_and_read() {
+        let mut state = InMemoryState::new();
+        let mut handler = BusinessLogicSyscallHandler::new(
+            &mut state,
+            bigint!(1),
+            bigint!(0),
+            BlockInfo::default(),
+            TxInfo::default(),
+        );
+
+        assert_eq!(handler.storage_read(&bigint!(5)), Ok(bigint!(0)));
+        assert_eq!(handler.storage_write(&bigint!(5), bigint!(8)), Ok(()));
+        assert_eq!(handler.storage_read(&bigint!(5)), Ok(bigint!(8)));
+        assert_eq!(
+            state.get_storage_at(&bigint!(1), &bigint!(5)),
+            Ok(bigint!(8))
+        );
+    }
+
+    #[test]
+    fn revert_storage_writes() {
+        let mut state = InMemoryState::new();
+        state.set_storage_at(&bigint!(1), &bigint!(5), bigint!(2));
+        let mut handler = BusinessLogicSyscallHandler::new(
+            &mut state,
+            bigint!(1),
+            bigint!(0),
+            BlockInfo::default(),
+            TxInfo::default(),
+        );
+        handler.storage_write(&bigint!(5), bigint!(8)).unwrap();
+        handler.storage_write(&bigint!(5), bigint!(9)).unwrap();
+        handler.storage_write(&bigint!(6), bigint!(3)).unwrap();
+        handler.revert_storage_writes();
+
+        assert_eq!(
+            state.get_storage_at(&bigint!(1), &bigint!(5)),
+            Ok(bigint!(2))
+        );
+        assert_eq!(
+            state.get_storage_at(&bigint!(1), &bigint!(6)),
+            Ok(bigint!(0))
+        );
+    }
+
+    #[test]
+    fn emit_event_and_send_message() {
+        let mut state = InMemoryState::new();
+        let mut handler = BusinessLogicSyscallHandler::new(
+            &mut state,
+            bigint!(1),
+            bigint!(0),
+            BlockInfo::default(),
+            TxInfo::default(),
+        );
+        handler.emit_event(vec![bigint!(2)], vec![bigint!(3), bigint!(4)]);
+        handler.send_message_to_l1(bigint!(5), vec![bigint!(6)]);
+
+        assert_eq!(
+            handler.events,
+            vec![Event {
+                from_address: bigint!(1),
+                keys: vec![bigint!(2)],
+                data: vec![bigint!(3), bigint!(4)],
+            }]
+        );
+        assert_eq!(
+            handler.l2_to_l1_messages,
+            vec![L2ToL1Message {
+                from_address: bigint!(1),
+                to_address: bigint!(5),
+                payload: vec![bigint!(6)],
+            }]
+        );
+    }
+
+    #[test]
+    fn call_contract_echo() {
+        let mut state = state_with_echo_contract();
+        let mut handler = BusinessLogicSyscallHandler::new(
+            &mut state,
+            bigint!(1),
+            bigint!(0),
+            BlockInfo::default(),
+            TxInfo::default(),
+        );
+
+        assert_eq!(
+            handler.call_contract(&bigint!(7), &echo_selector(), &[bigint!(1), bigint!(2)]),
+            Ok(vec![bigint!(1), bigint!(2)])
+        );
+    }
+
+    #[test]
+    fn library_call_echo() {
+        let mut state = state_with_echo_contract();
+        let mut handler = BusinessLogicSyscallHandler::new(
+            &mut state,
+            bigint!(1),
+            bigint!(0),
+            BlockInfo::default(),
+            TxInfo::default(),
+        );
+
+        assert_eq!(
+            handler.library_call(&bigint!(100), &echo_selector(), &[bigint!(3)]),
+            Ok(vec![bigint!(3)])
+        );
+    }
+
+    #[test]
+    fn call_contract_not_deployed() {
+        let mut state = InMemoryState::new();
+        let mut handler = BusinessLogicSyscallHandler::new(
+            &mut state,
+            bigint!(1),
+            bigint!(0),
+            BlockInfo::default(),
+            TxInfo::default(),
+        );
+
+        assert_eq!(
+            handler.call_contract(&bigint!(7), &echo_selector(), &[]),
+            Err(SyscallHandlerError::State(
+                StateError::NoClassHashForAddress(bigint!(7))
+            ))
+        );
+    }
+
+    #[test]
+    fn call_contract_missing_entry_point() {
+        let mut state = state_with_echo_contract();
+        let mut handler = BusinessLogicSyscallHandler::new(
+            &mut state,
+            bigint!(1),
+            bigint!(0),
+            BlockInfo::default(),
+            TxInfo::default(),
+        );
+
+        assert!(matches!(
+            handler.call_contract(&bigint!(7), &bigint!(1), &[]),
+            Err(SyscallHandlerError::CallFailed(address, _)) if address == bigint!(7)
+        ));
+    }
+}
@@ -0,0 +1,584 @@
+use crate::any_box;
+use crate::hint_processor::builtin_hint_processor::builtin_hint_processor_definition::{
+    get_ids_data, BuiltinHintProcessor, HintProcessorData,
+};
+use crate::hint_processor::builtin_hint_processor::hint_utils::get_ptr_from_var_name;
+use crate::hint_processor::hint_processor_definition::{HintProcessor, HintReference};
+use crate::hint_processor::hint_processor_utils::bigint_to_usize;
+use crate::serde::deserialize_program::ApTracking;
+use crate::starknet::errors::syscall_handler_errors::SyscallHandlerError;
+use crate::starknet::hint_code;
+use crate::starknet::syscall_handler::SyscallHandler;
+use crate::types::exec_scope::ExecutionScopes;
+use crate::types::relocatable::{MaybeRelocatable, Relocatable};
+use crate::vm::errors::vm_errors::VirtualMachineError;
+use crate::vm::vm_core::VirtualMachine;
+use num_bigint::{BigInt, Sign};
+use std::any::Any;
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+/// Hint processor executing the StarkNet syscall hints through a `SyscallHandler`.
+/// Every other hint is delegated to the `BuiltinHintProcessor`.
+pub struct SyscallHintProcessor<H: SyscallHandler> {
+    builtin_hint_processor: BuiltinHintProcessor,
+    pub syscall_handler: RefCell<H>,
+}
+
+impl<H: SyscallHandler> SyscallHintProcessor<H> {
+    pub fn new(syscall_handler: H) -> Self {
+        SyscallHintProcessor {
+            builtin_hint_processor: BuiltinHintProcessor::new_empty(),
+            syscall_handler: RefCell::new(syscall_handler),
+        }
+    }
+
+    fn execute_syscall(
+        &self,
+        vm: &mut VirtualMachine,
+        hint_data: &HintProcessorData,
+    ) -> Result<bool, VirtualMachineError> {
+        let ids_data = &hint_data.ids_data;
+        let ap_tracking = &hint_data.ap_tracking;
+        let mut syscall_handler = self.syscall_handler.borrow_mut();
+
+        match &*hint_data.code {
+            hint_code::STORAGE_READ => {
+                let syscall_ptr = syscall_request(vm, ids_data, ap_tracking, "StorageRead")?;
+                let address = vm.get_integer(&(syscall_ptr.clone() + 1))?.into_owned();
+                let value = syscall_handler.storage_read(&address)?;
+                vm.insert_value(&(syscall_ptr + 2), value)?;
+            }
+            hint_code::STORAGE_WRITE => {
+                let syscall_ptr = syscall_request(vm, ids_data, ap_tracking, "StorageWrite")?;
+                let address = vm.get_integer(&(syscall_ptr.clone() + 1))?.into_owned();
+                let value = vm.get_integer(&(syscall_ptr + 2))?.into_owned();
+                syscall_handler.storage_write(&address, value)?;
+            }
+            hint_code::CALL_CONTRACT | hint_code::LIBRARY_CALL => {
+                let (selector, is_library_call) = if hint_data.code == hint_code::CALL_CONTRACT {
+                    ("CallContract", false)
+                } else {
+                    ("LibraryCall", true)
+                };
+                let syscall_ptr = syscall_request(vm, ids_data, ap_tracking, selector)?;
+                let target = vm.get_integer(&(syscall_ptr.clone() + 1))?.into_owned();
+                let function_selector = vm.get_integer(&(syscall_ptr.clone() + 2))?.into_owned();
+                let calldata = get_felt_array(vm, &(syscall_ptr.clone() + 3))?;
+
+                let retdata = if is_library_call {
+                    syscall_handler.library_call(&target, &function_selector, &calldata)?
+                } else {
+                    syscall_handler.call_contract(&target, &function_selector, &calldata)?
+                };
+                write_felt_array(vm, &(syscall_ptr + 5), retdata)?;
+            }
+            hint_code::GET_CALLER_ADDRESS => {
+                let syscall_ptr = syscall_request(vm, ids_data, ap_tracking, "GetCallerAddress")?;
+                vm.insert_value(&(syscall_ptr + 1), syscall_handler.get_caller_address())?;
+            }
+            hint_code::GET_CONTRACT_ADDRESS => {
+                let syscall_ptr = syscall_request(vm, ids_data, ap_tracking, "GetContractAddress")?;
+                vm.insert_value(&(syscall_ptr + 1), syscall_handler.get_contract_address())?;
+            }
+            hint_code::GET_SEQUENCER_ADDRESS => {
+                let syscall_ptr =
+                    syscall_request(vm, ids_data, ap_tracking, "GetSequencerAddress")?;
+                vm.insert_value(&(syscall_ptr + 1), syscall_handler.get_sequencer_address())?;
+            }
+            hint_code::GET_BLOCK_NUMBER => {
+                let syscall_ptr = syscall_request(vm, ids_data, ap_tracking, "GetBlockNumber")?;
+                vm.insert_value(&(syscall_ptr + 1), syscall_handler.get_block_number())?;
+            }
+            hint_code::GET_BLOCK_TIMESTAMP => {
+                let syscall_ptr = syscall_request(vm, ids_data, ap_tracking, "GetBlockTimestamp")?;
+                vm.insert_value(&(syscall_ptr + 1), syscall_handler.get_block_timestamp())?;
+            }
+            hint_code::GET_TX_SIGNATURE => {
+                let syscall_ptr = syscall_request(vm, ids_data, ap_tracking, "GetTxSignature")?;
+                let signature = syscall_handler.get_tx_info().signature.clone();
+                write_felt_array(vm, &(syscall_ptr + 1), signature)?;
+            }
+            hint_code::GET_TX_INFO => {
+                let syscall_ptr = syscall_request(vm, ids_data, ap_tracking, "GetTxInfo")?;
+                let tx_info = syscall_handler.get_tx_info();
+                let signature = load_felts(vm, tx_info.signature.clone())?;
+                let tx_info_ptr = load_data(
+                    vm,
+                    vec![
+                        tx_info.version.clone().into(),
+                        tx_info.account_contract_address.clone().into(),
+                        tx_info.max_fee.clone().into(),
+                        BigInt::from(tx_info.signature.len()).into(),
+                        signature.into(),
+                        tx_info.transaction_hash.clone().into(),
+                        tx_info.chain_id.clone().into(),
+                        tx_info.nonce.clone().into(),
+                    ],
+                )?;
+                vm.insert_value(&(syscall_ptr + 1), tx_info_ptr)?;
+            }
+            hint_code::EMIT_EVENT => {
+                let syscall_ptr = syscall_request(vm, ids_data, ap_tracking, "EmitEvent")?;
+                let keys = get_felt_array(vm, &(syscall_ptr.clone() + 1))?;
+                let data = get_felt_array(vm, &(syscall_ptr + 3))?;
+                syscall_handler.emit_event(keys, data);
+            }
+            hint_code::SEND_MESSAGE_TO_L1 => {
+                let syscall_ptr = syscall_request(vm, ids_data, ap_tracking, "SendMessageToL1")?;
+                let to_address = vm.get_integer(&(syscall_ptr.clone() + 1))?.into_owned();
+                let payload = get_felt_array(vm, &(syscall_ptr + 2))?;
+                syscall_handler.send_message_to_l1(to_address, payload);
+            }
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+}
+
+impl<H: SyscallHandler> HintProcessor for SyscallHintProcessor<H> {
+    fn execute_hint(
+        &self,
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        hint_data: &Box<dyn Any>,
+        constants: &HashMap<String, BigInt>,
+    ) -> Result<(), VirtualMachineError> {
+        // Hints other than syscalls are compiled by the builtin hint processor, into its own data
+        if let Some(syscall_hint_data) = hint_data.downcast_ref::<HintProcessorData>() {
+            if self.execute_syscall(vm, syscall_hint_data)? {
+                return Ok(());
+            }
+        }
+        self.builtin_hint_processor
+            .execute_hint(vm, exec_scopes, hint_data, constants)
+    }
+
+    fn compile_hint(
+        &self,
+        code: &str,
+        accessible_scopes: &[String],
+        ap_tracking: &ApTracking,
+        reference_ids: &HashMap<String, usize>,
+        references: &HashMap<usize, HintReference>,
+    ) -> Result<Box<dyn Any>, VirtualMachineError> {
+        if !is_syscall_hint(code) {
+            return self.builtin_hint_processor.compile_hint(
+                code,
+                accessible_scopes,
+                ap_tracking,
+                reference_ids,
+                references,
+            );
+        }
+        let mut hint_data = HintProcessorData::new_default(
+            code.to_string(),
+            get_ids_data(reference_ids, references)?,
+        );
+        hint_data.ap_tracking = ap_tracking.clone();
+        hint_data.accessible_scopes = accessible_scopes.to_vec();
+        Ok(any_box!(hint_data))
+    }
+
+    fn supports_hint(&self, hint_code: &str) -> bool {
+        is_syscall_hint(hint_code) || self.builtin_hint_processor.supports_hint(hint_code)
+    }
+}
+
+fn is_syscall_hint(code: &str) -> bool {
+    matches!(
+        code,
+        hint_code::CALL_CONTRACT
+            | hint_code::LIBRARY_CALL
+            | hint_code::GET_CALLER_ADDRESS
+            | hint_code::GET_CONTRACT_ADDRESS
+            | hint_code::GET_SEQUENCER_ADDRESS
+            | hint_code::GET_BLOCK_NUMBER
+            | hint_code::GET_BLOCK_TIMESTAMP
+            | hint_code::GET_TX_SIGNATURE
+            | hint_code::GET_TX_INFO
+            | hint_code::STORAGE_READ
+            | hint_code::STORAGE_WRITE
+            | hint_code::EMIT_EVENT
+            | hint_code::SEND_MESSAGE_TO_L1
+    )
+}
+
+// Reads ids.syscall_ptr and checks that the request it points to has the expected selector.
+// Selectors are the syscall names encoded as short strings.
+fn syscall_request(
+    vm: &VirtualMachine,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+    syscall_name: &'static str,
+) -> Result<Relocatable, VirtualMachineError> {
+    let syscall_ptr = get_ptr_from_var_name("syscall_ptr", vm, ids_data, ap_tracking)?;
+    let selector = vm.get_integer(&syscall_ptr)?;
+    if selector.as_ref() != &BigInt::from_bytes_be(Sign::Plus, syscall_name.as_bytes()) {
+        return Err(SyscallHandlerError::UnexpectedSyscallSelector(
+            syscall_name,
+            selector.into_owned(),
+        )
+        .into());
+    }
+    Ok(syscall_ptr)
+}
+
+// Reads an array given by its length at `addr` and a pointer to its elements at `addr + 1`
+fn get_felt_array(
+    vm: &VirtualMachine,
+    addr: &Relocatable,
+) -> Result<Vec<BigInt>, VirtualMachineError> {
+    let len = bigint_to_usize(vm.get_integer(addr)?.as_ref())?;
+    let ptr = vm.get_relocatable(&(addr + 1))?;
+    Ok(vm
+        .get_integer_range(&ptr, len)?
+        .into_iter()
+        .map(Cow::into_owned)
+        .collect())
+}
+
+// Writes `values` into a new segment, then its length at `addr` and its base at `addr + 1`
+fn write_felt_array(
+    vm: &mut VirtualMachine,
+    addr: &Relocatable,
+    values: Vec<BigInt>,
+) -> Result<(), VirtualMachineError> {
+    vm.insert_value(addr, BigInt::from(values.len()))?;
+    let ptr = load_felts(vm, values)?;
+    vm.insert_value(&(addr + 1), ptr)
+}
+
+fn load_felts(
+    vm: &mut VirtualMachine,
+    values: Vec<BigInt>,
+) -> Result<Relocatable, VirtualMachineError> {
+    load_data(vm, values.into_iter().map(MaybeRelocatable::from).collect())
+}
+
+fn load_data(
+    vm: &mut VirtualMachine,
+    data: Vec<MaybeRelocatable>,
+) -> Result<Relocatable, VirtualMachineError> {
+    let base = vm.add_memory_segment();
+    vm.load_data(&MaybeRelocatable::from(base.clone()), data)?;
+    Ok(base)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bigint;
+    use crate::bigint_str;
+    use crate::starknet::state::{InMemoryState, StateReader};
+    use crate::starknet::syscall_handler::{BlockInfo, BusinessLogicSyscallHandler, Event, TxInfo};
+    use crate::utils::test_utils::*;
+    use crate::vm::errors::memory_errors::MemoryError;
+    use crate::vm::vm_memory::memory::Memory;
+
+    fn short_string(value: &str) -> BigInt {
+        BigInt::from_bytes_be(Sign::Plus, value.as_bytes())
+    }
+
+    fn echo_selector() -> BigInt {
+        bigint_str!(b"301710206996413058962444592991928216677664597027184567149740246420992804240")
+    }
+
+    fn execute_syscall_hint<H: SyscallHandler>(
+        vm: &mut VirtualMachine,
+        hint_processor: &SyscallHintProcessor<H>,
+        code: &str,
+    ) -> Result<(), VirtualMachineError> {
+        let ids_data = ids_data!["syscall_ptr"];
+        let hint_data = HintProcessorData::new_default(code.to_string(), ids_data);
+        hint_processor.execute_hint(
+            vm,
+            exec_scopes_ref!(),
+            &any_box!(hint_data),
+            &HashMap::new(),
+        )
+    }
+
+    fn test_handler(state: &mut InMemoryState) -> BusinessLogicSyscallHandler<InMemoryState> {
+        BusinessLogicSyscallHandler::new(
+            state,
+            bigint!(1),
+            bigint!(2),
+            BlockInfo {
+                block_number: bigint!(3),
+                block_timestamp: bigint!(4),
+                sequencer_address: bigint!(5),
+            },
+            TxInfo {
+                signature: vec![bigint!(6), bigint!(7)],
+                ..TxInfo::default()
+            },
+        )
+    }
+
+    #[test]
+    fn storage_write_and_read_syscalls() {
+        let mut vm = vm!();
+        vm.run_context.fp = 1;
+        vm.memory = memory![
+            ((1, 0), (2, 0)),
+            ((2, 0), (short_string("StorageWrite"))),
+            ((2, 1), 10),
+            ((2, 2), 20),
+            ((2, 3), (short_string("StorageRead"))),
+            ((2, 4), 10)
+        ];
+        vm.segments.num_segments = vm.memory.data.len();
+        let mut state = InMemoryState::new();
+        let hint_processor = SyscallHintProcessor::new(test_handler(&mut state));
+
+        assert_eq!(
+            execute_syscall_hint(&mut vm, &hint_processor, hint_code::STORAGE_WRITE),
+            Ok(())
+        );
+        vm.memory.data[1][0] = Some(MaybeRelocatable::from((2, 3)));
+        assert_eq!(
+            execute_syscall_hint(&mut vm, &hint_processor, hint_code::STORAGE_READ),
+            Ok(())
+        );
+        check_memory![vm.memory, ((2, 5), 20)];
+
+        drop(hint_processor);
+        assert_eq!(
+            state.get_storage_at(&bigint!(1), &bigint!(10)),
+            Ok(bigint!(20))
+        );
+    }
+
+    #[test]
+    fn storage_read_wrong_selector() {
+        let mut vm = vm!();
+        vm.run_context.fp = 1;
+        vm.memory = memory![((1, 0), (2, 0)), ((2, 0), (short_string("StorageWrite")))];
+        vm.segments.num_segments = vm.memory.data.len();
+        let mut state = InMemoryState::new();
+        let hint_processor = SyscallHintProcessor::new(test_handler(&mut state));
+
+        assert_eq!(
+            execute_syscall_hint(&mut vm, &hint_processor, hint_code::STORAGE_READ),
+            Err(VirtualMachineError::SyscallHandler(
+                SyscallHandlerError::UnexpectedSyscallSelector(
+                    "StorageRead",
+                    short_string("StorageWrite")
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn get_block_info_syscalls() {
+        let mut vm = vm!();
+        vm.run_context.fp = 1;
+        vm.memory = memory![
+            ((1, 0), (2, 0)),
+            ((2, 0), (short_string("GetCallerAddress"))),
+            ((2, 2), (short_string("GetContractAddress"))),
+            ((2, 4), (short_string("GetBlockNumber"))),
+            ((2, 6), (short_string("GetBlockTimestamp"))),
+            ((2, 8), (short_string("GetSequencerAddress")))
+        ];
+        vm.segments.num_segments = vm.memory.data.len();
+        let mut state = InMemoryState::new();
+        let hint_processor = SyscallHintProcessor::new(test_handler(&mut state));
+
+        for (offset, code) in [
+            hint_code::GET_CALLER_ADDRESS,
+            hint_code::GET_CONTRACT_ADDRESS,
+            hint_code::GET_BLOCK_NUMBER,
+            hint_code::GET_BLOCK_TIMESTAMP,
+            hint_code::GET_SEQUENCER_ADDRESS,
+        ]
+        .iter()
+        .enumerate()
+        {
+            vm.memory.data[1][0] = Some(MaybeRelocatable::from((2, 2 * offset)));
+            assert_eq!(execute_syscall_hint(&mut vm, &hint_processor, code), Ok(()));
+        }
+        check_memory![
+            vm.memory,
+            ((2, 1), 2),
+            ((2, 3), 1),
+            ((2, 5), 3),
+            ((2, 7), 4),
+            ((2, 9), 5)
+        ];
+    }
+
+    #[test]
+    fn get_tx_signature_syscall() {
+        let mut vm = vm!();
+        vm.run_context.fp = 1;
+        vm.memory = memory![((1, 0), (2, 0)), ((2, 0), (short_string("GetTxSignature")))];
+        vm.segments.num_segments = vm.memory.data.len();
+        let mut state = InMemoryState::new();
+        let hint_processor = SyscallHintProcessor::new(test_handler(&mut state));
+
+        assert_eq!(
+            execute_syscall_hint(&mut vm, &hint_processor, hint_code::GET_TX_SIGNATURE),
+            Ok(())
+        );
+        check_memory![
+            vm.memory,
+            ((2, 1), 2),
+            ((2, 2), (3, 0)),
+            ((3, 0), 6),
+            ((3, 1), 7)
+        ];
+    }
+
+    #[test]
+    fn get_tx_info_syscall() {
+        let mut vm = vm!();
+        vm.run_context.fp = 1;
+        vm.memory = memory![((1, 0), (2, 0)), ((2, 0), (short_string("GetTxInfo")))];
+        vm.segments.num_segments = vm.memory.data.len();
+        let mut state = InMemoryState::new();
+        let hint_processor = SyscallHintProcessor::new(test_handler(&mut state));
+
+        assert_eq!(
+            execute_syscall_hint(&mut vm, &hint_processor, hint_code::GET_TX_INFO),
+            Ok(())
+        );
+        check_memory![
+            vm.memory,
+            ((2, 1), (4, 0)),
+            ((3, 0), 6),
+            ((3, 1), 7),
+            ((4, 3), 2),
+            ((4, 4), (3, 0))
+        ];
+    }
+
+    #[test]
+    fn emit_event_syscall() {
+        let mut vm = vm!();
+        vm.run_context.fp = 1;
+        vm.memory = memory![
+            ((1, 0), (2, 0)),
+            ((2, 0), (short_string("EmitEvent"))),
+            ((2, 1), 1),
+            ((2, 2), (3, 0)),
+            ((2, 3), 2),
+            ((2, 4), (3, 1)),
+            ((3, 0), 8),
+            ((3, 1), 9),
+            ((3, 2), 10)
+        ];
+        vm.segments.num_segments = vm.memory.data.len();
+        let mut state = InMemoryState::new();
+        let hint_processor = SyscallHintProcessor::new(test_handler(&mut state));
+
+        assert_eq!(
+            execute_syscall_hint(&mut vm, &hint_processor, hint_code::EMIT_EVENT),
+            Ok(())
+        );
+        assert_eq!(
+            hint_processor.syscall_handler.borrow().events,
+            vec![Event {
+                from_address: bigint!(1),
+                keys: vec![bigint!(8)],
+                data: vec![bigint!(9), bigint!(10)],
+            }]
+        );
+    }
+
+    #[test]
+    fn call_contract_syscall() {
+        let mut vm = vm!();
+        vm.run_context.fp = 1;
+        vm.memory = memory![
+            ((1, 0), (2, 0)),
+            ((2, 0), (short_string("CallContract"))),
+            ((2, 1), 7),
+            ((2, 2), (echo_selector())),
+            ((2, 3), 2),
+            ((2, 4), (3, 0)),
+            ((3, 0), 11),
+            ((3, 1), 12)
+        ];
+        vm.segments.num_segments = vm.memory.data.len();
+        let mut state = InMemoryState::new();
+        state.declare_class(
+            bigint!(100),
+            crate::starknet::contract_class::ContractClass::from_file(std::path::Path::new(
+                "cairo_programs/manually_compiled/echo_contract.json",
+            ))
+            .unwrap(),
+        );
+        crate::starknet::state::State::deploy_contract(&mut state, bigint!(7), bigint!(100))
+            .unwrap();
+        let hint_processor = SyscallHintProcessor::new(test_handler(&mut state));
+
+        assert_eq!(
+            execute_syscall_hint(&mut vm, &hint_processor, hint_code::CALL_CONTRACT),
+            Ok(())
+        );
+        check_memory![
+            vm.memory,
+            ((2, 5), 2),
+            ((2, 6), (4, 0)),
+            ((4, 0), 11),
+            ((4, 1), 12)
+        ];
+    }
+
+    #[test]
+    fn compile_syscall_and_builtin_hints() {
+        let mut state = InMemoryState::new();
+        let hint_processor = SyscallHintProcessor::new(test_handler(&mut state));
+
+        for code in [hint_code::STORAGE_READ, "memory[ap] = segments.add()"] {
+            assert!(hint_processor.supports_hint(code));
+            assert!(hint_processor
+                .compile_hint(
+                    code,
+                    &[],
+                    &ApTracking::new(),
+                    &HashMap::new(),
+                    &HashMap::new()
+                )
+                .is_ok());
+        }
+        assert!(!hint_processor.supports_hint("unknown"));
+        assert_eq!(
+            hint_processor
+                .compile_hint(
+                    "unknown",
+                    &[],
+                    &ApTracking::new(),
+                    &HashMap::new(),
+                    &HashMap::new()
+                )
+                .err(),
+            Some(VirtualMachineError::UnknownHint("unknown".to_string()))
+        );
+    }
+
+    #[test]
+    fn delegates_non_syscall_hints() {
+        let mut vm = vm!();
+        let mut state = InMemoryState::new();
+        let hint_processor = SyscallHintProcessor::new(test_handler(&mut state));
+        let hint_data = HintProcessorData::new_default(
+            "memory[ap] = segments.add()".to_string(),
+            HashMap::new(),
+        );
+        add_segments!(vm, 2);
+
+        assert_eq!(
+            hint_processor.execute_hint(
+                &mut vm,
+                exec_scopes_ref!(),
+                &any_box!(hint_data),
+                &HashMap::new()
+            ),
+            Ok(())
+        );
+    }
+}
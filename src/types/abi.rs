@@ -0,0 +1,505 @@
+use crate::serde::deserialize_program::Member;
+use crate::types::program::Program;
+use crate::types::relocatable::{MaybeRelocatable, Relocatable};
+use crate::vm::errors::runner_errors::RunnerError;
+use crate::vm::errors::vm_errors::VirtualMachineError;
+use crate::vm::vm_core::VirtualMachine;
+use num_bigint::BigInt;
+use num_traits::ToPrimitive;
+use serde::{Serialize, Serializer};
+
+/// Type of a Cairo value, parsed from the `cairo_type` strings of the program identifiers
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CairoType {
+    Felt,
+    Pointer(Box<CairoType>),
+    /// Tuple members can be named, as in `(x: felt, y: felt)`
+    Tuple(Vec<(Option<String>, CairoType)>),
+    /// Full name of the struct, e.g. `starkware.cairo.common.uint256.Uint256`
+    Struct(String),
+}
+
+impl CairoType {
+    pub fn parse(cairo_type: &str) -> Result<CairoType, RunnerError> {
+        let cairo_type = cairo_type.trim();
+        if let Some(pointee) = cairo_type.strip_suffix('*') {
+            return Ok(CairoType::Pointer(Box::new(CairoType::parse(pointee)?)));
+        }
+        if let Some(members) = cairo_type
+            .strip_prefix('(')
+            .and_then(|members| members.strip_suffix(')'))
+        {
+            let mut tuple = Vec::new();
+            for member in split_tuple_members(members)
+                .ok_or_else(|| RunnerError::InvalidCairoType(cairo_type.to_string()))?
+            {
+                tuple.push(match member.split_once(':') {
+                    Some((name, member_type)) if !name.contains('(') => (
+                        Some(name.trim().to_string()),
+                        CairoType::parse(member_type)?,
+                    ),
+                    _ => (None, CairoType::parse(member)?),
+                });
+            }
+            return Ok(CairoType::Tuple(tuple));
+        }
+        match cairo_type {
+            "" => Err(RunnerError::InvalidCairoType(cairo_type.to_string())),
+            "felt" | "codeoffset" => Ok(CairoType::Felt),
+            _ => Ok(CairoType::Struct(cairo_type.to_string())),
+        }
+    }
+
+    /// Number of memory cells taken by a value of this type
+    pub fn size(&self, program: &Program) -> Result<usize, RunnerError> {
+        match self {
+            CairoType::Felt | CairoType::Pointer(_) => Ok(1),
+            CairoType::Tuple(members) => members
+                .iter()
+                .map(|(_, member_type)| member_type.size(program))
+                .sum(),
+            CairoType::Struct(name) => {
+                let mut size = 0;
+                for (_, member) in get_struct_members(program, name)? {
+                    size = size
+                        .max(member.offset + CairoType::parse(&member.cairo_type)?.size(program)?);
+                }
+                Ok(size)
+            }
+        }
+    }
+}
+
+// Splits the members of a tuple at the commas that aren't nested in inner tuples, None if the
+// parentheses aren't balanced
+fn split_tuple_members(members: &str) -> Option<Vec<&str>> {
+    let mut split = Vec::new();
+    let (mut depth, mut start) = (0usize, 0);
+    for (index, c) in members.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.checked_sub(1)?,
+            ',' if depth == 0 => {
+                split.push(&members[start..index]);
+                start = index + 1;
+            }
+            _ => (),
+        }
+    }
+    if depth != 0 {
+        return None;
+    }
+    // Allow the trailing comma of single element tuples, e.g. `(felt,)`
+    if !members[start..].trim().is_empty() {
+        split.push(&members[start..]);
+    }
+    Some(split)
+}
+
+/// Value read from memory according to its Cairo type. Arrays are recognized by the
+/// `x_len: felt, x: T*` member convention.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CairoValue {
+    Felt(BigInt),
+    Pointer(Relocatable),
+    Tuple(Vec<CairoValue>),
+    /// Members of a named tuple or of a struct, in memory order
+    Struct(Vec<(String, CairoValue)>),
+    Array(Vec<CairoValue>),
+}
+
+/// Felts are serialized as decimal strings and pointers as `segment:offset` strings. Structs and
+/// named tuples become json objects.
+impl Serialize for CairoValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            CairoValue::Felt(value) => serializer.collect_str(value),
+            CairoValue::Pointer(address) => serializer.collect_str(&format_args!(
+                "{}:{}",
+                address.segment_index, address.offset
+            )),
+            CairoValue::Tuple(values) | CairoValue::Array(values) => serializer.collect_seq(values),
+            CairoValue::Struct(members) => {
+                serializer.collect_map(members.iter().map(|(name, value)| (name, value)))
+            }
+        }
+    }
+}
+
+/// Type of the return values of the given function, read from its `Return` identifier. The
+/// function name is relative to `__main__`.
+pub fn get_return_type(program: &Program, function_name: &str) -> Result<CairoType, RunnerError> {
+    let return_name = format!("__main__.{function_name}.Return");
+    let return_identifier = program
+        .identifiers
+        .get(&return_name)
+        .ok_or_else(|| RunnerError::UnknownReturnType(function_name.to_string()))?;
+    // Older compilers describe the return values as a struct, newer ones as a type definition
+    match &return_identifier.cairo_type {
+        Some(cairo_type) => CairoType::parse(cairo_type),
+        None => Ok(CairoType::Struct(return_name)),
+    }
+}
+
+/// Decodes the return values of the given function, which must have just returned, according to
+/// its `Return` identifier. The function name is relative to `__main__`.
+pub fn decode_return_values(
+    program: &Program,
+    function_name: &str,
+    vm: &VirtualMachine,
+) -> Result<CairoValue, VirtualMachineError> {
+    let return_type = get_return_type(program, function_name)?;
+    let return_size = return_type.size(program)?;
+    let address = vm.get_ap().sub(return_size)?;
+    decode_value(program, &return_type, &address, vm)
+}
+
+/// Decodes the value of the given type stored at the given address
+pub fn decode_value(
+    program: &Program,
+    cairo_type: &CairoType,
+    address: &Relocatable,
+    vm: &VirtualMachine,
+) -> Result<CairoValue, VirtualMachineError> {
+    match cairo_type {
+        // Values are decoded by what memory holds, as pointers are often typed as felts
+        CairoType::Felt | CairoType::Pointer(_) => {
+            match vm
+                .get_maybe(address)?
+                .ok_or_else(|| RunnerError::MemoryGet(address.into()))?
+            {
+                MaybeRelocatable::Int(value) => Ok(CairoValue::Felt(value)),
+                MaybeRelocatable::RelocatableValue(pointer) => Ok(CairoValue::Pointer(pointer)),
+            }
+        }
+        CairoType::Tuple(members) => {
+            let mut offsets = Vec::with_capacity(members.len());
+            let mut offset = 0;
+            for (_, member_type) in members {
+                offsets.push(offset);
+                offset += member_type.size(program)?;
+            }
+            if !members.is_empty() && members.iter().all(|(name, _)| name.is_some()) {
+                let named_members = members
+                    .iter()
+                    .zip(offsets)
+                    .map(|((name, member_type), offset)| {
+                        (
+                            name.clone().unwrap_or_default(),
+                            member_type.clone(),
+                            offset,
+                        )
+                    })
+                    .collect();
+                return decode_members(program, named_members, address, vm);
+            }
+            let mut values = Vec::with_capacity(members.len());
+            for ((_, member_type), offset) in members.iter().zip(offsets) {
+                values.push(decode_value(program, member_type, &(address + offset), vm)?);
+            }
+            Ok(CairoValue::Tuple(values))
+        }
+        CairoType::Struct(name) => {
+            let mut members = Vec::new();
+            for (member_name, member) in get_struct_members(program, name)? {
+                members.push((
+                    member_name.clone(),
+                    CairoType::parse(&member.cairo_type)?,
+                    member.offset,
+                ));
+            }
+            decode_members(program, members, address, vm)
+        }
+    }
+}
+
+// Decodes (name, type, offset) members, merging `x_len: felt` and `x: T*` pairs into arrays
+fn decode_members(
+    program: &Program,
+    members: Vec<(String, CairoType, usize)>,
+    address: &Relocatable,
+    vm: &VirtualMachine,
+) -> Result<CairoValue, VirtualMachineError> {
+    let mut values = Vec::with_capacity(members.len());
+    let mut members = members.into_iter().peekable();
+    while let Some((name, member_type, offset)) = members.next() {
+        let is_array_len = matches!(
+            (&member_type, members.peek()),
+            (CairoType::Felt, Some((next_name, CairoType::Pointer(_), _)))
+                if name.strip_suffix("_len") == Some(next_name.as_str())
+        );
+        match decode_value(program, &member_type, &(address + offset), vm)? {
+            CairoValue::Felt(len) if is_array_len => {
+                if let Some((array_name, CairoType::Pointer(element_type), array_offset)) =
+                    members.next()
+                {
+                    let array = decode_array(
+                        program,
+                        &array_name,
+                        &element_type,
+                        &len,
+                        &(address + array_offset),
+                        vm,
+                    )?;
+                    values.push((array_name, array));
+                }
+            }
+            value => values.push((name, value)),
+        }
+    }
+    Ok(CairoValue::Struct(values))
+}
+
+fn decode_array(
+    program: &Program,
+    name: &str,
+    element_type: &CairoType,
+    len: &BigInt,
+    pointer_address: &Relocatable,
+    vm: &VirtualMachine,
+) -> Result<CairoValue, VirtualMachineError> {
+    let pointer = match decode_value(program, &CairoType::Felt, pointer_address, vm)? {
+        CairoValue::Pointer(pointer) => pointer,
+        _ => return Err(RunnerError::InvalidArrayPointer(name.to_string()).into()),
+    };
+    let element_size = element_type.size(program)?;
+    // The length is read from memory, so it is bounded by the size of the array segment
+    let segment_size = match pointer.segment_index {
+        index if index < 0 => vm.memory.temp_data.get((-(index + 1)) as usize),
+        index => vm.memory.data.get(index as usize),
+    }
+    .map_or(0, Vec::len);
+    let len = len
+        .to_usize()
+        .filter(|len| {
+            len.checked_mul(element_size.max(1))
+                .and_then(|size| size.checked_add(pointer.offset))
+                .map_or(false, |end| end <= segment_size)
+        })
+        .ok_or_else(|| RunnerError::InvalidArrayLength(name.to_string(), len.clone()))?;
+    let mut elements = Vec::new();
+    for index in 0..len {
+        elements.push(decode_value(
+            program,
+            element_type,
+            &(&pointer + index * element_size),
+            vm,
+        )?);
+    }
+    Ok(CairoValue::Array(elements))
+}
+
+fn get_struct_members<'a>(
+    program: &'a Program,
+    struct_name: &str,
+) -> Result<Vec<(&'a String, &'a Member)>, RunnerError> {
+    let mut members: Vec<_> = program
+        .identifiers
+        .get(struct_name)
+        .and_then(|identifier| identifier.members.as_ref())
+        .ok_or_else(|| RunnerError::UnknownStruct(struct_name.to_string()))?
+        .iter()
+        .collect();
+    members.sort_by_key(|(_, member)| member.offset);
+    Ok(members)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::serde::deserialize_program::Identifier;
+    use crate::utils::test_utils::*;
+    use crate::vm::errors::memory_errors::MemoryError;
+    use crate::vm::vm_memory::memory::Memory;
+    use crate::{bigint, relocatable};
+    use num_bigint::Sign;
+    use std::collections::HashMap;
+
+    fn struct_identifier(members: &[(&str, &str, usize)]) -> Identifier {
+        Identifier {
+            pc: None,
+            type_: Some(String::from("struct")),
+            value: None,
+            full_name: None,
+            members: Some(
+                members
+                    .iter()
+                    .map(|(name, cairo_type, offset)| {
+                        (
+                            name.to_string(),
+                            Member {
+                                cairo_type: cairo_type.to_string(),
+                                offset: *offset,
+                            },
+                        )
+                    })
+                    .collect(),
+            ),
+            cairo_type: None,
+            destination: None,
+        }
+    }
+
+    #[test]
+    fn parse_cairo_types() {
+        assert_eq!(
+            CairoType::parse("felt**"),
+            Ok(CairoType::Pointer(Box::new(CairoType::Pointer(Box::new(
+                CairoType::Felt
+            )))))
+        );
+        assert_eq!(
+            CairoType::parse("(felt, (x: felt, y: __main__.Point*))"),
+            Ok(CairoType::Tuple(vec![
+                (None, CairoType::Felt),
+                (
+                    None,
+                    CairoType::Tuple(vec![
+                        (Some(String::from("x")), CairoType::Felt),
+                        (
+                            Some(String::from("y")),
+                            CairoType::Pointer(Box::new(CairoType::Struct(String::from(
+                                "__main__.Point"
+                            ))))
+                        ),
+                    ])
+                ),
+            ]))
+        );
+        assert_eq!(
+            CairoType::parse("(felt,)"),
+            Ok(CairoType::Tuple(vec![(None, CairoType::Felt)]))
+        );
+        assert_eq!(
+            CairoType::parse("(felt, felt"),
+            Err(RunnerError::InvalidCairoType(String::from("(felt, felt")))
+        );
+    }
+
+    #[test]
+    fn decode_return_struct() {
+        let program = program!(
+            identifiers = HashMap::from([
+                (
+                    String::from("__main__.Point"),
+                    struct_identifier(&[("x", "felt", 0), ("y", "felt", 1)]),
+                ),
+                (
+                    String::from("__main__.f.Return"),
+                    struct_identifier(&[
+                        ("p", "__main__.Point", 0),
+                        ("arr_len", "felt", 2),
+                        ("arr", "felt*", 3),
+                        ("t", "(felt, felt)", 4),
+                    ]),
+                ),
+            ]),
+        );
+        let mut vm = vm!();
+        vm.run_context.ap = 6;
+        vm.memory = memory![
+            ((1, 0), 1),
+            ((1, 1), 2),
+            ((1, 2), 2),
+            ((1, 3), (2, 0)),
+            ((1, 4), 5),
+            ((1, 5), 6),
+            ((2, 0), 7),
+            ((2, 1), 8)
+        ];
+
+        let return_values = decode_return_values(&program, "f", &vm).unwrap();
+        assert_eq!(
+            return_values,
+            CairoValue::Struct(vec![
+                (
+                    String::from("p"),
+                    CairoValue::Struct(vec![
+                        (String::from("x"), CairoValue::Felt(bigint!(1))),
+                        (String::from("y"), CairoValue::Felt(bigint!(2))),
+                    ])
+                ),
+                (
+                    String::from("arr"),
+                    CairoValue::Array(vec![
+                        CairoValue::Felt(bigint!(7)),
+                        CairoValue::Felt(bigint!(8))
+                    ])
+                ),
+                (
+                    String::from("t"),
+                    CairoValue::Tuple(vec![
+                        CairoValue::Felt(bigint!(5)),
+                        CairoValue::Felt(bigint!(6))
+                    ])
+                ),
+            ])
+        );
+        assert_eq!(
+            serde_json::to_string(&return_values).unwrap(),
+            r#"{"p":{"x":"1","y":"2"},"arr":["7","8"],"t":["5","6"]}"#
+        );
+    }
+
+    #[test]
+    fn decode_return_type_definition() {
+        let program = program!(
+            identifiers = HashMap::from([(
+                String::from("__main__.f.Return"),
+                Identifier {
+                    pc: None,
+                    type_: Some(String::from("type_definition")),
+                    value: None,
+                    full_name: None,
+                    members: None,
+                    cairo_type: Some(String::from("(felt, felt*)")),
+                    destination: None,
+                },
+            )]),
+        );
+        let mut vm = vm!();
+        vm.run_context.ap = 2;
+        vm.memory = memory![((1, 0), 3), ((1, 1), (2, 4))];
+
+        let return_values = decode_return_values(&program, "f", &vm).unwrap();
+        assert_eq!(
+            return_values,
+            CairoValue::Tuple(vec![
+                CairoValue::Felt(bigint!(3)),
+                CairoValue::Pointer(relocatable!(2, 4))
+            ])
+        );
+        assert_eq!(
+            serde_json::to_string(&return_values).unwrap(),
+            r#"["3","2:4"]"#
+        );
+    }
+
+    #[test]
+    fn decode_return_values_unknown_function() {
+        let program = program!();
+        let vm = vm!();
+        assert_eq!(
+            decode_return_values(&program, "f", &vm),
+            Err(RunnerError::UnknownReturnType(String::from("f")).into())
+        );
+    }
+
+    #[test]
+    fn decode_return_array_longer_than_segment() {
+        let program = program!(
+            identifiers = HashMap::from([(
+                String::from("__main__.f.Return"),
+                struct_identifier(&[("arr_len", "felt", 0), ("arr", "felt*", 1)]),
+            )]),
+        );
+        let mut vm = vm!();
+        vm.run_context.ap = 2;
+        vm.memory = memory![((1, 0), 3), ((1, 1), (2, 0)), ((2, 0), 7), ((2, 1), 8)];
+
+        assert_eq!(
+            decode_return_values(&program, "f", &vm),
+            Err(RunnerError::InvalidArrayLength(String::from("arr"), bigint!(3)).into())
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use num_bigint::BigInt;
 use std::io;
 use thiserror::Error;
 
@@ -9,8 +10,18 @@ pub enum ProgramError {
     Parse(#[from] serde_json::Error),
     #[error("Entrypoint {0} not found")]
     EntrypointNotFound(String),
+    #[error("Entry point with selector {0:#x} not found")]
+    EntryPointSelectorNotFound(BigInt),
     #[error("Constant {0} has no value")]
     ConstWithoutValue(String),
+    #[error("Can't compute the program hash of a program without a main entrypoint")]
+    MissingMain,
+    #[error("Program data contains a relocatable value at position {0}")]
+    RelocatableInData(usize),
+    #[error("Can't compute the hash chain of an empty list")]
+    EmptyHashChain,
+    #[error("Value {0} can't be converted to a field element")]
+    FieldElementConversion(BigInt),
 }
 
 #[cfg(test)]
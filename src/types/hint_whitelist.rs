@@ -0,0 +1,88 @@
+use crate::types::errors::program_errors::ProgramError;
+use crate::types::program::Program;
+use crate::vm::errors::vm_errors::VirtualMachineError;
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::{BufReader, Read};
+use std::path::Path;
+
+/// Set of hints allowed to run, used to reject programs containing hints that weren't audited.
+/// Hint codes are compared ignoring leading and trailing whitespace.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct HintWhitelist {
+    allowed_hints: HashSet<String>,
+}
+
+impl HintWhitelist {
+    pub fn new(allowed_hints: Vec<String>) -> Self {
+        HintWhitelist {
+            allowed_hints: allowed_hints
+                .iter()
+                .map(|hint_code| hint_code.trim().to_string())
+                .collect(),
+        }
+    }
+
+    ///Loads the whitelist from a JSON file containing the list of allowed hint codes
+    pub fn from_file(path: &Path) -> Result<HintWhitelist, ProgramError> {
+        let file = File::open(path)?;
+        Self::from_reader(BufReader::new(file))
+    }
+
+    pub fn from_reader(reader: impl Read) -> Result<HintWhitelist, ProgramError> {
+        Ok(Self::new(serde_json::from_reader(reader)?))
+    }
+
+    pub fn is_allowed(&self, hint_code: &str) -> bool {
+        self.allowed_hints.contains(hint_code.trim())
+    }
+
+    ///Fails with the hints of the program which aren't allowed, sorted by pc
+    pub fn check_program(&self, program: &Program) -> Result<(), VirtualMachineError> {
+        let mut forbidden_hints: Vec<(usize, String)> = program
+            .hints
+            .iter()
+            .flat_map(|(pc, hints)| hints.iter().map(move |hint| (*pc, &hint.code)))
+            .filter(|(_, code)| !self.is_allowed(code))
+            .map(|(pc, code)| (pc, code.clone()))
+            .collect();
+        if forbidden_hints.is_empty() {
+            return Ok(());
+        }
+        forbidden_hints.sort();
+        Err(VirtualMachineError::HintsNotWhitelisted(forbidden_hints))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn load_whitelist_from_reader() {
+        let whitelist = HintWhitelist::from_reader(
+            r#"["memory[ap] = segments.add()", "ids.a = 1\nids.b = 2\n"]"#.as_bytes(),
+        )
+        .unwrap();
+
+        assert!(whitelist.is_allowed("memory[ap] = segments.add()"));
+        assert!(whitelist.is_allowed("ids.a = 1\nids.b = 2"));
+        assert!(!whitelist.is_allowed("ids.a = 1"));
+    }
+
+    #[test]
+    fn load_whitelist_from_invalid_json() {
+        assert!(matches!(
+            HintWhitelist::from_reader(r#"{"hints": []}"#.as_bytes()),
+            Err(ProgramError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn load_whitelist_from_missing_file() {
+        assert!(matches!(
+            HintWhitelist::from_file(Path::new("missing_hint_whitelist.json")),
+            Err(ProgramError::IO(_))
+        ));
+    }
+}
@@ -0,0 +1,315 @@
+use crate::serde::deserialize_program::{Identifier, Member};
+use crate::vm::errors::vm_errors::VirtualMachineError;
+use num_bigint::BigInt;
+use std::collections::HashMap;
+
+/// Resolves identifier names the way cairo-lang's IdentifierManager does: names are searched
+/// in the accessible scopes of the code referring to them (innermost scope first), and the
+/// aliases created by imports are followed up to the identifier they point to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IdentifierManager<'a> {
+    identifiers: &'a HashMap<String, Identifier>,
+}
+
+impl<'a> IdentifierManager<'a> {
+    pub fn new(identifiers: &'a HashMap<String, Identifier>) -> Self {
+        IdentifierManager { identifiers }
+    }
+
+    /// Returns the full name and definition of the identifier with the given full name,
+    /// following the aliases found along its path
+    pub fn get(&self, name: &str) -> Result<(String, &'a Identifier), VirtualMachineError> {
+        let mut full_name = name.to_string();
+        // Every resolution step replaces an alias, so more steps than identifiers means a cycle
+        for _ in 0..=self.identifiers.len() {
+            let (prefix_len, identifier) = self
+                .find_alias_or_identifier(&full_name)
+                .ok_or_else(|| VirtualMachineError::UnknownIdentifier(full_name.clone()))?;
+
+            if identifier.type_.as_deref() != Some("alias") {
+                return Ok((full_name, identifier));
+            }
+            let destination = identifier.destination.as_ref().ok_or_else(|| {
+                VirtualMachineError::AliasWithoutDestination(full_name[..prefix_len].to_string())
+            })?;
+            full_name = format!("{}{}", destination, &full_name[prefix_len..]);
+        }
+        Err(VirtualMachineError::IdentifierAliasCycle(name.to_string()))
+    }
+
+    /// Searches the identifier with the given name in the accessible scopes, from the innermost
+    /// one to the outermost one. Names that are already full names are also accepted.
+    pub fn search(
+        &self,
+        accessible_scopes: &[String],
+        name: &str,
+    ) -> Result<(String, &'a Identifier), VirtualMachineError> {
+        for scope in accessible_scopes.iter().rev() {
+            match self.get(&format!("{}.{}", scope, name)) {
+                Err(VirtualMachineError::UnknownIdentifier(_)) => continue,
+                result => return result,
+            }
+        }
+        self.get(name)
+    }
+
+    /// Returns the value of the constant with the given name, searched in the accessible scopes
+    pub fn get_const(
+        &self,
+        accessible_scopes: &[String],
+        name: &str,
+    ) -> Result<&'a BigInt, VirtualMachineError> {
+        let (full_name, identifier) = self.search(accessible_scopes, name)?;
+        match identifier.type_.as_deref() {
+            Some("const") => identifier
+                .value
+                .as_ref()
+                .ok_or(VirtualMachineError::UnknownIdentifier(full_name)),
+            type_ => Err(VirtualMachineError::UnexpectedIdentifierType(
+                full_name,
+                "const",
+                type_.unwrap_or_default().to_string(),
+            )),
+        }
+    }
+
+    /// Returns the full name and members of the struct with the given name, searched in the
+    /// accessible scopes
+    pub fn get_struct(
+        &self,
+        accessible_scopes: &[String],
+        name: &str,
+    ) -> Result<(String, &'a HashMap<String, Member>), VirtualMachineError> {
+        let (full_name, identifier) = self.search(accessible_scopes, name)?;
+        match (identifier.type_.as_deref(), &identifier.members) {
+            (Some("struct"), Some(members)) => Ok((full_name, members)),
+            (type_, _) => Err(VirtualMachineError::UnexpectedIdentifierType(
+                full_name,
+                "struct",
+                type_.unwrap_or_default().to_string(),
+            )),
+        }
+    }
+
+    /// Returns the value of every alias that refers to a constant, by the full name of the alias.
+    /// Aliases that can't be resolved are skipped, as they may never be used.
+    pub fn get_const_aliases(&self) -> HashMap<String, BigInt> {
+        self.identifiers
+            .iter()
+            .filter(|(_, identifier)| identifier.type_.as_deref() == Some("alias"))
+            .filter_map(|(name, _)| {
+                let (_, identifier) = self.get(name).ok()?;
+                match identifier.type_.as_deref() {
+                    Some("const") => Some((name.clone(), identifier.value.clone()?)),
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+
+    // Returns the shortest prefix of the name (made of whole components) that is an alias, or
+    // the identifier with the whole name if none of them is. The prefix is returned as its length.
+    fn find_alias_or_identifier(&self, name: &str) -> Option<(usize, &'a Identifier)> {
+        name.match_indices('.')
+            .map(|(index, _)| index)
+            .chain(std::iter::once(name.len()))
+            .find_map(|prefix_len| {
+                self.identifiers
+                    .get(&name[..prefix_len])
+                    .filter(|identifier| {
+                        prefix_len == name.len() || identifier.type_.as_deref() == Some("alias")
+                    })
+                    .map(|identifier| (prefix_len, identifier))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bigint;
+
+    fn identifier(type_: &str) -> Identifier {
+        Identifier {
+            pc: None,
+            type_: Some(type_.to_string()),
+            value: None,
+            full_name: None,
+            members: None,
+            cairo_type: None,
+            destination: None,
+        }
+    }
+
+    fn constant(value: i32) -> Identifier {
+        Identifier {
+            value: Some(bigint!(value)),
+            ..identifier("const")
+        }
+    }
+
+    fn alias(destination: &str) -> Identifier {
+        Identifier {
+            destination: Some(destination.to_string()),
+            ..identifier("alias")
+        }
+    }
+
+    fn identifiers() -> HashMap<String, Identifier> {
+        HashMap::from([
+            ("a.constants.SECP_REM".to_string(), constant(19)),
+            (
+                "a.constants.Point".to_string(),
+                Identifier {
+                    members: Some(HashMap::from([(
+                        "x".to_string(),
+                        Member {
+                            cairo_type: "felt".to_string(),
+                            offset: 0,
+                        },
+                    )])),
+                    ..identifier("struct")
+                },
+            ),
+            ("a.field".to_string(), identifier("function")),
+            (
+                "a.field.SECP_REM".to_string(),
+                alias("a.constants.SECP_REM"),
+            ),
+            ("a.field.Point".to_string(), alias("a.constants.Point")),
+            (
+                "a.field.SECP_REM_ALIAS".to_string(),
+                alias("a.field.SECP_REM"),
+            ),
+            ("b.consts".to_string(), alias("c.consts")),
+            ("c.consts".to_string(), alias("b.consts")),
+            ("b.field".to_string(), alias("a.field")),
+            ("a.field.LOCAL".to_string(), constant(3)),
+            ("a.field.inner.LOCAL".to_string(), constant(4)),
+        ])
+    }
+
+    #[test]
+    fn get_identifier_through_aliases() {
+        let identifiers = identifiers();
+        let manager = IdentifierManager::new(&identifiers);
+
+        assert_eq!(
+            manager.get("a.field.SECP_REM_ALIAS"),
+            Ok((
+                "a.constants.SECP_REM".to_string(),
+                &identifiers["a.constants.SECP_REM"]
+            ))
+        );
+    }
+
+    #[test]
+    fn get_identifier_through_scope_alias() {
+        let identifiers = identifiers();
+        let manager = IdentifierManager::new(&identifiers);
+
+        assert_eq!(
+            manager.get("b.field.inner.LOCAL"),
+            Ok((
+                "a.field.inner.LOCAL".to_string(),
+                &identifiers["a.field.inner.LOCAL"]
+            ))
+        );
+    }
+
+    #[test]
+    fn get_identifier_alias_cycle() {
+        let identifiers = identifiers();
+        let manager = IdentifierManager::new(&identifiers);
+
+        assert_eq!(
+            manager.get("b.consts.SECP_REM"),
+            Err(VirtualMachineError::IdentifierAliasCycle(
+                "b.consts.SECP_REM".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn get_unknown_identifier() {
+        let identifiers = identifiers();
+        let manager = IdentifierManager::new(&identifiers);
+
+        assert_eq!(
+            manager.get("a.field.MISSING"),
+            Err(VirtualMachineError::UnknownIdentifier(
+                "a.field.MISSING".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn search_identifier_innermost_scope_first() {
+        let identifiers = identifiers();
+        let manager = IdentifierManager::new(&identifiers);
+        let accessible_scopes = [
+            "a".to_string(),
+            "a.field".to_string(),
+            "a.field.inner".to_string(),
+        ];
+
+        assert_eq!(
+            manager.get_const(&accessible_scopes, "LOCAL"),
+            Ok(&bigint!(4))
+        );
+        assert_eq!(
+            manager.get_const(&accessible_scopes[..2], "LOCAL"),
+            Ok(&bigint!(3))
+        );
+    }
+
+    #[test]
+    fn get_const_by_short_name() {
+        let identifiers = identifiers();
+        let manager = IdentifierManager::new(&identifiers);
+        let accessible_scopes = ["a".to_string(), "a.field".to_string()];
+
+        assert_eq!(
+            manager.get_const(&accessible_scopes, "SECP_REM"),
+            Ok(&bigint!(19))
+        );
+        assert_eq!(manager.get_const(&[], "a.field.SECP_REM"), Ok(&bigint!(19)));
+        assert_eq!(
+            manager.get_const(&accessible_scopes, "Point"),
+            Err(VirtualMachineError::UnexpectedIdentifierType(
+                "a.constants.Point".to_string(),
+                "const",
+                "struct".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn get_struct_by_short_name() {
+        let identifiers = identifiers();
+        let manager = IdentifierManager::new(&identifiers);
+        let accessible_scopes = ["a".to_string(), "a.field".to_string()];
+
+        assert_eq!(
+            manager.get_struct(&accessible_scopes, "Point"),
+            Ok((
+                "a.constants.Point".to_string(),
+                identifiers["a.constants.Point"].members.as_ref().unwrap()
+            ))
+        );
+    }
+
+    #[test]
+    fn get_const_aliases() {
+        let identifiers = identifiers();
+        let manager = IdentifierManager::new(&identifiers);
+
+        assert_eq!(
+            manager.get_const_aliases(),
+            HashMap::from([
+                ("a.field.SECP_REM".to_string(), bigint!(19)),
+                ("a.field.SECP_REM_ALIAS".to_string(), bigint!(19)),
+            ])
+        );
+    }
+}
@@ -1,5 +1,8 @@
+pub mod abi;
 pub mod errors;
 pub mod exec_scope;
+pub mod hint_whitelist;
+pub mod identifier_manager;
 pub mod instance_definitions;
 pub mod instruction;
 pub mod layout;
@@ -1,9 +1,13 @@
+use crate::serde::deserialize_casm::deserialize_casm_program;
 use crate::serde::deserialize_program::{
     deserialize_program, Attribute, HintParams, Identifier, ReferenceManager,
 };
+use crate::starknet::contract_class::EntryPointType;
 use crate::types::errors::program_errors::ProgramError;
+use crate::types::identifier_manager::IdentifierManager;
 use crate::types::relocatable::MaybeRelocatable;
 use num_bigint::{BigInt, Sign};
+use starknet_crypto::{pedersen_hash, FieldElement};
 use std::fs::File;
 use std::io::{BufReader, Read};
 use std::{collections::HashMap, path::Path};
@@ -40,20 +44,7 @@ impl Program {
             builtins,
             prime,
             data,
-            constants: {
-                let mut constants = HashMap::new();
-                for (key, value) in identifiers.iter() {
-                    if value.type_.as_deref() == Some("const") {
-                        let value = value
-                            .value
-                            .clone()
-                            .ok_or_else(|| ProgramError::ConstWithoutValue(key.to_owned()))?;
-                        constants.insert(key.to_owned(), value);
-                    }
-                }
-
-                constants
-            },
+            constants: extract_constants(&identifiers)?,
             main,
             start: None,
             end: None,
@@ -77,6 +68,78 @@ impl Program {
     ) -> Result<Program, ProgramError> {
         deserialize_program(reader, entrypoint)
     }
+
+    /// Loads a Cairo 1 program from a CASM contract class, optionally using one of its entry
+    /// points as main. Its hints are run by the `Cairo1HintProcessor`.
+    pub fn from_casm_file(
+        path: &Path,
+        entry_point: Option<(EntryPointType, &BigInt)>,
+    ) -> Result<Program, ProgramError> {
+        let file = File::open(path)?;
+        let reader = BufReader::new(file);
+
+        deserialize_casm_program(reader, entry_point)
+    }
+
+    pub fn from_casm_reader(
+        reader: impl Read,
+        entry_point: Option<(EntryPointType, &BigInt)>,
+    ) -> Result<Program, ProgramError> {
+        deserialize_casm_program(reader, entry_point)
+    }
+
+    /// Returns the manager resolving the names of the program identifiers, as seen from the
+    /// accessible scopes of a piece of code
+    pub fn identifier_manager(&self) -> IdentifierManager<'_> {
+        IdentifierManager::new(&self.identifiers)
+    }
+
+    /// Computes the program hash as done by cairo-lang's `compute_program_hash_chain`:
+    /// a Pedersen hash chain over the program header (bootloader version, main pc and
+    /// builtins) followed by the program data.
+    pub fn compute_hash(&self, bootloader_version: usize) -> Result<BigInt, ProgramError> {
+        let main = self.main.ok_or(ProgramError::MissingMain)?;
+
+        // The data length is not part of the header, it is prepended to the whole chain.
+        let mut data_chain = Vec::with_capacity(3 + self.builtins.len() + self.data.len());
+        data_chain.push(BigInt::from(bootloader_version));
+        data_chain.push(BigInt::from(main));
+        data_chain.push(BigInt::from(self.builtins.len()));
+        data_chain.extend(
+            self.builtins
+                .iter()
+                .map(|name| BigInt::from_bytes_be(Sign::Plus, name.as_bytes())),
+        );
+        for (index, value) in self.data.iter().enumerate() {
+            match value {
+                MaybeRelocatable::Int(value) => data_chain.push(value.clone()),
+                MaybeRelocatable::RelocatableValue(_) => {
+                    return Err(ProgramError::RelocatableInData(index))
+                }
+            }
+        }
+
+        let mut chain = Vec::with_capacity(data_chain.len() + 1);
+        chain.push(BigInt::from(data_chain.len()));
+        chain.extend(data_chain);
+        compute_hash_chain(&chain)
+    }
+}
+
+/// Computes h(data[0], h(data[1], h(..., h(data[n-2], data[n-1])))) where h is the
+/// Pedersen hash function.
+fn compute_hash_chain(data: &[BigInt]) -> Result<BigInt, ProgramError> {
+    let (last, rest) = data.split_last().ok_or(ProgramError::EmptyHashChain)?;
+    let mut hash = to_field_element(last)?;
+    for value in rest.iter().rev() {
+        hash = pedersen_hash(&to_field_element(value)?, &hash);
+    }
+    Ok(BigInt::from_bytes_be(Sign::Plus, &hash.to_bytes_be()))
+}
+
+fn to_field_element(value: &BigInt) -> Result<FieldElement, ProgramError> {
+    FieldElement::from_dec_str(&value.to_str_radix(10))
+        .map_err(|_| ProgramError::FieldElementConversion(value.clone()))
 }
 
 impl Default for Program {
@@ -98,6 +161,26 @@ impl Default for Program {
         }
     }
 }
+
+/// Collects the value of every constant of the program by its full name. Constants can also be
+/// accessed through the aliases that import them, so these are included too.
+pub(crate) fn extract_constants(
+    identifiers: &HashMap<String, Identifier>,
+) -> Result<HashMap<String, BigInt>, ProgramError> {
+    let mut constants = HashMap::new();
+    for (key, value) in identifiers.iter() {
+        if value.type_.as_deref() == Some("const") {
+            let value = value
+                .value
+                .clone()
+                .ok_or_else(|| ProgramError::ConstWithoutValue(key.to_owned()))?;
+            constants.insert(key.to_owned(), value);
+        }
+    }
+    constants.extend(IdentifierManager::new(identifiers).get_const_aliases());
+    Ok(constants)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -165,6 +248,8 @@ mod tests {
                 value: None,
                 full_name: None,
                 members: None,
+                cairo_type: None,
+                destination: None,
             },
         );
 
@@ -176,6 +261,8 @@ mod tests {
                 value: Some(bigint!(0)),
                 full_name: None,
                 members: None,
+                cairo_type: None,
+                destination: None,
             },
         );
 
@@ -231,6 +318,8 @@ mod tests {
                 value: None,
                 full_name: None,
                 members: None,
+                cairo_type: None,
+                destination: None,
             },
         );
 
@@ -242,6 +331,8 @@ mod tests {
                 value: None,
                 full_name: None,
                 members: None,
+                cairo_type: None,
+                destination: None,
             },
         );
 
@@ -287,6 +378,8 @@ mod tests {
                 value: None,
                 full_name: None,
                 members: None,
+                cairo_type: None,
+                destination: None,
             },
         );
         identifiers.insert(
@@ -297,6 +390,8 @@ mod tests {
                 value: None,
                 full_name: Some("__main__.main.Args".to_string()),
                 members: Some(HashMap::new()),
+                cairo_type: None,
+                destination: None,
             },
         );
         identifiers.insert(
@@ -307,6 +402,8 @@ mod tests {
                 value: None,
                 full_name: Some("__main__.main.ImplicitArgs".to_string()),
                 members: Some(HashMap::new()),
+                cairo_type: None,
+                destination: None,
             },
         );
         identifiers.insert(
@@ -317,6 +414,8 @@ mod tests {
                 value: None,
                 full_name: Some("__main__.main.Return".to_string()),
                 members: Some(HashMap::new()),
+                cairo_type: None,
+                destination: None,
             },
         );
         identifiers.insert(
@@ -327,6 +426,8 @@ mod tests {
                 value: Some(bigint!(0)),
                 full_name: None,
                 members: None,
+                cairo_type: None,
+                destination: None,
             },
         );
 
@@ -381,6 +482,8 @@ mod tests {
                 value: None,
                 full_name: None,
                 members: None,
+                cairo_type: None,
+                destination: None,
             },
         );
         identifiers.insert(
@@ -391,6 +494,8 @@ mod tests {
                 value: None,
                 full_name: Some("__main__.main.Args".to_string()),
                 members: Some(HashMap::new()),
+                cairo_type: None,
+                destination: None,
             },
         );
         identifiers.insert(
@@ -401,6 +506,8 @@ mod tests {
                 value: None,
                 full_name: Some("__main__.main.ImplicitArgs".to_string()),
                 members: Some(HashMap::new()),
+                cairo_type: None,
+                destination: None,
             },
         );
         identifiers.insert(
@@ -411,6 +518,8 @@ mod tests {
                 value: None,
                 full_name: Some("__main__.main.Return".to_string()),
                 members: Some(HashMap::new()),
+                cairo_type: None,
+                destination: None,
             },
         );
         identifiers.insert(
@@ -421,6 +530,8 @@ mod tests {
                 value: Some(bigint!(0)),
                 full_name: None,
                 members: None,
+                cairo_type: None,
+                destination: None,
             },
         );
 
@@ -498,4 +609,80 @@ mod tests {
 
         assert_eq!(program, Program::default())
     }
+
+    #[test]
+    fn compute_hash_without_main() {
+        let program = Program::default();
+
+        assert!(matches!(
+            program.compute_hash(0),
+            Err(ProgramError::MissingMain)
+        ));
+    }
+
+    #[test]
+    fn compute_hash_with_relocatable_data() {
+        let program = Program {
+            data: vec![
+                MaybeRelocatable::from(bigint!(1)),
+                MaybeRelocatable::from((0, 0)),
+            ],
+            main: Some(0),
+            ..Program::default()
+        };
+
+        assert!(matches!(
+            program.compute_hash(0),
+            Err(ProgramError::RelocatableInData(1))
+        ));
+    }
+
+    #[test]
+    fn compute_hash_chain_of_program() {
+        let program = Program {
+            builtins: vec![String::from("output")],
+            data: vec![
+                MaybeRelocatable::from(bigint!(1)),
+                MaybeRelocatable::from(bigint!(2)),
+            ],
+            main: Some(0),
+            ..Program::default()
+        };
+
+        let felt = |value: BigInt| FieldElement::from_dec_str(&value.to_str_radix(10)).unwrap();
+        let output_builtin = BigInt::from_bytes_be(Sign::Plus, b"output");
+        // [len(data_chain), bootloader_version, main, len(builtins), builtins..., data...]
+        let expected = [
+            bigint!(6),
+            bigint!(0),
+            bigint!(0),
+            bigint!(1),
+            output_builtin,
+            bigint!(1),
+        ]
+        .into_iter()
+        .rev()
+        .fold(felt(bigint!(2)), |acc, value| {
+            pedersen_hash(&felt(value), &acc)
+        });
+
+        assert_eq!(
+            program.compute_hash(0).unwrap(),
+            BigInt::from_bytes_be(Sign::Plus, &expected.to_bytes_be())
+        );
+    }
+
+    #[test]
+    fn compute_hash_depends_on_bootloader_version() {
+        let program = Program {
+            data: vec![MaybeRelocatable::from(bigint!(1))],
+            main: Some(0),
+            ..Program::default()
+        };
+
+        assert_ne!(
+            program.compute_hash(0).unwrap(),
+            program.compute_hash(1).unwrap()
+        );
+    }
 }
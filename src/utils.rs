@@ -37,6 +37,49 @@ macro_rules! any_box {
     };
 }
 
+///Declares a struct mapped to a Cairo struct, implementing `CairoStruct` for it.
+///Each field is read from the Cairo member with the same name.
+#[macro_export]
+macro_rules! cairo_struct {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident = $cairo_type:literal {
+            $($field_vis:vis $field:ident: $field_type:ty),* $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        $vis struct $name {
+            $($field_vis $field: $field_type),*
+        }
+
+        impl $crate::hint_processor::cairo_struct::CairoStruct for $name {
+            const CAIRO_TYPE: &'static str = $cairo_type;
+
+            fn from_memory(
+                vm: &$crate::vm::vm_core::VirtualMachine,
+                var_name: &str,
+                base: &$crate::types::relocatable::Relocatable,
+                members: &std::collections::HashMap<
+                    String,
+                    $crate::serde::deserialize_program::Member,
+                >,
+            ) -> Result<Self, $crate::vm::errors::vm_errors::VirtualMachineError> {
+                Ok($name {
+                    $($field: $crate::hint_processor::cairo_struct::CairoStructMember::get_member(
+                        vm,
+                        &$crate::hint_processor::cairo_struct::get_member_addr(
+                            var_name,
+                            base,
+                            members,
+                            stringify!($field),
+                        )?,
+                    )?),*
+                })
+            }
+        }
+    };
+}
+
 pub fn is_subsequence<T: PartialEq>(subsequence: &[T], mut sequence: &[T]) -> bool {
     for search in subsequence {
         if let Some(index) = sequence.iter().position(|element| search == element) {
@@ -60,4 +60,6 @@ pub enum MemoryError {
     ErrorRetrievingMessage(String),
     #[error("Error verifying given signature")]
     ErrorVerifyingSignature,
+    #[error("Memory can't grow beyond {0} cells")]
+    MemoryCellsExhausted(usize),
 }
@@ -0,0 +1,27 @@
+use thiserror::Error;
+
+#[derive(Debug, PartialEq, Eq, Error)]
+pub enum PythonHintError {
+    #[error("Syntax error in hint at line {0}: {1}")]
+    Syntax(usize, String),
+    #[error("Name {0} is not defined")]
+    NameError(String),
+    #[error("Type error: {0}")]
+    TypeError(String),
+    #[error("Division by zero")]
+    ZeroDivision,
+    #[error("Index error: {0}")]
+    IndexError(String),
+    #[error("Key error: {0}")]
+    KeyError(String),
+    #[error("Assertion failed: {0}")]
+    AssertionFailed(String),
+    #[error("Unsupported hint construct: {0}")]
+    Unsupported(String),
+    #[error("Hint exceeded the limit of {0} operations")]
+    OperationLimitExceeded(usize),
+    #[error("Result of {0} exceeds {1} bits")]
+    IntegerTooLarge(&'static str, u64),
+    #[error("Concatenation result exceeds {0} elements")]
+    SequenceTooLong(usize),
+}
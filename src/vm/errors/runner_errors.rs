@@ -97,6 +97,36 @@ pub enum RunnerError {
     FoundNonInt,
     #[error("{0} is not divisible by {1}")]
     SafeDivFailUsize(usize, usize),
+    #[error("Page start must be in the output segment {1}, found segment {0}")]
+    PageNotOnOutputSegment(isize, isize),
+    #[error("Page id {0} was already used")]
+    PageIdAlreadyUsed(usize),
+    #[error("Page {0} exceeds the output segment of size {1}")]
+    PageOutOfOutputSegment(usize, usize),
+    #[error("Page {0} starts before the output base offset {1}")]
+    PageStartBeforeOutputBase(usize, usize),
+    #[error("Output attribute {0} was already set")]
+    OutputAttributeAlreadySet(String),
+    #[error("Invalid fact topology: {0}")]
+    InvalidFactTopology(String),
+    #[error("The arguments of {0} are unknown")]
+    UnknownEntrypointArgs(String),
+    #[error("{0} expects {1} arguments, got {2}")]
+    EntrypointArgCountMismatch(String, usize, usize),
+    #[error("Argument {0} of type {1} can't be built from the given value")]
+    InvalidEntrypointArg(String, String),
+    #[error("Implicit argument {0} doesn't match any builtin of the program")]
+    UnknownImplicitArg(String),
+    #[error("The return type of {0} is unknown")]
+    UnknownReturnType(String),
+    #[error("Invalid cairo type {0}")]
+    InvalidCairoType(String),
+    #[error("The members of struct {0} are unknown")]
+    UnknownStruct(String),
+    #[error("Invalid length {1} for array {0}")]
+    InvalidArrayLength(String, BigInt),
+    #[error("Array {0} doesn't point to a memory segment")]
+    InvalidArrayPointer(String),
     #[error(transparent)]
     MemoryError(#[from] MemoryError),
 }
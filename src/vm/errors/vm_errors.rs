@@ -1,8 +1,12 @@
 use super::exec_scope_errors::ExecScopeError;
+use super::python_hint_errors::PythonHintError;
 use super::trace_errors::TraceError;
+use crate::starknet::errors::syscall_handler_errors::SyscallHandlerError;
 use crate::types::relocatable::{MaybeRelocatable, Relocatable};
 use crate::vm::errors::memory_errors::MemoryError;
 use crate::vm::errors::runner_errors::RunnerError;
+use crate::vm::runners::cairo_runner::ExecutionResources;
+use crate::vm::runners::run_resources::ExhaustedResource;
 use num_bigint::BigInt;
 use thiserror::Error;
 
@@ -62,6 +66,8 @@ pub enum VirtualMachineError {
     NoRangeCheckBuiltin,
     #[error("Expected ecdsa builtin to be present")]
     NoSignatureBuiltin,
+    #[error("Expected output builtin to be present")]
+    NoOutputBuiltin,
     #[error("Failed to retrieve value from address {0:?}")]
     MemoryGet(MaybeRelocatable),
     #[error("Expected integer at address {0:?}")]
@@ -80,6 +86,8 @@ pub enum VirtualMachineError {
     ValueNotPositive(BigInt),
     #[error("Unknown Hint: {0}")]
     UnknownHint(String),
+    #[error("Unknown Hint: {0}, none of the hint processors {1:?} support it")]
+    UnknownHintInProcessors(String, Vec<String>),
     #[error("Value: {0} is outside valid range")]
     ValueOutsideValidRange(BigInt),
     #[error("split_int(): value is out of range")]
@@ -102,6 +110,8 @@ pub enum VirtualMachineError {
     SafeDivFailUsize(usize, usize),
     #[error("Attempted to devide by zero")]
     DividedByZero,
+    #[error("{0} has no inverse modulo {1}")]
+    NoModularInverse(BigInt, BigInt),
     #[error("Failed to calculate the square root of: {0})")]
     FailedToGetSqrt(BigInt),
     #[error("Assertion failed, {0} % {1} is equal to 0")]
@@ -214,6 +224,8 @@ pub enum VirtualMachineError {
     WrongHintData,
     #[error("Failed to compile hint: {0}")]
     CompileHintFail(String),
+    #[error("Hints not allowed by the hint whitelist (pc, code): {0:?}")]
+    HintsNotWhitelisted(Vec<(usize, String)>),
     #[error("op1_addr is Op1Addr.IMM, but no immediate given")]
     NoImm,
     #[error("Tried to compute an address but there was no register in the reference.")]
@@ -240,4 +252,53 @@ pub enum VirtualMachineError {
     InvalidArgCount(usize, usize),
     #[error("{0}, {1}")]
     ErrorMessageAttribute(String, Box<VirtualMachineError>),
+    #[error(transparent)]
+    SyscallHandler(#[from] SyscallHandlerError),
+    #[error("The struct members of ids.{0} are unknown")]
+    UnknownStructMembers(String),
+    #[error("ids.{0} has no member {1}")]
+    MissingStructMember(String, String),
+    #[error("Expected ids.{0} to be a {1}, found {2}")]
+    UnexpectedStructType(String, &'static str, String),
+    #[error("Failed to write the output of a print hint")]
+    PrintHintWriteFail,
+    #[error("Invalid simple bootloader input: {0}")]
+    InvalidBootloaderInput(String),
+    #[error("The program hash {0} in the task output doesn't match the computed hash {1}")]
+    ProgramHashMismatch(BigInt, BigInt),
+    #[error("The program was expected at {1:?}, found {0:?}")]
+    ProgramAddressMismatch(Relocatable, Relocatable),
+    #[error("Failed to write the fact topologies file")]
+    FactTopologiesWriteFail,
+    #[error(transparent)]
+    PythonHint(#[from] PythonHintError),
+    #[error("Run resources exhausted, {0:?} limit reached after using {1:?}")]
+    ResourcesExhausted(ExhaustedResource, ExecutionResources),
+    #[error("Out of gas, the gas budget is {0} but {1} gas was consumed")]
+    OutOfGas(u64, u64),
+    #[error("Security check failed: access to {1:?} is out of the {0} segment bounds")]
+    OutOfBuiltinSegmentAccess(String, Relocatable),
+    #[error("Security check failed: access to {0:?} is out of the program data")]
+    OutOfProgramSegmentAccess(Relocatable),
+    #[error("Security check failed: {0} cell at {1:?} holds {3:?}, but {2:?} was deduced")]
+    InconsistentBuiltinCell(
+        &'static str,
+        Relocatable,
+        MaybeRelocatable,
+        MaybeRelocatable,
+    ),
+    #[error("Security check failed: range check cell at {0:?} holds {1}, which is out of bounds")]
+    RangeCheckCellOutOfBounds(Relocatable, BigInt),
+    #[error("Security check failed: range check cell at {0:?} doesn't hold an integer")]
+    RangeCheckNonIntCell(Relocatable),
+    #[error("Could not resolve the struct member {0} of a reference expression")]
+    UnresolvedReferenceMember(String),
+    #[error("Unknown identifier {0}")]
+    UnknownIdentifier(String),
+    #[error("The alias {0} has no destination")]
+    AliasWithoutDestination(String),
+    #[error("Found a cycle of aliases while resolving the identifier {0}")]
+    IdentifierAliasCycle(String),
+    #[error("Expected the identifier {0} to be a {1}, found {2}")]
+    UnexpectedIdentifierType(String, &'static str, String),
 }
@@ -74,12 +74,28 @@ impl HashBuiltinRunner {
         &self,
         address: &Relocatable,
         memory: &Memory,
+    ) -> Result<Option<MaybeRelocatable>, RunnerError> {
+        if self.verified_addresses.borrow().contains(address) {
+            return Ok(None);
+        }
+        let result = self.compute_hash(address, memory)?;
+        if result.is_some() {
+            self.verified_addresses.borrow_mut().push(address.clone());
+        }
+        Ok(result)
+    }
+
+    /// Computes the hash stored at the output cell address from its input cells, without going
+    /// through the cache of deduce_memory_cell
+    pub fn compute_hash(
+        &self,
+        address: &Relocatable,
+        memory: &Memory,
     ) -> Result<Option<MaybeRelocatable>, RunnerError> {
         if address
             .offset
             .mod_floor(&(self.cells_per_instance as usize))
             != 2
-            || self.verified_addresses.borrow().contains(address)
         {
             return Ok(None);
         };
@@ -96,8 +112,6 @@ impl HashBuiltinRunner {
             num_a.as_ref().map(|x| x.as_ref().map(|x| x.as_ref())),
             num_b.as_ref().map(|x| x.as_ref().map(|x| x.as_ref())),
         ) {
-            self.verified_addresses.borrow_mut().push(address.clone());
-
             //Convert MaybeRelocatable to FieldElement
             let a_string = num_a.to_str_radix(10);
             let b_string = num_b.to_str_radix(10);
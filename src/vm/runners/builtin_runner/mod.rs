@@ -5,6 +5,8 @@ use crate::vm::errors::vm_errors::VirtualMachineError;
 use crate::vm::vm_core::VirtualMachine;
 use crate::vm::vm_memory::memory::Memory;
 use crate::vm::vm_memory::memory_segments::MemorySegmentManager;
+use num_bigint::BigInt;
+use num_traits::Zero;
 
 mod bitwise;
 mod ec_op;
@@ -19,7 +21,9 @@ pub use bitwise::BitwiseBuiltinRunner;
 pub use ec_op::EcOpBuiltinRunner;
 pub use hash::HashBuiltinRunner;
 use num_integer::div_floor;
-pub use output::OutputBuiltinRunner;
+pub use output::{
+    FactTopology, OutputBuiltinRunner, OutputBuiltinState, PublicMemoryPage, GPS_FACT_TOPOLOGY,
+};
 pub use range_check::RangeCheckBuiltinRunner;
 pub use signature::SignatureBuiltinRunner;
 
@@ -139,6 +143,19 @@ impl BuiltinRunner {
         }
     }
 
+    ///Returns the number of memory cells used by each instance of the builtin
+    pub fn cells_per_instance(&self) -> u32 {
+        match self {
+            BuiltinRunner::Bitwise(bitwise) => bitwise.cells_per_instance,
+            BuiltinRunner::EcOp(ec) => ec.cells_per_instance,
+            BuiltinRunner::Hash(hash) => hash.cells_per_instance,
+            BuiltinRunner::Output(_) => 1,
+            BuiltinRunner::RangeCheck(range_check) => range_check.cells_per_instance,
+            BuiltinRunner::Keccak(keccak) => keccak.cells_per_instance,
+            BuiltinRunner::Signature(signature) => signature.cells_per_instance,
+        }
+    }
+
     pub fn add_validation_rule(&self, memory: &mut Memory) -> Result<(), RunnerError> {
         match *self {
             BuiltinRunner::Bitwise(ref bitwise) => bitwise.add_validation_rule(memory),
@@ -351,6 +368,63 @@ impl BuiltinRunner {
         Ok(())
     }
 
+    /// Checks that every cell of the builtin segment holds a value the builtin could have
+    /// produced: deduced cells must match their deduction and range checked cells must be within
+    /// bounds. The error points to the first offending cell.
+    pub fn verify_cells(&self, vm: &VirtualMachine) -> Result<(), VirtualMachineError> {
+        if let BuiltinRunner::Output(_) | BuiltinRunner::Signature(_) = self {
+            return Ok(());
+        }
+
+        let base = self.base();
+        let segment = vm
+            .memory
+            .data
+            .get(
+                TryInto::<usize>::try_into(base)
+                    .map_err(|_| MemoryError::AddressInTemporarySegment(base))?,
+            )
+            .ok_or(MemoryError::NumOutOfBounds)?;
+
+        for (offset, value) in segment.iter().enumerate() {
+            let value = match value {
+                Some(value) => value,
+                None => continue,
+            };
+            let address = Relocatable::from((base, offset));
+            if let BuiltinRunner::RangeCheck(range_check) = self {
+                match value {
+                    MaybeRelocatable::Int(num)
+                        if &BigInt::zero() <= num && num < &range_check._bound => {}
+                    MaybeRelocatable::Int(num) => {
+                        return Err(VirtualMachineError::RangeCheckCellOutOfBounds(
+                            address,
+                            num.clone(),
+                        ))
+                    }
+                    MaybeRelocatable::RelocatableValue(_) => {
+                        return Err(VirtualMachineError::RangeCheckNonIntCell(address))
+                    }
+                }
+            } else if let Some(deduced) = match self {
+                // Hash deductions are cached once verified, so they are computed again here
+                BuiltinRunner::Hash(hash) => hash.compute_hash(&address, &vm.memory)?,
+                _ => self.deduce_memory_cell(&address, &vm.memory)?,
+            } {
+                if &deduced != value {
+                    return Err(VirtualMachineError::InconsistentBuiltinCell(
+                        self.get_memory_segment_addresses().0,
+                        address,
+                        deduced,
+                        value.clone(),
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     pub fn get_used_cells_and_allocated_size(
         &self,
         vm: &VirtualMachine,
@@ -433,14 +507,14 @@ mod tests {
     use crate::types::program::Program;
     use crate::vm::runners::cairo_runner::CairoRunner;
     use crate::{
-        bigint,
+        bigint, bigint_str, relocatable,
         types::instance_definitions::{
             bitwise_instance_def::BitwiseInstanceDef, ec_op_instance_def::EcOpInstanceDef,
         },
         utils::test_utils::*,
         vm::vm_core::VirtualMachine,
     };
-    use num_bigint::{BigInt, Sign};
+    use num_bigint::Sign;
 
     #[test]
     fn get_memory_accesses_missing_segment_used_sizes() {
@@ -1032,6 +1106,94 @@ mod tests {
         assert_eq!(builtin.run_security_checks(&mut vm), Ok(()));
     }
 
+    #[test]
+    fn verify_cells_bitwise_consistent() {
+        let builtin: BuiltinRunner =
+            BitwiseBuiltinRunner::new(&BitwiseInstanceDef::default(), true).into();
+        let mut vm = vm!();
+        vm.memory = memory![
+            ((0, 0), 12),
+            ((0, 1), 10),
+            ((0, 2), 8),
+            ((0, 3), 6),
+            ((0, 4), 14)
+        ];
+
+        assert_eq!(builtin.verify_cells(&vm), Ok(()));
+    }
+
+    #[test]
+    fn verify_cells_bitwise_inconsistent() {
+        let builtin: BuiltinRunner =
+            BitwiseBuiltinRunner::new(&BitwiseInstanceDef::default(), true).into();
+        let mut vm = vm!();
+        vm.memory = memory![((0, 0), 12), ((0, 1), 10), ((0, 2), 8), ((0, 3), 7)];
+
+        assert_eq!(
+            builtin.verify_cells(&vm),
+            Err(VirtualMachineError::InconsistentBuiltinCell(
+                "bitwise",
+                relocatable!(0, 3),
+                mayberelocatable!(6),
+                mayberelocatable!(7)
+            ))
+        );
+    }
+
+    #[test]
+    fn verify_cells_pedersen_inconsistent() {
+        let builtin = BuiltinRunner::Hash(HashBuiltinRunner::new(8, true));
+        let mut vm = vm!();
+        vm.memory = memory![((0, 0), 32), ((0, 1), 72), ((0, 2), 0)];
+        let hash = bigint_str!(
+            b"3270867057177188607814717243084834301278723532952411121381966378910183338911"
+        );
+        // Verifying the auto deductions caches the output cell as verified
+        assert_eq!(
+            builtin.deduce_memory_cell(&relocatable!(0, 2), &vm.memory),
+            Ok(Some(MaybeRelocatable::from(hash.clone())))
+        );
+
+        assert_eq!(
+            builtin.verify_cells(&vm),
+            Err(VirtualMachineError::InconsistentBuiltinCell(
+                "pedersen",
+                relocatable!(0, 2),
+                MaybeRelocatable::from(hash),
+                mayberelocatable!(0)
+            ))
+        );
+    }
+
+    #[test]
+    fn verify_cells_range_check_out_of_bounds() {
+        let builtin = BuiltinRunner::RangeCheck(RangeCheckBuiltinRunner::new(8, 8, true));
+        let mut vm = vm!();
+        vm.memory = memory![((0, 0), 1), ((0, 1), (-1))];
+
+        assert_eq!(
+            builtin.verify_cells(&vm),
+            Err(VirtualMachineError::RangeCheckCellOutOfBounds(
+                relocatable!(0, 1),
+                bigint!(-1)
+            ))
+        );
+    }
+
+    #[test]
+    fn verify_cells_range_check_non_int() {
+        let builtin = BuiltinRunner::RangeCheck(RangeCheckBuiltinRunner::new(8, 8, true));
+        let mut vm = vm!();
+        vm.memory = memory![((0, 0), (1, 0))];
+
+        assert_eq!(
+            builtin.verify_cells(&vm),
+            Err(VirtualMachineError::RangeCheckNonIntCell(relocatable!(
+                0, 0
+            )))
+        );
+    }
+
     #[test]
     fn run_security_ec_op_missing_memory_cells_with_offsets() {
         let builtin: BuiltinRunner =
@@ -1164,6 +1326,23 @@ mod tests {
         assert_eq!(range_check_builtin.ratio(), (Some(8)),);
     }
 
+    #[test]
+    fn get_cells_per_instance_tests() {
+        let bitwise_builtin: BuiltinRunner =
+            BitwiseBuiltinRunner::new(&BitwiseInstanceDef::default(), true).into();
+        assert_eq!(bitwise_builtin.cells_per_instance(), 5);
+        let ec_op_builtin: BuiltinRunner =
+            EcOpBuiltinRunner::new(&EcOpInstanceDef::default(), true).into();
+        assert_eq!(ec_op_builtin.cells_per_instance(), 7);
+        let hash_builtin: BuiltinRunner = HashBuiltinRunner::new(8, true).into();
+        assert_eq!(hash_builtin.cells_per_instance(), 3);
+        let output_builtin: BuiltinRunner = OutputBuiltinRunner::new(true).into();
+        assert_eq!(output_builtin.cells_per_instance(), 1);
+        let range_check_builtin: BuiltinRunner =
+            BuiltinRunner::RangeCheck(RangeCheckBuiltinRunner::new(8, 8, true));
+        assert_eq!(range_check_builtin.cells_per_instance(), 1);
+    }
+
     #[test]
     fn bitwise_get_used_instances_test() {
         let mut vm = vm!();
@@ -4,12 +4,43 @@ use crate::vm::errors::runner_errors::RunnerError;
 use crate::vm::vm_core::VirtualMachine;
 use crate::vm::vm_memory::memory::Memory;
 use crate::vm::vm_memory::memory_segments::MemorySegmentManager;
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Name of the attribute holding the fact topology tree structure of the output
+pub const GPS_FACT_TOPOLOGY: &str = "gps_fact_topology";
+
+/// Range of the output segment making up a public memory page
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublicMemoryPage {
+    pub start: usize,
+    pub size: usize,
+}
+
+/// Describes how the output pages are arranged into the fact tree of a program
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FactTopology {
+    pub tree_structure: Vec<usize>,
+    pub page_sizes: Vec<usize>,
+}
+
+/// Pages and attributes of the output builtin, along with the offset of the output segment
+/// they are relative to. Saved and restored around the tasks run by the bootloader
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputBuiltinState {
+    pub base_offset: usize,
+    pub pages: HashMap<usize, PublicMemoryPage>,
+    pub attributes: HashMap<String, Vec<usize>>,
+}
 
 #[derive(Debug, Clone)]
 pub struct OutputBuiltinRunner {
     base: isize,
     pub(crate) stop_ptr: Option<usize>,
     pub(crate) _included: bool,
+    pub(crate) base_offset: usize,
+    pub(crate) pages: HashMap<usize, PublicMemoryPage>,
+    pub(crate) attributes: HashMap<String, Vec<usize>>,
 }
 
 impl OutputBuiltinRunner {
@@ -18,6 +49,9 @@ impl OutputBuiltinRunner {
             base: 0,
             stop_ptr: None,
             _included: included,
+            base_offset: 0,
+            pages: HashMap::new(),
+            attributes: HashMap::new(),
         }
     }
 
@@ -116,6 +150,179 @@ impl OutputBuiltinRunner {
             Ok((pointer, stop_ptr))
         }
     }
+
+    pub fn get_state(&self) -> OutputBuiltinState {
+        OutputBuiltinState {
+            base_offset: self.base_offset,
+            pages: self.pages.clone(),
+            attributes: self.attributes.clone(),
+        }
+    }
+
+    pub fn set_state(&mut self, state: OutputBuiltinState) {
+        self.base_offset = state.base_offset;
+        self.pages = state.pages;
+        self.attributes = state.attributes;
+    }
+
+    /// Starts a new output state at base, with no pages nor attributes. Pages added from now on
+    /// are relative to base
+    pub fn new_state(&mut self, base: &Relocatable) -> Result<(), RunnerError> {
+        if base.segment_index != self.base {
+            return Err(RunnerError::PageNotOnOutputSegment(
+                base.segment_index,
+                self.base,
+            ));
+        }
+        self.set_state(OutputBuiltinState {
+            base_offset: base.offset,
+            pages: HashMap::new(),
+            attributes: HashMap::new(),
+        });
+        Ok(())
+    }
+
+    /// Marks the output cells [page_start, page_start + page_size) as the page page_id
+    pub fn add_page(
+        &mut self,
+        page_id: usize,
+        page_start: &Relocatable,
+        page_size: usize,
+    ) -> Result<(), RunnerError> {
+        if page_start.segment_index != self.base {
+            return Err(RunnerError::PageNotOnOutputSegment(
+                page_start.segment_index,
+                self.base,
+            ));
+        }
+        if self.pages.contains_key(&page_id) {
+            return Err(RunnerError::PageIdAlreadyUsed(page_id));
+        }
+        let start = page_start.offset.checked_sub(self.base_offset).ok_or(
+            RunnerError::PageStartBeforeOutputBase(page_id, self.base_offset),
+        )?;
+        self.pages.insert(
+            page_id,
+            PublicMemoryPage {
+                start,
+                size: page_size,
+            },
+        );
+        Ok(())
+    }
+
+    pub fn add_attribute(
+        &mut self,
+        attribute_name: String,
+        attribute_value: Vec<usize>,
+    ) -> Result<(), RunnerError> {
+        if self.attributes.contains_key(&attribute_name) {
+            return Err(RunnerError::OutputAttributeAlreadySet(attribute_name));
+        }
+        self.attributes.insert(attribute_name, attribute_value);
+        Ok(())
+    }
+
+    pub fn get_pages(&self) -> &HashMap<usize, PublicMemoryPage> {
+        &self.pages
+    }
+
+    pub fn get_attributes(&self) -> &HashMap<String, Vec<usize>> {
+        &self.attributes
+    }
+
+    /// Returns the (offset, page_id) pairs of the output cells, cells outside of every page
+    /// belong to page 0
+    pub fn get_public_memory(
+        &self,
+        vm: &VirtualMachine,
+    ) -> Result<Vec<(usize, usize)>, RunnerError> {
+        let size = self.get_used_cells(vm)?;
+        let mut public_memory: Vec<(usize, usize)> = (0..size).map(|offset| (offset, 0)).collect();
+        for (page_id, page) in self.pages.iter() {
+            let page_start = self.base_offset + page.start;
+            for offset in page_start..page_start + page.size {
+                let cell = public_memory
+                    .get_mut(offset)
+                    .ok_or(RunnerError::PageOutOfOutputSegment(*page_id, size))?;
+                cell.1 = *page_id;
+            }
+        }
+        Ok(public_memory)
+    }
+
+    /// Computes the fact topology of an output of the given size from the pages and the
+    /// gps_fact_topology attribute. Without the attribute the whole output must fit in page 0
+    pub fn get_fact_topology(&self, output_size: usize) -> Result<FactTopology, RunnerError> {
+        let tree_structure = match self.attributes.get(GPS_FACT_TOPOLOGY) {
+            Some(tree_structure) => {
+                if tree_structure.is_empty()
+                    || tree_structure.len() % 2 != 0
+                    || tree_structure.len() > 10
+                    || tree_structure.iter().any(|value| *value >= 1 << 30)
+                {
+                    return Err(RunnerError::InvalidFactTopology(format!(
+                        "invalid tree structure {:?}",
+                        tree_structure
+                    )));
+                }
+                tree_structure.clone()
+            }
+            None if self.pages.is_empty() => vec![1, 0],
+            None => {
+                return Err(RunnerError::InvalidFactTopology(format!(
+                    "the output has pages but no {} attribute",
+                    GPS_FACT_TOPOLOGY
+                )))
+            }
+        };
+        Ok(FactTopology {
+            tree_structure,
+            page_sizes: self.get_page_sizes(output_size)?,
+        })
+    }
+
+    // Pages must be numbered from 1 and be adjacent to each other up to the end of the output,
+    // page 0 takes the output cells before page 1
+    fn get_page_sizes(&self, output_size: usize) -> Result<Vec<usize>, RunnerError> {
+        let mut page_ids: Vec<&usize> = self.pages.keys().collect();
+        page_ids.sort();
+        let mut page_sizes = vec![output_size];
+        let mut expected_page_start = None;
+        for (expected_page_id, page_id) in (1..).zip(page_ids) {
+            if *page_id != expected_page_id {
+                return Err(RunnerError::InvalidFactTopology(format!(
+                    "expected page id {}, found {}",
+                    expected_page_id, page_id
+                )));
+            }
+            let page = &self.pages[page_id];
+            match expected_page_start {
+                None if page.start <= output_size => page_sizes[0] = page.start,
+                Some(expected_page_start) if page.start == expected_page_start => (),
+                _ => {
+                    return Err(RunnerError::InvalidFactTopology(format!(
+                        "unexpected start {} of page {}",
+                        page.start, page_id
+                    )))
+                }
+            }
+            if page.size == 0 || page.size > output_size {
+                return Err(RunnerError::InvalidFactTopology(format!(
+                    "invalid size {} of page {}",
+                    page.size, page_id
+                )));
+            }
+            expected_page_start = Some(page.start + page.size);
+            page_sizes.push(page.size);
+        }
+        match expected_page_start {
+            Some(page_end) if page_end != output_size => Err(RunnerError::InvalidFactTopology(
+                "pages must cover the entire program output".to_string(),
+            )),
+            _ => Ok(page_sizes),
+        }
+    }
 }
 
 impl Default for OutputBuiltinRunner {
@@ -366,4 +573,176 @@ mod tests {
         vm.segments.segment_used_sizes = Some(vec![4]);
         assert_eq!(builtin.get_used_cells(&vm), Ok(4));
     }
+
+    #[test]
+    fn add_page_not_on_output_segment() {
+        let mut builtin = OutputBuiltinRunner::new(true);
+        builtin.base = 2;
+        assert_eq!(
+            builtin.add_page(1, &Relocatable::from((1, 0)), 3),
+            Err(RunnerError::PageNotOnOutputSegment(1, 2))
+        );
+    }
+
+    #[test]
+    fn add_page_twice() {
+        let mut builtin = OutputBuiltinRunner::new(true);
+        assert_eq!(builtin.add_page(1, &Relocatable::from((0, 0)), 3), Ok(()));
+        assert_eq!(
+            builtin.add_page(1, &Relocatable::from((0, 3)), 3),
+            Err(RunnerError::PageIdAlreadyUsed(1))
+        );
+    }
+
+    #[test]
+    fn add_attribute_twice() {
+        let mut builtin = OutputBuiltinRunner::new(true);
+        assert_eq!(
+            builtin.add_attribute(GPS_FACT_TOPOLOGY.to_string(), vec![1, 0]),
+            Ok(())
+        );
+        assert_eq!(
+            builtin.add_attribute(GPS_FACT_TOPOLOGY.to_string(), vec![1, 0]),
+            Err(RunnerError::OutputAttributeAlreadySet(
+                GPS_FACT_TOPOLOGY.to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn get_public_memory_with_pages() {
+        let mut builtin = OutputBuiltinRunner::new(true);
+        builtin.add_page(1, &Relocatable::from((0, 1)), 2).unwrap();
+        builtin.add_page(2, &Relocatable::from((0, 3)), 1).unwrap();
+        let mut vm = vm!();
+        vm.segments.segment_used_sizes = Some(vec![5]);
+        assert_eq!(
+            builtin.get_public_memory(&vm),
+            Ok(vec![(0, 0), (1, 1), (2, 1), (3, 2), (4, 0)])
+        );
+    }
+
+    #[test]
+    fn get_public_memory_page_out_of_output_segment() {
+        let mut builtin = OutputBuiltinRunner::new(true);
+        builtin.add_page(1, &Relocatable::from((0, 1)), 4).unwrap();
+        let mut vm = vm!();
+        vm.segments.segment_used_sizes = Some(vec![3]);
+        assert_eq!(
+            builtin.get_public_memory(&vm),
+            Err(RunnerError::PageOutOfOutputSegment(1, 3))
+        );
+    }
+
+    #[test]
+    fn get_fact_topology_without_pages() {
+        let builtin = OutputBuiltinRunner::new(true);
+        assert_eq!(
+            builtin.get_fact_topology(4),
+            Ok(FactTopology {
+                tree_structure: vec![1, 0],
+                page_sizes: vec![4],
+            })
+        );
+    }
+
+    #[test]
+    fn get_fact_topology_with_pages() {
+        let mut builtin = OutputBuiltinRunner::new(true);
+        builtin.add_page(1, &Relocatable::from((0, 2)), 3).unwrap();
+        builtin.add_page(2, &Relocatable::from((0, 5)), 1).unwrap();
+        builtin
+            .add_attribute(GPS_FACT_TOPOLOGY.to_string(), vec![3, 2, 0, 2])
+            .unwrap();
+        assert_eq!(
+            builtin.get_fact_topology(6),
+            Ok(FactTopology {
+                tree_structure: vec![3, 2, 0, 2],
+                page_sizes: vec![2, 3, 1],
+            })
+        );
+    }
+
+    #[test]
+    fn get_fact_topology_pages_without_attribute() {
+        let mut builtin = OutputBuiltinRunner::new(true);
+        builtin.add_page(1, &Relocatable::from((0, 0)), 2).unwrap();
+        assert!(matches!(
+            builtin.get_fact_topology(2),
+            Err(RunnerError::InvalidFactTopology(_))
+        ));
+    }
+
+    #[test]
+    fn get_fact_topology_pages_not_covering_output() {
+        let mut builtin = OutputBuiltinRunner::new(true);
+        builtin.add_page(1, &Relocatable::from((0, 0)), 2).unwrap();
+        builtin
+            .add_attribute(GPS_FACT_TOPOLOGY.to_string(), vec![2, 1, 0, 2])
+            .unwrap();
+        assert_eq!(
+            builtin.get_fact_topology(3),
+            Err(RunnerError::InvalidFactTopology(
+                "pages must cover the entire program output".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn get_fact_topology_non_consecutive_page_ids() {
+        let mut builtin = OutputBuiltinRunner::new(true);
+        builtin.add_page(2, &Relocatable::from((0, 0)), 2).unwrap();
+        builtin
+            .add_attribute(GPS_FACT_TOPOLOGY.to_string(), vec![2, 1, 0, 2])
+            .unwrap();
+        assert_eq!(
+            builtin.get_fact_topology(2),
+            Err(RunnerError::InvalidFactTopology(
+                "expected page id 1, found 2".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn new_state_pages_relative_to_base() {
+        let mut builtin = OutputBuiltinRunner::new(true);
+        builtin.add_page(1, &Relocatable::from((0, 0)), 2).unwrap();
+        let state = builtin.get_state();
+        assert_eq!(builtin.new_state(&Relocatable::from((0, 4))), Ok(()));
+        assert!(builtin.get_pages().is_empty());
+        builtin.add_page(1, &Relocatable::from((0, 5)), 3).unwrap();
+        assert_eq!(
+            builtin.get_pages().get(&1),
+            Some(&PublicMemoryPage { start: 1, size: 3 })
+        );
+        assert_eq!(
+            builtin.get_fact_topology(4),
+            Err(RunnerError::InvalidFactTopology(format!(
+                "the output has pages but no {} attribute",
+                GPS_FACT_TOPOLOGY
+            )))
+        );
+        builtin.set_state(state.clone());
+        assert_eq!(builtin.get_state(), state);
+    }
+
+    #[test]
+    fn new_state_not_on_output_segment() {
+        let mut builtin = OutputBuiltinRunner::new(true);
+        builtin.base = 2;
+        assert_eq!(
+            builtin.new_state(&Relocatable::from((1, 4))),
+            Err(RunnerError::PageNotOnOutputSegment(1, 2))
+        );
+    }
+
+    #[test]
+    fn add_page_before_state_base() {
+        let mut builtin = OutputBuiltinRunner::new(true);
+        builtin.new_state(&Relocatable::from((0, 4))).unwrap();
+        assert_eq!(
+            builtin.add_page(1, &Relocatable::from((0, 2)), 3),
+            Err(RunnerError::PageStartBeforeOutputBase(1, 4))
+        );
+    }
 }
@@ -1,16 +1,19 @@
 use crate::{
     bigint,
-    hint_processor::hint_processor_definition::{HintProcessor, HintReference},
+    hint_processor::{
+        hint_processor_definition::{HintProcessor, HintReference},
+        hint_processor_utils::{compile_program_hints, get_program_reference_list},
+    },
     math_utils::safe_div,
     math_utils::safe_div_usize,
     types::{
         errors::program_errors::ProgramError,
         exec_scope::ExecutionScopes,
+        hint_whitelist::HintWhitelist,
         instance_definitions::{
             bitwise_instance_def::BitwiseInstanceDef, ec_op_instance_def::EcOpInstanceDef,
             ecdsa_instance_def::EcdsaInstanceDef,
         },
-        instruction::Register,
         layout::CairoLayout,
         program::Program,
         relocatable::{relocate_address, relocate_value, MaybeRelocatable, Relocatable},
@@ -25,8 +28,9 @@ use crate::{
         trace::get_perm_range_check_limits,
         {
             runners::builtin_runner::{
-                BitwiseBuiltinRunner, BuiltinRunner, EcOpBuiltinRunner, HashBuiltinRunner,
-                OutputBuiltinRunner, RangeCheckBuiltinRunner, SignatureBuiltinRunner,
+                BitwiseBuiltinRunner, BuiltinRunner, EcOpBuiltinRunner, FactTopology,
+                HashBuiltinRunner, OutputBuiltinRunner, RangeCheckBuiltinRunner,
+                SignatureBuiltinRunner,
             },
             trace::trace_entry::{relocate_trace_register, RelocatedTraceEntry},
             vm_core::VirtualMachine,
@@ -35,13 +39,17 @@ use crate::{
 };
 use num_bigint::BigInt;
 use num_traits::ToPrimitive;
+use serde::Serialize;
 use std::{
     any::Any,
     collections::{HashMap, HashSet},
     io,
+    rc::Rc,
 };
 
 use super::builtin_runner::KeccakBuiltinRunner;
+use super::gas_meter::{GasCosts, GasMeter};
+use super::run_resources::{ExhaustedResource, RunResources};
 
 pub struct CairoRunner {
     pub(crate) program: Program,
@@ -57,7 +65,11 @@ pub struct CairoRunner {
     segments_finalized: bool,
     execution_public_memory: Option<Vec<usize>>,
     proof_mode: bool,
+    hint_whitelist: Option<HintWhitelist>,
+    run_resources: RunResources,
     pub original_steps: Option<usize>,
+    pub consumed_gas: Option<u64>,
+    pub fact_topology: Option<FactTopology>,
     pub relocated_memory: Vec<Option<BigInt>>,
     pub relocated_trace: Option<Vec<RelocatedTraceEntry>>,
     pub exec_scopes: ExecutionScopes,
@@ -92,7 +104,11 @@ impl CairoRunner {
             run_ended: false,
             segments_finalized: false,
             proof_mode,
+            hint_whitelist: None,
+            run_resources: RunResources::default(),
             original_steps: None,
+            consumed_gas: None,
+            fact_topology: None,
             relocated_memory: Vec::new(),
             relocated_trace: None,
             exec_scopes: ExecutionScopes::new(),
@@ -412,6 +428,9 @@ impl CairoRunner {
         vm._program_base = Some(MaybeRelocatable::from(
             self.program_base.as_ref().ok_or(RunnerError::NoProgBase)?,
         ));
+        // Programs loaded during the run are subject to the same whitelist
+        vm.hint_whitelist = self.hint_whitelist.clone();
+        vm.identifiers = Rc::new(self.program.identifiers.clone());
         for (_, builtin) in vm.builtin_runners.iter() {
             builtin.add_validation_rule(&mut vm.memory)?;
         }
@@ -439,29 +458,20 @@ impl CairoRunner {
     }
 
     pub fn get_reference_list(&self) -> HashMap<usize, HintReference> {
-        let mut references = HashMap::<usize, HintReference>::new();
-
-        for (i, reference) in self.program.reference_manager.references.iter().enumerate() {
-            references.insert(
-                i,
-                HintReference {
-                    register: reference.value_address.register.clone(),
-                    offset1: reference.value_address.offset1,
-                    offset2: reference.value_address.offset2,
-                    inner_dereference: reference.value_address.inner_dereference,
-                    dereference: reference.value_address.dereference,
-                    immediate: reference.value_address.immediate.clone(),
-                    // only store `ap` tracking data if the reference is referred to it
-                    ap_tracking_data: if reference.value_address.register == Some(Register::FP) {
-                        None
-                    } else {
-                        Some(reference.ap_tracking_data.clone())
-                    },
-                    cairo_type: Some(reference.value_address.value_type.clone()),
-                },
-            );
+        get_program_reference_list(&self.program)
+    }
+
+    ///Restricts the hints the program may contain to those allowed by the whitelist.
+    ///Programs with other hints are rejected when their hints are compiled.
+    pub fn set_hint_whitelist(&mut self, hint_whitelist: HintWhitelist) {
+        self.hint_whitelist = Some(hint_whitelist);
+    }
+
+    fn check_hint_whitelist(&self) -> Result<(), VirtualMachineError> {
+        match &self.hint_whitelist {
+            Some(hint_whitelist) => hint_whitelist.check_program(&self.program),
+            None => Ok(()),
         }
-        references
     }
 
     /// Gets the data used by the HintProcessor to execute each hint
@@ -470,25 +480,8 @@ impl CairoRunner {
         references: &HashMap<usize, HintReference>,
         hint_executor: &dyn HintProcessor,
     ) -> Result<HashMap<usize, Vec<Box<dyn Any>>>, VirtualMachineError> {
-        let mut hint_data_dictionary = HashMap::<usize, Vec<Box<dyn Any>>>::new();
-        for (hint_index, hints) in self.program.hints.iter() {
-            for hint in hints {
-                let hint_data = hint_executor.compile_hint(
-                    &hint.code,
-                    &hint.flow_tracking_data.ap_tracking,
-                    &hint.flow_tracking_data.reference_ids,
-                    references,
-                );
-                hint_data_dictionary
-                    .entry(*hint_index)
-                    .or_insert(vec![])
-                    .push(
-                        hint_data
-                            .map_err(|_| VirtualMachineError::CompileHintFail(hint.code.clone()))?,
-                    );
-            }
-        }
-        Ok(hint_data_dictionary)
+        self.check_hint_whitelist()?;
+        compile_program_hints(&self.program, references, hint_executor)
     }
 
     pub fn get_constants(&self) -> &HashMap<String, BigInt> {
@@ -499,6 +492,10 @@ impl CairoRunner {
         &self.program.builtins
     }
 
+    pub fn get_program(&self) -> &Program {
+        &self.program
+    }
+
     pub fn run_until_pc(
         &mut self,
         address: Relocatable,
@@ -507,17 +504,99 @@ impl CairoRunner {
     ) -> Result<(), VirtualMachineError> {
         let references = self.get_reference_list();
         let hint_data_dictionary = self.get_hint_data_dictionary(&references, hint_processor)?;
+        self.initialize_gas_meter(vm);
+        vm.memory.set_max_cells(self.run_resources.max_memory_cells);
         while vm.run_context.pc != address {
-            vm.step(
+            self.check_run_resources(vm)?;
+            if let Err(error) = vm.step(
                 hint_processor,
                 &mut self.exec_scopes,
                 &hint_data_dictionary,
                 &self.program.constants,
-            )?;
+            ) {
+                return Err(self.memory_exhausted_error(vm, error));
+            }
         }
         Ok(())
     }
 
+    ///Limits the resources used by the following runs
+    pub fn set_run_resources(&mut self, run_resources: RunResources) {
+        self.run_resources = run_resources;
+    }
+
+    // Starts metering the vm if a gas budget was set, unless it already has its own gas meter
+    fn initialize_gas_meter(&self, vm: &mut VirtualMachine) {
+        if let Some(gas_budget) = self.run_resources.gas_budget {
+            if vm.gas_meter.is_none() {
+                let costs = GasCosts::from_builtin_ratios(1, &vm.builtin_runners);
+                vm.set_gas_meter(GasMeter::new(costs, gas_budget));
+            }
+        }
+    }
+
+    fn check_run_resources(&self, vm: &mut VirtualMachine) -> Result<(), VirtualMachineError> {
+        match self.run_resources.check(vm) {
+            Ok(()) => Ok(()),
+            Err(exhausted_resource) => Err(VirtualMachineError::ResourcesExhausted(
+                exhausted_resource,
+                self.get_partial_execution_resources(vm)?,
+            )),
+        }
+    }
+
+    // Writes beyond the memory cell limit fail before allocating the cells, they are reported
+    // like the limits checked between steps
+    fn memory_exhausted_error(
+        &self,
+        vm: &mut VirtualMachine,
+        error: VirtualMachineError,
+    ) -> VirtualMachineError {
+        match error {
+            VirtualMachineError::MemoryError(MemoryError::MemoryCellsExhausted(
+                max_memory_cells,
+            )) => match self.get_partial_execution_resources(vm) {
+                Ok(execution_resources) => VirtualMachineError::ResourcesExhausted(
+                    ExhaustedResource::MemoryCells(max_memory_cells),
+                    execution_resources,
+                ),
+                Err(error) => error,
+            },
+            error => error,
+        }
+    }
+
+    ///Returns the resources used so far by a run which hasn't ended.
+    ///Memory holes are only known after the run ends, so they are not counted.
+    pub fn get_partial_execution_resources(
+        &self,
+        vm: &mut VirtualMachine,
+    ) -> Result<ExecutionResources, VirtualMachineError> {
+        // The segment sizes are only needed to count the builtin instances, they are dropped
+        // afterwards so that the run can still be resumed and ended
+        let segment_sizes_computed = vm.segments.segment_used_sizes.is_some();
+        vm.segments.compute_effective_sizes(&vm.memory);
+        let builtin_instance_counter = vm
+            .builtin_runners
+            .iter()
+            .map(|(builtin_name, builtin_runner)| {
+                Ok((
+                    builtin_name.to_string(),
+                    builtin_runner.get_used_instances(vm)?,
+                ))
+            })
+            .collect::<Result<HashMap<_, _>, MemoryError>>();
+        if !segment_sizes_computed {
+            vm.segments.segment_used_sizes = None;
+        }
+
+        Ok(ExecutionResources {
+            n_steps: vm.current_step,
+            n_memory_holes: 0,
+            builtin_instance_counter: builtin_instance_counter?,
+        })
+    }
+
     /// Execute an exact number of steps on the program from the actual position.
     pub fn run_for_steps(
         &mut self,
@@ -527,18 +606,23 @@ impl CairoRunner {
     ) -> Result<(), VirtualMachineError> {
         let references = self.get_reference_list();
         let hint_data_dictionary = self.get_hint_data_dictionary(&references, hint_processor)?;
+        self.initialize_gas_meter(vm);
+        vm.memory.set_max_cells(self.run_resources.max_memory_cells);
 
         for remaining_steps in (1..=steps).rev() {
             if self.final_pc.as_ref() == Some(&vm.run_context.pc) {
                 return Err(VirtualMachineError::EndOfProgram(remaining_steps));
             }
 
-            vm.step(
+            self.check_run_resources(vm)?;
+            if let Err(error) = vm.step(
                 hint_processor,
                 &mut self.exec_scopes,
                 &hint_data_dictionary,
                 &self.program.constants,
-            )?;
+            ) {
+                return Err(self.memory_exhausted_error(vm, error));
+            }
         }
 
         Ok(())
@@ -712,6 +796,7 @@ impl CairoRunner {
 
         vm.memory.relocate_memory()?;
         vm.end_run(&self.exec_scopes)?;
+        self.consumed_gas = vm.get_gas_meter().map(GasMeter::consumed_gas);
 
         if disable_finalize_all {
             return Ok(());
@@ -909,6 +994,28 @@ impl CairoRunner {
         Ok(())
     }
 
+    /// Computes the fact topology of the program output from the pages and attributes added to
+    /// the output builtin. Returns None if the output builtin is not present in the program.
+    pub fn get_fact_topology(
+        &self,
+        vm: &VirtualMachine,
+    ) -> Result<Option<FactTopology>, RunnerError> {
+        let output_builtin = vm
+            .builtin_runners
+            .iter()
+            .find_map(|(_, builtin)| match builtin {
+                BuiltinRunner::Output(output_builtin) => Some(output_builtin),
+                _ => None,
+            });
+        match output_builtin {
+            Some(output_builtin) => {
+                let output_size = output_builtin.get_used_cells(vm)?;
+                Ok(Some(output_builtin.get_fact_topology(output_size)?))
+            }
+            None => Ok(None),
+        }
+    }
+
     // Finalizes the segments.
     //     Note:
     //     1.  end_run() must precede a call to this method.
@@ -953,8 +1060,18 @@ impl CairoRunner {
             let (_, size) = builtin_runner
                 .get_used_cells_and_allocated_size(vm)
                 .map_err(RunnerError::FinalizeSegements)?;
-            vm.segments
-                .finalize(Some(size), builtin_runner.base() as usize, None)
+            // The output cells are public, tagged with the id of the page they belong to
+            let public_memory = match builtin_runner {
+                BuiltinRunner::Output(output_builtin) => {
+                    Some(output_builtin.get_public_memory(vm)?)
+                }
+                _ => None,
+            };
+            vm.segments.finalize(
+                Some(size),
+                builtin_runner.base() as usize,
+                public_memory.as_ref(),
+            )
         }
         self.segments_finalized = true;
         Ok(())
@@ -1147,7 +1264,7 @@ pub struct SegmentInfo {
     pub size: usize,
 }
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
 pub struct ExecutionResources {
     pub n_steps: usize,
     pub n_memory_holes: usize,
@@ -1161,9 +1278,16 @@ mod tests {
         bigint, bigint_str,
         hint_processor::builtin_hint_processor::builtin_hint_processor_definition::BuiltinHintProcessor,
         relocatable,
-        serde::deserialize_program::{Identifier, ReferenceManager},
-        types::instance_definitions::bitwise_instance_def::BitwiseInstanceDef,
+        serde::deserialize_program::{
+            ApTracking, FlowTrackingData, HintParams, Identifier, Member, Reference,
+            ReferenceManager, ValueAddress,
+        },
+        serde::reference_expression::parse_reference_expression,
+        types::{
+            instance_definitions::bitwise_instance_def::BitwiseInstanceDef, instruction::Register,
+        },
         utils::test_utils::*,
+        vm::runners::run_resources::ExhaustedResource,
         vm::{trace::trace_entry::TraceEntry, vm_memory::memory::Memory},
     };
     use num_bigint::Sign;
@@ -2880,6 +3004,171 @@ mod tests {
         );
     }
 
+    #[test]
+    fn run_until_pc_with_run_resources() {
+        let program = program!(
+            builtins = vec![String::from("range_check")],
+            data = vec_data!(
+                (4612671182993129469_i64),
+                (5189976364521848832_i64),
+                (18446744073709551615_i128),
+                (5199546496550207487_i64),
+                (4612389712311386111_i64),
+                (5198983563776393216_i64),
+                (2),
+                (2345108766317314046_i64),
+                (5191102247248822272_i64),
+                (5189976364521848832_i64),
+                (7),
+                (1226245742482522112_i64),
+                ((
+                    b"3618502788666131213697322783095070105623107215331596699973092056135872020470",
+                    10
+                )),
+                (2345108766317314046_i64)
+            ),
+            main = Some(8),
+        );
+
+        let hint_processor = BuiltinHintProcessor::new_empty();
+        let mut cairo_runner = cairo_runner!(&program);
+        let mut vm = vm!();
+        let end = cairo_runner.initialize(&mut vm).unwrap();
+        cairo_runner.set_run_resources(RunResources {
+            max_steps: Some(5),
+            ..Default::default()
+        });
+
+        assert_eq!(
+            cairo_runner.run_until_pc(end.clone(), &mut vm, &hint_processor),
+            Err(VirtualMachineError::ResourcesExhausted(
+                ExhaustedResource::Steps(5),
+                ExecutionResources {
+                    n_steps: 5,
+                    n_memory_holes: 0,
+                    builtin_instance_counter: HashMap::from([("range_check".to_string(), 1)]),
+                }
+            ))
+        );
+        assert_eq!(vm.segments.segment_used_sizes, None);
+
+        // Full takes 10 steps.
+        cairo_runner.set_run_resources(RunResources {
+            max_steps: Some(10),
+            ..Default::default()
+        });
+        assert_eq!(
+            cairo_runner.run_until_pc(end, &mut vm, &hint_processor),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn run_until_pc_with_memory_cells_limit() {
+        let program = program!(
+            builtins = vec![String::from("range_check")],
+            data = vec_data!(
+                (4612671182993129469_i64),
+                (5189976364521848832_i64),
+                (18446744073709551615_i128),
+                (5199546496550207487_i64),
+                (4612389712311386111_i64),
+                (5198983563776393216_i64),
+                (2),
+                (2345108766317314046_i64),
+                (5191102247248822272_i64),
+                (5189976364521848832_i64),
+                (7),
+                (1226245742482522112_i64),
+                ((
+                    b"3618502788666131213697322783095070105623107215331596699973092056135872020470",
+                    10
+                )),
+                (2345108766317314046_i64)
+            ),
+            main = Some(8),
+        );
+
+        let hint_processor = BuiltinHintProcessor::new_empty();
+        let mut cairo_runner = cairo_runner!(&program);
+        let mut vm = vm!();
+        let end = cairo_runner.initialize(&mut vm).unwrap();
+        // The run can't allocate any cell beyond those written by the initialization
+        let allocated_cells = vm.memory.allocated_cells();
+        cairo_runner.set_run_resources(RunResources {
+            max_memory_cells: Some(allocated_cells),
+            ..Default::default()
+        });
+
+        assert!(matches!(
+            cairo_runner.run_until_pc(end, &mut vm, &hint_processor),
+            Err(VirtualMachineError::ResourcesExhausted(
+                ExhaustedResource::MemoryCells(max_memory_cells),
+                _
+            )) if max_memory_cells == allocated_cells
+        ));
+        assert_eq!(vm.memory.allocated_cells(), allocated_cells);
+    }
+
+    #[test]
+    fn run_until_pc_with_gas_budget() {
+        let program = program!(
+            builtins = vec![String::from("range_check")],
+            data = vec_data!(
+                (4612671182993129469_i64),
+                (5189976364521848832_i64),
+                (18446744073709551615_i128),
+                (5199546496550207487_i64),
+                (4612389712311386111_i64),
+                (5198983563776393216_i64),
+                (2),
+                (2345108766317314046_i64),
+                (5191102247248822272_i64),
+                (5189976364521848832_i64),
+                (7),
+                (1226245742482522112_i64),
+                ((
+                    b"3618502788666131213697322783095070105623107215331596699973092056135872020470",
+                    10
+                )),
+                (2345108766317314046_i64)
+            ),
+            main = Some(8),
+        );
+
+        let hint_processor = BuiltinHintProcessor::new_empty();
+        let run_resources = RunResources {
+            gas_budget: Some(20),
+            ..Default::default()
+        };
+
+        let mut cairo_runner = cairo_runner!(&program);
+        let mut vm = vm!();
+        let end = cairo_runner.initialize(&mut vm).unwrap();
+        cairo_runner.set_run_resources(run_resources.clone());
+        // 7 steps plus 2 range_check instances at a ratio of 8 each
+        assert_eq!(
+            cairo_runner.run_until_pc(end, &mut vm, &hint_processor),
+            Err(VirtualMachineError::OutOfGas(20, 23))
+        );
+
+        let mut cairo_runner = cairo_runner!(&program);
+        let mut vm = vm!();
+        let end = cairo_runner.initialize(&mut vm).unwrap();
+        cairo_runner.set_run_resources(RunResources {
+            gas_budget: Some(26),
+            ..run_resources
+        });
+        assert_eq!(
+            cairo_runner.run_until_pc(end, &mut vm, &hint_processor),
+            Ok(())
+        );
+        cairo_runner
+            .end_run(false, false, &mut vm, &hint_processor)
+            .unwrap();
+        assert_eq!(cairo_runner.consumed_gas, Some(26));
+    }
+
     #[test]
     /*Program used:
     %builtins range_check
@@ -4124,6 +4413,8 @@ mod tests {
                 value: None,
                 full_name: None,
                 members: None,
+                cairo_type: None,
+                destination: None,
             },
         )]
         .into_iter()
@@ -4150,6 +4441,8 @@ mod tests {
                     value: None,
                     full_name: None,
                     members: None,
+                    cairo_type: None,
+                    destination: None,
                 },
             ),
             (
@@ -4160,6 +4453,8 @@ mod tests {
                     value: None,
                     full_name: None,
                     members: None,
+                    cairo_type: None,
+                    destination: None,
                 },
             ),
         ]
@@ -4187,6 +4482,8 @@ mod tests {
                 value: None,
                 full_name: None,
                 members: None,
+                cairo_type: None,
+                destination: None,
             },
         )]
         .into_iter()
@@ -4368,4 +4665,165 @@ mod tests {
             Ok(()),
         );
     }
+
+    #[test]
+    fn get_reference_list_resolves_struct_members() {
+        let members = HashMap::from([(
+            "low".to_string(),
+            Member {
+                cairo_type: "felt".to_string(),
+                offset: 0,
+            },
+        )]);
+        let reference = |value_type: &str| Reference {
+            ap_tracking_data: ApTracking::new(),
+            pc: None,
+            value_address: ValueAddress {
+                register: Some(Register::FP),
+                offset1: -3,
+                offset2: 0,
+                immediate: None,
+                dereference: true,
+                inner_dereference: false,
+                value_type: value_type.to_string(),
+                expression: None,
+            },
+        };
+        let program = program!(
+            reference_manager = ReferenceManager {
+                references: vec![reference("a.Uint256*"), reference("felt")],
+            },
+            identifiers = HashMap::from([(
+                "a.Uint256".to_string(),
+                Identifier {
+                    pc: None,
+                    type_: Some("struct".to_string()),
+                    value: None,
+                    full_name: Some("a.Uint256".to_string()),
+                    members: Some(members.clone()),
+                    cairo_type: None,
+                    destination: None,
+                },
+            )]),
+        );
+        let cairo_runner = cairo_runner!(program);
+
+        let references = cairo_runner.get_reference_list();
+        assert_eq!(references[&0].members, Some(members));
+        assert_eq!(references[&1].members, None);
+    }
+
+    #[test]
+    fn get_reference_list_resolves_expression_members() {
+        let expression = parse_reference_expression("[cast([ap + (-1)] * 2, a.Point*)].y")
+            .unwrap()
+            .1;
+        let program = program!(
+            reference_manager = ReferenceManager {
+                references: vec![Reference {
+                    ap_tracking_data: ApTracking::new(),
+                    pc: None,
+                    value_address: ValueAddress::from_expression(expression),
+                }],
+            },
+            identifiers = HashMap::from([(
+                "a.Point".to_string(),
+                Identifier {
+                    pc: None,
+                    type_: Some("struct".to_string()),
+                    value: None,
+                    full_name: Some("a.Point".to_string()),
+                    members: Some(HashMap::from([
+                        (
+                            "x".to_string(),
+                            Member {
+                                cairo_type: "felt".to_string(),
+                                offset: 0,
+                            },
+                        ),
+                        (
+                            "y".to_string(),
+                            Member {
+                                cairo_type: "felt".to_string(),
+                                offset: 1,
+                            },
+                        ),
+                    ])),
+                    cairo_type: None,
+                    destination: None,
+                },
+            )]),
+        );
+        let cairo_runner = cairo_runner!(program);
+
+        let references = cairo_runner.get_reference_list();
+        assert_eq!(references[&0].cairo_type, Some("felt".to_string()));
+        assert!(references[&0].dereference);
+        assert_eq!(
+            references[&0].expression,
+            Some(
+                parse_reference_expression("[cast(cast([ap + (-1)] * 2, a.Point*) + 1, felt*)]")
+                    .unwrap()
+                    .1
+            )
+        );
+    }
+
+    fn hint_params(code: &str) -> HintParams {
+        HintParams {
+            code: code.to_string(),
+            accessible_scopes: Vec::new(),
+            flow_tracking_data: FlowTrackingData {
+                ap_tracking: ApTracking::new(),
+                reference_ids: HashMap::new(),
+            },
+        }
+    }
+
+    #[test]
+    fn get_hint_data_dictionary_with_whitelisted_hints() {
+        let program = program!(
+            hints = HashMap::from([(0, vec![hint_params("memory[ap] = segments.add()")])]),
+        );
+        let mut cairo_runner = cairo_runner!(program);
+        cairo_runner.set_hint_whitelist(HintWhitelist::new(vec![
+            "memory[ap] = segments.add()".to_string()
+        ]));
+        let hint_processor = BuiltinHintProcessor::new_empty();
+
+        let hint_data_dictionary =
+            cairo_runner.get_hint_data_dictionary(&HashMap::new(), &hint_processor);
+        assert_eq!(hint_data_dictionary.map(|hints| hints.len()), Ok(1));
+    }
+
+    #[test]
+    fn get_hint_data_dictionary_rejects_hints_not_whitelisted() {
+        let program = program!(
+            hints = HashMap::from([
+                (
+                    4,
+                    vec![
+                        hint_params("memory[ap] = segments.add()"),
+                        hint_params("memory[ap] = 1"),
+                    ]
+                ),
+                (2, vec![hint_params("memory[ap] = 2")]),
+            ]),
+        );
+        let mut cairo_runner = cairo_runner!(program);
+        cairo_runner.set_hint_whitelist(HintWhitelist::new(vec![
+            "memory[ap] = segments.add()".to_string()
+        ]));
+        let hint_processor = BuiltinHintProcessor::new_empty();
+
+        assert_eq!(
+            cairo_runner
+                .get_hint_data_dictionary(&HashMap::new(), &hint_processor)
+                .err(),
+            Some(VirtualMachineError::HintsNotWhitelisted(vec![
+                (2, "memory[ap] = 2".to_string()),
+                (4, "memory[ap] = 1".to_string()),
+            ]))
+        );
+    }
 }
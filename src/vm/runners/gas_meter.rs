@@ -0,0 +1,167 @@
+use crate::vm::errors::vm_errors::VirtualMachineError;
+use crate::vm::runners::builtin_runner::BuiltinRunner;
+use crate::vm::vm_core::VirtualMachine;
+use num_integer::div_ceil;
+use std::collections::HashMap;
+
+/// Gas price of each execution resource.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GasCosts {
+    ///Gas charged for each executed step
+    pub step: u64,
+    ///Gas charged for each used instance of the builtin, by builtin name
+    pub builtins: HashMap<String, u64>,
+}
+
+impl GasCosts {
+    ///Prices each builtin instance as the `ratio` steps it takes up in the layout, so that
+    ///builtin usage is charged proportionally to its share of the trace
+    pub fn from_builtin_ratios(step: u64, builtin_runners: &[(String, BuiltinRunner)]) -> Self {
+        GasCosts {
+            step,
+            builtins: builtin_runners
+                .iter()
+                .map(|(name, builtin_runner)| {
+                    let ratio = builtin_runner.ratio().unwrap_or(0) as u64;
+                    (name.clone(), step.saturating_mul(ratio))
+                })
+                .collect(),
+        }
+    }
+
+    ///Returns the gas consumed by the vm so far.
+    ///Builtin instances are counted from the memory written into their segments, so they can
+    ///be priced while the run is in progress.
+    pub fn compute_gas(&self, vm: &VirtualMachine) -> u64 {
+        let builtins_gas = vm
+            .builtin_runners
+            .iter()
+            .map(|(name, builtin_runner)| {
+                let used_cells = usize::try_from(builtin_runner.base())
+                    .ok()
+                    .and_then(|index| vm.memory.data.get(index))
+                    .map_or(0, Vec::len);
+                let used_instances =
+                    div_ceil(used_cells, builtin_runner.cells_per_instance() as usize);
+                self.builtins
+                    .get(name)
+                    .copied()
+                    .unwrap_or(0)
+                    .saturating_mul(used_instances as u64)
+            })
+            .fold(0, u64::saturating_add);
+        self.step
+            .saturating_mul(vm.current_step as u64)
+            .saturating_add(builtins_gas)
+    }
+}
+
+/// Meters the gas consumed by a vm against a budget, aborting the run with
+/// `VirtualMachineError::OutOfGas` once the budget is exceeded.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GasMeter {
+    costs: GasCosts,
+    budget: u64,
+    consumed_gas: u64,
+}
+
+impl GasMeter {
+    pub fn new(costs: GasCosts, budget: u64) -> Self {
+        GasMeter {
+            costs,
+            budget,
+            consumed_gas: 0,
+        }
+    }
+
+    pub fn costs(&self) -> &GasCosts {
+        &self.costs
+    }
+
+    pub fn budget(&self) -> u64 {
+        self.budget
+    }
+
+    pub fn consumed_gas(&self) -> u64 {
+        self.consumed_gas
+    }
+
+    ///Updates the consumed gas with the resources used by the vm so far
+    pub(crate) fn charge(&mut self, vm: &VirtualMachine) -> Result<(), VirtualMachineError> {
+        self.consumed_gas = self.costs.compute_gas(vm);
+        if self.consumed_gas > self.budget {
+            return Err(VirtualMachineError::OutOfGas(
+                self.budget,
+                self.consumed_gas,
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bigint;
+    use crate::types::relocatable::MaybeRelocatable;
+    use crate::utils::test_utils::*;
+    use crate::vm::errors::memory_errors::MemoryError;
+    use crate::vm::runners::builtin_runner::{HashBuiltinRunner, OutputBuiltinRunner};
+    use crate::vm::vm_memory::memory::Memory;
+    use num_bigint::{BigInt, Sign};
+
+    fn vm_with_builtins() -> VirtualMachine {
+        let mut vm = vm!();
+        let mut hash_builtin = HashBuiltinRunner::new(8, true);
+        let mut output_builtin = OutputBuiltinRunner::new(true);
+        add_segments!(vm, 2);
+        hash_builtin.initialize_segments(&mut vm.segments, &mut vm.memory);
+        output_builtin.initialize_segments(&mut vm.segments, &mut vm.memory);
+        vm.builtin_runners = vec![
+            ("pedersen".to_string(), hash_builtin.into()),
+            ("output".to_string(), output_builtin.into()),
+        ];
+        vm
+    }
+
+    #[test]
+    fn gas_costs_from_builtin_ratios() {
+        let vm = vm_with_builtins();
+
+        assert_eq!(
+            GasCosts::from_builtin_ratios(10, &vm.builtin_runners),
+            GasCosts {
+                step: 10,
+                builtins: HashMap::from([("pedersen".to_string(), 80), ("output".to_string(), 0)]),
+            }
+        );
+    }
+
+    #[test]
+    fn compute_gas_of_steps_and_builtins() {
+        let mut vm = vm_with_builtins();
+        vm.memory = memory![((2, 0), 1), ((2, 1), 2), ((2, 3), 3), ((3, 0), 4)];
+        vm.current_step = 7;
+        let costs = GasCosts::from_builtin_ratios(10, &vm.builtin_runners);
+
+        // 7 steps and 2 pedersen instances
+        assert_eq!(costs.compute_gas(&vm), 70 + 160);
+    }
+
+    #[test]
+    fn charge_gas_over_budget() {
+        let mut vm = vm_with_builtins();
+        let mut gas_meter =
+            GasMeter::new(GasCosts::from_builtin_ratios(10, &vm.builtin_runners), 50);
+
+        vm.current_step = 5;
+        assert_eq!(gas_meter.charge(&vm), Ok(()));
+        assert_eq!(gas_meter.consumed_gas(), 50);
+        vm.current_step = 6;
+        assert_eq!(
+            gas_meter.charge(&vm),
+            Err(VirtualMachineError::OutOfGas(50, 60))
+        );
+        assert_eq!(gas_meter.consumed_gas(), 60);
+    }
+}
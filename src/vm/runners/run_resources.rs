@@ -0,0 +1,134 @@
+use crate::vm::vm_core::VirtualMachine;
+use std::time::Instant;
+
+/// Limits on the resources a run may use. Runs exceeding any of them are aborted with
+/// `VirtualMachineError::ResourcesExhausted`.
+/// Unset limits are not enforced, so the default value doesn't restrict runs at all.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RunResources {
+    ///Maximum number of steps executed
+    pub max_steps: Option<usize>,
+    ///Maximum number of memory cells allocated across all segments, including temporary ones
+    pub max_memory_cells: Option<usize>,
+    ///Maximum number of segments, including temporary ones
+    pub max_segments: Option<usize>,
+    ///Point in time after which no more steps are executed
+    pub deadline: Option<Instant>,
+    ///Maximum gas consumed, charging one gas per step and `ratio` gas per builtin instance.
+    ///Runs exceeding it are aborted with `VirtualMachineError::OutOfGas` instead.
+    pub gas_budget: Option<u64>,
+}
+
+/// Resource whose limit was reached, along with the limit.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ExhaustedResource {
+    Steps(usize),
+    MemoryCells(usize),
+    Segments(usize),
+    Deadline,
+}
+
+impl RunResources {
+    ///Checks that the vm can execute another step without exceeding the limits
+    pub fn check(&self, vm: &VirtualMachine) -> Result<(), ExhaustedResource> {
+        if let Some(max_steps) = self.max_steps {
+            if vm.current_step >= max_steps {
+                return Err(ExhaustedResource::Steps(max_steps));
+            }
+        }
+        if let Some(max_segments) = self.max_segments {
+            if vm.memory.data.len() + vm.memory.temp_data.len() > max_segments {
+                return Err(ExhaustedResource::Segments(max_segments));
+            }
+        }
+        if let Some(max_memory_cells) = self.max_memory_cells {
+            if vm.memory.allocated_cells() > max_memory_cells {
+                return Err(ExhaustedResource::MemoryCells(max_memory_cells));
+            }
+        }
+        if let Some(deadline) = self.deadline {
+            if Instant::now() >= deadline {
+                return Err(ExhaustedResource::Deadline);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bigint;
+    use crate::relocatable;
+    use crate::types::relocatable::MaybeRelocatable;
+    use crate::utils::test_utils::*;
+    use crate::vm::errors::memory_errors::MemoryError;
+    use crate::vm::vm_memory::memory::Memory;
+    use num_bigint::{BigInt, Sign};
+    use std::time::Duration;
+
+    #[test]
+    fn unlimited_resources() {
+        let mut vm = vm!();
+        vm.memory = memory![((0, 0), 1), ((1, 5), 2)];
+        vm.current_step = 100;
+
+        assert_eq!(RunResources::default().check(&vm), Ok(()));
+    }
+
+    #[test]
+    fn steps_exhausted() {
+        let mut vm = vm!();
+        let run_resources = RunResources {
+            max_steps: Some(3),
+            ..Default::default()
+        };
+        vm.current_step = 2;
+        assert_eq!(run_resources.check(&vm), Ok(()));
+        vm.current_step = 3;
+        assert_eq!(run_resources.check(&vm), Err(ExhaustedResource::Steps(3)));
+    }
+
+    #[test]
+    fn memory_exhausted() {
+        let mut vm = vm!();
+        vm.memory = memory![((0, 0), 1), ((1, 5), 2)];
+        vm.memory.temp_data = vec![Vec::new()];
+        vm.memory
+            .insert_value(&relocatable!(-1, 0), bigint!(3))
+            .unwrap();
+        let run_resources = |max_memory_cells, max_segments| RunResources {
+            max_memory_cells: Some(max_memory_cells),
+            max_segments: Some(max_segments),
+            ..Default::default()
+        };
+
+        assert_eq!(run_resources(8, 3).check(&vm), Ok(()));
+        assert_eq!(
+            run_resources(7, 3).check(&vm),
+            Err(ExhaustedResource::MemoryCells(7))
+        );
+        assert_eq!(
+            run_resources(8, 2).check(&vm),
+            Err(ExhaustedResource::Segments(2))
+        );
+    }
+
+    #[test]
+    fn deadline_exhausted() {
+        let vm = vm!();
+        let run_resources = |deadline| RunResources {
+            deadline: Some(deadline),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            run_resources(Instant::now() + Duration::from_secs(60)).check(&vm),
+            Ok(())
+        );
+        assert_eq!(
+            run_resources(Instant::now()).check(&vm),
+            Err(ExhaustedResource::Deadline)
+        );
+    }
+}
@@ -17,6 +17,8 @@ use std::{collections::HashMap, mem::swap};
 ///     the builtins themselves.
 ///   - There mustn't be accesses to the program segment outside the program
 ///     data range.
+///   - Every builtin cell must hold a value the builtin could have produced
+///     (see `BuiltinRunner::verify_cells`).
 ///
 /// Note: Each builtin is responsible for checking its own segments' data.
 pub fn verify_secure_runner(
@@ -53,9 +55,12 @@ pub fn verify_secure_runner(
         });
     for (addr, value) in memory_iter {
         // Check builtin segment bounds.
-        if let Some((_, seg_info)) = builtin_segment_by_index.get(&addr.segment_index) {
+        if let Some((seg_name, seg_info)) = builtin_segment_by_index.get(&addr.segment_index) {
             if addr.offset >= seg_info.size {
-                return Err(RunnerError::FailedMemoryGet(MemoryError::NumOutOfBounds).into());
+                return Err(VirtualMachineError::OutOfBuiltinSegmentAccess(
+                    seg_name.to_string(),
+                    addr,
+                ));
             }
         }
 
@@ -63,7 +68,7 @@ pub fn verify_secure_runner(
         if addr.segment_index == program_base.segment_index
             && addr.offset >= runner.program.data.len()
         {
-            return Err(RunnerError::FailedMemoryGet(MemoryError::NumOutOfBounds).into());
+            return Err(VirtualMachineError::OutOfProgramSegmentAccess(addr));
         }
 
         // Check value validity (when relocatable, that the segment exists and
@@ -85,6 +90,7 @@ pub fn verify_secure_runner(
     swap(&mut tmp, &mut vm.builtin_runners);
     for (_, builtin_runner) in &tmp {
         builtin_runner.run_security_checks(vm)?;
+        builtin_runner.verify_cells(vm)?;
     }
     swap(&mut tmp, &mut vm.builtin_runners);
 
@@ -136,7 +142,9 @@ mod test {
 
         assert_eq!(
             verify_secure_runner(&runner, true, &mut vm),
-            Err(RunnerError::FailedMemoryGet(MemoryError::NumOutOfBounds).into())
+            Err(VirtualMachineError::OutOfProgramSegmentAccess(
+                relocatable!(0, 0)
+            ))
         );
     }
 
@@ -4,19 +4,27 @@ use super::{
 use crate::{
     bigint,
     hint_processor::{
-        hint_processor_definition::HintProcessor, hint_processor_utils::bigint_to_usize,
+        hint_processor_definition::HintProcessor,
+        hint_processor_utils::{
+            bigint_to_usize, compile_program_hints, get_program_reference_list,
+        },
     },
-    serde::deserialize_program::{ApTracking, Attribute},
+    serde::deserialize_program::{ApTracking, Attribute, Identifier},
     types::{
         exec_scope::ExecutionScopes,
+        hint_whitelist::HintWhitelist,
         instruction::{ApUpdate, FpUpdate, Instruction, Opcode, PcUpdate, Res},
+        program::Program,
         relocatable::{MaybeRelocatable, Relocatable},
     },
     vm::{
         context::run_context::RunContext,
         decoding::decoder::decode_instruction,
         errors::{memory_errors::MemoryError, vm_errors::VirtualMachineError},
-        runners::builtin_runner::{BuiltinRunner, RangeCheckBuiltinRunner},
+        runners::{
+            builtin_runner::{BuiltinRunner, OutputBuiltinRunner, RangeCheckBuiltinRunner},
+            gas_meter::GasMeter,
+        },
         trace::trace_entry::TraceEntry,
         vm_memory::{memory::Memory, memory_segments::MemorySegmentManager},
     },
@@ -24,7 +32,7 @@ use crate::{
 use num_bigint::BigInt;
 use num_integer::Integer;
 use num_traits::{ToPrimitive, Zero};
-use std::{any::Any, borrow::Cow, collections::HashMap};
+use std::{any::Any, borrow::Cow, collections::HashMap, rc::Rc};
 
 #[derive(PartialEq, Debug)]
 pub struct Operands {
@@ -74,6 +82,15 @@ pub struct HintData {
     pub ap_tracking_data: ApTracking,
 }
 
+// Hints of a program loaded into memory during the run (such as the tasks run by the
+// bootloader), executed when the pc reaches the segment the program was loaded into
+struct LoadedProgram {
+    base_offset: usize,
+    hint_data_dictionary: HashMap<usize, Vec<Box<dyn Any>>>,
+    constants: HashMap<String, BigInt>,
+    identifiers: Rc<HashMap<String, Identifier>>,
+}
+
 pub struct VirtualMachine {
     pub(crate) run_context: RunContext,
     pub(crate) prime: BigInt,
@@ -85,6 +102,11 @@ pub struct VirtualMachine {
     pub(crate) trace: Option<Vec<TraceEntry>>,
     pub(crate) current_step: usize,
     pub(crate) error_message_attributes: Vec<Attribute>,
+    pub(crate) gas_meter: Option<GasMeter>,
+    pub(crate) hint_whitelist: Option<HintWhitelist>,
+    // Identifiers of the program being run, used by hints to resolve names from their scopes
+    pub(crate) identifiers: Rc<HashMap<String, Identifier>>,
+    loaded_programs: HashMap<isize, Rc<LoadedProgram>>,
     skip_instruction_execution: bool,
 }
 
@@ -131,6 +153,10 @@ impl VirtualMachine {
             accessed_addresses: Some(Vec::new()),
             trace,
             current_step: 0,
+            gas_meter: None,
+            hint_whitelist: None,
+            identifiers: Rc::new(HashMap::new()),
+            loaded_programs: HashMap::new(),
             skip_instruction_execution: false,
             segments: MemorySegmentManager::new(),
             error_message_attributes,
@@ -525,7 +551,26 @@ impl VirtualMachine {
         hint_data_dictionary: &HashMap<usize, Vec<Box<dyn Any>>>,
         constants: &HashMap<String, BigInt>,
     ) -> Result<(), VirtualMachineError> {
-        if let Some(hint_list) = hint_data_dictionary.get(&self.run_context.pc.offset) {
+        let pc = self.run_context.pc.clone();
+        // Programs are only loaded by a few hints, so most runs skip the lookup
+        if !self.loaded_programs.is_empty() {
+            if let Some(loaded_program) = self.loaded_programs.get(&pc.segment_index).cloned() {
+                let hint_list = pc
+                    .offset
+                    .checked_sub(loaded_program.base_offset)
+                    .and_then(|offset| loaded_program.hint_data_dictionary.get(&offset));
+                for hint_data in hint_list.into_iter().flatten() {
+                    hint_executor.execute_hint(
+                        self,
+                        exec_scopes,
+                        hint_data,
+                        &loaded_program.constants,
+                    )?
+                }
+                return Ok(());
+            }
+        }
+        if let Some(hint_list) = hint_data_dictionary.get(&pc.offset) {
             for hint_data in hint_list.iter() {
                 hint_executor.execute_hint(self, exec_scopes, hint_data, constants)?
             }
@@ -561,7 +606,20 @@ impl VirtualMachine {
         constants: &HashMap<String, BigInt>,
     ) -> Result<(), VirtualMachineError> {
         self.step_hint(hint_executor, exec_scopes, hint_data_dictionary, constants)?;
-        self.step_instruction()
+        self.step_instruction()?;
+        self.charge_gas()
+    }
+
+    ///Updates the gas meter, if any, with the resources used after the last step
+    fn charge_gas(&mut self) -> Result<(), VirtualMachineError> {
+        match self.gas_meter.take() {
+            Some(mut gas_meter) => {
+                let result = gas_meter.charge(self);
+                self.gas_meter = Some(gas_meter);
+                result
+            }
+            None => Ok(()),
+        }
     }
 
     fn compute_op0_deductions(
@@ -879,6 +937,59 @@ impl VirtualMachine {
 
         Err(VirtualMachineError::NoSignatureBuiltin)
     }
+
+    pub fn get_output_builtin(&mut self) -> Result<&mut OutputBuiltinRunner, VirtualMachineError> {
+        for (name, builtin) in self.get_builtin_runners_as_mut() {
+            if name == &String::from("output") {
+                if let BuiltinRunner::Output(output_builtin) = builtin {
+                    return Ok(output_builtin);
+                };
+            }
+        }
+
+        Err(VirtualMachineError::NoOutputBuiltin)
+    }
+
+    /// Loads the data of a program into memory at program_base and compiles its hints, which
+    /// will be executed when the pc reaches them.
+    /// Fails if the program contains hints which aren't allowed by the hint whitelist
+    pub fn load_program(
+        &mut self,
+        program: &Program,
+        program_base: &Relocatable,
+        hint_executor: &dyn HintProcessor,
+    ) -> Result<(), VirtualMachineError> {
+        if let Some(hint_whitelist) = &self.hint_whitelist {
+            hint_whitelist.check_program(program)?;
+        }
+        self.segments.load_data(
+            &mut self.memory,
+            &MaybeRelocatable::from(program_base.clone()),
+            program.data.clone(),
+        )?;
+        let references = get_program_reference_list(program);
+        let hint_data_dictionary = compile_program_hints(program, &references, hint_executor)?;
+        self.loaded_programs.insert(
+            program_base.segment_index,
+            Rc::new(LoadedProgram {
+                base_offset: program_base.offset,
+                hint_data_dictionary,
+                constants: program.constants.clone(),
+                identifiers: Rc::new(program.identifiers.clone()),
+            }),
+        );
+        Ok(())
+    }
+
+    /// Returns the identifiers of the program the pc is in, which is either the main program
+    /// or one loaded during the run
+    pub fn get_identifiers(&self) -> Rc<HashMap<String, Identifier>> {
+        match self.loaded_programs.get(&self.run_context.pc.segment_index) {
+            Some(loaded_program) => loaded_program.identifiers.clone(),
+            None => self.identifiers.clone(),
+        }
+    }
+
     pub fn disable_trace(&mut self) {
         self.trace = None
     }
@@ -940,6 +1051,16 @@ impl VirtualMachine {
     pub fn compute_effective_sizes(&mut self) -> &Vec<usize> {
         self.segments.compute_effective_sizes(&self.memory)
     }
+
+    /// Meters the gas consumed by each following step, aborting the run with
+    /// `VirtualMachineError::OutOfGas` once the meter's budget is exceeded.
+    pub fn set_gas_meter(&mut self, gas_meter: GasMeter) {
+        self.gas_meter = Some(gas_meter)
+    }
+
+    pub fn get_gas_meter(&self) -> Option<&GasMeter> {
+        self.gas_meter.as_ref()
+    }
 }
 
 #[cfg(test)]
@@ -951,6 +1072,7 @@ mod tests {
             BuiltinHintProcessor, HintProcessorData,
         },
         relocatable,
+        serde::deserialize_program::{FlowTrackingData, HintParams},
         types::{
             instance_definitions::{
                 bitwise_instance_def::BitwiseInstanceDef, ec_op_instance_def::EcOpInstanceDef,
@@ -961,7 +1083,10 @@ mod tests {
         utils::test_utils::*,
         vm::{
             errors::{memory_errors::MemoryError, runner_errors::RunnerError},
-            runners::builtin_runner::{BitwiseBuiltinRunner, EcOpBuiltinRunner, HashBuiltinRunner},
+            runners::{
+                builtin_runner::{BitwiseBuiltinRunner, EcOpBuiltinRunner, HashBuiltinRunner},
+                gas_meter::GasCosts,
+            },
         },
     };
 
@@ -2754,6 +2879,56 @@ mod tests {
         assert!(accessed_addresses.contains(&Relocatable::from((1, 3))));
     }
 
+    #[test]
+    fn test_step_out_of_gas() {
+        let mut vm = vm!();
+        run_context!(vm, 3, 2, 2);
+        vm.memory =
+            memory![
+            ((0, 0), 5207990763031199744_i64),
+            ((0, 1), 2),
+            ((0, 2), 2345108766317314046_i64),
+            ((0, 3), 5189976364521848832_i64),
+            ((0, 4), 1),
+            ((0, 5), 1226245742482522112_i64),
+            (
+                (0, 6),
+                (b"3618502788666131213697322783095070105623107215331596699973092056135872020476",10)
+            ),
+            ((0, 7), 2345108766317314046_i64),
+            ((1, 0), (2, 0)),
+            ((1, 1), (3, 0))
+        ];
+        let costs = GasCosts {
+            step: 2,
+            builtins: HashMap::new(),
+        };
+        vm.set_gas_meter(GasMeter::new(costs, 7));
+        let hint_processor = BuiltinHintProcessor::new_empty();
+
+        for _ in 0..3 {
+            assert_eq!(
+                vm.step(
+                    &hint_processor,
+                    exec_scopes_ref!(),
+                    &HashMap::new(),
+                    &HashMap::new()
+                ),
+                Ok(())
+            );
+        }
+        assert_eq!(
+            vm.step(
+                &hint_processor,
+                exec_scopes_ref!(),
+                &HashMap::new(),
+                &HashMap::new()
+            ),
+            Err(VirtualMachineError::OutOfGas(7, 8))
+        );
+        assert_eq!(vm.get_gas_meter().map(GasMeter::consumed_gas), Some(8));
+    }
+
     #[test]
     /// Test the following program:
     /// ...
@@ -3822,4 +3997,100 @@ mod tests {
 
         assert_eq!(vm.compute_effective_sizes(), &vec![4]);
     }
+
+    #[test]
+    fn load_program_runs_its_hints() {
+        let mut vm = vm!();
+        for _ in 0..3 {
+            vm.segments.add(&mut vm.memory);
+        }
+        let program = Program {
+            data: vec![mayberelocatable!(1), mayberelocatable!(2)],
+            hints: HashMap::from([(
+                1,
+                vec![HintParams {
+                    code: "memory[ap] = segments.add()".to_string(),
+                    accessible_scopes: vec![],
+                    flow_tracking_data: FlowTrackingData {
+                        ap_tracking: ApTracking::new(),
+                        reference_ids: HashMap::new(),
+                    },
+                }],
+            )]),
+            ..Program::default()
+        };
+        let hint_processor = BuiltinHintProcessor::new_empty();
+        assert_eq!(
+            vm.load_program(&program, &relocatable!(2, 3), &hint_processor),
+            Ok(())
+        );
+        check_memory![vm.memory, ((2, 3), 1), ((2, 4), 2)];
+
+        // The hints of the main program aren't executed on the segment of the loaded program
+        let hint_data_dictionary = HashMap::from([(
+            0_usize,
+            vec![any_box!(HintProcessorData::new_default(
+                "memory[ap] = segments.add()".to_string(),
+                HashMap::new(),
+            ))],
+        )]);
+        vm.run_context.pc = relocatable!(2, 3);
+        assert_eq!(
+            vm.step_hint(
+                &hint_processor,
+                exec_scopes_ref!(),
+                &hint_data_dictionary,
+                &HashMap::new()
+            ),
+            Ok(())
+        );
+        assert_eq!(vm.segments.num_segments, 3);
+
+        vm.run_context.pc = relocatable!(2, 4);
+        assert_eq!(
+            vm.step_hint(
+                &hint_processor,
+                exec_scopes_ref!(),
+                &hint_data_dictionary,
+                &HashMap::new()
+            ),
+            Ok(())
+        );
+        check_memory![vm.memory, ((1, 0), (3, 0))];
+    }
+
+    #[test]
+    fn load_program_rejects_hints_not_whitelisted() {
+        let mut vm = vm!();
+        for _ in 0..3 {
+            vm.segments.add(&mut vm.memory);
+        }
+        vm.hint_whitelist = Some(HintWhitelist::new(vec![
+            "memory[ap] = segments.add()".to_string()
+        ]));
+        let program = Program {
+            data: vec![mayberelocatable!(1)],
+            hints: HashMap::from([(
+                0,
+                vec![HintParams {
+                    code: "memory[ap] = 1".to_string(),
+                    accessible_scopes: vec![],
+                    flow_tracking_data: FlowTrackingData {
+                        ap_tracking: ApTracking::new(),
+                        reference_ids: HashMap::new(),
+                    },
+                }],
+            )]),
+            ..Program::default()
+        };
+        let hint_processor = BuiltinHintProcessor::new_empty();
+        assert_eq!(
+            vm.load_program(&program, &relocatable!(2, 0), &hint_processor),
+            Err(VirtualMachineError::HintsNotWhitelisted(vec![(
+                0,
+                "memory[ap] = 1".to_string()
+            )]))
+        );
+        assert!(vm.loaded_programs.is_empty());
+    }
 }
@@ -19,6 +19,10 @@ pub struct Memory {
     pub(crate) relocation_rules: HashMap<usize, Relocatable>,
     pub validated_addresses: HashSet<MaybeRelocatable>,
     validation_rules: HashMap<usize, ValidationRule>,
+    // Number of cells across data and temp_data, including the gaps left between written cells
+    allocated_cells: usize,
+    // Maximum value of allocated_cells, writes that would exceed it fail before allocating
+    max_cells: Option<usize>,
 }
 
 impl Memory {
@@ -29,6 +33,8 @@ impl Memory {
             relocation_rules: HashMap::new(),
             validated_addresses: HashSet::<MaybeRelocatable>::new(),
             validation_rules: HashMap::new(),
+            allocated_cells: 0,
+            max_cells: None,
         }
     }
     ///Inserts an MaybeRelocatable value into an address given by a MaybeRelocatable::Relocatable
@@ -60,6 +66,14 @@ impl Memory {
         //Check if the element is inserted next to the last one on the segment
         //Forgoing this check would allow data to be inserted in a different index
         if segment.len() <= value_offset {
+            let allocated_cells = (value_offset - segment.len())
+                .checked_add(1)
+                .and_then(|new_cells| self.allocated_cells.checked_add(new_cells))
+                .filter(|cells| self.max_cells.map_or(true, |max_cells| *cells <= max_cells))
+                .ok_or(MemoryError::MemoryCellsExhausted(
+                    self.max_cells.unwrap_or(usize::MAX),
+                ))?;
+            self.allocated_cells = allocated_cells;
             segment.resize(value_offset + 1, None);
         }
         // At this point there's *something* in there
@@ -203,9 +217,21 @@ impl Memory {
         }
 
         self.relocation_rules.clear();
+        self.allocated_cells = self.data.iter().map(Vec::len).sum();
         Ok(())
     }
 
+    ///Returns the number of cells allocated across all segments, including temporary ones.
+    ///Gaps between written cells count as allocated.
+    pub fn allocated_cells(&self) -> usize {
+        self.allocated_cells
+    }
+
+    ///Limits the number of cells that can be allocated, None removes the limit
+    pub fn set_max_cells(&mut self, max_cells: Option<usize>) {
+        self.max_cells = max_cells;
+    }
+
     /// Add a new relocation rule.
     ///
     /// Will return an error if any of the following conditions are not met:
@@ -360,7 +386,7 @@ mod memory_tests {
     use num_bigint::BigInt;
 
     use crate::{
-        bigint, bigint_str,
+        bigint, bigint_str, relocatable,
         types::{
             instance_definitions::ecdsa_instance_def::EcdsaInstanceDef,
             relocatable::MaybeRelocatable,
@@ -431,6 +457,38 @@ mod memory_tests {
         );
     }
 
+    #[test]
+    fn allocated_cells_count_gaps() {
+        let mut memory = memory![((0, 0), 1), ((0, 3), 2), ((1, 1), 3)];
+        assert_eq!(memory.allocated_cells(), 6);
+        memory
+            .insert_value(&relocatable!(0, 2), bigint!(4))
+            .unwrap();
+        assert_eq!(memory.allocated_cells(), 6);
+        memory.temp_data.push(Vec::new());
+        memory
+            .insert_value(&relocatable!(-1, 1), bigint!(5))
+            .unwrap();
+        assert_eq!(memory.allocated_cells(), 8);
+    }
+
+    #[test]
+    fn insert_beyond_max_cells() {
+        let mut memory = memory![((0, 0), 1)];
+        memory.set_max_cells(Some(3));
+        assert_eq!(
+            memory.insert_value(&relocatable!(0, 1 << 40), bigint!(2)),
+            Err(VirtualMachineError::MemoryError(
+                MemoryError::MemoryCellsExhausted(3)
+            ))
+        );
+        assert_eq!(memory.data[0].len(), 1);
+        memory
+            .insert_value(&relocatable!(0, 2), bigint!(2))
+            .unwrap();
+        assert_eq!(memory.allocated_cells(), 3);
+    }
+
     #[test]
     fn insert_and_get_from_temp_segment_succesful() {
         let key = MaybeRelocatable::from((-1, 0));
@@ -1019,5 +1077,6 @@ mod memory_tests {
             ],
         );
         assert!(memory.temp_data.is_empty());
+        assert_eq!(memory.allocated_cells(), 6);
     }
 }
@@ -1,6 +1,19 @@
 use cairo_rs::cairo_run;
-use cairo_rs::hint_processor::builtin_hint_processor::builtin_hint_processor_definition::BuiltinHintProcessor;
+use cairo_rs::hint_processor::builtin_hint_processor::builtin_hint_processor_definition::{
+    BuiltinHintProcessor, HintFunc,
+};
+use cairo_rs::hint_processor::builtin_hint_processor::hint_utils::get_ptr_from_var_name;
+use cairo_rs::hint_processor::hint_processor_definition::HintReference;
+use cairo_rs::serde::deserialize_program::ApTracking;
+use cairo_rs::types::exec_scope::ExecutionScopes;
+use cairo_rs::vm::errors::vm_errors::VirtualMachineError;
+use cairo_rs::vm::runners::builtin_runner::FactTopology;
+use cairo_rs::vm::runners::run_resources::RunResources;
+use cairo_rs::vm::vm_core::VirtualMachine;
+use num_bigint::BigInt;
+use std::collections::HashMap;
 use std::path::Path;
+use std::rc::Rc;
 
 #[test]
 fn cairo_run_test() {
@@ -12,6 +25,8 @@ fn cairo_run_test() {
         false,
         "small",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -27,6 +42,8 @@ fn cairo_run_array_sum() {
         false,
         "small",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -42,6 +59,8 @@ fn cairo_run_big_struct() {
         false,
         "small",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -57,6 +76,8 @@ fn cairo_run_call_function_assign_param_by_name() {
         false,
         "small",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -72,6 +93,8 @@ fn cairo_run_function_return() {
         false,
         "small",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -87,6 +110,8 @@ fn cairo_run_function_return_if_print() {
         false,
         "small",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -102,6 +127,8 @@ fn cairo_run_function_return_to_variable() {
         false,
         "small",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -117,6 +144,8 @@ fn cairo_run_if_and_prime() {
         false,
         "small",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -132,6 +161,8 @@ fn cairo_run_if_in_function() {
         false,
         "small",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -147,6 +178,8 @@ fn cairo_run_if_list() {
         false,
         "small",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -162,6 +195,8 @@ fn cairo_run_jmp() {
         false,
         "small",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -177,6 +212,8 @@ fn cairo_run_jmp_if_condition() {
         false,
         "small",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -192,6 +229,8 @@ fn cairo_run_pointers() {
         false,
         "small",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -207,6 +246,8 @@ fn cairo_run_print() {
         false,
         "small",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -222,6 +263,8 @@ fn cairo_run_return() {
         false,
         "small",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -237,6 +280,8 @@ fn cairo_run_reversed_register_instructions() {
         false,
         "small",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -252,6 +297,8 @@ fn cairo_run_simple_print() {
         false,
         "small",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -267,6 +314,8 @@ fn cairo_run_test_addition_if() {
         false,
         "small",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -282,6 +331,8 @@ fn cairo_run_test_reverse_if() {
         false,
         "small",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -297,6 +348,8 @@ fn cairo_run_test_subtraction_if() {
         false,
         "small",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -312,6 +365,8 @@ fn cairo_run_use_imported_module() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -327,6 +382,8 @@ fn cairo_run_bitwise_output() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -342,6 +399,8 @@ fn cairo_run_bitwise_recursion() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -357,6 +416,8 @@ fn cairo_run_integration() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -372,6 +433,8 @@ fn cairo_run_integration_with_alloc_locals() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -387,6 +450,8 @@ fn cairo_run_compare_arrays() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -402,6 +467,8 @@ fn cairo_run_compare_greater_array() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -417,6 +484,8 @@ fn cairo_run_compare_lesser_array() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -432,6 +501,8 @@ fn cairo_run_assert_le_felt_hint() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -447,6 +518,8 @@ fn cairo_run_assert_250_bit_element_array() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -462,6 +535,8 @@ fn cairo_abs_value() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -477,6 +552,8 @@ fn cairo_run_compare_different_arrays() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -492,6 +569,8 @@ fn cairo_run_assert_nn() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -507,6 +586,8 @@ fn cairo_run_sqrt() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -522,6 +603,8 @@ fn cairo_run_assert_not_zero() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -537,6 +620,8 @@ fn cairo_run_split_int() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -552,6 +637,8 @@ fn cairo_run_split_int_big() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -567,6 +654,8 @@ fn cairo_run_split_felt() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -582,6 +671,8 @@ fn cairo_run_math_cmp() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -597,6 +688,8 @@ fn cairo_run_unsigned_div_rem() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -612,6 +705,8 @@ fn cairo_run_signed_div_rem() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -627,6 +722,8 @@ fn cairo_run_assert_lt_felt() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -642,6 +739,8 @@ fn cairo_run_memcpy() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -657,6 +756,8 @@ fn cairo_run_memset() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -672,6 +773,8 @@ fn cairo_run_pow() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -687,6 +790,8 @@ fn cairo_run_dict() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -702,6 +807,8 @@ fn cairo_run_dict_update() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -717,6 +824,42 @@ fn cairo_run_uint256() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
+        &hint_executor,
+    )
+    .expect("Couldn't run program");
+}
+
+#[test]
+fn cairo_run_uint256_improvements() {
+    let hint_executor = BuiltinHintProcessor::new_empty();
+    cairo_run::cairo_run(
+        Path::new("cairo_programs/uint256_improvements.json"),
+        "main",
+        false,
+        false,
+        "all",
+        false,
+        false,
+        &RunResources::default(),
+        &hint_executor,
+    )
+    .expect("Couldn't run program");
+}
+
+#[test]
+fn cairo_run_uint384() {
+    let hint_executor = BuiltinHintProcessor::new_empty();
+    cairo_run::cairo_run(
+        Path::new("cairo_programs/uint384.json"),
+        "main",
+        false,
+        false,
+        "all",
+        false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -732,6 +875,8 @@ fn cairo_run_find_element() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -747,6 +892,8 @@ fn cairo_run_search_sorted_lower() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -762,6 +909,8 @@ fn cairo_run_usort() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -777,6 +926,8 @@ fn cairo_run_usort_bad() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     );
     assert!(err.is_err());
@@ -796,6 +947,8 @@ fn cairo_run_dict_write_bad() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .is_err());
@@ -807,6 +960,8 @@ fn cairo_run_dict_write_bad() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .err();
@@ -826,6 +981,8 @@ fn cairo_run_dict_update_bad() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .is_err());
@@ -836,6 +993,8 @@ fn cairo_run_dict_update_bad() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .err();
@@ -855,6 +1014,8 @@ fn cairo_run_squash_dict() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -870,6 +1031,8 @@ fn cairo_run_dict_squash() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -885,6 +1048,8 @@ fn cairo_run_set_add() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -900,6 +1065,8 @@ fn cairo_run_secp() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -915,6 +1082,8 @@ fn cairo_run_signature() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -930,6 +1099,42 @@ fn cairo_run_secp_ec() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
+        &hint_executor,
+    )
+    .expect("Couldn't run program");
+}
+
+#[test]
+fn cairo_run_secp256r1_ec() {
+    let hint_executor = BuiltinHintProcessor::new_empty();
+    cairo_run::cairo_run(
+        Path::new("cairo_programs/secp256r1_ec.json"),
+        "main",
+        false,
+        false,
+        "all",
+        false,
+        false,
+        &RunResources::default(),
+        &hint_executor,
+    )
+    .expect("Couldn't run program");
+}
+
+#[test]
+fn cairo_run_ec_recover() {
+    let hint_executor = BuiltinHintProcessor::new_empty();
+    cairo_run::cairo_run(
+        Path::new("cairo_programs/ec_recover.json"),
+        "main",
+        false,
+        false,
+        "all",
+        false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -945,6 +1150,8 @@ fn cairo_run_blake2s_hello_world_hash() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -960,6 +1167,8 @@ fn cairo_run_finalize_blake2s() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -974,6 +1183,8 @@ fn cairo_run_unsafe_keccak() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -989,6 +1200,8 @@ fn cairo_run_blake2s_felts() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -1004,6 +1217,8 @@ fn cairo_run_unsafe_keccak_finalize() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -1019,6 +1234,8 @@ fn cairo_run_keccak_add_uint256() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -1034,6 +1251,8 @@ fn cairo_run_private_keccak() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -1049,6 +1268,8 @@ fn cairo_run_keccak_copy_inputs() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -1064,6 +1285,8 @@ fn cairo_run_finalize_keccak() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -1079,6 +1302,8 @@ fn cairo_run_operations_with_data() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -1094,6 +1319,8 @@ fn cairo_run_sha256() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -1109,6 +1336,8 @@ fn cairo_run_math_cmp_and_pow_integration() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -1124,6 +1353,8 @@ fn cairo_run_uint256_integration() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -1139,6 +1370,8 @@ fn cairo_run_set_integration() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -1154,6 +1387,8 @@ fn cairo_run_memory_module_integration() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -1169,6 +1404,8 @@ fn cairo_run_dict_integration() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -1184,6 +1421,8 @@ fn cairo_run_secp_integration() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -1199,6 +1438,42 @@ fn cairo_run_keccak_integration() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
+        &hint_executor,
+    )
+    .expect("Couldn't run program");
+}
+
+#[test]
+fn cairo_run_keccak_split_hints() {
+    let hint_executor = BuiltinHintProcessor::new_empty();
+    cairo_run::cairo_run(
+        Path::new("cairo_programs/keccak_split_hints.json"),
+        "main",
+        false,
+        false,
+        "recursive",
+        false,
+        false,
+        &RunResources::default(),
+        &hint_executor,
+    )
+    .expect("Couldn't run program");
+}
+
+#[test]
+fn cairo_run_print_hints() {
+    let hint_executor = BuiltinHintProcessor::new_empty();
+    cairo_run::cairo_run(
+        Path::new("cairo_programs/print_hints.json"),
+        "main",
+        false,
+        false,
+        "all",
+        false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -1214,6 +1489,8 @@ fn cairo_run_blake2s_integration() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -1229,6 +1506,8 @@ fn cairo_run_relocate_segments() {
         false,
         "small",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .expect("Couldn't run program");
@@ -1243,6 +1522,8 @@ fn cairo_run_error_msg_attr() {
         false,
         "all",
         false,
+        false,
+        &RunResources::default(),
         &hint_executor,
     )
     .err()
@@ -1250,3 +1531,98 @@ fn cairo_run_error_msg_attr() {
 
     assert!(err.to_string().contains("SafeUint256: addition overflow"));
 }
+
+#[test]
+fn cairo_run_output_pages() {
+    let hint_executor = BuiltinHintProcessor::new_empty();
+    let (cairo_runner, vm) = cairo_run::cairo_run_with_vm(
+        Path::new("cairo_programs/output_pages.json"),
+        "main",
+        false,
+        false,
+        "small",
+        false,
+        false,
+        &RunResources::default(),
+        &hint_executor,
+    )
+    .expect("Couldn't run program");
+
+    assert_eq!(
+        cairo_runner.get_fact_topology(&vm),
+        Ok(Some(FactTopology {
+            tree_structure: vec![2, 1, 0, 2],
+            page_sizes: vec![2, 3],
+        }))
+    );
+}
+
+#[test]
+fn cairo_run_output_pages_without_fact_topology_proof_mode() {
+    // Replaces the hint of output_pages.cairo by one that only adds the onchain data page, as
+    // the simple bootloader does
+    let mut hint_executor = BuiltinHintProcessor::new_empty();
+    hint_executor.add_hint(
+        String::from(ADD_ONCHAIN_DATA_PAGES),
+        Rc::new(HintFunc(Box::new(add_onchain_data_page))),
+    );
+    let (cairo_runner, vm) = cairo_run::cairo_run_with_vm(
+        Path::new("cairo_programs/proof_programs/output_pages.json"),
+        "main",
+        false,
+        false,
+        "all",
+        true,
+        false,
+        &RunResources::default(),
+        &hint_executor,
+    )
+    .expect("Couldn't run program");
+
+    assert_eq!(cairo_runner.fact_topology, None);
+    assert!(cairo_runner.get_fact_topology(&vm).is_err());
+}
+
+fn add_onchain_data_page(
+    vm: &mut VirtualMachine,
+    _exec_scopes: &mut ExecutionScopes,
+    ids_data: &HashMap<String, HintReference>,
+    ap_tracking: &ApTracking,
+    _constants: &HashMap<String, BigInt>,
+) -> Result<(), VirtualMachineError> {
+    let da_start = get_ptr_from_var_name("da_start", vm, ids_data, ap_tracking)?;
+    let output_ptr = get_ptr_from_var_name("output_ptr", vm, ids_data, ap_tracking)?;
+    let page_size = output_ptr.sub_rel(&da_start)?;
+    vm.get_output_builtin()?.add_page(1, &da_start, page_size)?;
+    Ok(())
+}
+
+const ADD_ONCHAIN_DATA_PAGES: &str = r#"from starkware.python.math_utils import div_ceil
+onchain_data_start = ids.da_start
+onchain_data_size = ids.output_ptr - onchain_data_start
+
+max_page_size = 3800
+n_pages = div_ceil(onchain_data_size, max_page_size)
+for i in range(n_pages):
+    start_offset = i * max_page_size
+    output_builtin.add_page(
+        page_id=1 + i,
+        page_start=onchain_data_start + start_offset,
+        page_size=min(onchain_data_size - start_offset, max_page_size),
+    )
+# Set the tree structure to a root with two children:
+# * A leaf which represents the main part
+# * An inner node for the onchain data part (which contains n_pages children).
+#
+# This is encoded using the following sequence:
+output_builtin.add_attribute('gps_fact_topology', [
+    # Push 1 + n_pages pages (all of the pages).
+    1 + n_pages,
+    # Create a parent node for the last n_pages.
+    n_pages,
+    # Don't push additional pages.
+    0,
+    # Take the first page (the main part) and the node that was created (onchain data)
+    # and use them to construct the root of the fact tree.
+    2,
+])"#;